@@ -0,0 +1,92 @@
+//! Counts the allocations of parsing a json document to an AST. The AST is either built from the
+//! resugared parse result, or directly from the result of the parser.
+//!
+//! Run with `cargo bench -p json --bench allocations`.
+use lwb_parser::language;
+use lwb_parser::language::Language;
+use lwb_parser::parser::ast::generate_ast::generate_ast;
+use lwb_parser::parser::peg::parse_options::ParseOptions;
+use lwb_parser::sources::source_file::SourceFile;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[rustfmt::skip]
+#[path = "../src/ast/mod.rs"]
+mod ast;
+
+language!(JSON at mod ast);
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, which counts the allocations and the bytes that are allocated.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, and returns its result with the number of allocations and allocated bytes.
+fn count<T>(f: impl FnOnce() -> T) -> (T, usize, usize) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let res = f();
+    (
+        res,
+        ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        BYTES.load(Ordering::Relaxed) - bytes,
+    )
+}
+
+/// A json document with `items` objects, which contain strings, numbers and nested arrays.
+fn json(items: usize) -> String {
+    let items: Vec<String> = (0..items)
+        .map(|i| {
+            format!(
+                "  {{\"id\": {i}, \"name\": \"item \\\"{i}\\\"\", \"price\": -{i}.25e3, \"tags\": [true, false, null, [\"\\u00e9\"]]}}"
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", items.join(",\n"))
+}
+
+fn main() {
+    let options = ParseOptions::new();
+    let parser = JSON::compiled_parser().unwrap();
+
+    println!("{:>8} {:>24} {:>24}", "items", "resugared", "raw");
+    for items in [1, 100, 1000] {
+        let file = SourceFile::new(json(items), "input.json");
+
+        let (sugared, sugared_allocations, sugared_bytes) = count(|| {
+            let outcome = parser.parse_file_outcome(&file, &options).unwrap();
+            generate_ast::<<JSON as Language>::Ast>(&outcome.tree)
+        });
+        let (raw, raw_allocations, raw_bytes) =
+            count(|| JSON::parse_outcome(&file, &options).unwrap().tree.unwrap());
+        assert_eq!(sugared, raw);
+
+        println!(
+            "{items:>8} {:>24} {:>24}",
+            format!("{sugared_allocations} ({sugared_bytes} B)"),
+            format!("{raw_allocations} ({raw_bytes} B)"),
+        );
+    }
+}
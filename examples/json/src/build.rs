@@ -1,6 +1,8 @@
 use lwb_parser::codegen::manager::Codegen;
 
 fn main() {
+    println!("cargo:rerun-if-env-changed=LWB_DIAGNOSTICS");
+
     let m = Codegen::new();
     m.codegen();
 }
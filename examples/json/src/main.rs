@@ -9,9 +9,14 @@ fn main() {}
 
 #[cfg(test)]
 mod tests {
+    use super::ast::{Number, Value};
     use super::JSON;
     use lwb_parser::language::Language;
+    use lwb_parser::parser::ast::generate_ast::{generate_ast, BasicAstInfo};
+    use lwb_parser::parser::peg::parse_options::ParseOptions;
+    use lwb_parser::parser::syntax_file::parse_language_outcome;
     use lwb_parser::sources::source_file::SourceFile;
+    use lwb_parser::sources::stream::ChunkedInput;
 
     macro_rules! json_test {
         (err: $src: literal) => {
@@ -34,4 +39,134 @@ mod tests {
         json_test!(r#"{"test": true}"#);
         json_test!("{}");
     }
+
+    #[test]
+    fn parse_json_lines() {
+        let input = "{\"a\": [1, 2]}\n  true\n12345 \"test\"\n";
+
+        // small chunks make items cross chunk boundaries
+        for chunk_size in [1, 3, 1024] {
+            let input =
+                ChunkedInput::new(input.as_bytes(), "test.jsonl").with_chunk_size(chunk_size);
+            let values = JSON::parse_many(input)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            assert_eq!(values.len(), 4);
+        }
+
+        let input = ChunkedInput::new("1\n[1,\n2".as_bytes(), "test.jsonl").with_chunk_size(2);
+        let values = JSON::parse_many(input).collect::<Vec<_>>();
+        assert_eq!(values.len(), 2);
+        assert!(values[0].is_ok());
+        assert!(values[1].is_err());
+    }
+
+    #[test]
+    fn parse_json_prefix() {
+        let sf = SourceFile::new("Hello {{ [1, 2] }}, {{ 3 }}!", "template");
+
+        let (value, end) = JSON::parse_prefix::<Value<BasicAstInfo>>("value", &sf, 8).unwrap();
+        assert!(matches!(value, Value::Array(..)));
+        assert_eq!(end, 15);
+
+        let (number, end) = JSON::parse_prefix::<Number<BasicAstInfo>>("number", &sf, 22).unwrap();
+        assert_eq!(number.1, "3");
+        assert_eq!(end, 24);
+
+        assert!(JSON::parse_prefix::<Value<BasicAstInfo>>("value", &sf, 0).is_err());
+        assert!(JSON::parse_prefix::<Value<BasicAstInfo>>("template", &sf, 8).is_err());
+    }
+
+    #[test]
+    fn parse_json_outcome() {
+        let sf = SourceFile::new("[1, 2, 3]", "test.json");
+        let outcome = JSON::parse_outcome(&sf, &ParseOptions::new().with_stats(true)).unwrap();
+        assert!(outcome.tree.is_some());
+        assert!(outcome.errors.is_empty());
+        assert!(outcome.stats.unwrap().sort("number").invocations >= 3);
+
+        let sf = SourceFile::new("[1, 2,, 3,, 4]", "test.json");
+        let outcome = JSON::parse_outcome(&sf, &ParseOptions::new()).unwrap();
+        assert!(outcome.tree.is_none());
+        assert!(!outcome.errors.is_empty());
+
+        let outcome = JSON::parse_outcome(&sf, &ParseOptions::new().with_recovery(false)).unwrap();
+        assert_eq!(outcome.errors.len(), 1);
+    }
+
+    #[test]
+    fn parse_json_native() {
+        // the generated parser gives the same outcome as interpreting the grammar
+        let inputs = [
+            "null",
+            r#"{"a": [1, -2.5e3, "\u00e9"], "b": {"c": true}}"#,
+            "[1, 2,, 3,, 4]",
+            r#"{"a" 1}"#,
+            "[[[[]]]",
+            "",
+        ];
+        for input in inputs {
+            let sf = SourceFile::new(input, "test.json");
+            for options in [
+                ParseOptions::new(),
+                ParseOptions::new().with_recovery(false),
+            ] {
+                let native = JSON::parse_outcome(&sf, &options).unwrap();
+                let interpreted = parse_language_outcome::<<JSON as Language>::Ast>(
+                    &sf,
+                    super::ast::PARSER,
+                    &options,
+                )
+                .unwrap();
+                assert_eq!(native.tree, interpreted.tree, "{input}");
+                assert_eq!(
+                    format!("{:?}", native.errors),
+                    format!("{:?}", interpreted.errors),
+                    "{input}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_json_compiled() {
+        // the parser is compiled once, and shared by all threads
+        let parser = JSON::compiled_parser().unwrap();
+        assert!(std::ptr::eq(parser, JSON::compiled_parser().unwrap()));
+
+        std::thread::scope(|s| {
+            for i in 0..4 {
+                s.spawn(move || {
+                    let sf = SourceFile::new(format!("[{i}, {{\"a\": {i}}}]"), "test.json");
+                    let outcome = JSON::parse_outcome(&sf, &ParseOptions::new()).unwrap();
+                    assert!(outcome.tree.is_some());
+
+                    // parses with other features desugar the grammar again
+                    let options = ParseOptions::new().with_feature("comments");
+                    let outcome = parser.parse_file_outcome(&sf, &options).unwrap();
+                    assert!(outcome.errors.is_empty());
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn parse_json_raw() {
+        // the AST built from the result of the parser is the one built from the resugared result
+        let parser = JSON::compiled_parser().unwrap();
+        let inputs = [
+            "null",
+            "[]",
+            r#"{"a": [1, -2.5e3, "\u00e9"], "b": {"c": true}}"#,
+            "[[[], [1]], {}]",
+        ];
+        for input in inputs {
+            let sf = SourceFile::new(input, "test.json");
+            let outcome = parser
+                .parse_file_outcome(&sf, &ParseOptions::new())
+                .unwrap();
+            let sugared: Value<BasicAstInfo> = generate_ast(&outcome.tree);
+            assert_eq!(JSON::parse(&sf), sugared, "{input}");
+        }
+    }
 }
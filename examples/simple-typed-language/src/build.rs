@@ -1,6 +1,8 @@
 use rust_lwb::codegen::manager::Codegen;
 
 fn main() {
+    println!("cargo:rerun-if-env-changed=LWB_DIAGNOSTICS");
+
     let m = Codegen::new();
     m.codegen();
 }
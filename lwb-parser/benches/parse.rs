@@ -0,0 +1,95 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use lwb_parser::language::Language;
+use lwb_parser::parser::peg::parse_options::ParseOptions;
+use lwb_parser::parser::peg::parser_compiled::CompiledParser;
+use lwb_parser::parser::peg::parser_sugar::parse_file_outcome;
+use lwb_parser::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use lwb_parser::parser::syntax_file::convert_syntax_file_ast::convert;
+use lwb_parser::parser::syntax_file::SyntaxFile;
+use lwb_parser::sources::source_file::SourceFile;
+
+const JSON: &str = include_str!("../../examples/json/src/json.syntax");
+const SYNTAX_FILE: &str = include_str!("../../rust-lwb-bootstrap/syntax-file.syntax");
+
+fn grammar(syntax: &str) -> SyntaxFileAst {
+    let syntax = SourceFile::new(syntax, "grammar.syntax");
+    convert(SyntaxFile::parse(&syntax)).unwrap()
+}
+
+/// A json document with `items` objects, which contain strings, numbers and nested arrays.
+fn json(items: usize) -> String {
+    let items: Vec<String> = (0..items)
+        .map(|i| {
+            format!(
+                "  {{\"id\": {i}, \"name\": \"item \\\"{i}\\\"\", \"price\": -{i}.25e3, \"tags\": [true, false, null, [\"\\u00e9\"]]}}"
+            )
+        })
+        .collect();
+    format!("[\n{}\n]\n", items.join(",\n"))
+}
+
+fn bench(c: &mut Criterion, name: &str, ast: &SyntaxFileAst, inputs: &[(usize, String)]) {
+    let options = ParseOptions::new();
+
+    let mut group = c.benchmark_group(name);
+    group.sample_size(10);
+    for (size, input) in inputs {
+        let file = SourceFile::new(input, "input");
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &file, |b, file| {
+            b.iter(|| {
+                let outcome = parse_file_outcome(ast, file, &options).unwrap();
+                assert!(outcome.errors.is_empty());
+            })
+        });
+    }
+    group.finish();
+}
+
+/// Many small files, for which loading the grammar takes longer than parsing them.
+fn bench_small_files(c: &mut Criterion, ast: &SyntaxFileAst) {
+    let options = ParseOptions::new();
+    let files: Vec<SourceFile> = (0..100)
+        .map(|i| SourceFile::new(format!("{{\"id\": {i}, \"tags\": [true, null]}}"), "input"))
+        .collect();
+
+    let mut group = c.benchmark_group("json-small-files");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(files.len() as u64));
+    group.bench_function("desugared", |b| {
+        b.iter(|| {
+            for file in &files {
+                assert!(parse_file_outcome(ast, file, &options)
+                    .unwrap()
+                    .errors
+                    .is_empty());
+            }
+        })
+    });
+    group.bench_function("compiled", |b| {
+        let parser = CompiledParser::new(ast);
+        b.iter(|| {
+            for file in &files {
+                assert!(parser
+                    .parse_file_outcome(file, &options)
+                    .unwrap()
+                    .errors
+                    .is_empty());
+            }
+        })
+    });
+    group.finish();
+}
+
+fn parse(c: &mut Criterion) {
+    let inputs = [100, 1000].map(|items| (items, json(items)));
+    bench(c, "json", &grammar(JSON), &inputs);
+    bench_small_files(c, &grammar(JSON));
+
+    //The grammar of syntax files, parsing itself
+    let inputs = [(SYNTAX_FILE.lines().count(), SYNTAX_FILE.to_string())];
+    bench(c, "syntax-file", &grammar(SYNTAX_FILE), &inputs);
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
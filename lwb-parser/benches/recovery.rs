@@ -0,0 +1,71 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use lwb_parser::language::Language;
+use lwb_parser::parser::peg::parse_options::ParseOptions;
+use lwb_parser::parser::peg::parser_sugar::parse_file_outcome;
+use lwb_parser::parser::syntax_file::convert_syntax_file_ast::convert;
+use lwb_parser::parser::syntax_file::SyntaxFile;
+use lwb_parser::sources::source_file::SourceFile;
+
+const STATEMENTS: &str = r#"
+program = statement*;
+statement:
+    let = "let" name "=" expr ";";
+    print = "print" expr ";";
+expr:
+    add = expr "+" term;
+    term = term;
+term:
+    mul = term "*" atom;
+    atom = atom;
+atom:
+    number = [0-9]+;
+    name = name;
+    paren = "(" expr ")";
+name = [a-z]+; {no-layout}
+layout = [ \n];
+start at program;
+"#;
+
+/// A program of `statements` statements, of which `errors` are broken, spread evenly over the input.
+fn program(statements: usize, errors: usize) -> String {
+    let mut program = String::new();
+    for i in 0..statements {
+        if errors > 0 && i % (statements / errors) == statements / errors / 2 {
+            //The parser has to skip the `$$ 3` to recover
+            program.push_str(&format!("let x{i} = (a + $$ 3) * 2;\n"));
+        } else {
+            program.push_str(&format!("let x = (a + {i}) * 2;\nprint x + y;\n"));
+        }
+    }
+    program
+}
+
+fn recovery(c: &mut Criterion) {
+    let syntax = SourceFile::new(STATEMENTS, "statements.syntax");
+    let ast = convert(SyntaxFile::parse(&syntax)).unwrap();
+    let options = ParseOptions::new().with_max_errors(usize::MAX);
+
+    let mut group = c.benchmark_group("recovery");
+    group.sample_size(10);
+    for statements in [250, 500, 1000, 2000] {
+        for errors in [0, 4, statements / 50] {
+            let file = SourceFile::new(program(statements, errors), "input.language");
+            group.throughput(Throughput::Bytes(file.contents().len() as u64));
+            group.bench_with_input(
+                BenchmarkId::new(format!("{errors} errors"), statements),
+                &file,
+                |b, file| {
+                    b.iter(|| {
+                        //A broken statement can cause more than one error, depending on how it is recovered from
+                        let outcome = parse_file_outcome(&ast, file, &options).unwrap();
+                        assert_eq!(outcome.errors.is_empty(), errors == 0);
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, recovery);
+criterion_main!(benches);
@@ -66,9 +66,9 @@ impl<'ast, 'arena> BreadthFirstAstIterator<'ast, 'arena> {
             Expression::Sequence(s) | Expression::Choice(s) => {
                 s.iter().for_each(|i| self.find_referenced_sorts(i, ckr))
             }
-            Expression::Repeat { e, .. } | Expression::Delimited { e, .. } => {
-                self.find_referenced_sorts(e, ckr)
-            }
+            Expression::Repeat { e, .. }
+            | Expression::Counted { e, .. }
+            | Expression::Delimited { e, .. } => self.find_referenced_sorts(e, ckr),
             Expression::Negative(_) => todo!(),
             Expression::Positive(_) => todo!(),
             _ => {}
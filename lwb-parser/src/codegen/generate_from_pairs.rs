@@ -2,12 +2,83 @@ use crate::codegen::check_recursive::{BreadthFirstAstIterator, RecursionChecker}
 use crate::codegen::error::CodegenError;
 use crate::codegen::sanitize_identifier;
 use crate::parser::peg::parser_sugar_ast::Annotation::SingleString;
-use crate::parser::peg::parser_sugar_ast::{Annotation, Expression, Sort, SyntaxFileAst};
+use crate::parser::peg::parser_sugar_ast::{
+    Annotation, Constructor, Expression, Sort, SyntaxFileAst,
+};
+use convert_case::{Case, Casing};
 use itertools::Itertools;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote};
 use std::collections::HashMap;
 
+/// The parse result that the AST is built from.
+#[derive(Clone, Copy)]
+enum Pairs<'a> {
+    /// The resugared [`ParsePairSort`](crate::parser::peg::parse_pair::ParsePairSort), by `FromPairs`.
+    Sugared,
+    /// The [`ParsePairRaw`](crate::parser::peg::parser_core_ast::ParsePairRaw) of the parser, by `FromRawPairs`.
+    /// The constructors are numbered as they were before sorts were merged into the sort they are part of,
+    /// so the expressions are the ones from before the merges. Holds the merges, from old to new sort names.
+    Raw(&'a HashMap<String, String>),
+}
+
+impl Pairs<'_> {
+    fn sort_pattern(self) -> TokenStream {
+        match self {
+            Pairs::Sugared => quote!(ParsePairExpression::Sort(_, ref s)),
+            Pairs::Raw(_) => quote!(ParsePairRaw::Name(_, ref s)),
+        }
+    }
+
+    fn list_pattern(self) -> TokenStream {
+        match self {
+            Pairs::Sugared => quote!(ParsePairExpression::List(_, ref l)),
+            Pairs::Raw(_) => quote!(ParsePairRaw::List(_, ref l)),
+        }
+    }
+
+    fn empty_pattern(self) -> TokenStream {
+        match self {
+            Pairs::Sugared => quote!(ParsePairExpression::Empty(ref span)),
+            Pairs::Raw(_) => quote!(ParsePairRaw::Empty(ref span)),
+        }
+    }
+
+    /// The text of the input at `span`
+    fn text(self, span: TokenStream) -> TokenStream {
+        match self {
+            Pairs::Sugared => quote!(#span.as_str().to_string()),
+            Pairs::Raw(_) => quote!(#span.as_str(generator.file()).to_string()),
+        }
+    }
+
+    /// The name of the generated type of a sort
+    fn type_name(self, sort: &str) -> String {
+        match self {
+            Pairs::Sugared => sort.to_string(),
+            Pairs::Raw(merges) => SyntaxFileAst::get_new_name(sort, merges),
+        }
+    }
+
+    /// Builds the AST of a sort from `s`
+    fn build(self, sort: &str) -> TokenStream {
+        let iname = format_ident!("{}", sanitize_identifier(&self.type_name(sort)));
+        match self {
+            Pairs::Sugared => quote!(#iname::from_pairs(s, generator)),
+            Pairs::Raw(merges) if merges.contains_key(sort) => {
+                let function = raw_function_name(sort);
+                quote!(#iname::#function(s, generator))
+            }
+            Pairs::Raw(_) => quote!(#iname::from_raw_pairs(s, generator)),
+        }
+    }
+}
+
+/// The function that builds the AST of a sort that was merged into another sort, from a raw parse result
+fn raw_function_name(sort: &str) -> Ident {
+    format_ident!("from_raw_pairs_{}", sort.to_case(Case::Snake))
+}
+
 fn generate_unpack_expression(
     expression: &Expression,
     sort: &str,
@@ -15,53 +86,76 @@ fn generate_unpack_expression(
     ckr: &RecursionChecker,
     non_exhaustive: TokenStream,
     sort_list: &HashMap<&str, &Sort>,
+    pairs: Pairs,
 ) -> Option<TokenStream> {
     let unreachable_exp = quote!(unreachable!("expected different parse pair expression in pair to ast conversion of {}", #sort););
+    let list_pattern = pairs.list_pattern();
 
     Some(match expression {
         Expression::Sort(name) => {
+            let type_name = pairs.type_name(name);
             if sort_list
-                .get(name.as_str())
+                .get(type_name.as_str())
                 .map(|i| i.annotations.contains(&Annotation::Hidden))
                 .unwrap_or_default()
             {
                 return None;
             }
 
-            let iname = format_ident!("{}", sanitize_identifier(name));
-
-            let inner = ckr.maybe_box(
-                name,
-                quote!(
-                    #iname::from_pairs(s, generator)
-                ),
-            );
+            let inner = ckr.maybe_box(&type_name, pairs.build(name));
+            let sort_pattern = pairs.sort_pattern();
 
             quote!(
-                if let ParsePairExpression::Sort(_, ref s) = #src {
+                if let #sort_pattern = #src {
                     #inner
                 } else { #unreachable_exp }
             )
         }
         Expression::CharacterClass(_) => {
+            let empty_pattern = pairs.empty_pattern();
+            let text = pairs.text(quote!(span));
             quote!(
-                if let ParsePairExpression::Empty(ref span) = #src {
-                    span.as_str().to_string()
+                if let #empty_pattern = #src {
+                    #text
                 } else { #unreachable_exp }
             )
         }
+        //In a raw parse result, the items of a delimited expression are between the delimiters
+        Expression::Delimited { min, max, e, .. } if matches!(pairs, Pairs::Raw(_)) => {
+            let ue = generate_unpack_expression(
+                e,
+                sort,
+                quote!(x),
+                ckr,
+                non_exhaustive,
+                sort_list,
+                pairs,
+            );
+            match (min, max, ue) {
+                (0, Some(1), Some(ue)) => quote!(delimited_items(&#src).next().map(|x| #ue)),
+                (_, _, Some(ue)) => quote!(delimited_items(&#src).map(|x| #ue).collect()),
+                (0, Some(1), None) => quote!(delimited_items(&#src).next().is_some()),
+                (_, _, None) => quote!(delimited_items(&#src).count()),
+            }
+        }
         Expression::Repeat { min, max, e } | Expression::Delimited { min, max, e, .. } => {
-            if let Some(ue) =
-                generate_unpack_expression(e, sort, quote!(x), ckr, non_exhaustive, sort_list)
-            {
+            if let Some(ue) = generate_unpack_expression(
+                e,
+                sort,
+                quote!(x),
+                ckr,
+                non_exhaustive,
+                sort_list,
+                pairs,
+            ) {
                 match (min, max) {
                     (0, Some(1)) => quote!(
-                        if let ParsePairExpression::List(_, ref l) = #src {
+                        if let #list_pattern = #src {
                             l.first().map(|x| #ue)
                         } else { #unreachable_exp }
                     ),
                     _ => quote!(
-                        if let ParsePairExpression::List(_, ref l) = #src {
+                        if let #list_pattern = #src {
                             l.iter().map(|x| #ue).collect()
                         } else { #unreachable_exp }
                     ),
@@ -69,18 +163,35 @@ fn generate_unpack_expression(
             } else {
                 match (min, max) {
                     (0, Some(1)) => quote!(
-                        if let ParsePairExpression::List(_, ref l) = #src {
+                        if let #list_pattern = #src {
                             l.first().is_some()
                         } else { #unreachable_exp }
                     ),
                     _ => quote!(
-                        if let ParsePairExpression::List(_, ref l) = #src {
+                        if let #list_pattern = #src {
                             l.iter().len()
                         } else { #unreachable_exp }
                     ),
                 }
             }
         }
+        //A counted repetition is unpacked like a repetition without bounds
+        Expression::Counted { e, .. } => {
+            let repeat = Expression::Repeat {
+                e: e.clone(),
+                min: 0,
+                max: None,
+            };
+            return generate_unpack_expression(
+                &repeat,
+                sort,
+                src,
+                ckr,
+                non_exhaustive,
+                sort_list,
+                pairs,
+            );
+        }
         Expression::Literal(_) => return None,
         Expression::Sequence(c) => {
             let mut expressions = Vec::new();
@@ -97,10 +208,11 @@ fn generate_unpack_expression(
                 if let Some(line) = generate_unpack_expression(
                     i,
                     sort,
-                    quote!(p[#index]),
+                    quote!(l[#index]),
                     ckr,
                     non_exhaustive.clone(),
                     sort_list,
+                    pairs,
                 ) {
                     expressions.push(line)
                 }
@@ -110,13 +222,13 @@ fn generate_unpack_expression(
                 return None;
             } else if let [ref expression] = expressions.as_slice() {
                 quote!(
-                    if let ParsePairExpression::List(_, ref l) = #src {
+                    if let #list_pattern = #src {
                         #expression
                     } else { #unreachable_exp }
                 )
             } else {
                 quote!(
-                    if let ParsePairExpression::List(_, ref l) = #src {
+                    if let #list_pattern = #src {
                         (#(#expressions),*)
                     } else { #unreachable_exp }
                 )
@@ -139,10 +251,21 @@ fn generate_unpack(
     non_exhaustive: TokenStream,
     sort_list: &HashMap<&str, &Sort>,
     dont_put_in_ast: bool,
+    pairs: Pairs,
 ) -> TokenStream {
+    let src = match pairs {
+        Pairs::Sugared => quote!(pair.constructor_value),
+        Pairs::Raw(_) => quote!(*value),
+    };
+
     if no_layout {
+        let span = match pairs {
+            Pairs::Sugared => quote!(#src.span()),
+            Pairs::Raw(_) => quote!(value.span()),
+        };
+        let text = pairs.text(span);
         return quote!(
-            return #constructor(info, pair.constructor_value.span().as_str().to_string());
+            return #constructor(info, #text);
         );
     }
 
@@ -153,10 +276,11 @@ fn generate_unpack(
             let nested = generate_unpack_expression(
                 a,
                 sort,
-                quote!(pair.constructor_value),
+                src.clone(),
                 ckr,
                 non_exhaustive.clone(),
                 sort_list,
+                pairs,
             );
 
             if dont_put_in_ast {
@@ -188,6 +312,7 @@ fn generate_unpack(
                     ckr,
                     non_exhaustive.clone(),
                     sort_list,
+                    pairs,
                 ) {
                     expressions.push(line)
                 }
@@ -198,23 +323,26 @@ fn generate_unpack(
                     #constructor(info #non_exhaustive)
                 )
             } else {
+                let list_pattern = pairs.list_pattern();
                 quote!(
-                    if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    if let #list_pattern = #src {
                         #constructor(info, #(#expressions),* #non_exhaustive)
                     } else { #unreachable_exp }
                 )
             }
         }
         a @ Expression::Repeat { .. }
+        | a @ Expression::Counted { .. }
         | a @ Expression::Delimited { .. }
         | a @ Expression::CharacterClass(_) => {
             if let Some(expression) = generate_unpack_expression(
                 a,
                 sort,
-                quote!(pair.constructor_value),
+                src.clone(),
                 ckr,
                 non_exhaustive.clone(),
                 sort_list,
+                pairs,
             ) {
                 quote!(#constructor(info, #expression #non_exhaustive))
             } else {
@@ -244,8 +372,84 @@ pub fn flatten_sequences(syntax: Expression) -> Expression {
     }
 }
 
+/// The `FromRawPairs` of `sort` from before the merges, which is built as `target`, the sort it became part of.
+fn generate_from_raw_pairs(
+    sort: &Sort,
+    target: &Sort,
+    ckr: &RecursionChecker,
+    non_exhaustive: TokenStream,
+    sort_list: &HashMap<&str, &Sort>,
+    merges: &HashMap<String, String>,
+) -> TokenStream {
+    let pairs = Pairs::Raw(merges);
+    let sort_name = &sort.name;
+
+    let unpack = |constr: &Constructor, constructor: TokenStream| {
+        //The constructor of the target that only refers to a sort that is part of it, is not in the AST
+        let dont_put_in_ast = matches!(&constr.expression, Expression::Sort(name)
+            if merges.contains_key(name) && SyntaxFileAst::get_new_name(name, merges) == target.name);
+
+        generate_unpack(
+            sort_name,
+            constructor,
+            &flatten_sequences(constr.expression.clone()),
+            constr.annotations.contains(&SingleString),
+            ckr,
+            non_exhaustive.clone(),
+            sort_list,
+            dont_put_in_ast,
+            pairs,
+        )
+    };
+
+    let (constructor, unpack_body) = if target.constructors.len() == 1 {
+        (quote!(_), unpack(&sort.constructors[0], quote!(Self)))
+    } else {
+        let (indices, unpacks): (Vec<_>, Vec<_>) = sort
+            .constructors
+            .iter()
+            .enumerate()
+            .filter(|(_, i)| {
+                !i.annotations
+                    .iter()
+                    .any(|i| matches!(i, Annotation::Error(_)))
+            })
+            .map(|(index, constr)| {
+                let name = format_ident!("{}", sanitize_identifier(&constr.name));
+                (
+                    Literal::usize_unsuffixed(index),
+                    unpack(constr, quote!(Self::#name)),
+                )
+            })
+            .unzip();
+
+        (
+            quote!(constructor),
+            quote!(
+                match constructor {
+                    #(
+                        #indices => #unpacks
+                    ),*,
+                    a => unreachable!("{}", a),
+                }
+            ),
+        )
+    };
+
+    quote!(
+        let ParsePairRaw::Choice(span, #constructor, ref value) = *pair else {
+            unreachable!("expected the choice of a constructor in pair to ast conversion of {}", #sort_name);
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate(#sort_name, span);
+
+        #unpack_body
+    )
+}
+
 pub fn generate_from_pairs(
     syntax: &SyntaxFileAst,
+    original: &SyntaxFileAst,
     non_exhaustive: bool,
 ) -> Result<TokenStream, CodegenError> {
     let mut impls = Vec::new();
@@ -284,6 +488,7 @@ pub fn generate_from_pairs(
                 non_exhaustive.clone(),
                 &sort_list,
                 constr.dont_put_in_ast,
+                Pairs::Sugared,
             )
         } else {
             let constructor_names_str = sort
@@ -319,6 +524,7 @@ pub fn generate_from_pairs(
                         non_exhaustive.clone(),
                         &sort_list,
                         constr.dont_put_in_ast,
+                        Pairs::Sugared,
                     )
                 })
                 .collect_vec();
@@ -343,6 +549,47 @@ pub fn generate_from_pairs(
                 }
             }
         ));
+
+        //The raw parse result still has the sorts from before they were merged into the sorts they are part of
+        let raw_body = generate_from_raw_pairs(
+            &original.sorts[&sort.name],
+            sort,
+            ckr,
+            non_exhaustive.clone(),
+            &sort_list,
+            &syntax.merges,
+        );
+        impls.push(quote!(
+            impl<M: AstInfo> FromRawPairs<M> for #sortname<M> {
+                fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(pair: &ParsePairRaw, generator: &mut G) -> Self {
+                    #raw_body
+                }
+            }
+        ));
+
+        for merged in syntax
+            .merges
+            .keys()
+            .filter(|name| SyntaxFileAst::get_new_name(name, &syntax.merges) == sort.name)
+            .sorted()
+        {
+            let function = raw_function_name(merged);
+            let raw_body = generate_from_raw_pairs(
+                &original.sorts[merged],
+                sort,
+                ckr,
+                non_exhaustive.clone(),
+                &sort_list,
+                &syntax.merges,
+            );
+            impls.push(quote!(
+                impl<M: AstInfo> #sortname<M> {
+                    fn #function<G: GenerateRawAstInfo<Result = M>>(pair: &ParsePairRaw, generator: &mut G) -> Self {
+                        #raw_body
+                    }
+                }
+            ));
+        }
     }
 
     Ok(quote!(
@@ -0,0 +1,343 @@
+use crate::codegen::error::CodegenError;
+use crate::parser::peg::parse_options::ParseOptions;
+use crate::parser::peg::parser_core_ast::{CoreAst, CoreExpression, CoreSort, Memoize, SortId};
+use crate::parser::peg::parser_core_first::First;
+use crate::parser::peg::parser_sugar::desugar_ast;
+use crate::parser::peg::parser_sugar_ast::{Annotation, Expression, SyntaxFileAst};
+use crate::sources::character_class::CharacterSet;
+use itertools::Itertools;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+/// Generates a Rust function for every sort of the grammar, and a [`NativeParser`](crate::parser::peg::parser_native::NativeParser)
+/// named `NATIVE_PARSER` which parses with them. Each function does what the parser does when it interprets
+/// the expression of the sort, while it skips alternatives that can't match the next character.
+///
+/// The functions don't report errors. Constructors annotated with `error` only report an error, so grammars
+/// which have them are always interpreted, and get no native parser. So are grammars with counted repetitions.
+pub fn generate_native_parser(ast: &SyntaxFileAst) -> Result<TokenStream, CodegenError> {
+    let error_constructors = ast
+        .sorts
+        .values()
+        .flat_map(|sort| &sort.constructors)
+        .any(|c| {
+            c.annotations
+                .iter()
+                .any(|a| matches!(a, Annotation::Error(_)))
+        });
+    let counted = ast
+        .sorts
+        .values()
+        .flat_map(|sort| &sort.constructors)
+        .any(|c| counts(&c.expression));
+    if error_constructors || counted {
+        return generate_no_native_parser();
+    }
+
+    //Features are checked while parsing, so every constructor is generated
+    let features = ast
+        .sorts
+        .values()
+        .flat_map(|sort| &sort.constructors)
+        .flat_map(|constructor| constructor_features(&constructor.annotations));
+    let options = ParseOptions::new()
+        .with_optimize(false)
+        .with_features(features);
+    let core_ast = desugar_ast(ast, &options);
+
+    let mut generator = Generator {
+        syntax: ast,
+        ast: &core_ast,
+        labels: 0,
+    };
+    let functions = core_ast
+        .sorts
+        .iter()
+        .map(|sort| generator.sort_function(sort))
+        .collect_vec();
+    let sorts = core_ast.sorts.iter().map(|sort| {
+        let function = sort_function_name(sort.id);
+        let memoize = sort.memo == Memoize::Always;
+        quote!(NativeSort::new(#function, #memoize))
+    });
+    let layout = core_ast.layout.0;
+
+    Ok(quote!(
+        use super::prelude::*;
+
+        pub const NATIVE_PARSER: Option<&NativeParser> = Some(&NativeParser::new(&[#(#sorts),*], SortId(#layout)));
+
+        #(#functions)*
+    ))
+}
+
+/// The `NATIVE_PARSER` of a language without a native parser.
+pub fn generate_no_native_parser() -> Result<TokenStream, CodegenError> {
+    Ok(quote!(
+        pub const NATIVE_PARSER: Option<&super::prelude::NativeParser> = None;
+    ))
+}
+
+/// Whether the expression has a counted repetition.
+fn counts(expression: &Expression) -> bool {
+    match expression {
+        Expression::Counted { .. } => true,
+        Expression::Sequence(es) | Expression::Choice(es) => es.iter().any(counts),
+        Expression::Repeat { e, .. } | Expression::Negative(e) | Expression::Positive(e) => {
+            counts(e)
+        }
+        Expression::Delimited { e, delim, .. } => counts(e) || counts(delim),
+        Expression::Sort(_) | Expression::Literal(_) | Expression::CharacterClass(_) => false,
+    }
+}
+
+fn constructor_features(annotations: &[Annotation]) -> impl Iterator<Item = &str> {
+    annotations.iter().filter_map(|a| match a {
+        Annotation::Feature(name) => Some(&name[..]),
+        _ => None,
+    })
+}
+
+fn sort_function_name(sort: SortId) -> Ident {
+    format_ident!("sort_{}", sort.0)
+}
+
+struct Generator<'a> {
+    syntax: &'a SyntaxFileAst,
+    ast: &'a CoreAst<'a>,
+    /// The number of labels that were generated, so every expression gets its own
+    labels: usize,
+}
+
+impl Generator<'_> {
+    fn sort_function(&mut self, sort: &CoreSort) -> TokenStream {
+        let name = sort_function_name(sort.id);
+        let doc = format!(" Parses `{}`", sort.name);
+
+        //Constructors which need a feature fail when it is not enabled
+        let features = match (self.syntax.sorts.get(sort.name), &sort.expr) {
+            (Some(syntax_sort), CoreExpression::Choice(..)) => syntax_sort
+                .constructors
+                .iter()
+                .map(|c| constructor_features(&c.annotations).collect_vec())
+                .collect_vec(),
+            _ => vec![],
+        };
+        let body = match &sort.expr {
+            CoreExpression::Choice(subexprs, firsts) => self.choice(subexprs, firsts, &features),
+            expr => self.expression(expr),
+        };
+        quote!(
+            #[doc = #doc]
+            fn #name<'src>(p: &mut NativeState<'_, 'src>, pos: SourceFileIterator<'src>) -> NativeResult<'src> {
+                #body
+            }
+        )
+    }
+
+    /// Generates a block which parses the expression at `pos`, and evaluates to its `NativeResult`.
+    fn expression(&mut self, expr: &CoreExpression) -> TokenStream {
+        match expr {
+            CoreExpression::Choice(subexprs, firsts) => self.choice(subexprs, firsts, &[]),
+            expr => {
+                let label = self.label();
+                let body = self.expression_body(expr, &label);
+                quote!(#label: {
+                    if !p.step() {
+                        break #label None;
+                    }
+                    #body
+                })
+            }
+        }
+    }
+
+    fn expression_body(&mut self, expr: &CoreExpression, label: &TokenStream) -> TokenStream {
+        match expr {
+            CoreExpression::Name(sort) => {
+                let function = sort_function_name(*sort);
+                let sort = self.ast.sort(*sort);
+                let memoize = sort.memo == Memoize::Always;
+                let id = sort.id.0;
+                quote!(p.name(SortId(#id), #memoize, pos, #function))
+            }
+            CoreExpression::Sequence(subexprs) => {
+                let len = subexprs.len();
+                let subexprs = subexprs.iter().map(|e| self.expression(e)).collect_vec();
+                quote!(
+                    let start = pos;
+                    let mut results = Vec::with_capacity(#len);
+                    #(
+                        let res = #subexprs;
+                        let Some((result, pos)) = res else {
+                            break #label None;
+                        };
+                        results.push(result);
+                    )*
+                    Some((p.list(start, results, pos), pos))
+                )
+            }
+            CoreExpression::Repeat {
+                subexpr, min, max, ..
+            } => {
+                let subexpr = self.expression(subexpr);
+                let max = match max {
+                    Some(max) => quote!(#max),
+                    None => quote!(u64::MAX),
+                };
+                //Before the minimum is reached, a failure is a failure of the repeat
+                let (i, fail) = if *min > 0 {
+                    (quote!(i), quote!(None if i < #min => break #label None,))
+                } else {
+                    (quote!(_), TokenStream::new())
+                };
+                quote!(
+                    let start = pos;
+                    let mut pos = pos;
+                    let mut results = vec![];
+                    for #i in 0..#max {
+                        let res = #subexpr;
+                        match res {
+                            Some((result, next)) => {
+                                results.push(result);
+                                //If the position hasn't changed, then we're in an infinite loop
+                                if next.position() == pos.position() {
+                                    break #label None;
+                                }
+                                pos = next;
+                            }
+                            #fail
+                            None => break,
+                        }
+                    }
+                    Some((p.list(start, results, pos), pos))
+                )
+            }
+            CoreExpression::CharacterClass(characters) => {
+                let accept = char_pattern(characters.set());
+                quote!(p.character_class(pos, |c| #accept))
+            }
+            CoreExpression::Literal(literal) => quote!(p.literal(pos, #literal)),
+            CoreExpression::Choice(..) => unreachable!("choices are generated by `choice`"),
+            CoreExpression::FlagNoLayout(subexpr) => {
+                let subexpr = self.expression(subexpr);
+                quote!(
+                    p.enter_no_layout();
+                    let res = #subexpr;
+                    p.exit_no_layout();
+                    res
+                )
+            }
+            CoreExpression::FlagNoErrors(subexpr, _) => {
+                let subexpr = self.expression(subexpr);
+                quote!(
+                    p.enter_no_errors();
+                    let res = #subexpr;
+                    p.exit_no_errors();
+                    res
+                )
+            }
+            CoreExpression::Error(..) => {
+                unreachable!("grammars with error constructors get no native parser")
+            }
+            CoreExpression::Counted { .. } => {
+                unreachable!("grammars with counted repetitions get no native parser")
+            }
+            CoreExpression::Fail => quote!(None),
+        }
+    }
+
+    /// Generates a choice. The alternatives that can't start with the next character are skipped.
+    /// `features` are the features that each alternative needs, if these are the constructors of a sort.
+    fn choice(
+        &mut self,
+        subexprs: &[CoreExpression],
+        firsts: &[First],
+        features: &[Vec<&str>],
+    ) -> TokenStream {
+        let label = self.label();
+        let layout = &self.ast.layout_first;
+        let alternatives = subexprs.iter().enumerate().map(|(i, subexpr)| {
+            let first = &firsts[i];
+            let subexpr = self.expression(subexpr);
+            let mut alternative = quote!(
+                let res = #subexpr;
+                if let Some((result, end)) = res {
+                    break #label Some((ParsePairRaw::Choice(result.span(), #i, Box::new(result)), end));
+                }
+            );
+            if !first.nullable {
+                let layout_disjoint = !layout.nullable && !layout.chars.intersects(&first.chars);
+                let accept = char_pattern(&first.chars);
+                alternative = quote!(
+                    if p.can_start(pos, &mut next_char_pos, #layout_disjoint, |c| #accept) {
+                        #alternative
+                    }
+                );
+            }
+            match features.get(i) {
+                Some(features) if !features.is_empty() => {
+                    //A disabled constructor fails without parsing anything, like an expression that can't start with any character
+                    let layout_disjoint = !layout.nullable;
+                    quote!(
+                        if #(p.feature_enabled(#features))&&* {
+                            #alternative
+                        } else {
+                            p.can_start(pos, &mut next_char_pos, #layout_disjoint, |_| false);
+                        }
+                    )
+                }
+                _ => alternative,
+            }
+        }).collect_vec();
+        quote!(#label: {
+            if !p.step() {
+                break #label None;
+            }
+            //The type is given, since none of the alternatives may use it
+            let mut next_char_pos: Option<SourceFileIterator<'src>> = None;
+            #(#alternatives)*
+            None
+        })
+    }
+
+    /// A new label for the block of an expression, which is left early when the expression fails
+    fn label(&mut self) -> TokenStream {
+        self.labels += 1;
+        format!("'e{}", self.labels)
+            .parse()
+            .expect("labels are valid tokens")
+    }
+}
+
+/// An expression which is true when the character `c` is in the set.
+fn char_pattern(set: &CharacterSet) -> TokenStream {
+    //Surrogates are not characters, so ranges start and end around them
+    let ranges = set
+        .ranges()
+        .into_iter()
+        .filter_map(|(from, to)| {
+            let from = if (0xD800..0xE000).contains(&from) {
+                0xE000
+            } else {
+                from
+            };
+            let to = if (0xD800..0xE000).contains(&to) {
+                0xD7FF
+            } else {
+                to
+            };
+            let (from, to) = (char::from_u32(from)?, char::from_u32(to)?);
+            match from.cmp(&to) {
+                std::cmp::Ordering::Less => Some(quote!(#from..=#to)),
+                std::cmp::Ordering::Equal => Some(quote!(#from)),
+                std::cmp::Ordering::Greater => None,
+            }
+        })
+        .collect_vec();
+    if ranges.is_empty() {
+        quote!(false)
+    } else {
+        quote!(matches!(c, #(#ranges)|*))
+    }
+}
@@ -246,6 +246,15 @@ fn generate_constructor_type(
                 Tree::Node(parts)
             }
         }
+        //A counted repetition has the type of a repetition without bounds
+        Expression::Counted { e, .. } => {
+            let repeat = Expression::Repeat {
+                e: e.clone(),
+                min: 0,
+                max: None,
+            };
+            generate_constructor_type(&repeat, ckr, sort_list)
+        }
         Expression::Repeat { e, min, max } | Expression::Delimited { e, min, max, .. } => {
             let subtype = generate_constructor_type(e.as_ref(), ckr, sort_list);
             let flattened_subtype = subtype.flatten().collect_vec();
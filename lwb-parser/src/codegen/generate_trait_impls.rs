@@ -40,7 +40,7 @@ pub fn generate_trait_impls(syntax: &SyntaxFileAst) -> Result<TokenStream, Codeg
             .map(|i| i.name.as_str())
             .collect_vec();
 
-        let (ast_info_body, constructor_body) = if constructor_names.len() == 1 {
+        let (ast_info_body, constructor_body) = if sort.constructors.len() == 1 {
             let constructor_name_str = &constructor_names_str[0];
             (
                 quote!(
@@ -4,12 +4,13 @@ use crate::codegen::error::CodegenError;
 use crate::codegen::error::CodegenError::NoExtension;
 use crate::codegen::generate_from_pairs::generate_from_pairs;
 use crate::codegen::generate_misc::{generate_parser, generate_root};
+use crate::codegen::generate_native_parser::{generate_native_parser, generate_no_native_parser};
 use crate::codegen::generate_structs::generate_structs;
 use crate::codegen::generate_trait_impls::generate_trait_impls;
 use crate::codegen::FormattingFile;
 use crate::config::toml::{find_config_path, read_config, ReadConfigError};
-use crate::config::Config;
-use crate::error::display_miette_error;
+use crate::config::{Config, ParserKind};
+use crate::diagnostics::{DiagnosticFormat, Diagnostics};
 use crate::language::Language;
 use crate::parser::syntax_file::{convert_syntax_file_ast, ParseError, SyntaxFile};
 use crate::sources::source_file::SourceFile;
@@ -58,6 +59,7 @@ pub(crate) struct Generated {
     from_pairs: TokenStream,
     root: TokenStream,
     parser: TokenStream,
+    native_parser: TokenStream,
 }
 
 fn codegen_internal(
@@ -71,16 +73,22 @@ fn codegen_internal(
 
     let legacy_ast = convert_syntax_file_ast::convert(ast)?; // TODO make peg parser use new ast
 
+    let native_parser = match config.syntax.parser {
+        ParserKind::Native => generate_native_parser(&legacy_ast)?,
+        ParserKind::Interpreted => generate_no_native_parser()?,
+    };
+
     let mut derives = vec!["Debug", "PartialEq"];
 
     if config.syntax.serde {
         derives.extend(["Serialize", "Deserialize"]);
     }
 
+    let original_ast = legacy_ast.clone();
     let legacy_ast = legacy_ast.simplify()?;
 
     let structs = generate_structs(&legacy_ast, &derives, config.syntax.non_exhaustive)?;
-    let from_pairs = generate_from_pairs(&legacy_ast, config.syntax.non_exhaustive)?;
+    let from_pairs = generate_from_pairs(&legacy_ast, &original_ast, config.syntax.non_exhaustive)?;
     let impls = generate_trait_impls(&legacy_ast)?;
     let root = generate_root(
         imports,
@@ -96,6 +104,7 @@ fn codegen_internal(
         from_pairs,
         root,
         parser,
+        native_parser,
     })
 }
 
@@ -111,6 +120,7 @@ pub fn __codegen_tokenstream(
         from_pairs,
         root,
         parser,
+        native_parser,
     } = codegen_internal(source, config, &[])?;
 
     if debug {
@@ -128,6 +138,7 @@ pub fn __codegen_tokenstream(
             #impls
         }
         mod parser {
+            #native_parser
             #parser
         }
 
@@ -181,16 +192,41 @@ impl Codegen {
         unwrap(Self::try_with_config(path))
     }
 
+    /// Sets how errors are written to the build output, instead of the format from the config.
+    pub fn with_diagnostic_format(mut self, format: DiagnosticFormat) -> Self {
+        self.config.syntax.diagnostics = format;
+        self
+    }
+
+    /// The format errors are written in. The `LWB_DIAGNOSTICS` environment variable takes precedence over the config.
+    fn diagnostic_format(&self) -> DiagnosticFormat {
+        match std::env::var("LWB_DIAGNOSTICS") {
+            Ok(format) => format.parse().unwrap_or_else(|e| panic!("{e}")),
+            Err(_) => self.config.syntax.diagnostics,
+        }
+    }
+
+    /// Generates the code, and panics with the errors in the [diagnostic format](Codegen::with_diagnostic_format)
+    /// when that fails. Build scripts should print `cargo:rerun-if-env-changed=LWB_DIAGNOSTICS`, so the errors are
+    /// written again when the format is changed.
     pub fn codegen(self) {
+        let format = self.diagnostic_format();
         if let Err(e) = self.try_codegen() {
-            match e {
-                CodegenError::ParseError(ParseError::PEG(errs)) => {
-                    for e in errs.iter().rev() {
-                        eprintln!("{}", display_miette_error(e));
-                    }
+            match (e, format) {
+                (CodegenError::ParseError(ParseError::PEG(mut errs)), DiagnosticFormat::Human) => {
+                    errs.reverse();
+                    let mut diagnostics = Diagnostics::new();
+                    diagnostics.extend(&errs);
+                    eprintln!("{}", diagnostics.render(format));
+                    panic!("failed to generate ast")
+                }
+                (e, DiagnosticFormat::Human) => panic!("failed to generate ast: {e}"),
+                (e, format) => {
+                    let mut diagnostics = Diagnostics::new();
+                    diagnostics.push_codegen_error(&e);
+                    eprintln!("{}", diagnostics.render(format));
                     panic!("failed to generate ast")
                 }
-                e => panic!("failed to generate ast: {e}"),
             }
         }
     }
@@ -220,6 +256,7 @@ impl Codegen {
             from_pairs,
             root,
             parser,
+            native_parser,
         } = codegen_internal(
             SourceFile::open(&self.config.syntax.definition)?,
             self.config,
@@ -231,6 +268,8 @@ impl Codegen {
         write!(f_ast_trait_impls, "{}", impls)?;
         write!(f_from_pairs, "{}", from_pairs)?;
 
+        //During bootstrap, the serialized ast is appended later
+        write!(f_serialized_parser, "{}", native_parser)?;
         if write_serialized_ast {
             write!(f_serialized_parser, "{}", parser)?;
         }
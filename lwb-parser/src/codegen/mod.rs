@@ -7,10 +7,11 @@ use std::process::Command;
 use std::{fs, io};
 
 mod check_recursive;
-mod error;
+pub mod error;
 mod generate_file_headers;
 mod generate_from_pairs;
 mod generate_misc;
+mod generate_native_parser;
 mod generate_structs;
 mod generate_trait_impls;
 pub mod manager;
@@ -1,6 +1,11 @@
 pub use crate::parser::ast::from_pairs::GenerateAstInfo;
-pub use crate::parser::ast::from_pairs::{FromPairs, FromPairsError};
+pub use crate::parser::ast::from_pairs::{
+    delimited_items, FromPairs, FromPairsError, FromRawPairs, GenerateRawAstInfo,
+};
 pub use crate::parser::ast::{AstInfo, AstNode};
 pub use crate::parser::peg::parse_pair::{ParsePairExpression, ParsePairSort};
+pub use crate::parser::peg::parser_core_ast::{ParsePairRaw, SortId};
+pub use crate::parser::peg::parser_native::{NativeParser, NativeResult, NativeSort, NativeState};
+pub use crate::sources::source_file::SourceFileIterator;
 
 pub use serde::{self, Deserialize, Serialize};
@@ -1,3 +1,4 @@
+use crate::diagnostics::DiagnosticFormat;
 use serde::Deserialize;
 
 pub mod toml;
@@ -32,6 +33,18 @@ impl Default for Mode {
     }
 }
 
+/// How the generated code parses, see [`SyntaxConfig::parser`].
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParserKind {
+    /// The grammar is embedded, and interpreted when parsing
+    #[default]
+    #[serde(rename = "interpreted")]
+    Interpreted,
+    /// A Rust function is generated for every sort
+    #[serde(rename = "native")]
+    Native,
+}
+
 #[derive(Deserialize)]
 pub struct SyntaxConfig {
     /// The location (path) where the generated code should be
@@ -61,6 +74,20 @@ pub struct SyntaxConfig {
     #[serde(default)]
     pub mode: Mode,
 
+    /// How errors in the syntax definition are written to the build output:
+    /// `human` (the default), `json` or `sarif`. Can be overridden with the
+    /// `LWB_DIAGNOSTICS` environment variable, so CI can ask for machine-readable output.
+    #[serde(default)]
+    pub diagnostics: DiagnosticFormat,
+
+    /// How the generated code parses: `interpreted` (the default) or `native`. Native parsers
+    /// have a Rust function for every sort, so the grammar doesn't have to be interpreted.
+    /// They give the same results, but take longer to compile. Only input without errors is parsed
+    /// natively, the errors are found by interpreting the grammar. Grammars with `error` constructors
+    /// are always interpreted, since these constructors only report errors.
+    #[serde(default)]
+    pub parser: ParserKind,
+
     #[doc(hidden)]
     #[serde(default = "default_true")]
     pub write_serialized_ast: bool, // always true except during bootstrap.
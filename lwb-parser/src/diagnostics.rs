@@ -0,0 +1,512 @@
+use crate::codegen::error::CodegenError;
+use crate::error::display_miette_error;
+use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_warning::ParseWarning;
+use crate::parser::syntax_file::ParseError;
+use crate::sources::line_index::ColumnUnit;
+use crate::sources::source_file::SourceFile;
+use crate::sources::span::Span;
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// How diagnostics (errors and warnings) are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+pub enum DiagnosticFormat {
+    /// Rendered with source code snippets, for people to read
+    #[default]
+    #[serde(rename = "human")]
+    Human,
+    /// A JSON array with one object per diagnostic, see [`DiagnosticRecord`]
+    #[serde(rename = "json")]
+    Json,
+    /// A [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log,
+    /// which is understood by many CI systems and editors
+    #[serde(rename = "sarif")]
+    Sarif,
+}
+
+#[derive(Debug, Error)]
+#[error("unknown diagnostic format {0}, expected one of human, json or sarif")]
+pub struct UnknownDiagnosticFormat(String);
+
+impl FromStr for DiagnosticFormat {
+    type Err = UnknownDiagnosticFormat;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            "sarif" => Ok(Self::Sarif),
+            _ => Err(UnknownDiagnosticFormat(s.to_string())),
+        }
+    }
+}
+
+/// A [`Diagnostic`] that knows which file its labels point into.
+pub trait FileDiagnostic: Diagnostic {
+    /// The file the labels of this diagnostic point into, if it has any labels.
+    /// For diagnostics about several files, this is the file of the first label.
+    fn file(&self) -> Option<&SourceFile>;
+
+    /// The labels of this diagnostic together with the file each of them points into.
+    /// By default, all labels point into [`file`](FileDiagnostic::file).
+    fn file_labels(&self) -> Vec<(SourceFile, LabeledSpan)> {
+        match (self.file(), self.labels()) {
+            (Some(file), Some(labels)) => labels.map(|l| (file.clone(), l)).collect(),
+            _ => vec![],
+        }
+    }
+}
+
+impl FileDiagnostic for PEGParseError {
+    fn file(&self) -> Option<&SourceFile> {
+        Some(&self.span.source)
+    }
+}
+
+impl FileDiagnostic for ParseWarning {
+    fn file(&self) -> Option<&SourceFile> {
+        Some(&self.span.source)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DiagnosticSeverity {
+    #[serde(rename = "error")]
+    Error,
+    #[serde(rename = "warning")]
+    Warning,
+    #[serde(rename = "advice")]
+    Advice,
+}
+
+impl From<Severity> for DiagnosticSeverity {
+    fn from(severity: Severity) -> Self {
+        match severity {
+            Severity::Error => Self::Error,
+            Severity::Warning => Self::Warning,
+            Severity::Advice => Self::Advice,
+        }
+    }
+}
+
+/// A range in a file. Offsets are in bytes, lines and columns start at 1
+/// and columns count characters.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiagnosticRange {
+    pub start: usize,
+    pub end: usize,
+    pub start_line: usize,
+    pub start_column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}
+
+impl DiagnosticRange {
+    fn new(file: &SourceFile, start: usize, end: usize) -> Self {
+        let (start_line, start_column) = line_column(file, start);
+        let (end_line, end_column) = line_column(file, end);
+        Self {
+            start,
+            end,
+            start_line,
+            start_column,
+            end_line,
+            end_column,
+        }
+    }
+}
+
+/// The line and column of a byte offset, both starting at 1.
+fn line_column(file: &SourceFile, offset: usize) -> (usize, usize) {
+    let position = file.line_col_in(offset, ColumnUnit::Utf32);
+    (position.line + 1, position.col + 1)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticLabel {
+    /// The name of the file the label points into
+    pub file: String,
+    pub message: Option<String>,
+    pub range: DiagnosticRange,
+}
+
+/// A diagnostic, converted to plain data so it can be exported.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticRecord {
+    /// The name of the file the diagnostic is about, if it is about a file
+    pub file: Option<String>,
+    pub severity: DiagnosticSeverity,
+    pub code: Option<String>,
+    pub message: String,
+    /// The range of the first label, which is where the problem is
+    pub range: Option<DiagnosticRange>,
+    pub labels: Vec<DiagnosticLabel>,
+    pub help: Option<String>,
+    /// The diagnostic rendered for people to read
+    #[serde(skip)]
+    pub rendered: String,
+}
+
+impl DiagnosticRecord {
+    pub fn new(diagnostic: &impl FileDiagnostic) -> Self {
+        let labels: Vec<DiagnosticLabel> = diagnostic
+            .file_labels()
+            .into_iter()
+            .map(|(file, l)| DiagnosticLabel {
+                file: file.name().to_string(),
+                message: l.label().map(str::to_string),
+                range: DiagnosticRange::new(&file, l.offset(), l.offset() + l.len()),
+            })
+            .collect();
+
+        Self {
+            file: diagnostic.file().map(|f| f.name().to_string()),
+            severity: diagnostic.severity().unwrap_or(Severity::Error).into(),
+            code: diagnostic.code().map(|c| c.to_string()),
+            message: diagnostic.to_string(),
+            range: labels.first().map(|l| l.range.clone()),
+            labels,
+            help: diagnostic.help().map(|h| h.to_string()),
+            rendered: display_miette_error(diagnostic),
+        }
+    }
+
+    /// A record for an error that is not about a location in a file.
+    pub fn from_error(error: &dyn Error) -> Self {
+        Self {
+            file: None,
+            severity: DiagnosticSeverity::Error,
+            code: None,
+            message: error.to_string(),
+            range: None,
+            labels: vec![],
+            help: None,
+            rendered: error.to_string(),
+        }
+    }
+}
+
+/// The labels of a [`MultiFileDiagnostic`] that point into one file.
+#[derive(Debug, Clone, Error)]
+#[error("in {}", .labels[0].0.source.name())]
+pub struct FileLabels {
+    /// Never empty, and all in the same file
+    labels: Vec<(Span, Option<String>)>,
+}
+
+impl FileLabels {
+    pub fn file(&self) -> &SourceFile {
+        &self.labels[0].0.source
+    }
+
+    /// The spans of the labels and their messages.
+    pub fn spans(&self) -> &[(Span, Option<String>)] {
+        &self.labels
+    }
+}
+
+impl Diagnostic for FileLabels {
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.labels[0].0)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.labels.iter().map(|(span, label)| {
+            LabeledSpan::new_with_span(label.clone(), span.clone())
+        })))
+    }
+}
+
+/// A diagnostic with labels in several files, for example about a name that is
+/// defined twice in different modules. Labels are grouped per file (in the order the files
+/// first appear), and the labels in each file after the first are rendered as related diagnostics.
+///
+/// ```
+/// # use lwb_parser::diagnostics::{DiagnosticFormat, Diagnostics, MultiFileDiagnostic};
+/// # use lwb_parser::sources::source_map::SourceMap;
+/// # use lwb_parser::sources::span::Span;
+/// let map = SourceMap::new();
+/// let a = map.file(map.set_overlay("a.lang", "fn f() {}")).unwrap();
+/// let b = map.file(map.set_overlay("b.lang", "fn f() {}")).unwrap();
+///
+/// let diagnostic = MultiFileDiagnostic::error("`f` is defined twice")
+///     .with_label(Span::from_length(&b, 3, 1), "defined again here")
+///     .with_label(Span::from_length(&a, 3, 1), "first defined here");
+///
+/// let mut diagnostics = Diagnostics::new();
+/// diagnostics.push(&diagnostic);
+/// assert_eq!(diagnostics.records[0].labels[1].file, "a.lang");
+/// ```
+#[derive(Debug, Clone, Error)]
+#[error("{message}")]
+pub struct MultiFileDiagnostic {
+    pub message: String,
+    pub severity: Severity,
+    pub help: Option<String>,
+    files: Vec<FileLabels>,
+}
+
+impl MultiFileDiagnostic {
+    pub fn new(message: impl Into<String>, severity: Severity) -> Self {
+        Self {
+            message: message.into(),
+            severity,
+            help: None,
+            files: vec![],
+        }
+    }
+
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(message, Severity::Error)
+    }
+
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(message, Severity::Warning)
+    }
+
+    /// Adds a label, in the file that the span is in.
+    pub fn with_label(mut self, span: Span, label: impl Into<String>) -> Self {
+        self.push_label(span, Some(label.into()));
+        self
+    }
+
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    pub fn push_label(&mut self, span: Span, label: Option<String>) {
+        match self
+            .files
+            .iter_mut()
+            .find(|f| f.file().same_file(&span.source))
+        {
+            Some(file) => file.labels.push((span, label)),
+            None => self.files.push(FileLabels {
+                labels: vec![(span, label)],
+            }),
+        }
+    }
+
+    /// The labels, grouped per file.
+    pub fn files(&self) -> &[FileLabels] {
+        &self.files
+    }
+}
+
+impl Diagnostic for MultiFileDiagnostic {
+    fn severity(&self) -> Option<Severity> {
+        Some(self.severity)
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.help
+            .as_ref()
+            .map(|h| Box::new(h) as Box<dyn Display + 'a>)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        self.files.first().and_then(|f| f.source_code())
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        self.files.first().and_then(Diagnostic::labels)
+    }
+
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        Some(Box::new(
+            self.files.iter().skip(1).map(|f| f as &dyn Diagnostic),
+        ))
+    }
+}
+
+impl FileDiagnostic for MultiFileDiagnostic {
+    fn file(&self) -> Option<&SourceFile> {
+        self.files.first().map(FileLabels::file)
+    }
+
+    fn file_labels(&self) -> Vec<(SourceFile, LabeledSpan)> {
+        self.files
+            .iter()
+            .flat_map(|f| {
+                f.labels.iter().map(|(span, label)| {
+                    (
+                        span.source.clone(),
+                        LabeledSpan::new_with_span(label.clone(), span.clone()),
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+/// A list of diagnostics, which can be written in each of the [`DiagnosticFormat`]s.
+///
+/// ```
+/// # use lwb_parser::diagnostics::{DiagnosticFormat, Diagnostics};
+/// # use lwb_parser::language::Language;
+/// # use lwb_parser::parser::peg::parser_sugar::parse_file;
+/// # use lwb_parser::parser::syntax_file::convert_syntax_file_ast::convert;
+/// # use lwb_parser::parser::syntax_file::SyntaxFile;
+/// # use lwb_parser::sources::source_file::SourceFile;
+/// let sf = SourceFile::new("a = \"a\"*;\nstart at a;\n", "test.syntax");
+/// let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+///
+/// let input = SourceFile::new("aab", "input");
+/// let (_, errs) = parse_file(&ast, &input);
+///
+/// let mut diagnostics = Diagnostics::new();
+/// diagnostics.extend(&errs);
+/// println!("{}", diagnostics.render(DiagnosticFormat::Sarif));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    pub records: Vec<DiagnosticRecord>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, diagnostic: &impl FileDiagnostic) {
+        self.records.push(DiagnosticRecord::new(diagnostic));
+    }
+
+    pub fn extend<'a, D: FileDiagnostic + 'a>(
+        &mut self,
+        diagnostics: impl IntoIterator<Item = &'a D>,
+    ) {
+        self.records
+            .extend(diagnostics.into_iter().map(DiagnosticRecord::new));
+    }
+
+    /// Adds the diagnostics in a parse error. Errors without a location in a file are added without one.
+    pub fn push_parse_error(&mut self, error: &ParseError) {
+        match error {
+            ParseError::PEG(errs) => self.extend(errs),
+            e => self.records.push(DiagnosticRecord::from_error(e)),
+        }
+    }
+
+    /// Adds the diagnostics in a code generation error. Errors without a location in a file are added without one.
+    pub fn push_codegen_error(&mut self, error: &CodegenError) {
+        match error {
+            CodegenError::ParseError(e) => self.push_parse_error(e),
+            e => self.records.push(DiagnosticRecord::from_error(e)),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.records
+            .iter()
+            .any(|r| r.severity == DiagnosticSeverity::Error)
+    }
+
+    pub fn render(&self, format: DiagnosticFormat) -> String {
+        match format {
+            DiagnosticFormat::Human => self
+                .records
+                .iter()
+                .map(|r| r.rendered.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            DiagnosticFormat::Json => self.to_json(),
+            DiagnosticFormat::Sarif => self.to_sarif(),
+        }
+    }
+
+    /// The diagnostics as a JSON array of [`DiagnosticRecord`]s.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(&self.records).expect("diagnostics can always be serialized")
+    }
+
+    /// The diagnostics as a SARIF 2.1.0 log with a single run.
+    pub fn to_sarif(&self) -> String {
+        let results: Vec<Value> = self.records.iter().map(sarif_result).collect();
+        let log = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "lwb",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "informationUri": "https://jonay2000.github.io/rust-lwb/",
+                    }
+                },
+                "results": results,
+            }]
+        });
+        serde_json::to_string_pretty(&log).expect("diagnostics can always be serialized")
+    }
+}
+
+fn sarif_result(record: &DiagnosticRecord) -> Value {
+    let level = match record.severity {
+        DiagnosticSeverity::Error => "error",
+        DiagnosticSeverity::Warning => "warning",
+        DiagnosticSeverity::Advice => "note",
+    };
+    //The first label says what went wrong, the message only where
+    let mut message = record.message.clone();
+    if let Some(label) = record.labels.first().and_then(|l| l.message.as_ref()) {
+        message = format!("{message}\n{label}");
+    }
+    if let Some(help) = &record.help {
+        message = format!("{message}\n{help}");
+    }
+
+    let location = |file: &str, range: &DiagnosticRange| {
+        json!({
+            "physicalLocation": {
+                "artifactLocation": { "uri": file },
+                "region": {
+                    "startLine": range.start_line,
+                    "startColumn": range.start_column,
+                    "endLine": range.end_line,
+                    "endColumn": range.end_column,
+                    "byteOffset": range.start,
+                    "byteLength": range.end - range.start,
+                }
+            }
+        })
+    };
+
+    let mut result = json!({
+        "ruleId": record.code.as_deref().unwrap_or(match record.severity {
+            DiagnosticSeverity::Error => "lwb::error",
+            _ => "lwb::warning",
+        }),
+        "level": level,
+        "message": { "text": message },
+    });
+    if let (Some(file), Some(range)) = (&record.file, &record.range) {
+        result["locations"] = json!([location(file, range)]);
+        result["relatedLocations"] = record
+            .labels
+            .iter()
+            .skip(1)
+            .enumerate()
+            .map(|(id, l)| {
+                let mut related = location(&l.file, &l.range);
+                related["id"] = json!(id);
+                if let Some(label) = &l.message {
+                    related["message"] = json!({ "text": label });
+                }
+                related
+            })
+            .collect();
+    }
+    result
+}
@@ -1,5 +1,12 @@
-use crate::parser::syntax_file::ParseError;
+use crate::parser::ast::generate_ast::BasicAstNode;
+use crate::parser::peg::parse_options::ParseOptions;
+use crate::parser::peg::parse_outcome::ParseOutcome;
+use crate::parser::peg::parse_warning::ParseWarning;
+use crate::parser::peg::parser_compiled::CompiledParser;
+use crate::parser::syntax_file::{parse_language_many, ParseError, ParseLanguageMany};
 use crate::sources::source_file::SourceFile;
+use crate::sources::stream::ChunkedInput;
+use std::io::Read;
 
 pub trait Language {
     type Ast;
@@ -16,7 +23,71 @@ pub trait Language {
     }
 
     /// Tries to parse a source file. Returns an error if parsing failed.
-    fn try_parse(source: &SourceFile) -> Result<Self::Ast, ParseError>;
+    fn try_parse(source: &SourceFile) -> Result<Self::Ast, ParseError> {
+        Self::try_parse_with_options(source, &ParseOptions::default())
+    }
+
+    /// Like [`try_parse`](Language::try_parse), but configured by the options. The parse stops early
+    /// when it exceeds the limits in the options. In that case [`ParseError::Cancelled`] or
+    /// [`ParseError::BudgetExceeded`] is returned.
+    fn try_parse_with_options(
+        source: &SourceFile,
+        options: &ParseOptions,
+    ) -> Result<Self::Ast, ParseError> {
+        let outcome = Self::parse_outcome(source, options)?;
+        match outcome.tree {
+            Some(ast) => Ok(ast),
+            None => Err(ParseError::PEG(outcome.errors)),
+        }
+    }
+
+    /// Like [`try_parse`](Language::try_parse), but also returns the warnings given by constructors with a
+    /// `warning` or `deprecated` annotation. Warnings never make the parse fail.
+    fn try_parse_with_warnings(
+        source: &SourceFile,
+    ) -> Result<(Self::Ast, Vec<ParseWarning>), ParseError> {
+        let outcome = Self::parse_outcome(source, &ParseOptions::default())?;
+        match outcome.tree {
+            Some(ast) => Ok((ast, outcome.warnings)),
+            None => Err(ParseError::PEG(outcome.errors)),
+        }
+    }
+
+    /// Parses a source file, and returns the AST together with all errors, warnings and (if requested)
+    /// statistics of the parse. The AST is only there when there were no errors.
+    /// Only fails when the parse could not finish, for example because it was cancelled.
+    ///
+    /// The AST is generated for the starting sort of the grammar, so options that start at another sort give
+    /// [`ParseError::StartingSort`]. To start at another sort, use
+    /// [`parse_language_outcome`](crate::parser::syntax_file::parse_language_outcome).
+    fn parse_outcome(
+        source: &SourceFile,
+        options: &ParseOptions,
+    ) -> Result<ParseOutcome<Option<Self::Ast>>, ParseError>;
+
+    /// Parses a single sort starting at byte `offset` in the source file, as far as possible.
+    /// Returns the AST, and the offset directly after the part that was parsed.
+    /// `T` should be the type that was generated for `sort`.
+    ///
+    /// This is useful to parse fragments that are embedded in some other language.
+    fn parse_prefix<T: BasicAstNode>(
+        sort: &str,
+        source: &SourceFile,
+        offset: usize,
+    ) -> Result<(T, usize), ParseError>;
+
+    /// Parses a stream that contains many items, for example newline delimited json.
+    /// The iterator reads the input in chunks, and yields an AST for every item.
+    fn parse_many<R: Read>(input: ChunkedInput<R>) -> ParseLanguageMany<'static, Self::Ast, R> {
+        match Self::compiled_parser() {
+            Ok(parser) => parse_language_many(input, parser),
+            Err(e) => e.into(),
+        }
+    }
+
+    /// The parser of the language. It is loaded and compiled the first time it is used, and then
+    /// shared by all parses of the language, also on other threads.
+    fn compiled_parser() -> Result<&'static CompiledParser<'static>, ParseError>;
 }
 
 #[macro_export]
@@ -29,10 +100,49 @@ macro_rules! language {
         impl $crate::language::Language for $name {
             type Ast = AST::AST_ROOT<$crate::parser::ast::generate_ast::BasicAstInfo>;
 
-            fn try_parse(
+            fn parse_outcome(
                 source: &$crate::sources::source_file::SourceFile,
-            ) -> Result<Self::Ast, $crate::parser::syntax_file::ParseError> {
-                $crate::parser::syntax_file::parse_language(source, AST::PARSER)
+                options: &$crate::parser::peg::parse_options::ParseOptions,
+            ) -> Result<
+                $crate::parser::peg::parse_outcome::ParseOutcome<Option<Self::Ast>>,
+                $crate::parser::syntax_file::ParseError,
+            > {
+                $crate::parser::syntax_file::__parse_compiled_language_root_outcome(
+                    source,
+                    Self::compiled_parser()?,
+                    options,
+                )
+            }
+
+            fn parse_prefix<T: $crate::parser::ast::generate_ast::BasicAstNode>(
+                sort: &str,
+                source: &$crate::sources::source_file::SourceFile,
+                offset: usize,
+            ) -> Result<(T, usize), $crate::parser::syntax_file::ParseError> {
+                $crate::parser::syntax_file::parse_compiled_language_prefix(
+                    sort,
+                    source,
+                    offset,
+                    Self::compiled_parser()?,
+                )
+            }
+
+            fn compiled_parser() -> Result<
+                &'static $crate::parser::peg::parser_compiled::CompiledParser<'static>,
+                $crate::parser::syntax_file::ParseError,
+            > {
+                static SYNTAX: std::sync::OnceLock<
+                    $crate::parser::peg::parser_sugar_ast::SyntaxFileAst,
+                > = std::sync::OnceLock::new();
+                static COMPILED: std::sync::OnceLock<
+                    $crate::parser::peg::parser_compiled::CompiledParser<'static>,
+                > = std::sync::OnceLock::new();
+                $crate::parser::syntax_file::__compiled_language_parser(
+                    &SYNTAX,
+                    &COMPILED,
+                    AST::PARSER,
+                    AST::NATIVE_PARSER,
+                )
             }
         }
     };
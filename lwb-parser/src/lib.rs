@@ -28,6 +28,9 @@ pub mod codegen;
 /// Code related to configuring rust-lwb
 pub mod config;
 
+/// Exporting errors and warnings in machine-readable formats (JSON and SARIF)
+pub mod diagnostics;
+
 // Mostly used in bootstrapper
 pub use bincode;
 pub mod error;
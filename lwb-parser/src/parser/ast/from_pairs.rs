@@ -1,5 +1,8 @@
 use crate::parser::ast::AstInfo;
 use crate::parser::peg::parse_pair::ParsePairSort;
+use crate::parser::peg::parser_core_ast::ParsePairRaw;
+use crate::sources::compact_span::CompactSpan;
+use crate::sources::source_file::SourceFile;
 use thiserror::Error;
 
 pub trait GenerateAstInfo {
@@ -27,3 +30,49 @@ pub trait FromPairs<M: AstInfo> {
     where
         Self: Sized;
 }
+
+/// Like [`GenerateAstInfo`], for ASTs that are built from the raw result of the parser, see [`FromRawPairs`].
+pub trait GenerateRawAstInfo {
+    type Result: AstInfo;
+
+    /// The file that was parsed. The spans in a raw parse result are offsets into this file.
+    fn file(&self) -> &SourceFile;
+
+    /// The info of a node of `sort`, which was parsed at `span` in the [`file`](GenerateRawAstInfo::file).
+    fn generate(&mut self, sort: &str, span: CompactSpan) -> Self::Result;
+}
+
+/// Like [`FromPairs`], but builds the AST directly from the raw result of the parser, without resugaring it first.
+/// `pair` is the result of a sort, which chose one of its constructors. The AST is the same as the one [`FromPairs`] builds.
+pub trait FromRawPairs<M: AstInfo> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self
+    where
+        Self: Sized;
+}
+
+/// The items of a `delimited` expression in a raw parse result, without the delimiters.
+/// A delimited expression is desugared to a choice between a sequence of an item and a repeat
+/// of delimiter and item, and an empty sequence.
+pub fn delimited_items(pair: &ParsePairRaw) -> impl Iterator<Item = &ParsePairRaw> {
+    let (first, rest) = match pair {
+        ParsePairRaw::List(_, list) => match list.first() {
+            Some(ParsePairRaw::Choice(_, 0, choice)) => match &**choice {
+                ParsePairRaw::List(_, seq) => (seq.first(), seq.get(1)),
+                _ => unreachable!("delimited items are a sequence"),
+            },
+            _ => (None, None),
+        },
+        _ => unreachable!("delimited is a sequence"),
+    };
+    let rest = match rest {
+        Some(ParsePairRaw::List(_, rest)) => &rest[..],
+        _ => &[],
+    };
+    first.into_iter().chain(rest.iter().map(|pair| match pair {
+        ParsePairRaw::List(_, l) => &l[1],
+        _ => unreachable!("a delimiter is followed by an item"),
+    }))
+}
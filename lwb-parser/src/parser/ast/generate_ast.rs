@@ -1,7 +1,13 @@
-use crate::codegen_prelude::{AstInfo, GenerateAstInfo, ParsePairSort};
+use crate::codegen_prelude::{AstInfo, GenerateAstInfo, GenerateRawAstInfo, ParsePairSort};
 use crate::parser::ast::{AstNode, NodeId, SpannedAstInfo};
+use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_options::{ParseLimiter, ParseOptions};
+use crate::parser::peg::parser_core_ast::ParsePairRaw;
+use crate::sources::compact_span::CompactSpan;
+use crate::sources::source_file::SourceFile;
 use crate::sources::span::Span;
 use serde::{Deserialize, Serialize};
+use std::panic::{self, AssertUnwindSafe};
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct BasicAstInfo {
@@ -30,16 +36,38 @@ impl SpannedAstInfo for BasicAstInfo {
 pub trait BasicAstNode: AstNode<BasicAstInfo> {}
 
 impl<T> BasicAstNode for T where T: AstNode<BasicAstInfo> {}
+/// Unwinds out of the generated AST code, when the AST is nested too deeply to build it. See [`build`].
+struct NestingTooDeep(Span);
 
-#[derive(Default)]
-struct AstInfoGenerator {
+/// Stops building the AST, when that uses more stack space than the options allow.
+fn check_stack(limiter: &ParseLimiter, span: impl FnOnce() -> Span) {
+    if limiter.stack_exceeded() {
+        panic::resume_unwind(Box::new(NestingTooDeep(span())));
+    }
+}
+
+/// Builds an AST with the generated code, which recurses for every nested sort.
+/// The generators stop it with a [`NestingTooDeep`] when it uses too much stack space, which is turned
+/// into a "nesting too deep" error here. Resuming the unwind does not run the panic hook.
+fn build<AST>(f: impl FnOnce() -> AST) -> Result<AST, PEGParseError> {
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| {
+        match payload.downcast::<NestingTooDeep>() {
+            Ok(nesting) => PEGParseError::fail_nesting(nesting.0),
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    })
+}
+
+struct AstInfoGenerator<'o> {
+    limiter: ParseLimiter<'o>,
     curr_id: u64,
 }
 
-impl GenerateAstInfo for AstInfoGenerator {
+impl GenerateAstInfo for AstInfoGenerator<'_> {
     type Result = BasicAstInfo;
 
     fn generate(&mut self, pair: &ParsePairSort) -> Self::Result {
+        check_stack(&self.limiter, || pair.span());
         let res = BasicAstInfo {
             span: pair.span(),
             node_id: NodeId::new(self.curr_id),
@@ -49,9 +77,93 @@ impl GenerateAstInfo for AstInfoGenerator {
     }
 }
 
+/// Like [`AstInfoGenerator`], for the raw parse result of `file`.
+struct RawAstInfoGenerator<'a, 'o> {
+    file: &'a SourceFile,
+    limiter: ParseLimiter<'o>,
+    curr_id: u64,
+}
+
+impl GenerateRawAstInfo for RawAstInfoGenerator<'_, '_> {
+    type Result = BasicAstInfo;
+
+    fn file(&self) -> &SourceFile {
+        self.file
+    }
+
+    fn generate(&mut self, _sort: &str, span: CompactSpan) -> Self::Result {
+        check_stack(&self.limiter, || span.to_span(self.file));
+        let res = BasicAstInfo {
+            span: span.to_span(self.file),
+            node_id: NodeId::new(self.curr_id),
+        };
+        self.curr_id += 1;
+        res
+    }
+}
+
+/// Builds the AST of a parse result.
+///
+/// # Panics
+/// When the AST is nested too deeply to build it within the stack of the thread, see [`try_generate_ast`].
 pub fn generate_ast<AST>(pairs: &ParsePairSort) -> AST
 where
     AST: AstNode<BasicAstInfo>,
 {
-    AST::from_pairs(pairs, &mut AstInfoGenerator::default())
+    try_generate_ast(pairs, &ParseOptions::default()).unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Like [`generate_ast`], but fails with a "nesting too deep" error when building the AST
+/// uses more stack space than the [`max_stack`](ParseOptions::with_max_stack) of the options.
+pub fn try_generate_ast<AST>(
+    pairs: &ParsePairSort,
+    options: &ParseOptions,
+) -> Result<AST, PEGParseError>
+where
+    AST: AstNode<BasicAstInfo>,
+{
+    build(|| {
+        AST::from_pairs(
+            pairs,
+            &mut AstInfoGenerator {
+                limiter: ParseLimiter::new(options),
+                curr_id: 0,
+            },
+        )
+    })
+}
+
+/// Like [`generate_ast`], but builds the AST directly from the raw result of the parser, see [`FromRawPairs`](crate::parser::ast::from_pairs::FromRawPairs).
+/// `file` is the file that was parsed. The node ids are the same as the ones [`generate_ast`] gives.
+///
+/// # Panics
+/// When the AST is nested too deeply to build it within the stack of the thread, see [`try_generate_ast_from_raw`].
+pub fn generate_ast_from_raw<AST>(pair: &ParsePairRaw, file: &SourceFile) -> AST
+where
+    AST: AstNode<BasicAstInfo>,
+{
+    try_generate_ast_from_raw(pair, file, &ParseOptions::default())
+        .unwrap_or_else(|e| panic!("{e}"))
+}
+
+/// Like [`generate_ast_from_raw`], but fails with a "nesting too deep" error when building the AST
+/// uses more stack space than the [`max_stack`](ParseOptions::with_max_stack) of the options.
+pub fn try_generate_ast_from_raw<AST>(
+    pair: &ParsePairRaw,
+    file: &SourceFile,
+    options: &ParseOptions,
+) -> Result<AST, PEGParseError>
+where
+    AST: AstNode<BasicAstInfo>,
+{
+    build(|| {
+        AST::from_raw_pairs(
+            pair,
+            &mut RawAstInfoGenerator {
+                file,
+                limiter: ParseLimiter::new(options),
+                curr_id: 0,
+            },
+        )
+    })
 }
@@ -1,5 +1,6 @@
 use crate::codegen_prelude::{GenerateAstInfo, ParsePairSort};
-use crate::parser::ast::from_pairs::FromPairs;
+use crate::parser::ast::from_pairs::{FromPairs, FromRawPairs, GenerateRawAstInfo};
+use crate::parser::peg::parser_core_ast::ParsePairRaw;
 use crate::sources::span::Span;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
@@ -28,7 +29,7 @@ pub trait AstInfo: Debug + PartialEq {
     fn node_id(&self) -> NodeId;
 }
 
-pub trait AstNode<M: AstInfo>: FromPairs<M> {
+pub trait AstNode<M: AstInfo>: FromPairs<M> + FromRawPairs<M> {
     fn as_str<'a>(&'a self) -> &'a str
     where
         M: SpannedAstInfo + 'a,
@@ -61,6 +62,21 @@ where
     }
 }
 
+impl<M: AstInfo, T> FromRawPairs<M> for Box<T>
+where
+    T: AstNode<M>,
+{
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Box::new(T::from_raw_pairs(pair, generator))
+    }
+}
+
 impl<M: AstInfo, T> AstNode<M> for Box<T>
 where
     T: AstNode<M>,
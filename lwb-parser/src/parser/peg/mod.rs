@@ -1,9 +1,21 @@
 pub mod parse_error;
+pub mod parse_options;
+pub mod parse_outcome;
 pub mod parse_pair;
 pub mod parse_result;
+pub mod parse_stats;
+pub mod parse_trace;
+pub mod parse_warning;
+pub mod parser_compiled;
 pub mod parser_core;
 pub mod parser_core_ast;
 pub mod parser_core_expression;
 pub mod parser_core_file;
+pub mod parser_core_first;
+pub mod parser_core_memo;
+pub mod parser_core_optimize;
+pub mod parser_incremental;
+pub mod parser_native;
+pub mod parser_stream;
 pub mod parser_sugar;
 pub mod parser_sugar_ast;
@@ -7,18 +7,71 @@ use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use thiserror::Error;
 
+/// How many of the innermost enclosing sorts are kept in the context of an error.
+/// Keeping all of them would make errors in deeply nested input very expensive.
+pub const MAX_ERROR_CONTEXT: usize = 8;
+
+/// How many of the innermost enclosing sorts are shown in the message of an error.
+const SUMMARY_CONTEXT: usize = 3;
+
+/// The longest offending text that is put into error messages with `{text}`.
+const MAX_OFFENDING_TEXT: usize = 40;
+
 /// A parsing error represents a single error that occurred during parsing.
 /// The parsing error occurs at a certain position in a file, represented by the span.
 /// The parsing error consists of multiple `ParseErrorSub`, which each represent a single thing that went wrong at this position.
 #[derive(Debug, Clone, Error)]
-#[error("A parse error occured!")]
 pub struct PEGParseError {
     pub span: Span,
     pub expected: Vec<Expect>,
     pub fail_left_rec: bool,
     pub fail_loop: bool,
+    /// Sorts were nested deeper than the maximum depth
+    pub fail_nesting: bool,
     /// first the name of the sort that caused the error, then the error message
     pub msgs: Vec<(String, String)>,
+    /// The sorts that were being parsed when the error occurred, innermost last.
+    /// Only the innermost [`MAX_ERROR_CONTEXT`] sorts are kept.
+    pub context: Vec<ErrorContext>,
+}
+
+/// A sort that was being parsed when an error occurred.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorContext {
+    pub sort: String,
+    /// The constructor of the sort that was being tried, if known
+    pub constructor: Option<String>,
+    /// Where parsing the sort started, this is before any layout in front of it
+    pub position: usize,
+    /// Where the sort itself starts, after the layout in front of it
+    pub start: usize,
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.constructor {
+            // `num = num;` style constructors only say which sort is parsed
+            Some(constructor) if constructor != &self.sort => {
+                write!(f, "`{constructor}` {}", self.sort)
+            }
+            _ => write!(f, "{}", self.sort),
+        }
+    }
+}
+
+impl Display for PEGParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.context.is_empty() {
+            return write!(f, "A parse error occured!");
+        }
+
+        write!(f, "A parse error occured in ")?;
+        if self.context.len() > SUMMARY_CONTEXT {
+            write!(f, "… → ")?;
+        }
+        let start = self.context.len().saturating_sub(SUMMARY_CONTEXT);
+        write!(f, "{}", self.context[start..].iter().join(" → "))
+    }
 }
 
 // add error bound so IDEs don't complain. Error is always derived by thiserror.
@@ -41,6 +94,8 @@ where
 
     /// Labels to apply to this Diagnostic's [Diagnostic::source_code]
     fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let context_labels = self.context_labels();
+
         // immediately return when the grammar gave a custom error
         if let Some(i) = self
             .expected
@@ -49,10 +104,17 @@ where
         {
             let label = LabeledSpan::new_with_span(Some(i.to_string()), self.span.clone());
 
-            return Some(Box::new(vec![label].into_iter()));
+            return Some(Box::new(vec![label].into_iter().chain(context_labels)));
         }
 
-        let expect_str = self.expected.iter().map(|exp| exp.to_string()).join(", ");
+        let expected = self
+            .expected
+            .iter()
+            .map(|exp| exp.to_string())
+            .sorted()
+            .dedup()
+            .collect_vec();
+        let expect_str = expected.join(", ");
         let mut labels = vec![];
 
         //Leftrec label
@@ -65,8 +127,16 @@ where
             labels.push(LabeledSpan::new_with_span(Some("Encountered an infinite loop here. This is a problem with the grammar, and may hide other errors.".to_string()), self.span.clone()));
         }
 
+        //Nesting label
+        if self.fail_nesting {
+            labels.push(LabeledSpan::new_with_span(
+                Some("Nesting too deep here.".to_string()),
+                self.span.clone(),
+            ));
+        }
+
         //Expected label
-        match self.expected.len() {
+        match expected.len() {
             0 => {}
             1 => labels.push(LabeledSpan::new_with_span(
                 Some(format!("Expected {} here", expect_str)),
@@ -78,6 +148,7 @@ where
             )),
         }
 
+        labels.extend(context_labels);
         Some(Box::new(labels.into_iter()))
     }
 
@@ -86,6 +157,9 @@ where
         for (sort, msg) in &self.msgs {
             helps.push(format!("if a {sort} would be parsed here then {msg}"));
         }
+        if let Some(suggestion) = self.suggestion() {
+            helps.push(format!("did you mean `{suggestion}`?"));
+        }
 
         if helps.is_empty() {
             None
@@ -96,22 +170,85 @@ where
 }
 
 impl PEGParseError {
+    /// Secondary labels, pointing at the start of each sort the error occurred in.
+    /// When several sorts start at the same position, only the innermost one is labelled.
+    fn context_labels(&self) -> Vec<LabeledSpan> {
+        let mut labels: Vec<LabeledSpan> = vec![];
+        for context in self.context.iter().rev() {
+            let position = context.start;
+            if position >= self.span.position || labels.iter().any(|l| l.offset() == position) {
+                continue;
+            }
+            labels.push(LabeledSpan::new(
+                Some(format!("while parsing this {context}")),
+                position,
+                0,
+            ));
+        }
+        labels
+    }
+
+    /// The text that caused the error: everything from the start of the span up to the next whitespace.
+    /// Empty at the end of the file and for binary source files.
+    pub fn offending_text(&self) -> &str {
+        if self.span.source.is_binary() {
+            return "";
+        }
+        let source = &self.span.source;
+        let rest = &source.contents()[source.str_offset(self.span.position)..];
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(rest.len(), |(i, _)| i);
+        match rest[..end].char_indices().nth(MAX_OFFENDING_TEXT) {
+            Some((i, _)) => &rest[..i],
+            None => &rest[..end],
+        }
+    }
+
+    /// An expected keyword that is close to the word at the position of the error,
+    /// for when the input probably contains a typo.
+    pub fn suggestion(&self) -> Option<&str> {
+        let word = self.offending_text();
+        let word = &word[..word
+            .char_indices()
+            .find(|(_, c)| !c.is_alphanumeric() && *c != '_')
+            .map_or(word.len(), |(i, _)| i)];
+        if word.is_empty() {
+            return None;
+        }
+
+        self.expected
+            .iter()
+            .filter_map(|e| match e {
+                Expect::ExpectString(s) if s != word => Some(s.as_str()),
+                _ => None,
+            })
+            .map(|s| (edit_distance(word, s), s))
+            .filter(|(d, s)| *d <= (s.chars().count() / 3).max(1))
+            .min()
+            .map(|(_, s)| s)
+    }
+
     pub fn expect(span: Span, expect: Expect, sort_context: &ExpressionContext) -> Self {
-        PEGParseError {
+        let mut err = PEGParseError {
             span,
             expected: vec![expect],
             fail_left_rec: false,
             fail_loop: false,
-            msgs: if let Some(name) = sort_context.name {
-                sort_context
-                    .error
-                    .iter()
-                    .map(|i| (name.to_string(), i.to_string()))
-                    .collect()
-            } else {
-                vec![]
-            },
+            fail_nesting: false,
+            msgs: vec![],
+            context: vec![],
+        };
+        if let Some(name) = sort_context.name {
+            let text = err.offending_text().to_string();
+            err.msgs = sort_context
+                .error
+                .iter()
+                .map(|i| (name.to_string(), i.replace("{text}", &text)))
+                .collect();
         }
+        err
     }
 
     pub fn fail_left_recursion(span: Span) -> Self {
@@ -120,7 +257,9 @@ impl PEGParseError {
             expected: vec![],
             fail_left_rec: true,
             fail_loop: false,
+            fail_nesting: false,
             msgs: vec![],
+            context: vec![],
         }
     }
 
@@ -130,9 +269,45 @@ impl PEGParseError {
             expected: vec![],
             fail_left_rec: false,
             fail_loop: true,
+            fail_nesting: false,
             msgs: vec![],
+            context: vec![],
+        }
+    }
+
+    pub fn fail_nesting(span: Span) -> Self {
+        PEGParseError {
+            span,
+            expected: vec![],
+            fail_left_rec: false,
+            fail_loop: false,
+            fail_nesting: true,
+            msgs: vec![],
+            context: vec![],
+        }
+    }
+}
+
+/// The number of characters that have to be inserted, removed, replaced or swapped with their neighbour to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a = a.chars().collect_vec();
+    let b = b.chars().collect_vec();
+    //`rows[i][j]` is the distance between the first `i` characters of `a` and the first `j` of `b`
+    let mut rows = vec![(0..=b.len()).collect_vec()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
         }
+        rows.push(row);
     }
+    rows[a.len()][b.len()]
 }
 
 /// Represents a single thing that went wrong at this position.
@@ -182,7 +357,7 @@ impl PEGParseError {
     /// is discarded.
     ///
     /// Highest precedence is defined as furthest starting position for now. This might be changed later.
-    pub fn combine(mut self, mut other: PEGParseError) -> PEGParseError {
+    pub fn combine(mut self, other: PEGParseError) -> PEGParseError {
         assert_eq!(self.span.source.name(), other.span.source.name());
 
         //Compare the starting positions of the span
@@ -192,12 +367,27 @@ impl PEGParseError {
             Ordering::Equal => {
                 //The span is extended such that the longest one is kept.
                 self.span.length = self.span.length.max(other.span.length);
-                //Merge the expected tokens
-                self.expected.append(&mut other.expected);
+                //Merge the expected tokens, without duplicates
+                for expect in other.expected {
+                    if !self.expected.contains(&expect) {
+                        self.expected.push(expect);
+                    }
+                }
                 //Left recursion
                 self.fail_left_rec |= other.fail_left_rec;
+                //Nesting
+                self.fail_nesting |= other.fail_nesting;
 
-                self.msgs.extend(other.msgs);
+                //The same message is found again when a sort that was not cached is parsed again
+                for msg in other.msgs {
+                    if !self.msgs.contains(&msg) {
+                        self.msgs.push(msg);
+                    }
+                }
+                //Keep the most specific context
+                if other.context.len() > self.context.len() {
+                    self.context = other.context;
+                }
 
                 self
             }
@@ -0,0 +1,444 @@
+use crate::parser::peg::parse_trace::TraceSink;
+use derive_more::Display;
+use std::cell::Cell;
+use std::collections::{BTreeSet, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// How often (in steps) the deadline and the cancellation token are checked.
+/// Looking at the clock every step would slow down parsing considerably.
+const CHECK_INTERVAL: u64 = 1024;
+
+/// The part of the stack that is left unused when the stack limit is derived from the stack of the thread,
+/// see [`ParseOptions::with_max_stack`]. This leaves room for what the parser does after it checks the stack,
+/// and for the caller.
+const STACK_RESERVE_FRACTION: usize = 8;
+const MIN_STACK_RESERVE: usize = 64 * 1024;
+
+/// Options for a single parse. These configure how errors are reported and recovered from,
+/// where parsing starts and which features of the grammar are enabled.
+///
+/// They can also limit how much work the parser may do, which is useful when parsing untrusted input,
+/// or parsing in a language server where a parse may become irrelevant before it finishes.
+///
+/// ```
+/// # use lwb_parser::parser::peg::parse_options::{CancellationToken, ErrorOrder, ParseOptions};
+/// # use std::time::Duration;
+/// let token = CancellationToken::new();
+/// let options = ParseOptions::new()
+///     .with_max_errors(10)
+///     .with_error_order(ErrorOrder::Position)
+///     .with_feature("async")
+///     .with_max_steps(1_000_000)
+///     .with_timeout(Duration::from_secs(1))
+///     .with_cancellation_token(token.clone());
+///
+/// // from another thread, while parsing:
+/// token.cancel();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParseOptions {
+    max_steps: Option<u64>,
+    deadline: Option<Instant>,
+    cancellation_token: Option<CancellationToken>,
+    max_depth: Option<usize>,
+    max_stack: Option<usize>,
+    trace: Option<Arc<dyn TraceSink>>,
+    max_errors: Option<usize>,
+    recovery: bool,
+    error_order: ErrorOrder,
+    starting_sort: Option<String>,
+    features: HashSet<String>,
+    stats: bool,
+    optimize: bool,
+    max_memo_entries: Option<usize>,
+}
+
+/// The order in which the errors of a parse are returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorOrder {
+    /// In the order the parser found them, while recovering from each error.
+    #[default]
+    Found,
+    /// Ordered by the position at which they start in the file.
+    Position,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_steps: None,
+            deadline: None,
+            cancellation_token: None,
+            max_depth: None,
+            max_stack: None,
+            trace: None,
+            max_errors: None,
+            recovery: true,
+            error_order: ErrorOrder::default(),
+            starting_sort: None,
+            features: HashSet::new(),
+            stats: false,
+            optimize: true,
+            max_memo_entries: None,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Options without any limits, except that parsing stops before the stack of the thread overflows,
+    /// see [`with_max_stack`](Self::with_max_stack).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop parsing after this many steps. A step is a single expression that is parsed,
+    /// including any attempts that are made while recovering from errors.
+    pub fn with_max_steps(mut self, max_steps: u64) -> Self {
+        self.max_steps = Some(max_steps);
+        self
+    }
+
+    /// Stop parsing when it has not finished at this moment.
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Stop parsing when it takes longer than `timeout`, counted from now.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+
+    /// Sets how deeply sorts may be nested in the input. When sorts are nested deeper,
+    /// parsing fails with a "nesting too deep" error.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets how much stack space (in bytes) the parser may use. Every nested sort uses
+    /// stack space, so deeply nested input would overflow the stack. Instead, parsing fails
+    /// with a "nesting too deep" error when the parser uses more than this.
+    ///
+    /// By default, this is most of the stack that is left on the thread that parses, when that is known
+    /// (on Linux and macOS). Otherwise, the stack is not limited by default.
+    /// When setting this limit, make sure the parser runs on a thread with a large enough stack.
+    pub fn with_max_stack(mut self, max_stack: usize) -> Self {
+        self.max_stack = Some(max_stack);
+        self
+    }
+
+    /// Send everything the parser does to a trace sink, usually a [`Trace`](crate::parser::peg::parse_trace::Trace).
+    /// This is useful to find out why a grammar does not parse what it should.
+    /// Tracing makes parsing a lot slower.
+    pub fn with_trace(mut self, sink: impl TraceSink + 'static) -> Self {
+        self.trace = Some(Arc::new(sink));
+        self
+    }
+
+    pub(crate) fn trace(&self) -> Option<&dyn TraceSink> {
+        self.trace.as_deref()
+    }
+
+    /// Stop parsing when `token` is cancelled.
+    pub fn with_cancellation_token(mut self, token: CancellationToken) -> Self {
+        self.cancellation_token = Some(token);
+        self
+    }
+
+    /// Stop recovering from errors once this many errors were found.
+    /// When parsing fails, at least one error is always reported.
+    pub fn with_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors.max(1));
+        self
+    }
+
+    pub fn max_errors(&self) -> usize {
+        self.max_errors.unwrap_or(usize::MAX)
+    }
+
+    /// Sets whether the parser tries to recover from errors, to find more errors further on in the file.
+    /// This is enabled by default. Without recovery, parsing stops at the first error.
+    pub fn with_recovery(mut self, recovery: bool) -> Self {
+        self.recovery = recovery;
+        self
+    }
+
+    pub fn recovery(&self) -> bool {
+        self.recovery
+    }
+
+    /// Sets the order in which errors are returned.
+    pub fn with_error_order(mut self, error_order: ErrorOrder) -> Self {
+        self.error_order = error_order;
+        self
+    }
+
+    pub fn error_order(&self) -> ErrorOrder {
+        self.error_order
+    }
+
+    /// Start parsing at this sort, instead of the starting sort of the grammar.
+    pub fn with_starting_sort(mut self, sort: impl AsRef<str>) -> Self {
+        self.starting_sort = Some(sort.as_ref().to_string());
+        self
+    }
+
+    pub fn starting_sort(&self) -> Option<&str> {
+        self.starting_sort.as_deref()
+    }
+
+    /// Enables the constructors that are annotated with `{feature: <name>}`.
+    /// Constructors with a feature that is not enabled never parse.
+    pub fn with_feature(mut self, name: impl AsRef<str>) -> Self {
+        self.features.insert(name.as_ref().to_string());
+        self
+    }
+
+    /// Enables several features at once, see [`with_feature`](ParseOptions::with_feature).
+    pub fn with_features<S: AsRef<str>>(self, names: impl IntoIterator<Item = S>) -> Self {
+        names
+            .into_iter()
+            .fold(self, |options, name| options.with_feature(name))
+    }
+
+    pub fn feature_enabled(&self, name: &str) -> bool {
+        self.features.contains(name)
+    }
+
+    /// Collect statistics about the parse, see [`ParseStats`](crate::parser::peg::parse_stats::ParseStats).
+    /// Collecting statistics makes parsing slower.
+    pub fn with_stats(mut self, stats: bool) -> Self {
+        self.stats = stats;
+        self
+    }
+
+    pub fn stats(&self) -> bool {
+        self.stats
+    }
+
+    /// Optimize the grammar before parsing, which is on by default. This only makes parsing faster:
+    /// the trees and errors are the same as without optimizing.
+    /// Traces and statistics always show the grammar as written.
+    pub fn with_optimize(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+
+    pub fn optimize(&self) -> bool {
+        self.optimize
+    }
+
+    /// Limits the memory used by the cache of the parser to about this many entries. When the cache grows
+    /// past it, the entries the parser can't use anymore are evicted: the ones before the position where the
+    /// outermost sort that is being parsed started, and the ones of earlier attempts to recover from errors which
+    /// depend on how the error was recovered from. Evicting never makes the parser parse anything again, so the
+    /// trees and errors are the same as without a limit.
+    pub fn with_max_memo_entries(mut self, max_memo_entries: usize) -> Self {
+        self.max_memo_entries = Some(max_memo_entries);
+        self
+    }
+
+    pub fn max_memo_entries(&self) -> Option<usize> {
+        self.max_memo_entries
+    }
+
+    /// The options that change how the grammar is desugared: the starting sort, the features and whether it is optimized.
+    pub(crate) fn desugar_options(&self) -> ParseOptions {
+        ParseOptions {
+            starting_sort: self.starting_sort.clone(),
+            features: self.features.clone(),
+            optimize: self.optimize,
+            ..ParseOptions::default()
+        }
+    }
+
+    /// Identifies how the grammar is desugared with these options, where `grammar_sort` is the starting sort of the grammar.
+    /// Starting at that sort is the same as not giving a starting sort.
+    pub(crate) fn desugar_key(&self, grammar_sort: &str) -> DesugarKey {
+        DesugarKey {
+            starting_sort: self
+                .starting_sort
+                .clone()
+                .filter(|sort| sort != grammar_sort),
+            features: self.features.iter().cloned().collect(),
+            optimize: self.optimize,
+        }
+    }
+}
+
+/// The options that change how a grammar is desugared, see [`ParseOptions::desugar_key`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct DesugarKey {
+    starting_sort: Option<String>,
+    features: BTreeSet<String>,
+    optimize: bool,
+}
+
+/// Used to cancel a parse, possibly from another thread.
+/// Clones of a token share their state, so cancelling one cancels all of them.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel all parses using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// A budget that a parse can exceed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum Budget {
+    #[display(fmt = "the maximum of {} steps", _0)]
+    Steps(u64),
+    #[display(fmt = "the deadline")]
+    Deadline,
+}
+
+/// The reason a parse stopped before it finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ParseAborted {
+    #[error("parsing was cancelled")]
+    Cancelled,
+
+    #[error("parsing exceeded {0}")]
+    BudgetExceeded(Budget),
+}
+
+/// Counts the steps of a single parse, and decides when it should stop according to the [`ParseOptions`].
+pub(crate) struct ParseLimiter<'o> {
+    options: &'o ParseOptions,
+    /// Approximate position of the stack when the parse started
+    stack_start: usize,
+    /// How much stack space the parse may use from `stack_start`
+    max_stack: usize,
+    steps: Cell<u64>,
+    aborted: Cell<Option<ParseAborted>>,
+}
+
+impl<'o> ParseLimiter<'o> {
+    pub fn new(options: &'o ParseOptions) -> Self {
+        let stack_start = stack_position();
+        let max_stack = options.max_stack.unwrap_or_else(|| match stack_end() {
+            Some(end) => {
+                let left = stack_start.saturating_sub(end);
+                left - (left / STACK_RESERVE_FRACTION)
+                    .max(MIN_STACK_RESERVE)
+                    .min(left)
+            }
+            None => usize::MAX,
+        });
+        Self {
+            options,
+            stack_start,
+            max_stack,
+            steps: Cell::new(0),
+            aborted: Cell::new(None),
+        }
+    }
+
+    /// Counts a single step. Returns false if the parse should stop.
+    pub fn step(&self) -> bool {
+        if self.aborted.get().is_some() {
+            return false;
+        }
+
+        let steps = self.steps.get() + 1;
+        self.steps.set(steps);
+
+        if let Some(max_steps) = self.options.max_steps {
+            if steps > max_steps {
+                self.abort(ParseAborted::BudgetExceeded(Budget::Steps(max_steps)));
+            }
+        }
+        // Also check on the first step, so a parse that was cancelled before it started stops immediately
+        if steps % CHECK_INTERVAL == 1 {
+            if matches!(self.options.deadline, Some(deadline) if Instant::now() >= deadline) {
+                self.abort(ParseAborted::BudgetExceeded(Budget::Deadline));
+            }
+            if matches!(&self.options.cancellation_token, Some(token) if token.is_cancelled()) {
+                self.abort(ParseAborted::Cancelled);
+            }
+        }
+
+        self.aborted.get().is_none()
+    }
+
+    fn abort(&self, reason: ParseAborted) {
+        if self.aborted.get().is_none() {
+            self.aborted.set(Some(reason));
+        }
+    }
+
+    /// How deeply sorts may be nested
+    pub fn max_depth(&self) -> usize {
+        self.options.max_depth.unwrap_or(usize::MAX)
+    }
+
+    /// Returns true if the parse uses more stack space than it may.
+    pub fn stack_exceeded(&self) -> bool {
+        self.stack_start.abs_diff(stack_position()) > self.max_stack
+    }
+
+    /// Returns why the parse stopped, if it did.
+    pub fn aborted(&self) -> Option<ParseAborted> {
+        self.aborted.get()
+    }
+}
+
+/// Approximate address of the top of the stack of the current thread.
+#[inline(always)]
+fn stack_position() -> usize {
+    let marker = 0u8;
+    std::hint::black_box(&marker) as *const u8 as usize
+}
+
+/// The lowest address of the stack of the current thread, which the stack grows down to.
+#[cfg(target_os = "linux")]
+fn stack_end() -> Option<usize> {
+    let mut attr = std::mem::MaybeUninit::<libc::pthread_attr_t>::uninit();
+    let mut addr = std::ptr::null_mut();
+    let mut size = 0;
+    // SAFETY: the attributes are only read after `pthread_getattr_np` initialized them, and destroyed after
+    let res = unsafe {
+        if libc::pthread_getattr_np(libc::pthread_self(), attr.as_mut_ptr()) != 0 {
+            return None;
+        }
+        let res = libc::pthread_attr_getstack(attr.as_ptr(), &mut addr, &mut size);
+        libc::pthread_attr_destroy(attr.as_mut_ptr());
+        res
+    };
+    (res == 0).then_some(addr as usize)
+}
+
+/// The lowest address of the stack of the current thread, which the stack grows down to.
+#[cfg(target_os = "macos")]
+fn stack_end() -> Option<usize> {
+    // SAFETY: these only read the attributes of the current thread
+    let (start, size) = unsafe {
+        let thread = libc::pthread_self();
+        (
+            libc::pthread_get_stackaddr_np(thread) as usize,
+            libc::pthread_get_stacksize_np(thread),
+        )
+    };
+    start.checked_sub(size)
+}
+
+/// The stack of the current thread is unknown on other platforms.
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn stack_end() -> Option<usize> {
+    None
+}
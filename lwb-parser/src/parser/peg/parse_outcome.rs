@@ -0,0 +1,40 @@
+use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_stats::ParseStats;
+use crate::parser::peg::parse_warning::ParseWarning;
+
+/// Everything that is known after a parse finished.
+///
+/// Depending on the level at which the parse was done, the tree is a raw parse pair,
+/// a [`ParsePairSort`](crate::codegen_prelude::ParsePairSort), or a generated AST.
+#[derive(Debug, Clone)]
+pub struct ParseOutcome<T> {
+    pub tree: T,
+    /// The errors found while parsing, in the order given by [`ParseOptions::with_error_order`](crate::parser::peg::parse_options::ParseOptions::with_error_order).
+    pub errors: Vec<PEGParseError>,
+    /// Problems that were found while parsing, which do not stop the input from being parsed.
+    /// These are given by constructors with a `warning` or `deprecated` annotation, in the order of the input.
+    /// Only parses with the grammar as it was written (so not the ones on core syntax) can find warnings.
+    pub warnings: Vec<ParseWarning>,
+    /// True when errors occurred, but the parser recovered from them and parsed the rest of the input.
+    pub recovered: bool,
+    /// Statistics of the parse, if they were requested with [`ParseOptions::with_stats`](crate::parser::peg::parse_options::ParseOptions::with_stats).
+    pub stats: Option<ParseStats>,
+}
+
+impl<T> ParseOutcome<T> {
+    /// True when the input was parsed without errors.
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Converts the tree, keeping everything else.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> ParseOutcome<U> {
+        ParseOutcome {
+            tree: f(self.tree),
+            errors: self.errors,
+            warnings: self.warnings,
+            recovered: self.recovered,
+            stats: self.stats,
+        }
+    }
+}
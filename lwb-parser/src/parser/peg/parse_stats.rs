@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Counters that were collected for a single sort while parsing.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortStats {
+    /// How many times the sort was parsed, including the times the result was memoized
+    pub invocations: u64,
+    /// How many times the result was already in the cache
+    pub memo_hits: u64,
+    /// How many times the result was not in the cache yet
+    pub memo_misses: u64,
+    /// How many cache entries were inserted
+    pub inserted: u64,
+    /// How many cache entries were removed again, because a left recursive seed was regrown
+    pub reverted: u64,
+    /// How many cache entries were evicted, because the cache grew past
+    /// [`max_memo_entries`](crate::parser::peg::parse_options::ParseOptions::with_max_memo_entries)
+    #[serde(default)]
+    pub evicted: u64,
+    /// How many times a left recursive seed was regrown
+    pub left_recursion_iterations: u64,
+    /// Time spent parsing this sort, not including the time spent in the sorts it contains
+    pub time: Duration,
+}
+
+impl SortStats {
+    pub(crate) fn add(&mut self, other: &SortStats) {
+        self.invocations += other.invocations;
+        self.memo_hits += other.memo_hits;
+        self.memo_misses += other.memo_misses;
+        self.inserted += other.inserted;
+        self.reverted += other.reverted;
+        self.evicted += other.evicted;
+        self.left_recursion_iterations += other.left_recursion_iterations;
+        self.time += other.time;
+    }
+}
+
+/// Statistics of a parse, per sort. Includes all attempts made while recovering from errors.
+/// See [`parse_file_with_stats`](crate::parser::peg::parser_sugar::parse_file_with_stats).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseStats {
+    pub sorts: BTreeMap<String, SortStats>,
+}
+
+impl ParseStats {
+    /// The counters of a sort. Sorts that were never parsed have all counters at zero.
+    pub fn sort(&self, name: &str) -> SortStats {
+        self.sorts.get(name).copied().unwrap_or_default()
+    }
+
+    /// The counters of all sorts added together.
+    pub fn total(&self) -> SortStats {
+        let mut total = SortStats::default();
+        for stats in self.sorts.values() {
+            total.add(stats);
+        }
+        total
+    }
+
+    pub(crate) fn sort_mut(&mut self, name: &str) -> &mut SortStats {
+        //Don't allocate a new string for every counter that is updated
+        if !self.sorts.contains_key(name) {
+            self.sorts.insert(name.to_string(), SortStats::default());
+        }
+        self.sorts.get_mut(name).unwrap()
+    }
+
+    /// Renders the statistics as a table, with the sorts that took the most time first.
+    pub fn render_table(&self) -> String {
+        let mut sorts = self.sorts.iter().collect::<Vec<_>>();
+        sorts.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.time));
+
+        let width = sorts
+            .iter()
+            .map(|(name, _)| name.len())
+            .chain(["sort".len(), "total".len()])
+            .max()
+            .unwrap();
+        let total = self.total();
+
+        let mut res = String::new();
+        let _ = writeln!(
+            res,
+            "{:width$} {:>12} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>12}",
+            "sort",
+            "invocations",
+            "hits",
+            "misses",
+            "inserted",
+            "reverted",
+            "evicted",
+            "leftrec",
+            "time"
+        );
+        for (name, stats) in sorts.into_iter().chain([(&"total".to_string(), &total)]) {
+            let _ = writeln!(
+                res,
+                "{:width$} {:>12} {:>10} {:>10} {:>10} {:>10} {:>10} {:>10} {:>12}",
+                name,
+                stats.invocations,
+                stats.memo_hits,
+                stats.memo_misses,
+                stats.inserted,
+                stats.reverted,
+                stats.evicted,
+                stats.left_recursion_iterations,
+                format!("{:.3?}", stats.time)
+            );
+        }
+        res
+    }
+
+    /// Renders the statistics as json, so they can be processed by other tools.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("stats can always be serialized")
+    }
+}
@@ -0,0 +1,265 @@
+use crate::sources::source_file::SourceFile;
+use std::fmt::{Debug, Display, Formatter, Write};
+use std::sync::{Arc, Mutex};
+
+/// Something that happened while parsing. All positions are byte offsets into the parsed file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// Started parsing a sort
+    Enter { sort: String, position: usize },
+    /// Finished parsing a sort. `end` is the position after the sort when it was successful,
+    /// or the position where it failed otherwise.
+    Exit {
+        sort: String,
+        position: usize,
+        end: usize,
+        ok: bool,
+    },
+    /// The result of parsing a sort at this position was already known
+    MemoHit {
+        sort: String,
+        position: usize,
+        end: usize,
+        ok: bool,
+    },
+    /// A left recursive sort managed to parse more input
+    SeedGrown {
+        sort: String,
+        position: usize,
+        end: usize,
+    },
+    /// One of the alternatives of a choice was tried
+    Alternative {
+        sort: String,
+        index: usize,
+        position: usize,
+        end: usize,
+        ok: bool,
+    },
+    /// Something that was expected was not found
+    Expected { position: usize, expected: String },
+    /// Layout was skipped
+    Layout { position: usize, end: usize },
+    /// The parser started again, to recover from an error at `position` by skipping `skip` characters
+    Retry { position: usize, skip: usize },
+}
+
+impl Display for TraceEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TraceEvent::Enter { sort, position } => write!(f, "enter {sort} at {position}"),
+            TraceEvent::Exit {
+                sort,
+                position,
+                end,
+                ok,
+            } => write!(f, "{} {sort} {position}..{end}", result(*ok)),
+            TraceEvent::MemoHit {
+                sort,
+                position,
+                end,
+                ok,
+            } => write!(f, "{} {sort} {position}..{end} (memoized)", result(*ok)),
+            TraceEvent::SeedGrown { sort, end, .. } => {
+                write!(f, "left recursive {sort} grown to {end}")
+            }
+            TraceEvent::Alternative {
+                sort,
+                index,
+                position,
+                end,
+                ok,
+            } => write!(
+                f,
+                "{} alternative {index} of {sort} {position}..{end}",
+                result(*ok)
+            ),
+            TraceEvent::Expected { position, expected } => {
+                write!(f, "expected {expected} at {position}")
+            }
+            TraceEvent::Layout { position, end } => write!(f, "layout {position}..{end}"),
+            TraceEvent::Retry { position, skip } => write!(
+                f,
+                "retry, skipping {skip} characters at the error at {position}"
+            ),
+        }
+    }
+}
+
+fn result(ok: bool) -> &'static str {
+    if ok {
+        "ok"
+    } else {
+        "failed"
+    }
+}
+
+/// Receives the events of a parse. See [`ParseOptions::with_trace`](crate::parser::peg::parse_options::ParseOptions::with_trace).
+pub trait TraceSink: Debug + Send + Sync {
+    fn event(&self, event: TraceEvent);
+}
+
+/// A trace sink that records all events, so they can be rendered after parsing.
+///
+/// ```
+/// # use lwb_parser::language::Language;
+/// # use lwb_parser::parser::peg::parse_options::ParseOptions;
+/// # use lwb_parser::parser::peg::parse_trace::Trace;
+/// # use lwb_parser::parser::syntax_file::SyntaxFile;
+/// # use lwb_parser::sources::source_file::SourceFile;
+/// let trace = Trace::new();
+/// let options = ParseOptions::new().with_trace(trace.clone());
+///
+/// let sf = SourceFile::new("a = \"a\";\nstart at a;\n", "test.syntax");
+/// SyntaxFile::try_parse_with_options(&sf, &options).unwrap();
+///
+/// println!("{}", trace.render_terminal(&sf));
+/// let html = trace.render_html(&sf);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Trace(Arc<Mutex<Vec<TraceEvent>>>);
+
+impl TraceSink for Trace {
+    fn event(&self, event: TraceEvent) {
+        self.0.lock().unwrap().push(event);
+    }
+}
+
+impl Trace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// All events that were recorded so far, in order.
+    pub fn events(&self) -> Vec<TraceEvent> {
+        self.0.lock().unwrap().clone()
+    }
+
+    /// Builds a tree out of the events, in which every sort contains the events that happened while parsing it.
+    pub fn tree(&self) -> Vec<TraceTree> {
+        let mut stack = vec![vec![]];
+        for event in self.events() {
+            match event {
+                TraceEvent::Enter { .. } => stack.push(vec![]),
+                TraceEvent::Exit { .. } if stack.len() > 1 => {
+                    let children = stack.pop().unwrap();
+                    stack.last_mut().unwrap().push(TraceTree::Sort {
+                        exit: event,
+                        children,
+                    });
+                }
+                _ => stack.last_mut().unwrap().push(TraceTree::Event(event)),
+            }
+        }
+
+        //Sorts that were never exited (because parsing was aborted) are closed here
+        while stack.len() > 1 {
+            let children = stack.pop().unwrap();
+            stack.last_mut().unwrap().extend(children);
+        }
+        stack.pop().unwrap()
+    }
+
+    /// Renders the trace as an indented tree, to print on a terminal.
+    /// Every line shows the text that was parsed, from `file`.
+    pub fn render_terminal(&self, file: &SourceFile) -> String {
+        fn render(res: &mut String, trees: &[TraceTree], file: &SourceFile, indent: usize) {
+            for tree in trees {
+                let (event, children) = match tree {
+                    TraceTree::Sort { exit, children } => (exit, &children[..]),
+                    TraceTree::Event(event) => (event, &[][..]),
+                };
+                let _ = write!(res, "{}{event}", "  ".repeat(indent));
+                if let Some(text) = event_text(event, file) {
+                    let _ = write!(res, " {text:?}");
+                }
+                res.push('\n');
+                render(res, children, file, indent + 1);
+            }
+        }
+
+        let mut res = String::new();
+        render(&mut res, &self.tree(), file, 0);
+        res
+    }
+
+    /// Renders the trace as a html page, in which the tree can be navigated by folding and unfolding sorts.
+    /// Failed sorts are shown in red.
+    pub fn render_html(&self, file: &SourceFile) -> String {
+        fn render(res: &mut String, trees: &[TraceTree], file: &SourceFile) {
+            for tree in trees {
+                match tree {
+                    TraceTree::Sort { exit, children } => {
+                        let class = match exit {
+                            TraceEvent::Exit { ok: true, .. } => "ok",
+                            _ => "failed",
+                        };
+                        let _ = write!(
+                            res,
+                            "<details><summary class=\"{class}\">{}{}</summary>",
+                            escape_html(&exit.to_string()),
+                            event_text(exit, file)
+                                .map(|t| format!(" <code>{}</code>", escape_html(t)))
+                                .unwrap_or_default()
+                        );
+                        render(res, children, file);
+                        res.push_str("</details>");
+                    }
+                    TraceTree::Event(event) => {
+                        let _ = write!(res, "<div>{}</div>", escape_html(&event.to_string()));
+                    }
+                }
+            }
+        }
+
+        let mut res = String::from(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>parse trace</title><style>\
+            body { font-family: monospace; } \
+            details, div { margin-left: 1.5em; } \
+            .ok { color: green; } \
+            .failed { color: darkred; } \
+            code { background: #eee; }\
+            </style></head><body>",
+        );
+        render(&mut res, &self.tree(), file);
+        res.push_str("</body></html>");
+        res
+    }
+}
+
+/// The events of a parse, as a tree.
+#[derive(Debug, Clone)]
+pub enum TraceTree {
+    /// A sort that was parsed, with its exit event and everything that happened while parsing it.
+    Sort {
+        exit: TraceEvent,
+        children: Vec<TraceTree>,
+    },
+    Event(TraceEvent),
+}
+
+/// The text that was parsed successfully by this event, if any.
+fn event_text<'a>(event: &TraceEvent, file: &'a SourceFile) -> Option<&'a str> {
+    match event {
+        TraceEvent::Exit {
+            position,
+            end,
+            ok: true,
+            ..
+        }
+        | TraceEvent::MemoHit {
+            position,
+            end,
+            ok: true,
+            ..
+        } => Some(&file.contents()[file.str_offset(*position)..file.str_offset(*end)]),
+        _ => None,
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,59 @@
+use crate::sources::span::Span;
+use miette::{Diagnostic, LabeledSpan, Severity, SourceCode};
+use std::fmt::{Display, Formatter};
+use thiserror::Error;
+
+/// A warning that was given by the grammar, because a constructor with a
+/// `warning` or `deprecated` annotation was parsed. Unlike a [`PEGParseError`](crate::parser::peg::parse_error::PEGParseError),
+/// a warning does not stop the input from being parsed.
+#[derive(Debug, Clone, Error)]
+pub struct ParseWarning {
+    /// The part of the input that was parsed by the constructor
+    pub span: Span,
+    pub sort: String,
+    pub constructor: String,
+    /// The message from the annotation
+    pub message: String,
+    /// True when the warning came from a `deprecated` annotation
+    pub deprecated: bool,
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.deprecated {
+            write!(f, "`{}` {} is deprecated", self.constructor, self.sort)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+impl Diagnostic for ParseWarning {
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Warning)
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.span)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        let label = if self.deprecated {
+            "deprecated"
+        } else {
+            "warning given here"
+        };
+        Some(Box::new(
+            vec![LabeledSpan::new_with_span(
+                Some(label.to_string()),
+                self.span.clone(),
+            )]
+            .into_iter(),
+        ))
+    }
+
+    fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+        self.deprecated
+            .then(|| Box::new(&self.message) as Box<dyn Display + 'a>)
+    }
+}
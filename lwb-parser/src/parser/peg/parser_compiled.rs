@@ -0,0 +1,303 @@
+use crate::codegen_prelude::ParsePairSort;
+use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_options::{DesugarKey, ParseAborted, ParseOptions};
+use crate::parser::peg::parse_outcome::ParseOutcome;
+use crate::parser::peg::parser_core_ast::{CoreAst, ParsePairRaw};
+use crate::parser::peg::parser_core_file;
+use crate::parser::peg::parser_native::NativeParser;
+use crate::parser::peg::parser_stream::ParseMany;
+use crate::parser::peg::parser_sugar::{
+    collect_warnings, desugar_ast, resugar_outcome, resugar_sort,
+};
+use crate::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use crate::parser::syntax_file::ParseError;
+use crate::sources::source_file::SourceFile;
+use crate::sources::stream::ChunkedInput;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Read;
+use std::sync::{Arc, Mutex};
+
+/// A grammar that is ready to parse with. Parsing a file with a [`SyntaxFileAst`] desugars it first,
+/// which takes longer than parsing a small file. A compiled parser is desugared once, and can then
+/// parse many files, also from several threads at the same time.
+///
+/// The grammar is desugared for the starting sort, the features and the optimization in the options it
+/// was compiled with. The first parse with other options for these desugars the grammar again, and
+/// keeps the result for later parses with the same options.
+///
+/// Every [`Language`](crate::language::Language) has one, see
+/// [`compiled_parser`](crate::language::Language::compiled_parser).
+///
+/// ```
+/// # use lwb_parser::language::Language;
+/// # use lwb_parser::parser::peg::parse_options::ParseOptions;
+/// # use lwb_parser::parser::peg::parser_compiled::CompiledParser;
+/// # use lwb_parser::parser::syntax_file::convert_syntax_file_ast::convert;
+/// # use lwb_parser::parser::syntax_file::SyntaxFile;
+/// # use lwb_parser::sources::source_file::SourceFile;
+/// let sf = SourceFile::new("a = \"a\"*;\nstart at a;\n", "test.syntax");
+/// let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+/// let parser = CompiledParser::new(&ast);
+///
+/// std::thread::scope(|s| {
+///     for input in ["a", "aa", "aaa"] {
+///         let parser = &parser;
+///         s.spawn(move || {
+///             let input = SourceFile::new(input, "input");
+///             let outcome = parser.parse_file_outcome(&input, &ParseOptions::new()).unwrap();
+///             assert!(outcome.errors.is_empty());
+///         });
+///     }
+/// });
+/// ```
+#[derive(Debug)]
+pub struct CompiledParser<'a> {
+    ast: &'a SyntaxFileAst,
+    /// The grammar desugared for every set of options it was parsed with
+    core_asts: Mutex<HashMap<DesugarKey, Arc<CoreAst<'a>>>>,
+    /// The options the grammar was compiled with, see [`ParseOptions::desugar_options`]
+    options: ParseOptions,
+    native: Option<&'a NativeParser>,
+}
+
+impl Clone for CompiledParser<'_> {
+    fn clone(&self) -> Self {
+        Self {
+            ast: self.ast,
+            core_asts: Mutex::new(self.core_asts.lock().unwrap().clone()),
+            options: self.options.clone(),
+            native: self.native,
+        }
+    }
+}
+
+impl<'a> CompiledParser<'a> {
+    pub fn new(ast: &'a SyntaxFileAst) -> Self {
+        Self::with_options(ast, &ParseOptions::default())
+    }
+
+    /// Like [`new`](CompiledParser::new), but desugars the grammar for the starting sort, features
+    /// and optimization of the options.
+    ///
+    /// Panics when the options give a starting sort which does not exist.
+    pub fn with_options(ast: &'a SyntaxFileAst, options: &ParseOptions) -> Self {
+        let core_ast = Arc::new(desugar_ast(ast, options));
+        Self {
+            ast,
+            core_asts: Mutex::new(HashMap::from([(
+                options.desugar_key(&ast.starting_sort),
+                core_ast,
+            )])),
+            options: options.desugar_options(),
+            native: None,
+        }
+    }
+
+    /// Parses with the parser that was generated as Rust code for the grammar, if it is given.
+    /// The outcome of a parse is the same, see [`NativeParser`].
+    pub fn with_native(mut self, native: Option<&'a NativeParser>) -> Self {
+        self.native = native;
+        self
+    }
+
+    pub fn ast(&self) -> &'a SyntaxFileAst {
+        self.ast
+    }
+
+    /// The grammar desugared for the options, which is only desugared the first time it is parsed with them.
+    pub(crate) fn core_ast(&self, options: &ParseOptions) -> Arc<CoreAst<'a>> {
+        let key = options.desugar_key(&self.ast.starting_sort);
+        if let Some(core_ast) = self.core_asts.lock().unwrap().get(&key) {
+            return core_ast.clone();
+        }
+
+        //Desugar without holding the lock, so parses with other options don't wait for it
+        let core_ast = Arc::new(desugar_ast(self.ast, options));
+        self.core_asts
+            .lock()
+            .unwrap()
+            .entry(key)
+            .or_insert(core_ast)
+            .clone()
+    }
+
+    /// Parses a file, like [`parse_file_outcome`](crate::parser::peg::parser_sugar::parse_file_outcome).
+    ///
+    /// Panics when the options give a starting sort which does not exist.
+    pub fn parse_file_outcome(
+        &self,
+        file: &SourceFile,
+        options: &ParseOptions,
+    ) -> Result<ParseOutcome<ParsePairSort<'a>>, ParseAborted> {
+        let outcome = self.parse_raw_outcome(file, options)?;
+        Ok(resugar_outcome(self.ast, file, options, outcome))
+    }
+
+    /// Like [`parse_file_outcome`](CompiledParser::parse_file_outcome), but returns the result of the
+    /// parser as is, without resugaring it.
+    pub fn parse_raw_outcome(
+        &self,
+        file: &SourceFile,
+        options: &ParseOptions,
+    ) -> Result<ParseOutcome<ParsePairRaw>, ParseAborted> {
+        let core_ast = self.core_ast(options);
+        let mut outcome =
+            parser_core_file::parse_file_outcome_native(&core_ast, file, options, self.native)?;
+        collect_warnings(self.ast, file, options, &mut outcome);
+        Ok(outcome)
+    }
+
+    /// Parses a single sort at byte `offset` in a file, like [`parse_prefix`](crate::parser::peg::parser_sugar::parse_prefix).
+    /// The grammar is desugared again the first time a sort is parsed which is not the starting sort it was compiled with.
+    pub fn parse_prefix(
+        &self,
+        sort: &str,
+        file: &SourceFile,
+        offset: usize,
+    ) -> Result<(ParsePairSort<'a>, usize, Vec<PEGParseError>), ParseError> {
+        self.parse_prefix_with_options(sort, file, offset, &self.options)
+    }
+
+    /// Like [`parse_prefix`](CompiledParser::parse_prefix), but the parse stops early when it exceeds the
+    /// limits given in the options. The starting sort of the options is replaced by `sort`.
+    pub fn parse_prefix_with_options(
+        &self,
+        sort: &str,
+        file: &SourceFile,
+        offset: usize,
+        options: &ParseOptions,
+    ) -> Result<(ParsePairSort<'a>, usize, Vec<PEGParseError>), ParseError> {
+        let (res, end, errs) = self.parse_raw_prefix_with_options(sort, file, offset, options)?;
+        let sort = &self.ast.sorts[sort];
+        Ok((resugar_sort(self.ast, file, sort, &res), end, errs))
+    }
+
+    /// Like [`parse_prefix`](CompiledParser::parse_prefix), but returns the result of the parser as is,
+    /// without resugaring it.
+    pub fn parse_raw_prefix(
+        &self,
+        sort: &str,
+        file: &SourceFile,
+        offset: usize,
+    ) -> Result<(ParsePairRaw, usize, Vec<PEGParseError>), ParseError> {
+        self.parse_raw_prefix_with_options(sort, file, offset, &self.options)
+    }
+
+    /// Like [`parse_prefix_with_options`](CompiledParser::parse_prefix_with_options), but returns the result
+    /// of the parser as is, without resugaring it.
+    pub fn parse_raw_prefix_with_options(
+        &self,
+        sort: &str,
+        file: &SourceFile,
+        offset: usize,
+        options: &ParseOptions,
+    ) -> Result<(ParsePairRaw, usize, Vec<PEGParseError>), ParseError> {
+        if !self.ast.sorts.contains_key(sort) {
+            return Err(ParseError::UnknownSort(sort.to_string()));
+        }
+        let options = options.clone().with_starting_sort(sort);
+        let core_ast = self.core_ast(&options);
+
+        let sort_id = core_ast.sort_id(sort).expect("sort exists");
+        let (res, end, err) =
+            parser_core_file::parse_prefix(&core_ast, file, sort_id, offset, &options)?;
+
+        Ok((res, end, err.into_iter().collect()))
+    }
+
+    /// Parses a stream that contains many items, like [`parse_many`](crate::parser::peg::parser_stream::parse_many).
+    pub fn parse_many<R: Read>(&'a self, input: ChunkedInput<R>) -> ParseMany<'a, R> {
+        self.parse_many_with_options(input, &self.options)
+    }
+
+    /// Like [`parse_many`](CompiledParser::parse_many), but every item is parsed with the options,
+    /// see [`parse_many_with_options`](crate::parser::peg::parser_stream::parse_many_with_options).
+    pub fn parse_many_with_options<R: Read>(
+        &'a self,
+        input: ChunkedInput<R>,
+        options: &ParseOptions,
+    ) -> ParseMany<'a, R> {
+        ParseMany::new(Cow::Borrowed(self), input, options.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::Language;
+    use crate::parser::peg::parse_options::ParseOptions;
+    use crate::parser::peg::parser_compiled::CompiledParser;
+    use crate::parser::peg::parser_core_ast::{CoreExpression, RepeatId};
+    use crate::parser::syntax_file::convert_syntax_file_ast::convert;
+    use crate::parser::syntax_file::SyntaxFile;
+    use crate::sources::source_file::SourceFile;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_core_ast_cached() {
+        let sf = SourceFile::new("a = b*;\nb = \"b\";\nstart at a;\n", "test.syntax");
+        let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+        let parser = CompiledParser::new(&ast);
+
+        //Starting at the starting sort of the grammar is the same as not giving one
+        let default = parser.core_ast(&ParseOptions::new());
+        let starting_sort = ParseOptions::new().with_starting_sort("a");
+        assert!(Arc::ptr_eq(&default, &parser.core_ast(&starting_sort)));
+
+        for options in [
+            ParseOptions::new().with_starting_sort("b"),
+            ParseOptions::new().with_feature("f"),
+            ParseOptions::new().with_optimize(false),
+        ] {
+            let core_ast = parser.core_ast(&options);
+            assert!(!Arc::ptr_eq(&default, &core_ast));
+            assert!(Arc::ptr_eq(&core_ast, &parser.core_ast(&options)));
+        }
+
+        //Parsing a prefix of another sort keeps the grammar desugared for it
+        let file = SourceFile::new("bb", "input");
+        parser.parse_raw_prefix("b", &file, 0).unwrap();
+        let prefix = parser.core_ast(&ParseOptions::new().with_starting_sort("b"));
+        parser.parse_raw_prefix("b", &file, 1).unwrap();
+        assert!(Arc::ptr_eq(
+            &prefix,
+            &parser.core_ast(&ParseOptions::new().with_starting_sort("b"))
+        ));
+        assert_eq!(parser.core_asts.lock().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_repeat_ids() {
+        fn repeat_ids(expr: &CoreExpression, ids: &mut Vec<RepeatId>) {
+            match expr {
+                CoreExpression::Sequence(subexprs) | CoreExpression::Choice(subexprs, _) => {
+                    subexprs.iter().for_each(|e| repeat_ids(e, ids))
+                }
+                CoreExpression::Repeat { subexpr, id, .. } => {
+                    ids.push(*id);
+                    repeat_ids(subexpr, ids);
+                }
+                CoreExpression::FlagNoLayout(subexpr)
+                | CoreExpression::FlagNoErrors(subexpr, _) => repeat_ids(subexpr, ids),
+                _ => {}
+            }
+        }
+        let syntax = "a:\n    x = b* \"x\";\n    y = (b \"y\")*;\nb = \"b\"?;\nstart at a;\n";
+        let sf = SourceFile::new(syntax, "test.syntax");
+        let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+        let parser = CompiledParser::new(&ast);
+        let ids = |options: &ParseOptions| {
+            let mut ids = vec![];
+            for sort in &parser.core_ast(options).sorts {
+                repeat_ids(&sort.expr, &mut ids);
+            }
+            ids
+        };
+
+        //Every repetition has its own id, which it keeps when the grammar is desugared again
+        let expected = ids(&ParseOptions::new());
+        assert_eq!(expected, [RepeatId(0), RepeatId(1), RepeatId(2)]);
+        assert_eq!(ids(&ParseOptions::new().with_feature("f")), expected);
+        assert_eq!(ids(&ParseOptions::new().with_optimize(false)), expected);
+    }
+}
@@ -1,22 +1,113 @@
-use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_error::{ErrorContext, PEGParseError, MAX_ERROR_CONTEXT};
+use crate::parser::peg::parse_options::{ParseLimiter, ParseOptions};
 use crate::parser::peg::parse_result::ParseResult;
-use crate::parser::peg::parser_core_ast::{CoreAst, ParsePairRaw};
+use crate::parser::peg::parse_stats::{ParseStats, SortStats};
+use crate::parser::peg::parse_trace::{TraceEvent, TraceSink};
+use crate::parser::peg::parser_core_ast::{CoreAst, CoreSort, ParsePairRaw, RepeatId, SortId};
+use crate::parser::peg::parser_incremental::{Memo, Reuse};
+use crate::parser::peg::parser_native::NativeParser;
 use crate::sources::source_file::SourceFile;
+use std::cell::RefCell;
 use std::collections::{HashMap, VecDeque};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// This stores the immutable data that is used during the parsing process.
 pub struct ParserContext<'src> {
     pub(crate) file: &'src SourceFile,
     pub(crate) ast: &'src CoreAst<'src>,
+    pub(crate) options: &'src ParseOptions,
     pub errors: HashMap<usize, usize>,
+    /// Shared with the contexts made by [`for_errors`](ParserContext::for_errors)
+    pub(crate) limiter: Rc<ParseLimiter<'src>>,
+    pub(crate) trace: Option<&'src dyn TraceSink>,
+    /// Statistics of all parser states used in this parse, if they are collected
+    pub(crate) stats: Option<RefCell<ParseStats>>,
+    /// The results of the previous parse that can be reused, when reparsing incrementally
+    pub(crate) reuse: Option<Reuse<'src>>,
+    /// Where the memo of the first attempt of the parse is kept, if it is asked for
+    pub(crate) memo: Option<RefCell<Option<Memo>>>,
+    /// How many entries the cache of a parser state may have before entries are evicted
+    pub(crate) max_memo_entries: Option<usize>,
+    /// The parser generated as Rust code for the grammar, if there is one
+    pub(crate) native: Option<&'src NativeParser>,
+}
+
+impl<'src> ParserContext<'src> {
+    pub(crate) fn new(
+        file: &'src SourceFile,
+        ast: &'src CoreAst<'src>,
+        options: &'src ParseOptions,
+    ) -> Self {
+        Self {
+            file,
+            ast,
+            options,
+            errors: HashMap::new(),
+            limiter: Rc::new(ParseLimiter::new(options)),
+            trace: options.trace(),
+            stats: None,
+            reuse: None,
+            memo: None,
+            max_memo_entries: options.max_memo_entries(),
+            native: None,
+        }
+    }
+
+    /// A context to parse with outside of the parse, like [`skip_layout_for_errors`](crate::parser::peg::parser_core_file::skip_layout_for_errors).
+    /// It is not traced, doesn't reuse results and doesn't skip the errors that were recovered from,
+    /// but it shares the limiter of this context, so its steps and stack space count for the parse.
+    pub(crate) fn for_errors(&self) -> Self {
+        Self {
+            file: self.file,
+            ast: self.ast,
+            options: self.options,
+            errors: HashMap::new(),
+            limiter: self.limiter.clone(),
+            trace: None,
+            stats: None,
+            reuse: None,
+            memo: None,
+            max_memo_entries: self.max_memo_entries,
+            native: None,
+        }
+    }
+
+    /// Sends an event to the trace sink, if there is one.
+    /// The event is only created when it is traced.
+    pub(crate) fn trace(&self, event: impl FnOnce() -> TraceEvent) {
+        if let Some(trace) = self.trace {
+            trace.event(event());
+        }
+    }
+
+    /// Adds the statistics collected by a parser state to the statistics of the parse.
+    pub(crate) fn merge_stats(&self, cache: &ParserState<'src>) {
+        if let (Some(stats), Some(cache_stats)) = (&self.stats, &cache.stats) {
+            let mut stats = stats.borrow_mut();
+            for (sort, sort_stats) in self.ast.sorts.iter().zip(cache_stats) {
+                //Sorts that were never parsed are left out
+                if *sort_stats != SortStats::default() {
+                    stats.sort_mut(sort.name).add(sort_stats);
+                }
+            }
+        }
+    }
 }
 
 /// This stores the mutable data that is used during the parsing process.
 /// It contains a cache of the results of each (source position, rule).
 /// It also has a stack which contains information about the order in which the keys were inserted, so they can be removed in order when needed.
+/// Each key on the stack is numbered, so the stack can still be reverted after entries were evicted from it.
 pub struct ParserState<'src> {
-    pub(crate) cache: HashMap<(usize, &'src str), ParserCacheEntry<'src>>,
-    pub(crate) cache_stack: VecDeque<(usize, &'src str)>,
+    pub(crate) cache: HashMap<(usize, SortId), ParserCacheEntry<'src>>,
+    pub(crate) cache_stack: VecDeque<(usize, (usize, SortId))>,
+    // How many entries were inserted into the cache, which is the number of the next entry on the cache stack
+    inserted: usize,
+    // The number of entries in the cache at which entries are evicted, if there is a limit
+    max_entries: Option<usize>,
+    evict_at: usize,
     pub best_error: Option<PEGParseError>,
     // True if layout should be allowed at the moment
     pub allow_layout: bool,
@@ -24,56 +115,469 @@ pub struct ParserState<'src> {
     pub no_layout_nest_count: usize,
     // How many times no errors has been nested
     pub no_errors_nest_count: usize,
+    // How many sorts are currently being parsed inside each other
+    pub depth: usize,
+    // The sorts that are currently being parsed, innermost last. Errors record the innermost of these.
+    pub(crate) context_stack: Vec<ContextFrame<'src>>,
+    // Statistics per sort, by the id of the sort. Only collected when this is not None
+    pub(crate) stats: Option<Vec<SortStats>>,
+    // Time spent in the sorts that are contained in the sort currently being parsed
+    child_time: Duration,
+    // How far the sort currently being parsed looked into the input, see `examine`
+    pub(crate) examined: usize,
+    // The index in the context stack of the outermost sort whose result depends on an unfinished
+    // left recursive result, so it can't be reused by an incremental reparse. usize::MAX if there is none.
+    tainted_from: usize,
+    // True if alternatives of choices are skipped when they can't match the next character.
+    // They don't report the errors they would have given, so this is only done while the errors are not needed yet.
+    pub(crate) predict: bool,
+    // True if sorts are parsed with their optimized expression. Like skipping alternatives, this is only
+    // done until the parse fails, since the errors are found with the grammar as written.
+    pub(crate) optimized: bool,
+    // For every attempt to parse the file after recovering from errors (see `retry`), the lowest position of the
+    // errors that are recovered from in this and later attempts. Entries of an attempt are only valid in a later
+    // attempt if they only looked at input before that position. The number of attempts is the length of this.
+    recovered_before: Vec<usize>,
+    // The items parsed by repetitions while recovering from errors, by the id of the repetition
+    // and its position. Attempts after the first resume repetitions from here.
+    repeats: HashMap<(RepeatId, usize), RepeatProgress>,
+}
+
+/// The items that were parsed by a repetition, see [`ParserState::resume_repeat`].
+pub(crate) struct RepeatProgress {
+    /// The attempt in which the items were last checked to be valid
+    attempt: usize,
+    /// The flags the repetition was parsed with, since these change the result
+    allow_layout: bool,
+    no_layout: bool,
+    no_errors: bool,
+    pub items: Vec<RepeatItem>,
+}
+
+/// An item parsed by a repetition, and the state of the repetition after it.
+#[derive(Clone)]
+pub(crate) struct RepeatItem {
+    pub result: ParsePairRaw,
+    pub pos: usize,
+    pub pos_err: usize,
+    pub recovered: bool,
+    /// The end of the input that was looked at by the repetition up to and including this item (exclusive)
+    pub examined: usize,
+}
+
+/// A sort that is currently being parsed, see [`ErrorContext`].
+pub(crate) struct ContextFrame<'src> {
+    pub sort: &'src CoreSort<'src>,
+    pub constructor: Option<&'src str>,
+    pub position: usize,
+}
+
+/// Returned by [`ParserState::stats_enter`], to be passed to [`ParserState::stats_exit`].
+pub(crate) struct StatsTimer {
+    start: Instant,
+    outer_child_time: Duration,
 }
 
 /// A single entry in the cache. Contains the value, and a flag whether it has been read.
 pub struct ParserCacheEntry<'src> {
     read: bool,
-    value: ParseResult<'src, ParsePairRaw>,
+    value: ParseResult<'src, Arc<ParsePairRaw>>,
+    /// The attempt in which the entry was inserted or last checked to be valid, see [`ParserState::retry`]
+    attempt: usize,
+    /// None while the entry is still being computed, or when it can't be reused
+    pub(crate) reuse: Option<ReuseInfo>,
+}
+
+impl ParserCacheEntry<'_> {
+    /// Whether the entry can be used in the current attempt, given the `recovered_before` of the state.
+    fn valid_in(&self, recovered_before: &[usize]) -> bool {
+        self.attempt == recovered_before.len()
+            || matches!(self.reuse, Some(info) if info.examined < recovered_before[self.attempt])
+    }
+}
+
+/// What an incremental reparse needs to know to decide whether a cache entry can be reused.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReuseInfo {
+    /// The end of the input that was looked at to compute the entry (exclusive)
+    pub examined: usize,
+    /// The flags the entry was computed with, since these change the result
+    pub allow_layout: bool,
+    pub no_layout: bool,
+    /// Whether layout was allowed after the entry was computed
+    pub allow_layout_after: bool,
 }
 
 impl<'src> ParserState<'src> {
+    /// Create a new state with an empty cache
+    pub(crate) fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            cache_stack: VecDeque::new(),
+            inserted: 0,
+            max_entries: None,
+            evict_at: usize::MAX,
+            best_error: None,
+            no_layout_nest_count: 0usize,
+            no_errors_nest_count: 0usize,
+            allow_layout: true,
+            depth: 0,
+            context_stack: vec![],
+            stats: None,
+            child_time: Duration::ZERO,
+            examined: 0,
+            tainted_from: usize::MAX,
+            predict: false,
+            optimized: false,
+            recovered_before: vec![],
+            repeats: HashMap::new(),
+        }
+    }
+
+    /// Create a new state, which collects statistics if the context does.
+    /// Alternatives are skipped until the parse fails, but not while recovering from errors,
+    /// and not when the parse is traced, so the trace shows every alternative that is tried.
+    pub(crate) fn for_context(state: &ParserContext<'src>) -> Self {
+        let mut cache = Self::new();
+        if state.stats.is_some() {
+            cache.stats = Some(vec![SortStats::default(); state.ast.sorts.len()]);
+        }
+        cache.max_entries = state.max_memo_entries;
+        cache.evict_at = state.max_memo_entries.unwrap_or(usize::MAX);
+        cache.predict = state.errors.is_empty() && state.trace.is_none();
+        cache.optimized = cache.predict && state.stats.is_none();
+        cache
+    }
+
+    /// Create a new state to parse again after a parse with skipped alternatives failed,
+    /// which reports every error using the grammar as written.
+    pub(crate) fn for_errors(state: &ParserContext<'src>) -> Self {
+        let mut cache = Self::for_context(state);
+        cache.predict = false;
+        cache.optimized = false;
+        cache
+    }
+
+    /// Counts an invocation of a sort which is not cached, and starts timing it.
+    pub(crate) fn stats_enter(&mut self, sort: SortId) -> Option<StatsTimer> {
+        let stats = self.stats.as_mut()?;
+        stats[sort.0].invocations += 1;
+        Some(StatsTimer {
+            start: Instant::now(),
+            outer_child_time: std::mem::take(&mut self.child_time),
+        })
+    }
+
+    /// Stops timing a sort. Only the time that was not spent in contained sorts is counted for the sort itself.
+    pub(crate) fn stats_exit(&mut self, sort: SortId, timer: Option<StatsTimer>) {
+        if let (Some(stats), Some(timer)) = (self.stats.as_mut(), timer) {
+            let elapsed = timer.start.elapsed();
+            stats[sort.0].time += elapsed.saturating_sub(self.child_time);
+            self.child_time = timer.outer_child_time + elapsed;
+        }
+    }
+
+    /// Counts a single iteration of growing a left recursive seed.
+    pub(crate) fn stats_left_recursion(&mut self, sort: SortId) {
+        if let Some(stats) = self.stats.as_mut() {
+            stats[sort.0].left_recursion_iterations += 1;
+        }
+    }
+
     /// Get a mutable reference to an entry
     pub fn get_mut(
         &mut self,
-        key: &(usize, &'src str),
-    ) -> Option<&mut ParseResult<'src, ParsePairRaw>> {
-        if let Some(v) = self.cache.get_mut(key) {
-            v.read = true;
-            Some(&mut v.value)
-        } else {
-            None
+        key: &(usize, SortId),
+    ) -> Option<&mut ParseResult<'src, Arc<ParsePairRaw>>> {
+        //Entries of earlier attempts are removed when they depend on how an error was recovered from
+        let attempt = self.attempt();
+        let valid = self
+            .cache
+            .get(key)
+            .map(|v| v.valid_in(&self.recovered_before));
+        if valid == Some(false) {
+            self.cache.remove(key);
         }
+
+        if let Some(stats) = self.stats.as_mut() {
+            let stats = &mut stats[key.1 .0];
+            if valid == Some(true) {
+                stats.memo_hits += 1;
+                stats.invocations += 1;
+            } else {
+                stats.memo_misses += 1;
+            }
+        }
+        let v = self.cache.get_mut(key)?;
+        v.read = true;
+        v.attempt = attempt;
+        match v.reuse {
+            Some(info) => self.examined = self.examined.max(info.examined),
+            None => {
+                //The result is still being computed, so everything inside the sort that computes it depends on it.
+                //Otherwise it can't be reused itself, and neither can anything that reads it.
+                let frame = self
+                    .context_stack
+                    .iter()
+                    .rposition(|f| f.position == key.0 && f.sort.id == key.1);
+                self.tainted_from = self.tainted_from.min(frame.map_or(0, |i| i + 1));
+            }
+        }
+        Some(&mut v.value)
     }
 
     /// Check if an entry has been read
-    pub fn is_read(&self, key: &(usize, &'src str)) -> Option<bool> {
+    pub fn is_read(&self, key: &(usize, SortId)) -> Option<bool> {
         self.cache.get(key).map(|v| v.read)
     }
 
     /// Insert a new entry into the cache
-    pub fn insert(&mut self, key: (usize, &'src str), value: ParseResult<'src, ParsePairRaw>) {
+    pub fn insert(&mut self, key: (usize, SortId), value: ParseResult<'src, Arc<ParsePairRaw>>) {
+        if let Some(stats) = self.stats.as_mut() {
+            stats[key.1 .0].inserted += 1;
+        }
+        self.cache.insert(
+            key,
+            ParserCacheEntry {
+                read: false,
+                value,
+                attempt: self.attempt(),
+                reuse: None,
+            },
+        );
+        self.cache_stack.push_back((self.inserted, key));
+        self.inserted += 1;
+        if self.cache.len() > self.evict_at {
+            self.evict();
+        }
+    }
+
+    /// Removes the entries that can't be used anymore. Every sort that is being parsed may still backtrack and parse
+    /// the input after its start again, so only the entries before the position where the outermost one started
+    /// are removed, and the entries of earlier attempts which are not valid anymore. The entries of the sorts that
+    /// are being parsed are kept, since they are still needed to grow left recursive seeds.
+    fn evict(&mut self) {
+        let committed = self.context_stack.first().map_or(0, |f| f.position);
+        let frames = &self.context_stack;
+        let recovered_before = &self.recovered_before;
+        let stats = &mut self.stats;
+        self.cache.retain(|&key, entry| {
+            let keep = (key.0 >= committed && entry.valid_in(recovered_before))
+                || frames
+                    .iter()
+                    .any(|f| f.position == key.0 && f.sort.id == key.1);
+            if let (false, Some(stats)) = (keep, stats.as_mut()) {
+                stats[key.1 .0].evicted += 1;
+            }
+            keep
+        });
+        let cache = &self.cache;
+        self.cache_stack.retain(|(_, key)| cache.contains_key(key));
+
+        //Evicting again only after the cache grew by half keeps the time spent evicting proportional to the inserts
+        let max_entries = self
+            .max_entries
+            .expect("only evicted when there is a limit");
+        let len = self.cache.len();
+        self.evict_at = max_entries.max(len + max_entries.max(len) / 2);
+    }
+
+    /// Insert the final result of the sort that is currently being parsed, which is the innermost one on the context stack.
+    /// Unless it depends on an unfinished result, it can be reused by an incremental reparse.
+    pub(crate) fn insert_final(
+        &mut self,
+        key: (usize, SortId),
+        value: ParseResult<'src, Arc<ParsePairRaw>>,
+        info: ReuseInfo,
+    ) {
+        let reusable = self.tainted_from >= self.context_stack.len();
+        self.insert(key, value);
+        if reusable {
+            self.cache.get_mut(&key).expect("just inserted").reuse = Some(info);
+        }
+    }
+
+    /// Insert a result of the previous parse, which was reused by an incremental reparse.
+    pub(crate) fn insert_reused(
+        &mut self,
+        key: (usize, SortId),
+        value: ParseResult<'src, Arc<ParsePairRaw>>,
+        info: ReuseInfo,
+    ) {
+        self.insert(key, value);
+        self.cache.get_mut(&key).expect("just inserted").reuse = Some(info);
+    }
+
+    /// Forgets that sorts deeper than the context stack depend on unfinished results, after a sort is popped.
+    pub(crate) fn untaint(&mut self) {
+        if self.tainted_from >= self.context_stack.len() {
+            self.tainted_from = usize::MAX;
+        }
+    }
+
+    /// Records that the input was looked at up to `end` (exclusive).
+    pub(crate) fn examine(&mut self, end: usize) {
+        self.examined = self.examined.max(end);
+    }
+
+    /// Takes the final entries out of the cache, which can be reused by an incremental reparse.
+    pub(crate) fn take_reusable(
+        &mut self,
+    ) -> impl Iterator<
+        Item = (
+            (usize, SortId),
+            ParseResult<'src, Arc<ParsePairRaw>>,
+            ReuseInfo,
+        ),
+    > + '_ {
+        self.cache_stack.clear();
         self.cache
-            .insert(key, ParserCacheEntry { read: false, value });
-        self.cache_stack.push_back(key);
+            .drain()
+            .filter_map(|(key, entry)| Some((key, entry.value, entry.reuse?)))
+    }
+
+    /// Prepares this state for parsing the file again after recovering from an error at `position`.
+    /// Entries which only looked at the input before `position` stay valid, since they don't depend on how the
+    /// error is recovered from. The errors they found are not kept, so they are not found again when the entries are used.
+    ///
+    /// The other entries are only removed when they are looked up, and repetitions resume from the items that are
+    /// still valid (see [`resume_repeat`](ParserState::resume_repeat)). So an attempt only parses the part of the file
+    /// after the error again, and the sorts and repetitions that contain the error.
+    pub(crate) fn retry(&mut self, position: usize) {
+        for before in &mut self.recovered_before {
+            *before = (*before).min(position);
+        }
+        self.recovered_before.push(position);
+
+        self.best_error = None;
+        self.allow_layout = true;
+        self.no_layout_nest_count = 0;
+        self.no_errors_nest_count = 0;
+        self.depth = 0;
+        self.context_stack.clear();
+        self.examined = 0;
+        self.tainted_from = usize::MAX;
+        self.predict = false;
+        self.optimized = false;
+        //Statistics are merged after every attempt
+        if let Some(stats) = self.stats.as_mut() {
+            stats.fill(SortStats::default());
+        }
+        self.child_time = Duration::ZERO;
+    }
+
+    /// How many times the file was parsed again after recovering from an error.
+    fn attempt(&self) -> usize {
+        self.recovered_before.len()
+    }
+
+    /// The items the repetition `repeat` at `position` parsed in an earlier attempt, which are still valid.
+    /// When an error is recovered from, the repetitions that contain the error continue after the last of these,
+    /// instead of taking every item from the cache again. Only done while recovering from errors.
+    pub(crate) fn resume_repeat(
+        &mut self,
+        repeat: RepeatId,
+        position: usize,
+    ) -> Option<Vec<RepeatItem>> {
+        let attempt = self.attempt();
+        let (allow_layout, no_layout, no_errors) = (
+            self.allow_layout,
+            self.no_layout_nest_count > 0,
+            self.no_errors_nest_count > 0,
+        );
+        let progress = self
+            .repeats
+            .entry((repeat, position))
+            .or_insert_with(|| RepeatProgress {
+                attempt,
+                allow_layout,
+                no_layout,
+                no_errors,
+                items: vec![],
+            });
+        if (
+            progress.allow_layout,
+            progress.no_layout,
+            progress.no_errors,
+        ) != (allow_layout, no_layout, no_errors)
+        {
+            *progress = RepeatProgress {
+                attempt,
+                allow_layout,
+                no_layout,
+                no_errors,
+                items: vec![],
+            };
+            return None;
+        }
+        if progress.attempt < attempt {
+            let before = self.recovered_before[progress.attempt];
+            let valid = progress
+                .items
+                .partition_point(|item| item.examined < before);
+            progress.items.truncate(valid);
+            progress.attempt = attempt;
+        }
+        Some(progress.items.clone())
     }
 
-    /// Check how many items are currently in the stack
+    /// Records the item at `index` of a repetition while recovering from errors, see [`resume_repeat`](ParserState::resume_repeat).
+    /// Items that depend on a left recursive result which is still growing are not recorded, and neither are the items after them.
+    pub(crate) fn record_repeat(
+        &mut self,
+        repeat: RepeatId,
+        position: usize,
+        index: usize,
+        item: RepeatItem,
+    ) {
+        if self.tainted_from != usize::MAX {
+            return;
+        }
+        match self.repeats.get_mut(&(repeat, position)) {
+            Some(progress) if progress.items.len() == index => progress.items.push(item),
+            _ => {}
+        }
+    }
+
+    /// A marker for the current state of the stack, which is the number of the next entry that is inserted
     pub fn state_current(&self) -> usize {
-        self.cache_stack.len()
+        self.inserted
     }
 
     /// Remove all the items that were inserted after the given stack marker
     pub fn state_revert(&mut self, state: usize) {
-        self.cache_stack.drain(state..).for_each(|key| {
+        while let Some(&(number, key)) = self.cache_stack.back() {
+            if number < state {
+                break;
+            }
+            self.cache_stack.pop_back();
+            if let Some(stats) = self.stats.as_mut() {
+                stats[key.1 .0].reverted += 1;
+            }
             self.cache.remove(&key);
-        })
+        }
     }
 
-    pub fn add_error(&mut self, error: PEGParseError) {
+    pub fn add_error(&mut self, mut error: PEGParseError) {
         if self.no_errors_nest_count > 0 {
             return;
         }
+        //Only record the context if the error is not immediately discarded by `combine`
+        if error.context.is_empty()
+            && !matches!(&self.best_error, Some(best) if best.span.position > error.span.position)
+        {
+            let start = self.context_stack.len().saturating_sub(MAX_ERROR_CONTEXT);
+            error.context = self.context_stack[start..]
+                .iter()
+                .map(|frame| ErrorContext {
+                    sort: frame.sort.name.to_string(),
+                    constructor: frame.constructor.map(str::to_string),
+                    position: frame.position,
+                    //The layout is skipped once the error is final, see `skip_context_layout`
+                    start: frame.position,
+                })
+                .collect();
+        }
         match self.best_error.take() {
             Some(old_error) => self.best_error = Some(PEGParseError::combine(old_error, error)),
             None => self.best_error = Some(error),
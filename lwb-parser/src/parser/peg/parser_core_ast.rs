@@ -1,56 +1,169 @@
+use crate::parser::peg::parse_error::Expect;
+use crate::parser::peg::parser_core_first::First;
+use crate::parser::peg::parser_core_optimize::OptimizedExpression;
 use crate::parser::peg::parser_sugar_ast::Annotation;
-use crate::sources::character_class::CharacterClass;
-use crate::sources::span::Span;
+use crate::parser::syntax_file::builtin_sorts::IntegerFormat;
+use crate::sources::character_class::CompiledCharacterClass;
+use crate::sources::compact_span::CompactSpan;
 use std::collections::HashMap;
+use std::mem;
+use std::sync::Arc;
 
-#[derive(Debug, Clone)]
+/// Identifies a sort of a [`CoreAst`]. It is the index of the sort in [`CoreAst::sorts`],
+/// so the parser can look up sorts and cache their results without hashing their names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SortId(pub usize);
+
+/// Identifies a repetition of a [`CoreAst`]. The repetitions are numbered when the grammar is desugared,
+/// so a repetition keeps its id when the expression it is in is cloned or optimized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RepeatId(pub usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CoreExpression<'src> {
-    Name(&'src str),
+    Name(SortId),
     Sequence(Vec<CoreExpression<'src>>),
     Repeat {
         subexpr: Box<CoreExpression<'src>>,
         min: u64,
         max: Option<u64>,
+        id: RepeatId,
     },
-    CharacterClass(CharacterClass),
-    Choice(Vec<CoreExpression<'src>>),
+    /// Repeats `subexpr` as many times as the value of the integer that the item at index `count` of the
+    /// sequence around it parsed. It is only parsed as an item of that sequence.
+    Counted {
+        subexpr: Box<CoreExpression<'src>>,
+        count: usize,
+        integer: IntegerFormat,
+        id: RepeatId,
+    },
+    CharacterClass(CompiledCharacterClass),
+    /// Matches the text as a whole, with layout only before it. Reports that the text was expected when it fails.
+    Literal(&'src str),
+    /// The alternatives, with the first set of each alternative. The first sets are computed after
+    /// desugaring, see [`compute_first_sets`](crate::parser::peg::parser_core_first::compute_first_sets).
+    /// While they are empty, every alternative is tried.
+    Choice(Vec<CoreExpression<'src>>, Vec<First>),
     FlagNoLayout(Box<CoreExpression<'src>>),
-    FlagNoErrors(Box<CoreExpression<'src>>, String),
+    /// Errors inside the expression are not reported. When it fails, the `Expect` is reported instead.
+    FlagNoErrors(Box<CoreExpression<'src>>, Expect),
     Error(Box<CoreExpression<'src>>, String),
+    /// Never parses, and doesn't report an error either
+    Fail,
 }
 
 #[derive(Debug, Clone)]
 pub struct CoreSort<'src> {
+    pub id: SortId,
     pub name: &'src str,
+    /// The names of the constructors, in the same order as the choice in `expr`
+    pub constructors: Vec<&'src str>,
     pub expr: CoreExpression<'src>,
     pub annotations: Vec<Annotation>,
+    /// The expression after optimizing the grammar, if that changed it
+    pub optimized: Option<OptimizedExpression<'src>>,
+    /// When the results of this sort are stored in the cache of the parser
+    pub memo: Memoize,
+}
+
+/// When the results of a sort are memoized, see [`CoreSort::memo`].
+/// Sorts that are not memoized are parsed again every time they are used at the same position,
+/// which is faster for sorts that are cheap to parse, and keeps the cache small.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Memoize {
+    Always,
+    /// Not memoized while the errors are not needed yet. Errors are always found with every sort memoized.
+    Never,
 }
 
 #[derive(Debug, Clone)]
 pub struct CoreAst<'src> {
-    pub sorts: HashMap<&'src str, CoreSort<'src>>,
-    pub starting_sort: &'src str,
+    /// The sorts, by their [`SortId`]
+    pub sorts: Vec<CoreSort<'src>>,
+    pub sort_ids: HashMap<&'src str, SortId>,
+    pub starting_sort: SortId,
+    pub layout: SortId,
+    /// The first set of the layout sort
+    pub layout_first: First,
+}
+
+impl<'src> CoreAst<'src> {
+    pub fn sort(&self, id: SortId) -> &CoreSort<'src> {
+        &self.sorts[id.0]
+    }
+
+    pub fn sort_id(&self, name: &str) -> Option<SortId> {
+        self.sort_ids.get(name).copied()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum ParsePairRaw {
-    Name(Span, Box<ParsePairRaw>),
-    List(Span, Vec<ParsePairRaw>),
-    Choice(Span, usize, Box<ParsePairRaw>),
-    Empty(Span),
-    Error(Span),
+    /// The result of a sort, which is shared with the cache of the parser
+    Name(CompactSpan, Arc<ParsePairRaw>),
+    List(CompactSpan, Vec<ParsePairRaw>),
+    Choice(CompactSpan, usize, Box<ParsePairRaw>),
+    Empty(CompactSpan),
+    Error(CompactSpan),
 }
 
 impl ParsePairRaw {
     /// What span does this parse pair occupy?
-    pub fn span(&self) -> Span {
+    pub fn span(&self) -> CompactSpan {
+        match self {
+            ParsePairRaw::Name(span, _) => *span,
+            ParsePairRaw::List(span, _) => *span,
+            ParsePairRaw::Choice(span, _, _) => *span,
+            ParsePairRaw::Empty(span) => *span,
+            ParsePairRaw::Error(span) => *span,
+        }
+    }
+
+    /// The pairs of a list.
+    pub(crate) fn into_list(mut self) -> Vec<ParsePairRaw> {
+        match &mut self {
+            ParsePairRaw::List(_, list) => mem::take(list),
+            pair => unreachable!("{pair:?} is not a list"),
+        }
+    }
+
+    /// The index of the alternative that was chosen, and its pair.
+    pub(crate) fn into_choice(mut self) -> (usize, ParsePairRaw) {
+        match &mut self {
+            ParsePairRaw::Choice(span, i, pair) => {
+                (*i, mem::replace(pair, ParsePairRaw::Empty(*span)))
+            }
+            pair => unreachable!("{pair:?} is not a choice"),
+        }
+    }
+
+    /// Moves the pairs in this pair to `pairs`, leaving it without nested pairs.
+    /// A pair of a sort is only moved when it is not shared with other pairs.
+    fn take_nested(&mut self, pairs: &mut Vec<ParsePairRaw>) {
         match self {
-            ParsePairRaw::Name(span, _) => span,
-            ParsePairRaw::List(span, _) => span,
-            ParsePairRaw::Choice(span, _, _) => span,
-            ParsePairRaw::Empty(span) => span,
-            ParsePairRaw::Error(span) => span,
+            ParsePairRaw::Name(span, pair) => {
+                if let Some(pair) = Arc::get_mut(pair) {
+                    pairs.push(mem::replace(pair, ParsePairRaw::Empty(*span)));
+                }
+            }
+            ParsePairRaw::List(_, list) => pairs.append(list),
+            ParsePairRaw::Choice(span, _, pair) => {
+                pairs.push(mem::replace(pair, ParsePairRaw::Empty(*span)))
+            }
+            ParsePairRaw::Empty(_) | ParsePairRaw::Error(_) => {}
+        }
+    }
+}
+
+/// Dropping the nested pairs one by one would use stack space for every level of nesting,
+/// so deeply nested input would overflow the stack after it was parsed. Instead, the nested pairs are
+/// taken out first, so every pair is dropped without any pairs in it.
+impl Drop for ParsePairRaw {
+    fn drop(&mut self) {
+        let mut pairs = vec![];
+        self.take_nested(&mut pairs);
+        while let Some(mut pair) = pairs.pop() {
+            pair.take_nested(&mut pairs);
         }
-        .clone()
     }
 }
@@ -2,15 +2,30 @@
 
 use crate::parser::peg::parse_error::{Expect, PEGParseError};
 use crate::parser::peg::parse_result::ParseResult;
-use crate::parser::peg::parser_core::{ParserContext, ParserState};
-use crate::parser::peg::parser_core_ast::{CoreExpression, CoreSort, ParsePairRaw};
+use crate::parser::peg::parse_trace::TraceEvent;
+use crate::parser::peg::parser_core::{
+    ContextFrame, ParserContext, ParserState, RepeatItem, ReuseInfo,
+};
+use crate::parser::peg::parser_core_ast::{
+    CoreExpression, CoreSort, Memoize, ParsePairRaw, RepeatId, SortId,
+};
+use crate::parser::peg::parser_core_file::{skip_layout, skip_layout_for_errors};
+use crate::parser::peg::parser_core_first::First;
+use crate::parser::peg::parser_core_optimize::reshape;
+use crate::parser::peg::parser_incremental::reuse_entry;
 use crate::parser::peg::parser_sugar_ast::Annotation;
+use crate::parser::syntax_file::builtin_sorts::IntegerFormat;
+use crate::sources::character_class::CompiledCharacterClass;
+use crate::sources::compact_span::CompactSpan;
 use crate::sources::source_file::SourceFileIterator;
 use crate::sources::span::Span;
+use std::sync::Arc;
 
 pub struct ExpressionContext<'src> {
     pub name: Option<&'src str>,
     pub error: Option<&'src String>,
+    /// The sort that is being parsed, used to find out which constructor is tried
+    pub sort: Option<&'src CoreSort<'src>>,
 }
 
 impl<'src> ExpressionContext<'src> {
@@ -18,6 +33,7 @@ impl<'src> ExpressionContext<'src> {
         Self {
             name: None,
             error: None,
+            sort: None,
         }
     }
 }
@@ -26,16 +42,11 @@ impl<'src> ExpressionContext<'src> {
 pub fn parse_expression_name<'src>(
     state: &ParserContext<'src>,
     cache: &mut ParserState<'src>,
-    expr_name: &'src str,
+    sort: SortId,
     pos: SourceFileIterator<'src>,
-) -> ParseResult<'src, ParsePairRaw> {
-    let sort: &'src CoreSort = state
-        .ast
-        .sorts
-        .get(expr_name)
-        .expect("name is guaranteed to exist");
+) -> ParseResult<'src, Arc<ParsePairRaw>> {
+    let sort: &'src CoreSort = state.ast.sort(sort);
 
-    let expr = &sort.expr;
     let sort_context = ExpressionContext {
         name: Some(sort.name),
         error: sort.annotations.iter().find_map(|i| {
@@ -45,25 +56,73 @@ pub fn parse_expression_name<'src>(
                 None
             }
         }),
+        sort: Some(sort),
     };
 
+    //Sorts that are cheap to parse again are not memoized, until the errors are needed
+    let memoize = sort.memo == Memoize::Always || !cache.predict;
+
     //Check if this result is cached
-    let key = (pos.position(), expr_name);
-    if let Some(cached) = cache.get_mut(&key) {
-        return cached.clone();
+    let key = (pos.position(), sort.id);
+    if memoize {
+        if let Some(cached) = cache.get_mut(&key) {
+            let cached = cached.clone();
+            state.trace(|| TraceEvent::MemoHit {
+                sort: sort.name.to_string(),
+                position: key.0,
+                end: result_end(&cached),
+                ok: cached.ok,
+            });
+            return cached;
+        }
+
+        //When reparsing incrementally, the result may be known from the previous parse
+        if let Some(reused) = reuse_entry(state, cache, key) {
+            return reused;
+        }
+    }
+
+    //Every nested sort uses stack space, so stop before the stack overflows.
+    if cache.depth >= state.limiter.max_depth() || state.limiter.stack_exceeded() {
+        let span = Span::from_length(state.file, pos.position(), 0);
+        cache.add_error(PEGParseError::fail_nesting(span.clone()));
+        return ParseResult::new_err(Arc::new(ParsePairRaw::Error(span.into())), pos, pos);
     }
+    cache.depth += 1;
+    let examined_outer = std::mem::replace(&mut cache.examined, key.0);
+    let allow_layout = cache.allow_layout;
+    let no_layout = cache.no_layout_nest_count > 0;
+    cache.context_stack.push(ContextFrame {
+        sort,
+        constructor: None,
+        position: key.0,
+    });
+    let timer = cache.stats_enter(sort.id);
+    let error_before = cache
+        .best_error
+        .as_ref()
+        .map(|e| (e.span.position, e.expected.len()));
+    state.trace(|| TraceEvent::Enter {
+        sort: sort.name.to_string(),
+        position: key.0,
+    });
 
     //Before executing, put a value for the current position in the cache.
-    //This value is used if the rule is left-recursive
+    //This value is used if the rule is left-recursive. Sorts that are not memoized are never left-recursive.
     let cache_state = cache.state_current();
-    cache.insert(
-        key,
-        ParseResult::new_err(
-            ParsePairRaw::Error(Span::from_length(state.file, pos.position(), 0)),
-            pos.clone(),
-            pos.clone(),
-        ),
-    );
+    if memoize {
+        cache.insert(
+            key,
+            ParseResult::new_err(
+                Arc::new(ParsePairRaw::Error(CompactSpan::from_length(
+                    pos.position(),
+                    0,
+                ))),
+                pos,
+                pos,
+            ),
+        );
+    }
 
     //Now execute the actual rule, taking into account left recursion
     //The way this is done is heavily inspired by http://web.cs.ucla.edu/~todd/research/pepm08.pdf
@@ -72,10 +131,14 @@ pub fn parse_expression_name<'src>(
     //- Try to parse the current (rule, position). If this fails, there is definitely no left recursion. Otherwise, we now have a seed.
     //- Put the new seed in the cache, and rerun on the current (rule, position). Make sure to revert the cache to the previous state.
     //- At some point, the above will fail. Either because no new input is parsed, or because the entire parse now failed. At this point, we have reached the maximum size.
-    let mut res = parse_expression(state, cache, expr, pos.clone(), &sort_context);
+    let mut res = parse_sort_expression(state, cache, sort, pos, &sort_context).map(Arc::new);
+    let read = memoize
+        && cache
+            .is_read(&key)
+            .expect("sorts being parsed are not evicted");
     let res = if res.ok {
         //Do we have a leftrec case?
-        if !cache.is_read(&key).unwrap() {
+        if !read {
             //There was no leftrec, just return the value
             res
         } else {
@@ -86,13 +149,20 @@ pub fn parse_expression_name<'src>(
                 cache.insert(key, res.clone());
 
                 //Grow the seed
-                let new_res = parse_expression(state, cache, expr, pos.clone(), &sort_context);
+                cache.stats_left_recursion(sort.id);
+                let new_res =
+                    parse_sort_expression(state, cache, sort, pos, &sort_context).map(Arc::new);
                 if !new_res.ok {
                     break;
                 }
                 if new_res.pos.position() <= res.pos.position() {
                     break;
                 }
+                state.trace(|| TraceEvent::SeedGrown {
+                    sort: sort.name.to_string(),
+                    position: key.0,
+                    end: new_res.pos.position(),
+                });
                 res = new_res;
             }
             //The seed is at its maximum size
@@ -102,7 +172,7 @@ pub fn parse_expression_name<'src>(
     } else {
         // Left recursion value was used, but did not make a seed.
         // This is an illegal grammar!
-        if cache.is_read(&key).unwrap() {
+        if read {
             cache.add_error(PEGParseError::fail_left_recursion(Span::from_length(
                 state.file,
                 pos.position(),
@@ -112,223 +182,132 @@ pub fn parse_expression_name<'src>(
         res
     };
 
-    cache.insert(key, res.clone());
+    if let Some(display_name) = sort.annotations.iter().find_map(|a| match a {
+        Annotation::DisplayName(name) => Some(name),
+        _ => None,
+    }) {
+        if !res.ok {
+            collapse_expected(state, cache, pos, error_before, display_name);
+        }
+    }
+
+    let info = ReuseInfo {
+        examined: cache.examined,
+        allow_layout,
+        no_layout,
+        allow_layout_after: cache.allow_layout,
+    };
+    if memoize {
+        cache.insert_final(key, res.clone(), info);
+    }
+    cache.examined = examined_outer.max(info.examined);
+    cache.depth -= 1;
+    cache.context_stack.pop();
+    cache.untaint();
+    cache.stats_exit(sort.id, timer);
+    state.trace(|| TraceEvent::Exit {
+        sort: sort.name.to_string(),
+        position: key.0,
+        end: result_end(&res),
+        ok: res.ok,
+    });
 
     //Return result
     res
 }
 
+/// When a sort with a display name could not even start, errors should say that the display name was
+/// expected, instead of listing everything that could start the sort.
+/// `error_before` is the position and number of expected items of the best error before the sort was parsed,
+/// so the items that were added by the sort can be replaced.
+///
+/// This is only done when the sort failed, and only when its errors are reported (see [`ParserState::predict`]).
+/// Finding where the sort starts doesn't change the state of the parse, so it can't change the result.
+fn collapse_expected<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    pos: SourceFileIterator<'src>,
+    error_before: Option<(usize, usize)>,
+    display_name: &str,
+) {
+    if cache.no_errors_nest_count > 0 || cache.predict || cache.best_error.is_none() {
+        return;
+    }
+    let start = if cache.allow_layout {
+        skip_layout_for_errors(state, pos)
+    } else {
+        pos.position()
+    };
+
+    let Some(err) = cache.best_error.as_mut() else {
+        return;
+    };
+    if err.span.position != start {
+        return;
+    }
+    let inner_start = match error_before {
+        Some((position, len)) if position == start => len,
+        _ => 0,
+    };
+    if inner_start < err.expected.len() {
+        err.expected.truncate(inner_start);
+        err.expected
+            .push(Expect::ExpectSort(display_name.to_string()));
+    }
+}
+
+/// Parses an expression at the current position.
+/// The larger cases are parsed in separate functions. This function is on the stack
+/// for every nested expression, so this keeps the stack usage of deeply nested input low.
 pub fn parse_expression<'src>(
     state: &ParserContext<'src>,
     cache: &mut ParserState<'src>,
     expr: &'src CoreExpression,
-    mut pos: SourceFileIterator<'src>,
+    pos: SourceFileIterator<'src>,
     sort_context: &ExpressionContext<'src>,
 ) -> ParseResult<'src, ParsePairRaw> {
+    //If the parse was stopped, fail immediately so the parser returns as quickly as possible
+    if !state.limiter.step() {
+        let span = CompactSpan::from_length(pos.position(), 0);
+        return ParseResult::new_err(ParsePairRaw::Error(span), pos, pos);
+    }
+
     match expr {
         //To parse a sort, call parse_sort recursively.
-        CoreExpression::Name(sort_name) => {
-            let res = parse_expression_name(state, cache, sort_name, pos);
-            res.map(|s| ParsePairRaw::Name(s.span(), Box::new(s)))
+        CoreExpression::Name(sort) => {
+            let res = parse_expression_name(state, cache, *sort, pos);
+            res.map(|s| ParsePairRaw::Name(s.span(), s))
         }
         //To parse a character class, check if the character is accepted, and make an ok/error based on that.
         CoreExpression::CharacterClass(characters) => {
-            while cache.allow_layout && !pos.clone().accept(characters) {
-                let (ok, after_layout_pos) =
-                    skip_single_layout(state, cache, pos.clone(), sort_context);
-                if !ok {
-                    break;
-                };
-                pos = after_layout_pos;
-            }
-            let span = Span::from_length(state.file, pos.position(), 1);
-            if pos.accept(characters) {
-                if cache.no_layout_nest_count > 0 {
-                    cache.allow_layout = false;
-                }
-                ParseResult::new_ok(ParsePairRaw::Empty(span), pos.clone(), pos, false)
-            } else {
-                cache.add_error(PEGParseError::expect(
-                    span.clone(),
-                    Expect::ExpectCharClass(characters.clone()),
-                    sort_context,
-                ));
-                ParseResult::new_err(ParsePairRaw::Error(span), pos.clone(), pos)
-            }
+            parse_character_class(state, cache, characters, pos, sort_context)
         }
+        //To parse a literal, compare the whole text at once.
+        CoreExpression::Literal(literal) => parse_literal(state, cache, literal, pos, sort_context),
         //To parse a sequence, parse each constructor in the sequence.
         //The results are added to `results`, and the best error and position are updated each time.
         //Finally, construct a `ParsePairConstructor::List` with the results.
         CoreExpression::Sequence(subexprs) => {
-            let mut results = vec![];
-            let start_pos = pos.position();
-            let mut pos_err = pos.clone();
-            let mut recovered = false;
-
-            //Parse all subconstructors in sequence
-            for (i, subexpr) in subexprs.iter().enumerate() {
-                let res = parse_expression(state, cache, subexpr, pos, sort_context);
-                pos = res.pos;
-                pos_err.max_pos(res.pos_err.clone());
-                results.push(res.result);
-                recovered |= res.recovered;
-                if !res.ok {
-                    if let Some(&offset) = state.errors.get(&res.pos_err.position()) {
-                        //The first token of the sequence can not be skipped, otherwise we can just parse a lot of empty sequences, if a sequence happens in a repeat
-                        if i != 0 && cache.no_errors_nest_count == 0 {
-                            pos = res.pos_err;
-                            //If we're at the end of the file, don't try
-                            if pos.peek().is_none() {
-                                let span = Span::from_end(state.file, start_pos, pos.position());
-                                return ParseResult::new_err(
-                                    ParsePairRaw::List(span, results),
-                                    pos,
-                                    pos_err,
-                                );
-                            }
-                            pos.skip_n(offset);
-                            recovered = true;
-                            continue;
-                        }
-                    }
-
-                    let start_pos = results
-                        .get(0)
-                        .map(|pp| pp.span().position)
-                        .unwrap_or(start_pos);
-                    let span = Span::from_end(state.file, start_pos, pos.position());
-                    return ParseResult::new_err(ParsePairRaw::List(span, results), pos, pos_err);
-                }
-            }
-
-            //Construct result
-            let start_pos = results
-                .get(0)
-                .map(|pp| pp.span().position)
-                .unwrap_or(start_pos);
-            let span = Span::from_end(state.file, start_pos, pos.position());
-            ParseResult::new_ok(ParsePairRaw::List(span, results), pos, pos_err, recovered)
+            parse_sequence(state, cache, subexprs, pos, sort_context)
         }
         //To parse a sequence, first parse the minimum amount that is needed.
         //Then keep trying to parse the constructor until the maximum is reached.
         //The results are added to `results`, and the best error and position are updated each time.
         //Finally, construct a `ParsePairConstructor::List` with the results.
-        CoreExpression::Repeat { subexpr, min, max } => {
-            let mut results = vec![];
-            let start_pos = pos.position();
-            let mut last_pos = pos.position();
-            let mut pos_err = pos.clone();
-            let mut recovered = false;
-
-            //Parse at most maximum times
-            for i in 0..max.unwrap_or(u64::MAX) {
-                let res =
-                    parse_expression(state, cache, subexpr.as_ref(), pos.clone(), sort_context);
-                pos_err.max_pos(res.pos_err.clone());
-                recovered |= res.recovered;
-
-                if res.ok {
-                    pos = res.pos;
-                    results.push(res.result);
-                } else {
-                    //If we know about this error, try to continue?
-                    //Don't try to continue if we haven't made any progress (already failed on first character), since we will just fail again
-                    //Also don't try to continue if we don't allow errors at the moment, since we don't want to try to recover inside of an no-errors segment
-                    if let Some(&offset) = state.errors.get(&res.pos_err.position()) {
-                        if (offset > 0 || pos.position() != res.pos_err.position())
-                            && cache.no_errors_nest_count == 0
-                        {
-                            pos = res.pos_err.clone();
-                            //If we're at the end of the file, don't try
-                            if pos.peek().is_none() {
-                                let span = Span::from_end(state.file, start_pos, pos.position());
-                                return ParseResult::new_err(
-                                    ParsePairRaw::List(span, results),
-                                    pos,
-                                    pos_err,
-                                );
-                            }
-                            pos.skip_n(offset);
-                            results.push(res.result);
-                            recovered = true;
-                            continue;
-                        }
-                    }
-                    //If we have not yet reached the minimum, we error.
-                    //Otherwise, we break and ok after the loop body.
-                    //In case we reached the minimum, we don't push the error, even though the failure might've been an error.
-                    //This is because it's probably OK, and we want no Error pairs in the parse tree when it's OK.
-                    if i < *min {
-                        pos = res.pos;
-                        results.push(res.result);
-                        let start_pos = results
-                            .get(0)
-                            .map(|pp| pp.span().position)
-                            .unwrap_or(start_pos);
-                        let span = Span::from_end(state.file, start_pos, pos.position());
-                        return ParseResult::new_err(
-                            ParsePairRaw::List(span, results),
-                            pos,
-                            pos_err,
-                        );
-                    } else {
-                        break;
-                    }
-                }
-                //If the position hasn't changed, then we're in an infinite loop
-                if last_pos == pos.position() {
-                    let span = Span::from_length(state.file, pos.position(), 0);
-                    cache.add_error(PEGParseError::fail_loop(span.clone()));
-                    return ParseResult::new_err(ParsePairRaw::List(span, results), pos, pos_err);
-                }
-                last_pos = pos.position();
-            }
-
-            //Construct result
-            let start_pos = results
-                .get(0)
-                .map(|pp| pp.span().position)
-                .unwrap_or(start_pos);
-            let span = Span::from_end(state.file, start_pos, pos.position());
-            ParseResult::new_ok(
-                ParsePairRaw::List(span, results),
-                pos.clone(),
-                pos_err,
-                recovered,
-            )
+        CoreExpression::Repeat {
+            subexpr,
+            min,
+            max,
+            id,
+        } => parse_repeat(state, cache, subexpr, *id, *min, *max, pos, sort_context),
+        //The count is found in the sequence around it, see `parse_sequence`
+        CoreExpression::Counted { .. } => {
+            unreachable!("a counted repetition is parsed as an item of a sequence")
         }
         //To parse a choice, try each constructor, keeping track of the best error that occurred while doing so.
         //If none of the constructors succeed, we will return this error.
-        CoreExpression::Choice(subexprs) => {
-            //Try each constructor, keeping track of the best error that occurred while doing so.
-            //If none of the constructors succeed, we will return this error.
-            let mut results = vec![];
-            assert!(!subexprs.is_empty());
-            for (i, subexpr) in subexprs.iter().enumerate() {
-                let res = parse_expression(state, cache, subexpr, pos.clone(), sort_context);
-                if res.ok && !res.recovered {
-                    return ParseResult::new_ok(
-                        ParsePairRaw::Choice(res.result.span(), i, Box::new(res.result)),
-                        res.pos,
-                        res.pos_err,
-                        res.recovered,
-                    );
-                }
-                results.push(res);
-            }
-            //Chose best candidate
-            let (i, res) = results
-                .into_iter()
-                .enumerate()
-                .max_by_key(|(_, r)| r.pos_err.position())
-                .unwrap();
-            ParseResult::new(
-                ParsePairRaw::Choice(res.result.span(), i, Box::new(res.result)),
-                res.pos,
-                res.pos_err,
-                res.ok,
-                res.recovered,
-            )
+        CoreExpression::Choice(subexprs, firsts) => {
+            parse_choice(state, cache, subexprs, firsts, pos, sort_context)
         }
         //No layout is parsed by setting the no layout flag during parsing
         //After the block is completed, if no layout nest count is 0, re-allow layout.
@@ -343,46 +322,542 @@ pub fn parse_expression<'src>(
         }
         //No errors is parsed by setting the no errors flag during parsing
         //After the block is completed, is not ok, produce an error.
-        CoreExpression::FlagNoErrors(subexpr, name) => {
-            cache.no_errors_nest_count += 1;
-            let start_pos = pos.position();
-            let res = parse_expression(state, cache, subexpr, pos.clone(), sort_context);
-            cache.no_errors_nest_count -= 1;
-            if !res.ok {
-                let mut next_pos = res.pos.clone();
-                next_pos.skip_n(1);
-                let span = Span::from_end(state.file, start_pos, next_pos.position());
-                let err =
-                    PEGParseError::expect(span, Expect::ExpectSort(name.to_string()), sort_context);
-                cache.add_error(err);
+        CoreExpression::FlagNoErrors(subexpr, expect) => {
+            parse_flag_no_errors(state, cache, subexpr, expect, pos, sort_context)
+        }
+        CoreExpression::Error(e, msg) => parse_error(state, cache, e, msg, pos, sort_context),
+        CoreExpression::Fail => ParseResult::new_err(
+            ParsePairRaw::Error(CompactSpan::from_length(pos.position(), 0)),
+            pos,
+            pos,
+        ),
+    }
+}
+
+/// Parses the expression of a sort, or its optimized expression when the state uses those.
+/// A successful result of the optimized expression is turned into the result of the expression as written.
+fn parse_sort_expression<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    sort: &'src CoreSort<'src>,
+    pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    match &sort.optimized {
+        Some(optimized) if cache.optimized => {
+            let mut res = parse_expression(state, cache, &optimized.expr, pos, sort_context);
+            if res.ok {
+                res.result = reshape(state.file, &optimized.shape, res.result);
             }
             res
         }
-        CoreExpression::Error(e, msg) => {
-            let pos_backup = pos.clone();
-            let start_pos = pos.position();
-            let mut res = parse_expression(state, cache, e, pos.clone(), sort_context);
+        _ => parse_expression(state, cache, &sort.expr, pos, sort_context),
+    }
+}
 
-            if res.ok {
-                let mut next_pos = res.pos.clone();
+fn parse_character_class<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    characters: &'src CompiledCharacterClass,
+    pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    let (pos, next) = accept_after_layout(state, cache, pos, |c| characters.contains(c));
+    let span = CompactSpan::from_length(pos.position(), 1);
+    if let Some(next) = next {
+        cache.examine(next.position());
+        if cache.no_layout_nest_count > 0 {
+            cache.allow_layout = false;
+        }
+        ParseResult::new_ok(ParsePairRaw::Empty(span), next, next, false)
+    } else {
+        //Also at the end of the file, so inserting text there changes the result
+        cache.examine(span.start() + 1);
+        //The error would be discarded inside literals and layout, so it is not made at all
+        if cache.no_errors_nest_count == 0 {
+            state.trace(|| TraceEvent::Expected {
+                position: span.start(),
+                expected: characters.to_string(),
+            });
+            cache.add_error(PEGParseError::expect(
+                Span::from_length(state.file, span.start(), 1),
+                Expect::ExpectCharClass(characters.class().clone()),
+                sort_context,
+            ));
+        }
+        ParseResult::new_err(ParsePairRaw::Error(span), pos, pos)
+    }
+}
+
+/// Accepts the next character if it matches, skipping layout before it when layout is allowed.
+/// Like a character class, it tries the character before each piece of layout that is skipped.
+/// Returns the position of the character, and the position after it if it was accepted.
+fn accept_after_layout<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    mut pos: SourceFileIterator<'src>,
+    accept: impl Fn(char) -> bool,
+) -> (SourceFileIterator<'src>, Option<SourceFileIterator<'src>>) {
+    loop {
+        let mut next = pos;
+        if matches!(next.peek(), Some(&c) if accept(c)) {
+            next.advance();
+            return (pos, Some(next));
+        }
+        if !cache.allow_layout {
+            return (pos, None);
+        }
+        let (ok, after_layout_pos) = skip_single_layout(state, cache, pos);
+        if !ok {
+            return (pos, None);
+        }
+        pos = after_layout_pos;
+    }
+}
+
+/// Parses a literal. This gives the same result as parsing its characters in a sequence, without layout
+/// between them and without errors, and then reporting the literal as expected if that fails.
+fn parse_literal<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    literal: &'src str,
+    pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    let start_pos = pos.position();
+    let mut chars = literal.chars();
+    let Some(first) = chars.next() else {
+        let span = CompactSpan::from_length(start_pos, 0);
+        return ParseResult::new_ok(ParsePairRaw::List(span, vec![]), pos, pos, false);
+    };
+
+    //Layout is only allowed before the first character
+    cache.no_layout_nest_count += 1;
+    let (start, next) = accept_after_layout(state, cache, pos, |c| c == first);
+    let end = next.map(|mut next| {
+        cache.allow_layout = false;
+        for c in chars {
+            if next.peek() != Some(&c) {
+                return Err(next);
+            }
+            next.advance();
+        }
+        Ok(next)
+    });
+    cache.no_layout_nest_count -= 1;
+    if cache.no_layout_nest_count == 0 {
+        cache.allow_layout = true;
+    }
+
+    match end.unwrap_or(Err(start)) {
+        Ok(end) => {
+            cache.examine(end.position());
+            let span = CompactSpan::from_end(start.position(), end.position());
+            ParseResult::new_ok(ParsePairRaw::List(span, vec![]), end, end, false)
+        }
+        Err(end) => {
+            //Also at the end of the file, so inserting text there changes the result
+            cache.examine(end.position() + 1);
+            if cache.no_errors_nest_count == 0 {
+                let mut next_pos = end;
                 next_pos.skip_n(1);
                 let span = Span::from_end(state.file, start_pos, next_pos.position());
-                let err =
-                    PEGParseError::expect(span, Expect::Custom(msg.to_string()), sort_context);
-                cache.add_error(err);
+                let expect = Expect::ExpectString(literal.to_string());
+                state.trace(|| TraceEvent::Expected {
+                    position: start_pos,
+                    expected: expect.to_string(),
+                });
+                cache.add_error(PEGParseError::expect(span, expect, sort_context));
+            }
+            let span = CompactSpan::from_end(start.position(), end.position());
+            ParseResult::new_err(ParsePairRaw::List(span, vec![]), end, end)
+        }
+    }
+}
+
+fn parse_flag_no_errors<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    subexpr: &'src CoreExpression,
+    expect: &Expect,
+    pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    cache.no_errors_nest_count += 1;
+    let start_pos = pos.position();
+    let res = parse_expression(state, cache, subexpr, pos, sort_context);
+    cache.no_errors_nest_count -= 1;
+    if !res.ok {
+        let mut next_pos = res.pos;
+        next_pos.skip_n(1);
+        let span = Span::from_end(state.file, start_pos, next_pos.position());
+        if cache.no_errors_nest_count == 0 {
+            state.trace(|| TraceEvent::Expected {
+                position: start_pos,
+                expected: expect.to_string(),
+            });
+        }
+        let err = PEGParseError::expect(span, expect.clone(), sort_context);
+        cache.add_error(err);
+    }
+    res
+}
 
-                res.pos = pos_backup;
+fn parse_error<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    e: &'src CoreExpression,
+    msg: &str,
+    pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    let pos_backup = pos;
+    let start_pos = pos.position();
+    let mut res = parse_expression(state, cache, e, pos, sort_context);
+
+    if res.ok {
+        let mut next_pos = res.pos;
+        next_pos.skip_n(1);
+        let span = Span::from_end(state.file, start_pos, next_pos.position());
+        //The message may contain the text that was parsed
+        let text = Span::from_end(state.file, start_pos, res.pos.position());
+        let msg = msg.replace("{text}", text.as_str());
+        let err = PEGParseError::expect(span, Expect::Custom(msg), sort_context);
+        cache.add_error(err);
+
+        res.pos = pos_backup;
+    }
+    res
+}
+
+fn parse_sequence<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    subexprs: &'src [CoreExpression],
+    mut pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    let mut results = vec![];
+    let start_pos = pos.position();
+    let mut pos_err = pos;
+    let mut recovered = false;
+
+    //Parse all subconstructors in sequence
+    for (i, subexpr) in subexprs.iter().enumerate() {
+        let res = match subexpr {
+            CoreExpression::Counted {
+                subexpr,
+                count,
+                integer,
+                id,
+            } => parse_counted(
+                state,
+                cache,
+                subexpr,
+                *id,
+                &results[*count],
+                integer,
+                pos,
+                sort_context,
+            ),
+            subexpr => parse_expression(state, cache, subexpr, pos, sort_context),
+        };
+        pos = res.pos;
+        pos_err.max_pos(res.pos_err);
+        results.push(res.result);
+        recovered |= res.recovered;
+        if !res.ok {
+            if let Some(&offset) = state.errors.get(&res.pos_err.position()) {
+                //The first token of the sequence can not be skipped, otherwise we can just parse a lot of empty sequences, if a sequence happens in a repeat
+                if i != 0 && cache.no_errors_nest_count == 0 {
+                    pos = res.pos_err;
+                    //If we're at the end of the file, don't try
+                    if pos.peek().is_none() {
+                        let span = CompactSpan::from_end(start_pos, pos.position());
+                        return ParseResult::new_err(
+                            ParsePairRaw::List(span, results),
+                            pos,
+                            pos_err,
+                        );
+                    }
+                    pos.skip_n(offset);
+                    recovered = true;
+                    continue;
+                }
             }
-            res
+
+            let start_pos = results
+                .get(0)
+                .map(|pp| pp.span().start())
+                .unwrap_or(start_pos);
+            let span = CompactSpan::from_end(start_pos, pos.position());
+            return ParseResult::new_err(ParsePairRaw::List(span, results), pos, pos_err);
         }
     }
+
+    //Construct result
+    let start_pos = results
+        .get(0)
+        .map(|pp| pp.span().start())
+        .unwrap_or(start_pos);
+    let span = CompactSpan::from_end(start_pos, pos.position());
+    ParseResult::new_ok(ParsePairRaw::List(span, results), pos, pos_err, recovered)
 }
 
-pub fn skip_single_layout<'src>(
+/// Parses `subexpr` as many times as the value of the integer that was parsed as `count`.
+/// When that is not a count, because it is negative or it is not as wide as the integer, this fails.
+#[allow(clippy::too_many_arguments)]
+fn parse_counted<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    subexpr: &'src CoreExpression,
+    id: RepeatId,
+    count: &ParsePairRaw,
+    integer: &IntegerFormat,
+    pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    let span = count.span();
+    match integer.decode(&state.file.bytes()[span.start()..span.end()]) {
+        Some(n) => parse_repeat(state, cache, subexpr, id, n, Some(n), pos, sort_context),
+        None => {
+            if cache.no_errors_nest_count == 0 {
+                let span = Span::from_end(state.file, span.start(), span.end());
+                let expect = Expect::Custom("a count that is not negative".to_string());
+                cache.add_error(PEGParseError::expect(span, expect, sort_context));
+            }
+            let span = CompactSpan::from_length(pos.position(), 0);
+            ParseResult::new_err(ParsePairRaw::List(span, vec![]), pos, pos)
+        }
+    }
+}
+
+/// Parses `subexpr` at least `min` and at most `max` times. `id` identifies the repetition, see [`ParserState::resume_repeat`].
+#[allow(clippy::too_many_arguments)]
+fn parse_repeat<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    subexpr: &'src CoreExpression,
+    id: RepeatId,
+    min: u64,
+    max: Option<u64>,
+    mut pos: SourceFileIterator<'src>,
+    sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    let mut results = vec![];
+    let start_pos = pos.position();
+    let mut pos_err = pos;
+    let mut recovered = false;
+
+    //While recovering from errors, continue after the items that were parsed by an earlier attempt
+    let recovering = !state.errors.is_empty();
+    if let Some(items) = recovering
+        .then(|| cache.resume_repeat(id, start_pos))
+        .flatten()
+    {
+        for item in items {
+            pos = state.file.iter_at(item.pos);
+            pos_err.max_pos(state.file.iter_at(item.pos_err));
+            recovered |= item.recovered;
+            cache.examine(item.examined);
+            results.push(item.result);
+        }
+    }
+    let mut last_pos = pos.position();
+
+    //Parse at most maximum times
+    for i in results.len() as u64..max.unwrap_or(u64::MAX) {
+        let res = parse_expression(state, cache, subexpr, pos, sort_context);
+        pos_err.max_pos(res.pos_err);
+        recovered |= res.recovered;
+
+        if res.ok {
+            pos = res.pos;
+            results.push(res.result);
+        } else {
+            //If we know about this error, try to continue?
+            //Don't try to continue if we haven't made any progress (already failed on first character), since we will just fail again
+            //Also don't try to continue if we don't allow errors at the moment, since we don't want to try to recover inside of an no-errors segment
+            if let Some(&offset) = state.errors.get(&res.pos_err.position()) {
+                if (offset > 0 || pos.position() != res.pos_err.position())
+                    && cache.no_errors_nest_count == 0
+                {
+                    pos = res.pos_err;
+                    //If we're at the end of the file, don't try
+                    if pos.peek().is_none() {
+                        let span = CompactSpan::from_end(start_pos, pos.position());
+                        return ParseResult::new_err(
+                            ParsePairRaw::List(span, results),
+                            pos,
+                            pos_err,
+                        );
+                    }
+                    pos.skip_n(offset);
+                    results.push(res.result);
+                    recovered = true;
+                    record_repeat_item(cache, id, start_pos, &results, pos, pos_err, recovered);
+                    continue;
+                }
+            }
+            //If we have not yet reached the minimum, we error.
+            //Otherwise, we break and ok after the loop body.
+            //In case we reached the minimum, we don't push the error, even though the failure might've been an error.
+            //This is because it's probably OK, and we want no Error pairs in the parse tree when it's OK.
+            if i < min {
+                pos = res.pos;
+                results.push(res.result);
+                let start_pos = results
+                    .get(0)
+                    .map(|pp| pp.span().start())
+                    .unwrap_or(start_pos);
+                let span = CompactSpan::from_end(start_pos, pos.position());
+                return ParseResult::new_err(ParsePairRaw::List(span, results), pos, pos_err);
+            } else {
+                break;
+            }
+        }
+        //If the position hasn't changed, then we're in an infinite loop
+        if last_pos == pos.position() {
+            let span = Span::from_length(state.file, pos.position(), 0);
+            cache.add_error(PEGParseError::fail_loop(span.clone()));
+            return ParseResult::new_err(ParsePairRaw::List(span.into(), results), pos, pos_err);
+        }
+        last_pos = pos.position();
+        if recovering {
+            record_repeat_item(cache, id, start_pos, &results, pos, pos_err, recovered);
+        }
+    }
+
+    //Construct result
+    let start_pos = results
+        .get(0)
+        .map(|pp| pp.span().start())
+        .unwrap_or(start_pos);
+    let span = CompactSpan::from_end(start_pos, pos.position());
+    ParseResult::new_ok(ParsePairRaw::List(span, results), pos, pos_err, recovered)
+}
+
+/// Records the last item of `results` of the repetition `id`, see [`ParserState::resume_repeat`].
+fn record_repeat_item<'src>(
+    cache: &mut ParserState<'src>,
+    id: RepeatId,
+    start_pos: usize,
+    results: &[ParsePairRaw],
+    pos: SourceFileIterator<'src>,
+    pos_err: SourceFileIterator<'src>,
+    recovered: bool,
+) {
+    let item = RepeatItem {
+        result: results.last().expect("an item was parsed").clone(),
+        pos: pos.position(),
+        pos_err: pos_err.position(),
+        recovered,
+        examined: cache.examined,
+    };
+    cache.record_repeat(id, start_pos, results.len() - 1, item);
+}
+
+fn parse_choice<'src>(
     state: &ParserContext<'src>,
     cache: &mut ParserState<'src>,
+    subexprs: &'src [CoreExpression],
+    firsts: &'src [First],
     pos: SourceFileIterator<'src>,
     sort_context: &ExpressionContext<'src>,
+) -> ParseResult<'src, ParsePairRaw> {
+    //Try each constructor, keeping track of the best error that occurred while doing so.
+    //If none of the constructors succeed, we will return this error.
+    let mut results = vec![];
+    assert!(!subexprs.is_empty());
+    //If these are the constructors of the sort, errors should know which constructor was tried
+    let constructors = sort_context
+        .sort
+        .filter(|sort| matches!(&sort.expr, CoreExpression::Choice(c, _) if std::ptr::eq(&c[..], subexprs)))
+        .map(|sort| &sort.constructors);
+    //The position of the next character after layout, which is only found when an alternative needs it
+    let mut next_char_pos = None;
+    for (i, subexpr) in subexprs.iter().enumerate() {
+        if let (Some(constructors), Some(frame)) = (constructors, cache.context_stack.last_mut()) {
+            frame.constructor = constructors.get(i).copied();
+        }
+        if let (true, Some(first)) = (cache.predict, firsts.get(i)) {
+            if let Some(skipped) = predict_alternative(state, cache, first, pos, &mut next_char_pos)
+            {
+                results.push(skipped);
+                continue;
+            }
+        }
+        let res = parse_expression(state, cache, subexpr, pos, sort_context);
+        state.trace(|| TraceEvent::Alternative {
+            sort: sort_context.name.unwrap_or_default().to_string(),
+            index: i,
+            position: pos.position(),
+            end: result_end(&res),
+            ok: res.ok,
+        });
+        if res.ok && !res.recovered {
+            return ParseResult::new_ok(
+                ParsePairRaw::Choice(res.result.span(), i, Box::new(res.result)),
+                res.pos,
+                res.pos_err,
+                res.recovered,
+            );
+        }
+        results.push(res);
+    }
+    //Chose best candidate
+    let (i, res) = results
+        .into_iter()
+        .enumerate()
+        .max_by_key(|(_, r)| r.pos_err.position())
+        .unwrap();
+    ParseResult::new(
+        ParsePairRaw::Choice(res.result.span(), i, Box::new(res.result)),
+        res.pos,
+        res.pos_err,
+        res.ok,
+        res.recovered,
+    )
+}
+
+/// Checks whether an alternative with this first set can match at `pos`. If it can't, this returns
+/// the failed result it would have given, without the errors it would have reported.
+/// `next_char_pos` is where the next character after layout is, once it is known.
+fn predict_alternative<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    first: &First,
+    pos: SourceFileIterator<'src>,
+    next_char_pos: &mut Option<SourceFileIterator<'src>>,
+) -> Option<ParseResult<'src, ParsePairRaw>> {
+    if first.nullable {
+        return None;
+    }
+    let mut next = if cache.allow_layout {
+        //The alternative tries its first character before every piece of layout, so
+        //it can only be predicted when it can't start with the start of layout
+        let layout = &state.ast.layout_first;
+        if layout.nullable || layout.chars.intersects(&first.chars) {
+            return None;
+        }
+        *next_char_pos.get_or_insert_with(|| skip_layout(state, cache, pos))
+    } else {
+        pos
+    };
+    match next.peek() {
+        Some(&c) if first.chars.contains(c) => None,
+        _ => {
+            cache.examine(next.position() + 1);
+            let span = CompactSpan::from_length(next.position(), 0);
+            Some(ParseResult::new_err(ParsePairRaw::Error(span), next, next))
+        }
+    }
+}
+
+pub fn skip_single_layout<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    pos: SourceFileIterator<'src>,
 ) -> (bool, SourceFileIterator<'src>) {
     //Automatically make layout rule no-layout and no-errors
     let prev_allow_layout = cache.allow_layout;
@@ -390,13 +865,37 @@ pub fn skip_single_layout<'src>(
     cache.no_errors_nest_count += 1;
     cache.allow_layout = false;
 
-    let layout_sort = state.ast.sorts.get("layout").expect("Layout exists");
-    let layout_expr = &layout_sort.expr;
-    let layout_res = parse_expression(state, cache, layout_expr, pos.clone(), sort_context);
+    let layout_sort = state.ast.sort(state.ast.layout);
+    let layout_expr = match &layout_sort.optimized {
+        Some(optimized) if cache.optimized => &optimized.expr,
+        _ => &layout_sort.expr,
+    };
+    //Errors in layout are never reported, the context is only used to trace the layout sort
+    let layout_context = ExpressionContext {
+        name: Some(layout_sort.name),
+        error: None,
+        sort: None,
+    };
+    let layout_res = parse_expression(state, cache, layout_expr, pos, &layout_context);
 
     cache.no_layout_nest_count -= 1;
     cache.no_errors_nest_count -= 1;
     cache.allow_layout = prev_allow_layout;
 
+    if layout_res.ok {
+        state.trace(|| TraceEvent::Layout {
+            position: pos.position(),
+            end: layout_res.pos.position(),
+        });
+    }
     (layout_res.ok, layout_res.pos)
 }
+
+/// The position after a successful result, or where it failed otherwise
+fn result_end<O: Clone>(res: &ParseResult<'_, O>) -> usize {
+    if res.ok {
+        res.pos.position()
+    } else {
+        res.pos_err.position()
+    }
+}
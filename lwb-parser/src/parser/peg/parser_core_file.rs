@@ -1,97 +1,299 @@
 use crate::parser::peg::parse_error::{Expect, PEGParseError};
+use crate::parser::peg::parse_options::{ErrorOrder, ParseAborted, ParseOptions};
+use crate::parser::peg::parse_outcome::ParseOutcome;
 use crate::parser::peg::parse_result::ParseResult;
+use crate::parser::peg::parse_stats::ParseStats;
+use crate::parser::peg::parse_trace::TraceEvent;
 use crate::parser::peg::parser_core::{ParserContext, ParserState};
-use crate::parser::peg::parser_core_ast::{CoreAst, ParsePairRaw};
+use crate::parser::peg::parser_core_ast::{CoreAst, ParsePairRaw, SortId};
 use crate::parser::peg::parser_core_expression::{
     parse_expression_name, skip_single_layout, ExpressionContext,
 };
+use crate::parser::peg::parser_incremental::Memo;
+use crate::parser::peg::parser_native::NativeParser;
+use crate::parser::syntax_file::ParseError;
 use crate::sources::source_file::{SourceFile, SourceFileIterator};
 use crate::sources::span::Span;
-use std::collections::{HashMap, VecDeque};
+use std::cell::RefCell;
+use std::sync::Arc;
 
 /// Parses a file, given the syntax to parse it with, and the file.
 /// When successful, it returns a `ParsePairSort`.
 /// When unsuccessful, it returns a `ParseError`.
-#[allow(clippy::unnecessary_unwrap)] //Clippy gives a suggestion which makes code ugly
 pub fn parse_file<'src>(
     ast: &'src CoreAst<'src>,
     file: &'src SourceFile,
 ) -> (ParsePairRaw, Vec<PEGParseError>) {
-    //Create a new parser state
-    let mut state = ParserContext {
-        file,
-        ast,
-        errors: HashMap::new(),
+    parse_file_with_options(ast, file, &ParseOptions::default())
+        .expect("parsing without limits is never aborted")
+}
+
+/// Like [`parse_file`], but the parse stops early when it exceeds
+/// the limits given in the options.
+pub fn parse_file_with_options<'src>(
+    ast: &'src CoreAst<'src>,
+    file: &'src SourceFile,
+    options: &'src ParseOptions,
+) -> Result<(ParsePairRaw, Vec<PEGParseError>), ParseAborted> {
+    let outcome = parse_file_outcome(ast, file, options)?;
+    Ok((outcome.tree, outcome.errors))
+}
+
+/// Like [`parse_file_with_options`], but also collects statistics about the parse.
+/// Collecting statistics makes parsing slower.
+pub fn parse_file_with_stats<'src>(
+    ast: &'src CoreAst<'src>,
+    file: &'src SourceFile,
+    options: &'src ParseOptions,
+) -> Result<(ParsePairRaw, Vec<PEGParseError>, ParseStats), ParseAborted> {
+    let mut state = ParserContext::new(file, ast, options);
+    state.stats = Some(RefCell::default());
+    let outcome = parse_file_context(&mut state, options)?;
+    Ok((
+        outcome.tree,
+        outcome.errors,
+        outcome.stats.expect("stats are collected"),
+    ))
+}
+
+/// Parses a file, configured by the options. Returns everything that is known after the parse,
+/// or why the parse stopped before it finished.
+/// Panics when the options give a starting sort which does not exist.
+pub fn parse_file_outcome<'src>(
+    ast: &'src CoreAst<'src>,
+    file: &'src SourceFile,
+    options: &'src ParseOptions,
+) -> Result<ParseOutcome<ParsePairRaw>, ParseAborted> {
+    parse_file_outcome_native(ast, file, options, None)
+}
+
+/// Like [`parse_file_outcome`], but the parse uses the parser generated as Rust code for the grammar, if it is given.
+pub(crate) fn parse_file_outcome_native<'src>(
+    ast: &'src CoreAst<'src>,
+    file: &'src SourceFile,
+    options: &'src ParseOptions,
+    native: Option<&'src NativeParser>,
+) -> Result<ParseOutcome<ParsePairRaw>, ParseAborted> {
+    let mut state = ParserContext::new(file, ast, options);
+    state.native = native;
+    if options.stats() {
+        state.stats = Some(RefCell::default());
+    }
+    parse_file_context(&mut state, options)
+}
+
+pub(crate) fn parse_file_context<'src>(
+    state: &mut ParserContext<'src>,
+    options: &ParseOptions,
+) -> Result<ParseOutcome<ParsePairRaw>, ParseAborted> {
+    let sort = match options.starting_sort() {
+        Some(sort) => state.ast.sort_id(sort).expect("sort exists"),
+        None => state.ast.starting_sort,
     };
 
+    let (tree, mut errors, recovered) = parse_file_recover(state, sort, options)?;
+    errors
+        .iter_mut()
+        .for_each(|err| skip_context_layout(state, err));
+    if options.error_order() == ErrorOrder::Position {
+        errors.sort_by_key(|e| e.span.position);
+    }
+    errors.truncate(options.max_errors());
+
+    Ok(ParseOutcome {
+        tree,
+        errors,
+        warnings: vec![],
+        recovered,
+        stats: state.stats.take().map(RefCell::into_inner),
+    })
+}
+
+/// Parses the file as `sort`, recovering from errors as far as the options allow.
+/// Returns the result, the errors and whether the parser recovered from them.
+///
+/// To recover from an error, the position of the error is added to `state.errors` with how many characters
+/// to skip there, after which the file is parsed again. When that doesn't get past the error, one more character
+/// is skipped, and so on. Every attempt uses the cache of the previous one, and drops the entries that examined the
+/// changed position only when they are looked up. Repetitions continue after the items they had parsed before the
+/// error, so an attempt does not parse the part of the file before the error again.
+#[allow(clippy::unnecessary_unwrap)] //Clippy gives a suggestion which makes code ugly
+fn parse_file_recover<'src>(
+    state: &mut ParserContext<'src>,
+    sort: SortId,
+    options: &ParseOptions,
+) -> Result<(ParsePairRaw, Vec<PEGParseError>, bool), ParseAborted> {
+    let file = state.file;
+
     //Parse the starting sort
     let mut errors = vec![];
+    let mut cache = ParserState::for_context(state);
 
     let mut last_err_pos: Option<usize> = None;
     let mut last_err_offset = 0usize;
-    loop {
-        let (res, err) = parse_file_sub(&state, state.ast.starting_sort, file.iter());
+    let (result, recovered) = loop {
+        let (mut res, mut err) = parse_file_attempt(state, &mut cache, sort, file.iter());
+        //The errors of the cache entries that are kept are not found again, but they are all before the error position.
+        //In the rare case that the best error is before it as well, the attempt is repeated without them.
+        if let (Some(pos), false) = (last_err_pos, res.ok) {
+            if matches!(&err, Some(err) if err.span.position < pos) {
+                cache = ParserState::for_context(state);
+                (res, err) = parse_file_attempt(state, &mut cache, sort, file.iter());
+            }
+        }
+        if let Some(aborted) = state.limiter.aborted() {
+            return Err(aborted);
+        }
         if !res.ok {
             let err = err.expect("Not ok means an error happened.");
 
+            //Input that is nested too deeply can't be recovered from, skipping ahead won't make it any shallower
+            if err.fail_nesting {
+                errors.push(err);
+                break (res.result, false);
+            }
+
             //If this is the first time we encounter this, error, log it and retry
             if last_err_pos.is_none()
                 || last_err_pos.unwrap() + last_err_offset < res.pos_err.position()
             {
                 errors.push(err);
+                if !options.recovery() || errors.len() >= options.max_errors() {
+                    break (res.result, false);
+                }
+
                 last_err_pos = Some(res.pos_err.position());
                 last_err_offset = 0;
                 state.errors.insert(last_err_pos.unwrap(), last_err_offset);
+                cache.retry(last_err_pos.unwrap());
+                state.trace(|| TraceEvent::Retry {
+                    position: res.pos_err.position(),
+                    skip: 0,
+                });
 
                 continue;
             } else {
                 //If the error now spans rest of file, we could not recover
-                let len_left = res.pos_err.clone().count();
+                let len_left = res.pos_err.count();
                 if last_err_offset >= len_left {
-                    return (res.result, errors);
+                    break (res.result, false);
                 }
 
                 //Increase offset by 1 and repeat
                 last_err_offset += 1;
                 state.errors.insert(last_err_pos.unwrap(), last_err_offset);
+                cache.retry(last_err_pos.unwrap());
+                state.trace(|| TraceEvent::Retry {
+                    position: last_err_pos.unwrap(),
+                    skip: last_err_offset,
+                });
             }
         } else {
-            return (res.result, errors);
+            break (res.result, !errors.is_empty());
         }
+    };
+
+    //Without the cache, nothing else refers to the tree anymore, so it doesn't have to be copied
+    drop(cache);
+    Ok((Arc::unwrap_or_clone(result), errors, recovered))
+}
+
+/// Parses as much of a file as possible as the given sort, starting at byte `offset`.
+/// Unlike [`parse_file`], the rest of the file does not need to be consumed. Layout before the
+/// parsed part is skipped, but layout after it is not.
+/// Returns the result, the offset directly after the parsed part and the error if parsing failed.
+/// No error recovery is attempted, since it is not known where the parsed part should end.
+///
+/// Fails when the sort is not in the grammar, when `offset` is not at the start of a character
+/// in the file, or when the parse exceeds the limits given in the options.
+pub fn parse_prefix<'src>(
+    ast: &'src CoreAst<'src>,
+    file: &'src SourceFile,
+    sort: SortId,
+    offset: usize,
+    options: &'src ParseOptions,
+) -> Result<(ParsePairRaw, usize, Option<PEGParseError>), ParseError> {
+    if sort.0 >= ast.sorts.len() {
+        return Err(ParseError::UnknownSort(sort.0.to_string()));
     }
+    if !file.is_char_boundary(offset) {
+        return Err(ParseError::InvalidOffset(offset));
+    }
+    let state = ParserContext::new(file, ast, options);
+
+    let pos = skip_layout(&state, &mut ParserState::new(), file.iter_at(offset));
+    let (res, cache) = parse_prefix_sub(&state, sort, pos);
+    if let Some(aborted) = state.limiter.aborted() {
+        return Err(aborted.into());
+    }
+    let mut err = if res.ok { None } else { cache.best_error };
+    if let Some(err) = &mut err {
+        skip_context_layout(&state, err);
+    }
+    Ok((Arc::unwrap_or_clone(res.result), res.pos.position(), err))
 }
 
 pub fn parse_file_sub<'src>(
     state: &ParserContext<'src>,
-    sort: &'src str,
+    sort: SortId,
     pos: SourceFileIterator<'src>,
 ) -> (ParseResult<'src, ParsePairRaw>, Option<PEGParseError>) {
-    let mut cache = ParserState {
-        cache: HashMap::new(),
-        cache_stack: VecDeque::new(),
-        best_error: None,
-        no_layout_nest_count: 0usize,
-        no_errors_nest_count: 0usize,
-        allow_layout: true,
-    };
+    let (res, err) = parse_file_attempt(state, &mut ParserState::for_context(state), sort, pos);
+    (res.map(Arc::unwrap_or_clone), err)
+}
 
-    let mut res = parse_expression_name(state, &mut cache, sort, pos);
-    if !res.ok {
-        return (res, Some(cache.best_error.unwrap()));
+/// Like [`parse_file_sub`], but with a cache which may contain entries of an earlier attempt.
+fn parse_file_attempt<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    sort: SortId,
+    pos: SourceFileIterator<'src>,
+) -> (ParseResult<'src, Arc<ParsePairRaw>>, Option<PEGParseError>) {
+    let (res, err) = match native_parser(state, cache) {
+        Some(native) => (native.parse_file(state, sort, pos), None),
+        None => parse_file_attempt_once(state, cache, sort, pos),
+    };
+    //Skipped alternatives don't report their errors, so to find the error the parse is done again without skipping
+    if !res.ok && cache.predict && state.limiter.aborted().is_none() {
+        *cache = ParserState::for_errors(state);
+        return parse_file_attempt_once(state, cache, sort, pos);
     }
+    (res, err)
+}
 
-    //If there is no input left, return Ok. Skip layout first
-    loop {
-        let (ok, after_layout_pos) = skip_single_layout(
-            state,
-            &mut cache,
-            res.pos.clone(),
-            &ExpressionContext::empty(),
-        );
-        if !ok {
-            break;
-        };
-        res.pos = after_layout_pos;
+/// The parser generated as Rust code, if it can be used for this attempt. It gives the result of an attempt
+/// which skips alternatives, but not its errors, and it doesn't collect statistics or keep the memo.
+fn native_parser<'src>(
+    state: &ParserContext<'src>,
+    cache: &ParserState<'src>,
+) -> Option<&'src NativeParser> {
+    let interpreted = !cache.predict
+        || cache.stats.is_some()
+        || state.reuse.is_some()
+        || state.memo.is_some()
+        || state.max_memo_entries.is_some();
+    state.native.filter(|_| !interpreted)
+}
+
+fn parse_file_attempt_once<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    sort: SortId,
+    pos: SourceFileIterator<'src>,
+) -> (ParseResult<'src, Arc<ParsePairRaw>>, Option<PEGParseError>) {
+    let mut res = parse_expression_name(state, cache, sort, pos);
+    if res.ok {
+        //If there is no input left, return Ok. Skip layout first
+        res.pos = skip_layout(state, cache, res.pos);
+    }
+    state.merge_stats(cache);
+    //Only the first attempt skips alternatives, the later ones parse again without the entries of this one
+    if let (Some(memo), true) = (&state.memo, cache.predict) {
+        *memo.borrow_mut() = Some(Memo::new(state.file, cache));
+    }
+    if !res.ok {
+        //There may be no error when the parse was aborted
+        return (res, cache.best_error.take());
     }
 
     if res.pos.peek().is_none() {
@@ -101,7 +303,7 @@ pub fn parse_file_sub<'src>(
         //I'm not entirely sure this logic always returns relevant errors. Maybe we should inform the user the parse was actually fine, but didn't parse enough?
         // TODO: ^
         res.ok = false;
-        match cache.best_error {
+        match cache.best_error.take() {
             Some(err) => (res, Some(err)),
             None => {
                 let curpos = res.pos.position();
@@ -119,3 +321,54 @@ pub fn parse_file_sub<'src>(
         }
     }
 }
+
+/// Parses a sort starting at `pos`, without requiring that it ends at the end of the file.
+/// Returns the result, and the parser state that was used, which contains the best error if parsing failed.
+pub fn parse_prefix_sub<'src>(
+    state: &ParserContext<'src>,
+    sort: SortId,
+    pos: SourceFileIterator<'src>,
+) -> (ParseResult<'src, Arc<ParsePairRaw>>, ParserState<'src>) {
+    let mut cache = ParserState::for_context(state);
+    let res = parse_expression_name(state, &mut cache, sort, pos);
+    //Like in a file, the error is found by parsing again without skipping alternatives
+    if !res.ok && cache.predict && state.limiter.aborted().is_none() {
+        cache = ParserState::for_errors(state);
+        let res = parse_expression_name(state, &mut cache, sort, pos);
+        return (res, cache);
+    }
+    (res, cache)
+}
+
+/// Sorts start before the layout in front of them. Moves the start of the sorts an error occurred in past that
+/// layout, so their labels point at the sorts themselves.
+pub(crate) fn skip_context_layout(state: &ParserContext, error: &mut PEGParseError) {
+    for context in &mut error.context {
+        context.start = skip_layout_for_errors(state, state.file.iter_at(context.position));
+    }
+}
+
+/// Like [`skip_layout`], but for positions that are only needed to report errors. This is not part of the parse,
+/// so it doesn't use the cache of the parse and is not traced, but it is limited like the parse.
+/// Returns the position after the layout.
+pub(crate) fn skip_layout_for_errors<'src>(
+    state: &ParserContext<'src>,
+    pos: SourceFileIterator<'src>,
+) -> usize {
+    skip_layout(&state.for_errors(), &mut ParserState::new(), pos).position()
+}
+
+/// Skips as much layout as possible, starting at `pos`.
+pub fn skip_layout<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    mut pos: SourceFileIterator<'src>,
+) -> SourceFileIterator<'src> {
+    loop {
+        let (ok, after_layout_pos) = skip_single_layout(state, cache, pos);
+        if !ok {
+            return pos;
+        };
+        pos = after_layout_pos;
+    }
+}
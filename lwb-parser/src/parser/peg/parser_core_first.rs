@@ -0,0 +1,137 @@
+use crate::parser::peg::parser_core_ast::{CoreAst, CoreExpression};
+use crate::sources::character_class::CharacterSet;
+
+/// The characters an expression can start with, and whether it can succeed without consuming any input.
+/// The parser uses these to skip the alternatives of a choice that can't match the next character.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct First {
+    /// The characters the expression can start with
+    pub chars: CharacterSet,
+    /// True when the expression can succeed without consuming any input
+    pub nullable: bool,
+}
+
+impl First {
+    fn nullable() -> Self {
+        Self {
+            chars: CharacterSet::default(),
+            nullable: true,
+        }
+    }
+
+    /// The first set of this expression followed by another one.
+    /// The other one only matters when this one is nullable.
+    fn then(mut self, next: impl FnOnce() -> First) -> Self {
+        if self.nullable {
+            let next = next();
+            self.chars.extend(&next.chars);
+            self.nullable = next.nullable;
+        }
+        self
+    }
+
+    /// The first set of a choice between this expression and another one.
+    fn or(mut self, other: &First) -> Self {
+        self.chars.extend(&other.chars);
+        self.nullable |= other.nullable;
+        self
+    }
+}
+
+/// Computes the first sets of the alternatives of every choice in the grammar, and of the layout sort.
+///
+/// The sets may contain more than what can actually be parsed (like a repeat of something that is nullable,
+/// which fails because it doesn't make progress), but never less. So an alternative can safely be skipped
+/// when the next character is not in its first set and it is not nullable.
+pub(crate) fn compute_first_sets(ast: &mut CoreAst) {
+    let sorts = sort_first_sets(ast);
+    for sort in &mut ast.sorts {
+        fill_first_sets(&mut sort.expr, &sorts);
+        if let Some(optimized) = &mut sort.optimized {
+            fill_first_sets(&mut optimized.expr, &sorts);
+        }
+    }
+    ast.layout_first = sorts[ast.layout.0].clone();
+}
+
+/// The first set of every sort, by the id of the sort.
+pub(crate) fn sort_first_sets(ast: &CoreAst) -> Vec<First> {
+    //The first sets of sorts depend on each other, so they are grown until none of them change anymore
+    let mut sorts = vec![First::default(); ast.sorts.len()];
+    loop {
+        let mut changed = false;
+        for sort in &ast.sorts {
+            let first = first_set(&sort.expr, &sorts);
+            if first != sorts[sort.id.0] {
+                sorts[sort.id.0] = first;
+                changed = true;
+            }
+        }
+        if !changed {
+            return sorts;
+        }
+    }
+}
+
+fn first_set(expr: &CoreExpression, sorts: &[First]) -> First {
+    match expr {
+        CoreExpression::Name(sort) => sorts[sort.0].clone(),
+        CoreExpression::Sequence(subexprs) => {
+            subexprs.iter().fold(First::nullable(), |first, e| {
+                first.then(|| first_set(e, sorts))
+            })
+        }
+        CoreExpression::Repeat { max: Some(0), .. } => First::nullable(),
+        CoreExpression::Repeat { subexpr, min, .. } => {
+            let first = first_set(subexpr, sorts);
+            First {
+                nullable: first.nullable || *min == 0,
+                ..first
+            }
+        }
+        //The count may be 0
+        CoreExpression::Counted { subexpr, .. } => First {
+            nullable: true,
+            ..first_set(subexpr, sorts)
+        },
+        CoreExpression::CharacterClass(characters) => First {
+            chars: characters.set().clone(),
+            nullable: false,
+        },
+        CoreExpression::Literal(literal) => match literal.chars().next() {
+            Some(c) => First {
+                chars: c.into(),
+                nullable: false,
+            },
+            None => First::nullable(),
+        },
+        CoreExpression::Choice(subexprs, _) => subexprs
+            .iter()
+            .fold(First::default(), |first, e| first.or(&first_set(e, sorts))),
+        CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _)
+        | CoreExpression::Error(subexpr, _) => first_set(subexpr, sorts),
+        CoreExpression::Fail => First::default(),
+    }
+}
+
+fn fill_first_sets(expr: &mut CoreExpression, sorts: &[First]) {
+    match expr {
+        CoreExpression::Choice(subexprs, firsts) => {
+            *firsts = subexprs.iter().map(|e| first_set(e, sorts)).collect();
+            subexprs.iter_mut().for_each(|e| fill_first_sets(e, sorts));
+        }
+        CoreExpression::Sequence(subexprs) => {
+            subexprs.iter_mut().for_each(|e| fill_first_sets(e, sorts))
+        }
+        CoreExpression::Repeat { subexpr, .. }
+        | CoreExpression::Counted { subexpr, .. }
+        | CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _)
+        | CoreExpression::Error(subexpr, _) => fill_first_sets(subexpr, sorts),
+        CoreExpression::Name(_)
+        | CoreExpression::CharacterClass(_)
+        | CoreExpression::Literal(_)
+        | CoreExpression::Fail => {}
+    }
+}
@@ -0,0 +1,96 @@
+use crate::parser::peg::parser_core_ast::{CoreAst, CoreExpression, Memoize, SortId};
+use crate::parser::peg::parser_core_optimize::{recursive_sorts, single_state_sorts};
+use crate::parser::peg::parser_sugar_ast::{Annotation, MemoPolicy};
+use std::collections::HashSet;
+
+/// Sorts of at most this many expressions, counting the expressions of the sorts they use,
+/// are parsed again instead of memoized.
+const MAX_UNMEMOIZED_SIZE: usize = 16;
+
+/// Chooses which sorts are memoized in a parse of `starting_sort`, see [`Memoize`].
+///
+/// Looking up a result in the cache takes about as long as parsing a small sort again, and every entry uses memory.
+/// So small sorts that only use other small sorts, like the sorts of tokens, are not memoized. The `memo` annotation
+/// overrides this: `{memo: always}` memoizes a sort, `{memo: never}` doesn't.
+///
+/// Sorts that are used in their own expression are always memoized, since left recursion is parsed with the cache.
+/// Unless it is annotated, a sort is only parsed again when that gives the same result as taking it from the cache,
+/// which is when it is always parsed in the same state, like inlined sorts (see
+/// [`optimize_ast`](crate::parser::peg::parser_core_optimize::optimize_ast)).
+pub(crate) fn choose_memoization(ast: &mut CoreAst, starting_sort: SortId) {
+    let recursive = recursive_sorts(ast);
+    let single_state = single_state_sorts(ast, starting_sort);
+    let mut sizes = vec![None; ast.sorts.len()];
+
+    let memo: Vec<Memoize> = ast
+        .sorts
+        .iter()
+        .map(|sort| {
+            let policy = sort.annotations.iter().find_map(|a| match a {
+                Annotation::Memo(policy) => Some(*policy),
+                _ => None,
+            });
+            let cheap = match policy {
+                Some(MemoPolicy::Always) => false,
+                Some(MemoPolicy::Never) => true,
+                None => {
+                    sort.id != ast.layout
+                        && single_state.contains(&sort.id)
+                        && sort_size(ast, sort.id, &recursive, &mut sizes) <= MAX_UNMEMOIZED_SIZE
+                }
+            };
+            if cheap && !recursive.contains(&sort.id) {
+                Memoize::Never
+            } else {
+                Memoize::Always
+            }
+        })
+        .collect();
+
+    for (sort, memo) in ast.sorts.iter_mut().zip(memo) {
+        sort.memo = memo;
+    }
+}
+
+/// The number of expressions of a sort, including the expressions of the sorts it uses.
+/// Recursive sorts have no size, so they count as `usize::MAX`.
+fn sort_size(
+    ast: &CoreAst,
+    sort: SortId,
+    recursive: &HashSet<SortId>,
+    sizes: &mut Vec<Option<usize>>,
+) -> usize {
+    if recursive.contains(&sort) {
+        return usize::MAX;
+    }
+    if let Some(size) = sizes[sort.0] {
+        return size;
+    }
+    let size = expression_size(ast, &ast.sort(sort).expr, recursive, sizes);
+    sizes[sort.0] = Some(size);
+    size
+}
+
+fn expression_size(
+    ast: &CoreAst,
+    expr: &CoreExpression,
+    recursive: &HashSet<SortId>,
+    sizes: &mut Vec<Option<usize>>,
+) -> usize {
+    match expr {
+        CoreExpression::Name(sort) => sort_size(ast, *sort, recursive, sizes),
+        CoreExpression::Sequence(subexprs) | CoreExpression::Choice(subexprs, _) => {
+            subexprs.iter().fold(1, |size, e| {
+                size.saturating_add(expression_size(ast, e, recursive, sizes))
+            })
+        }
+        CoreExpression::Repeat { subexpr, .. }
+        | CoreExpression::Counted { subexpr, .. }
+        | CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _)
+        | CoreExpression::Error(subexpr, _) => {
+            1usize.saturating_add(expression_size(ast, subexpr, recursive, sizes))
+        }
+        CoreExpression::CharacterClass(_) | CoreExpression::Literal(_) | CoreExpression::Fail => 1,
+    }
+}
@@ -0,0 +1,852 @@
+use crate::parser::peg::parser_core_ast::{
+    CoreAst, CoreExpression, ParsePairRaw, RepeatId, SortId,
+};
+use crate::parser::peg::parser_core_first::{sort_first_sets, First};
+use crate::sources::character_class::{CharacterClass, CharacterSet};
+use crate::sources::compact_span::CompactSpan;
+use crate::sources::source_file::SourceFile;
+use std::collections::{HashMap, HashSet};
+use std::iter;
+use std::sync::Arc;
+
+/// The expression of a sort after the grammar was optimized, see [`optimize_ast`].
+#[derive(Debug, Clone)]
+pub struct OptimizedExpression<'src> {
+    pub expr: CoreExpression<'src>,
+    /// How a result of `expr` is turned into the result of the expression as written
+    pub shape: Shape,
+}
+
+/// Describes how the parse pair of an optimized expression is turned into the pair that the
+/// expression as written gives, see [`reshape`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Shape {
+    /// The pairs are the same
+    Same,
+    /// A sort that was inlined, so its pair is put into a name pair again
+    Name(Box<Shape>),
+    /// A sequence, of which the items are given to these shapes in order.
+    /// A [`Group`](Shape::Group) takes as many items as it has shapes itself.
+    Sequence(Vec<Shape>),
+    /// A sequence that was flattened into the sequence around it, or a sequence with a single item
+    /// which was replaced by that item. Its items are put into a list again.
+    Group(Vec<Shape>),
+    Repeat(Box<Shape>),
+    /// A choice, with the shape of each of its alternatives. These give the pair of the choice as written.
+    Choice(Vec<Shape>),
+    /// The alternative at this index of the choice as written
+    Alternative(usize, Box<Shape>),
+    /// Alternatives of single characters which were merged into one character class.
+    /// For each alternative, the characters it accepts, whether it was a literal, and its shape.
+    Characters(Vec<(CharacterSet, bool, Shape)>),
+    /// Alternatives that start with the same expression, which is parsed once before a choice between the
+    /// rest of each alternative. This has the shape of that choice, and for each alternative whether it
+    /// was a sequence (otherwise it was only the expression they start with) and its shape.
+    Factored(Box<Shape>, Vec<(bool, Shape)>),
+}
+
+/// Turns the pair of a successful parse of an optimized expression into the pair that the expression
+/// as written would have given.
+pub(crate) fn reshape(file: &SourceFile, shape: &Shape, pair: ParsePairRaw) -> ParsePairRaw {
+    match (shape, pair) {
+        (Shape::Same, pair) => pair,
+        (Shape::Name(shape), pair) => {
+            let pair = reshape(file, shape, pair);
+            ParsePairRaw::Name(pair.span(), Arc::new(pair))
+        }
+        (Shape::Sequence(shapes), pair @ ParsePairRaw::List(..)) => ParsePairRaw::List(
+            pair.span(),
+            reshape_items(file, shapes, &mut pair.into_list().into_iter()),
+        ),
+        (Shape::Group(shapes), pair) => reshape_group(file, shapes, &mut iter::once(pair)),
+        (Shape::Repeat(shape), pair @ ParsePairRaw::List(..)) => ParsePairRaw::List(
+            pair.span(),
+            pair.into_list()
+                .into_iter()
+                .map(|item| reshape(file, shape, item))
+                .collect(),
+        ),
+        (Shape::Choice(shapes), pair @ ParsePairRaw::Choice(..)) => {
+            let (i, pair) = pair.into_choice();
+            reshape(file, &shapes[i], pair)
+        }
+        (Shape::Alternative(i, shape), pair) => {
+            let pair = reshape(file, shape, pair);
+            ParsePairRaw::Choice(pair.span(), *i, Box::new(pair))
+        }
+        (Shape::Characters(alternatives), pair) => {
+            let start = pair.span().start();
+            let mut next = file.iter_at(start);
+            let c = *next.peek().expect("a character was parsed");
+            let (_, literal, shape) = alternatives
+                .iter()
+                .find(|(set, _, _)| set.contains(c))
+                .expect("an alternative accepts the character");
+            let pair = if *literal {
+                next.advance();
+                ParsePairRaw::List(CompactSpan::from_end(start, next.position()), vec![])
+            } else {
+                pair
+            };
+            reshape(file, shape, pair)
+        }
+        (Shape::Factored(choice, alternatives), pair @ ParsePairRaw::List(..)) => {
+            let span = pair.span();
+            let [first, rest]: [ParsePairRaw; 2] = pair
+                .into_list()
+                .try_into()
+                .expect("a factored choice gives two items");
+            //The rest of a factored choice is a choice
+            let (i, rest) = reshape(file, choice, rest).into_choice();
+            let (sequence, shape) = &alternatives[i];
+            let pair = match rest {
+                rest @ ParsePairRaw::List(..) if *sequence => {
+                    ParsePairRaw::List(span, iter::once(first).chain(rest.into_list()).collect())
+                }
+                _ => first,
+            };
+            reshape(file, shape, pair)
+        }
+        (shape, pair) => unreachable!("{pair:?} does not have the shape {shape:?}"),
+    }
+}
+
+fn reshape_items(
+    file: &SourceFile,
+    shapes: &[Shape],
+    items: &mut impl Iterator<Item = ParsePairRaw>,
+) -> Vec<ParsePairRaw> {
+    shapes
+        .iter()
+        .map(|shape| reshape_next(file, shape, items))
+        .collect()
+}
+
+/// Reshapes the next item, or the next items when the shape is a group (possibly in a choice or sort).
+fn reshape_next(
+    file: &SourceFile,
+    shape: &Shape,
+    items: &mut impl Iterator<Item = ParsePairRaw>,
+) -> ParsePairRaw {
+    match shape {
+        Shape::Group(shapes) => reshape_group(file, shapes, items),
+        Shape::Name(shape) => {
+            let pair = reshape_next(file, shape, items);
+            ParsePairRaw::Name(pair.span(), Arc::new(pair))
+        }
+        Shape::Alternative(i, shape) => {
+            let pair = reshape_next(file, shape, items);
+            ParsePairRaw::Choice(pair.span(), *i, Box::new(pair))
+        }
+        shape => reshape(file, shape, items.next().expect("an item for every shape")),
+    }
+}
+
+/// Puts the items of a group into a list again, with the span a sequence would have given it.
+fn reshape_group(
+    file: &SourceFile,
+    shapes: &[Shape],
+    items: &mut impl Iterator<Item = ParsePairRaw>,
+) -> ParsePairRaw {
+    let items = reshape_items(file, shapes, items);
+    let start = items.first().expect("groups are not empty").span().start();
+    let end = pair_end(file, items.last().expect("groups are not empty"));
+    ParsePairRaw::List(CompactSpan::from_end(start, end), items)
+}
+
+/// Where the input that a successful pair parsed ends. Character classes have a span with a length of 1,
+/// even when the character takes more bytes, so the end of those is found in the file.
+fn pair_end(file: &SourceFile, pair: &ParsePairRaw) -> usize {
+    match pair {
+        ParsePairRaw::Name(_, pair) => pair_end(file, pair),
+        ParsePairRaw::Choice(_, _, pair) => pair_end(file, pair),
+        ParsePairRaw::Empty(span) => {
+            let mut next = file.iter_at(span.start());
+            next.advance();
+            next.position()
+        }
+        ParsePairRaw::List(span, _) | ParsePairRaw::Error(span) => span.end(),
+    }
+}
+
+/// Whether layout is allowed at some point in the parse. Inside a no-layout expression, layout is still
+/// allowed before its first character, so that is a separate state.
+/// This is a set of these states, of which the parser may be in any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct LayoutStates(u8);
+
+impl LayoutStates {
+    const LAYOUT: Self = Self(1);
+    const NO_LAYOUT_START: Self = Self(2);
+    const NO_LAYOUT: Self = Self(4);
+    const ALL: Self = Self(7);
+
+    fn contains(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    fn without(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// The states after parsing an expression that may have parsed a character, or failed after that
+    fn after(self) -> Self {
+        if self.contains(Self::NO_LAYOUT_START) {
+            self.union(Self::NO_LAYOUT)
+        } else {
+            self
+        }
+    }
+
+    /// The states after parsing a character
+    fn after_character(self) -> Self {
+        if self.contains(Self::NO_LAYOUT_START) {
+            self.without(Self::NO_LAYOUT_START).union(Self::NO_LAYOUT)
+        } else {
+            self
+        }
+    }
+
+    /// The states inside a no-layout expression
+    fn no_layout(self) -> Self {
+        if self.contains(Self::LAYOUT) {
+            self.without(Self::LAYOUT).union(Self::NO_LAYOUT_START)
+        } else {
+            self
+        }
+    }
+
+    /// The states after an expression succeeded, when it was parsed in these states
+    fn succeed(self, expr: &CoreExpression) -> Self {
+        match expr {
+            CoreExpression::CharacterClass(_) => self.after_character(),
+            CoreExpression::Literal(literal) if !literal.is_empty() => self.after_character(),
+            CoreExpression::Literal(_) | CoreExpression::Fail => self,
+            CoreExpression::Sequence(subexprs) => {
+                subexprs.iter().fold(self, |states, e| states.succeed(e))
+            }
+            CoreExpression::FlagNoLayout(subexpr) => {
+                //The parser allows layout again after the outermost no-layout expression
+                let inner = self.without(Self::LAYOUT).succeed(subexpr);
+                inner.union(Self(self.0 & Self::LAYOUT.0))
+            }
+            CoreExpression::FlagNoErrors(subexpr, _) | CoreExpression::Error(subexpr, _) => {
+                self.succeed(subexpr)
+            }
+            //A memoized sort doesn't change the state, even when it parsed characters
+            CoreExpression::Name(_)
+            | CoreExpression::Repeat { .. }
+            | CoreExpression::Counted { .. }
+            | CoreExpression::Choice(..) => self.after(),
+        }
+    }
+}
+
+/// Optimizes the expressions of all sorts, for the parse of `starting_sort`. The expressions as written are kept,
+/// since parsing them gives the errors. The optimized expression is only parsed until the parse fails,
+/// after which the parser starts again with the expressions as written, see
+/// [`parse_file_attempt`](crate::parser::peg::parser_core_file).
+///
+/// The optimizations are:
+/// - Trivial sorts, which have a single constructor that is a single sort, character class or literal
+///   (or a repetition of it), are inlined where they are used.
+/// - Nested sequences and nested choices are flattened, and sequences and choices of a single expression are replaced by it.
+/// - Choices between single characters are merged into a single character class.
+/// - Adjacent alternatives of a choice that start with the same expression parse that expression once,
+///   followed by a choice between the rest of them.
+///
+/// Each of these gives the same result, as long as the parse succeeds. The results are turned back into the results
+/// of the expressions as written with the [`Shape`] of the optimized expression, so they can be resugared.
+pub(crate) fn optimize_ast(ast: &mut CoreAst, starting_sort: SortId) {
+    let mut optimizer = Optimizer::new(ast, starting_sort);
+    for sort in &ast.sorts {
+        optimizer.optimize_sort(sort.id);
+    }
+    let mut optimized = optimizer.optimized;
+    for sort in &mut ast.sorts {
+        sort.optimized = optimized
+            .remove(&sort.id)
+            .filter(|o| o.shape != Shape::Same || o.expr != sort.expr);
+    }
+}
+
+/// The states in which each sort may be parsed, starting at the starting sort.
+/// Layout is parsed without allowing layout inside it.
+fn sort_entries(ast: &CoreAst, starting_sort: SortId) -> HashMap<SortId, LayoutStates> {
+    let mut entries = HashMap::from([(starting_sort, LayoutStates::LAYOUT)]);
+    let layout = entries.entry(ast.layout).or_default();
+    *layout = layout.union(LayoutStates::NO_LAYOUT);
+    loop {
+        let mut found = entries.clone();
+        for (&sort, &states) in &entries {
+            find_names(&ast.sort(sort).expr, states, &mut found);
+        }
+        if found == entries {
+            return entries;
+        }
+        entries = found;
+    }
+}
+
+/// Whether a sort that is parsed in these states is always parsed in the same state. Parsing such a sort
+/// at the same position again gives the same result as taking it from the cache, which is not the case
+/// when it is parsed with and without layout at the same position.
+fn single_state(states: LayoutStates) -> bool {
+    states == LayoutStates::LAYOUT || states == LayoutStates::NO_LAYOUT
+}
+
+/// The sorts that are always parsed in the same state in a parse of `starting_sort`, see [`single_state`].
+pub(crate) fn single_state_sorts(ast: &CoreAst, starting_sort: SortId) -> HashSet<SortId> {
+    sort_entries(ast, starting_sort)
+        .into_iter()
+        .filter(|&(_, states)| single_state(states))
+        .map(|(sort, _)| sort)
+        .collect()
+}
+
+/// The sorts that are used in their own expression, directly or through other sorts.
+pub(crate) fn recursive_sorts(ast: &CoreAst) -> HashSet<SortId> {
+    let mut recursive = HashSet::new();
+    for sort in &ast.sorts {
+        let mut seen = HashSet::new();
+        let mut todo = vec![sort.id];
+        while let Some(used) = todo.pop() {
+            let mut names = vec![];
+            used_names(&ast.sort(used).expr, &mut names);
+            if names.contains(&sort.id) {
+                recursive.insert(sort.id);
+                break;
+            }
+            todo.extend(names.into_iter().filter(|n| seen.insert(*n)));
+        }
+    }
+    recursive
+}
+
+/// An optimized alternative of a choice, with the shape that gives the pair of the choice as written
+type Alternative<'src> = (CoreExpression<'src>, Shape);
+
+struct Optimizer<'a, 'src> {
+    ast: &'a CoreAst<'src>,
+    layout_first: First,
+    /// The states in which each sort may be parsed
+    entries: HashMap<SortId, LayoutStates>,
+    /// The sorts that are used in their own expression
+    recursive: HashSet<SortId>,
+    /// The sorts that contain an error expression, which succeeds without moving past what it parsed
+    resetting: HashSet<SortId>,
+    optimized: HashMap<SortId, OptimizedExpression<'src>>,
+}
+
+impl<'a, 'src> Optimizer<'a, 'src> {
+    fn new(ast: &'a CoreAst<'src>, starting_sort: SortId) -> Self {
+        let layout_first = sort_first_sets(ast).swap_remove(ast.layout.0);
+        let mut optimizer = Self {
+            ast,
+            layout_first,
+            entries: sort_entries(ast, starting_sort),
+            recursive: recursive_sorts(ast),
+            resetting: HashSet::new(),
+            optimized: HashMap::new(),
+        };
+        optimizer.find_resetting();
+        optimizer
+    }
+
+    fn find_resetting(&mut self) {
+        loop {
+            let resetting: HashSet<SortId> = self
+                .ast
+                .sorts
+                .iter()
+                .filter(|sort| may_reset(&sort.expr, &self.resetting))
+                .map(|sort| sort.id)
+                .collect();
+            if resetting.len() == self.resetting.len() {
+                break;
+            }
+            self.resetting = resetting;
+        }
+    }
+
+    fn optimize_sort(&mut self, id: SortId) -> &OptimizedExpression<'src> {
+        if !self.optimized.contains_key(&id) {
+            let sort = self.ast.sort(id);
+            //Sorts that are never parsed may be parsed in any state
+            let states = match self.entries.get(&id) {
+                Some(&states) if states != LayoutStates::default() => states,
+                _ => LayoutStates::ALL,
+            };
+            let (expr, shape) = self.optimize(&sort.expr, states);
+            self.optimized
+                .insert(id, OptimizedExpression { expr, shape });
+        }
+        &self.optimized[&id]
+    }
+
+    /// A sort is inlined when it is trivial, and it is always parsed in the same state, see [`single_state`].
+    fn inlined(&mut self, id: SortId) -> Option<OptimizedExpression<'src>> {
+        let sort = self.ast.sort(id);
+        let states = self.entries.get(&id).copied().unwrap_or_default();
+        if id == self.ast.layout
+            || self.recursive.contains(&id)
+            || sort.constructors.len() != 1
+            || !single_state(states)
+        {
+            return None;
+        }
+        let optimized = self.optimize_sort(id);
+        is_trivial(&optimized.expr).then(|| optimized.clone())
+    }
+
+    fn may_reset(&self, expr: &CoreExpression) -> bool {
+        may_reset(expr, &self.resetting)
+    }
+
+    /// Optimizes an expression which is parsed in one of `states`.
+    fn optimize(
+        &mut self,
+        expr: &CoreExpression<'src>,
+        states: LayoutStates,
+    ) -> (CoreExpression<'src>, Shape) {
+        match expr {
+            CoreExpression::Name(sort) => match self.inlined(*sort) {
+                Some(inlined) => (inlined.expr, Shape::Name(Box::new(inlined.shape))),
+                None => (expr.clone(), Shape::Same),
+            },
+            CoreExpression::Sequence(subexprs) => self.optimize_sequence(subexprs, states),
+            CoreExpression::Repeat {
+                subexpr,
+                min,
+                max,
+                id,
+            } => {
+                let (subexpr, shape) =
+                    self.optimize(subexpr, states.union(states.succeed(subexpr)));
+                let repeat = CoreExpression::Repeat {
+                    subexpr: Box::new(subexpr),
+                    min: *min,
+                    max: *max,
+                    id: *id,
+                };
+                match shape {
+                    Shape::Same => (repeat, Shape::Same),
+                    shape => (repeat, Shape::Repeat(Box::new(shape))),
+                }
+            }
+            CoreExpression::Counted {
+                subexpr,
+                count,
+                integer,
+                id,
+            } => {
+                let (subexpr, shape) =
+                    self.optimize(subexpr, states.union(states.succeed(subexpr)));
+                let counted = CoreExpression::Counted {
+                    subexpr: Box::new(subexpr),
+                    count: *count,
+                    integer: *integer,
+                    id: *id,
+                };
+                match shape {
+                    Shape::Same => (counted, Shape::Same),
+                    shape => (counted, Shape::Repeat(Box::new(shape))),
+                }
+            }
+            CoreExpression::Choice(subexprs, _) => self.optimize_choice(subexprs, states),
+            CoreExpression::FlagNoLayout(subexpr) => {
+                let (subexpr, shape) = self.optimize(subexpr, states.no_layout());
+                (CoreExpression::FlagNoLayout(Box::new(subexpr)), shape)
+            }
+            CoreExpression::FlagNoErrors(subexpr, expect) => {
+                let (subexpr, shape) = self.optimize(subexpr, states);
+                let expr = CoreExpression::FlagNoErrors(Box::new(subexpr), expect.clone());
+                (expr, shape)
+            }
+            CoreExpression::Error(subexpr, message) => {
+                let (subexpr, shape) = self.optimize(subexpr, states);
+                (
+                    CoreExpression::Error(Box::new(subexpr), message.clone()),
+                    shape,
+                )
+            }
+            CoreExpression::CharacterClass(_)
+            | CoreExpression::Literal(_)
+            | CoreExpression::Fail => (expr.clone(), Shape::Same),
+        }
+    }
+
+    fn optimize_sequence(
+        &mut self,
+        subexprs: &[CoreExpression<'src>],
+        mut states: LayoutStates,
+    ) -> (CoreExpression<'src>, Shape) {
+        let mut items = vec![];
+        let mut shapes = vec![];
+        for subexpr in subexprs {
+            let (item, shape) = self.optimize(subexpr, states);
+            states = states.succeed(subexpr);
+            match item {
+                //The end of a group is found from its last item, so that item should end where the group ends
+                CoreExpression::Sequence(inner)
+                    if !inner.is_empty()
+                        && !self.may_reset(inner.last().unwrap())
+                        && flattens(&shape)
+                        && !counts(subexprs)
+                        && !counts(&inner) =>
+                {
+                    shapes.push(flatten(shape, inner.len()));
+                    items.extend(inner);
+                }
+                item => {
+                    items.push(item);
+                    shapes.push(shape);
+                }
+            }
+        }
+        self.finish_sequence(items, shapes)
+    }
+
+    /// Makes a sequence of optimized items, which have these shapes.
+    fn finish_sequence(
+        &self,
+        mut items: Vec<CoreExpression<'src>>,
+        shapes: Vec<Shape>,
+    ) -> (CoreExpression<'src>, Shape) {
+        if items.len() == 1 && !self.may_reset(&items[0]) {
+            return (items.pop().unwrap(), Shape::Group(shapes));
+        }
+        let shape = if shapes.iter().all(|s| *s == Shape::Same) {
+            Shape::Same
+        } else {
+            Shape::Sequence(shapes)
+        };
+        (CoreExpression::Sequence(items), shape)
+    }
+
+    fn optimize_choice(
+        &mut self,
+        subexprs: &[CoreExpression<'src>],
+        states: LayoutStates,
+    ) -> (CoreExpression<'src>, Shape) {
+        //Each alternative, with the shape that gives the pair of the choice as written
+        let mut alternatives = vec![];
+        for (i, subexpr) in subexprs.iter().enumerate() {
+            //An alternative that failed may have parsed characters
+            let alternative_states = if i == 0 { states } else { states.after() };
+            let (alternative, shape) = self.optimize(subexpr, alternative_states);
+            match alternative {
+                //The alternatives of a choice can only be taken apart when it gives its pair as written
+                CoreExpression::Choice(inner, _)
+                    if matches!(shape, Shape::Same | Shape::Choice(_)) =>
+                {
+                    let inner_shapes = match shape {
+                        Shape::Choice(inner_shapes) => inner_shapes,
+                        _ => (0..inner.len())
+                            .map(|j| Shape::Alternative(j, Box::new(Shape::Same)))
+                            .collect(),
+                    };
+                    alternatives.extend(
+                        inner.into_iter().zip(
+                            inner_shapes
+                                .into_iter()
+                                .map(|s| Shape::Alternative(i, Box::new(s))),
+                        ),
+                    );
+                }
+                alternative => {
+                    alternatives.push((alternative, Shape::Alternative(i, Box::new(shape))))
+                }
+            }
+        }
+        self.finish_choice(alternatives, states)
+    }
+
+    /// Makes a choice between optimized alternatives, merging and factoring them where possible.
+    fn finish_choice(
+        &self,
+        alternatives: Vec<Alternative<'src>>,
+        states: LayoutStates,
+    ) -> (CoreExpression<'src>, Shape) {
+        let alternatives = self.merge_characters(alternatives, states);
+        let mut alternatives = self.factor(alternatives, states);
+        if alternatives.len() == 1 {
+            return alternatives.pop().unwrap();
+        }
+        let (alternatives, shapes): (Vec<_>, Vec<_>) = alternatives.into_iter().unzip();
+        let same = shapes
+            .iter()
+            .enumerate()
+            .all(|(i, s)| matches!(s, Shape::Alternative(j, s) if i == *j && **s == Shape::Same));
+        let shape = if same {
+            Shape::Same
+        } else {
+            Shape::Choice(shapes)
+        };
+        (CoreExpression::Choice(alternatives, vec![]), shape)
+    }
+
+    /// Merges adjacent alternatives that parse a single character into one character class.
+    ///
+    /// An alternative that doesn't accept a character tries again after every piece of layout, before the
+    /// next alternative is tried. So the alternatives can only be merged when they can't accept the start of layout.
+    fn merge_characters(
+        &self,
+        alternatives: Vec<Alternative<'src>>,
+        states: LayoutStates,
+    ) -> Vec<Alternative<'src>> {
+        let layout_possible = states.without(LayoutStates::NO_LAYOUT) != LayoutStates::default();
+        let character = |expr: &CoreExpression| -> Option<(CharacterSet, bool, CharacterClass)> {
+            let (set, literal, class) = match expr {
+                CoreExpression::CharacterClass(class) => {
+                    (class.set().clone(), false, class.class().clone())
+                }
+                CoreExpression::Literal(literal) if literal.chars().count() == 1 => {
+                    let c = literal.chars().next().unwrap();
+                    (c.into(), true, c.into())
+                }
+                _ => return None,
+            };
+            let mergeable = !layout_possible
+                || (!self.layout_first.nullable && !self.layout_first.chars.intersects(&set));
+            mergeable.then_some((set, literal, class))
+        };
+
+        let mut merged: Vec<Alternative> = vec![];
+        //The alternatives of the run of characters that is being merged, and their classes
+        let mut run: Vec<(Alternative, (CharacterSet, bool, CharacterClass))> = vec![];
+        let finish_run = |run: &mut Vec<_>, merged: &mut Vec<Alternative<'src>>| {
+            if run.len() < 2 {
+                merged.extend(run.drain(..).map(|(alternative, _)| alternative));
+                return;
+            }
+            let mut classes = vec![];
+            let mut characters = vec![];
+            for ((_, shape), (set, literal, class)) in run.drain(..) {
+                classes.push(class);
+                characters.push((set, literal, shape));
+            }
+            let class = CharacterClass::Choice(classes).compile();
+            merged.push((
+                CoreExpression::CharacterClass(class),
+                Shape::Characters(characters),
+            ));
+        };
+        for alternative in alternatives {
+            match character(&alternative.0) {
+                Some(character) => run.push((alternative, character)),
+                None => {
+                    finish_run(&mut run, &mut merged);
+                    merged.push(alternative);
+                }
+            }
+        }
+        finish_run(&mut run, &mut merged);
+        merged
+    }
+
+    /// Replaces adjacent alternatives that start with the same expression by a sequence of that expression,
+    /// followed by a choice between the rest of the alternatives.
+    ///
+    /// When an alternative fails, the next one starts in the state the failed alternative left, so it may
+    /// parse the expression differently inside a no-layout expression. So that is not done there.
+    fn factor(
+        &self,
+        alternatives: Vec<Alternative<'src>>,
+        states: LayoutStates,
+    ) -> Vec<Alternative<'src>> {
+        if states.contains(LayoutStates::NO_LAYOUT_START) {
+            return alternatives;
+        }
+        fn first<'e, 'src>(expr: &'e CoreExpression<'src>) -> Option<&'e CoreExpression<'src>> {
+            match expr {
+                CoreExpression::Sequence(items) if counts(items) => None,
+                CoreExpression::Sequence(items) => items.first(),
+                expr => Some(expr),
+            }
+        }
+
+        let mut factored = vec![];
+        let mut alternatives = alternatives.into_iter().peekable();
+        while let Some(alternative) = alternatives.next() {
+            let mut run = vec![alternative];
+            while let Some(next) = alternatives.peek() {
+                match (first(&run[0].0), first(&next.0)) {
+                    (Some(a), Some(b)) if same_expression(a, b) => {
+                        run.push(alternatives.next().unwrap())
+                    }
+                    _ => break,
+                }
+            }
+            if run.len() < 2 {
+                factored.extend(run);
+                continue;
+            }
+
+            let prefix = first(&run[0].0).unwrap().clone();
+            let mut rests = vec![];
+            let mut shapes = vec![];
+            for (k, (alternative, shape)) in run.into_iter().enumerate() {
+                let (sequence, rest) = match alternative {
+                    CoreExpression::Sequence(mut items) => {
+                        items.remove(0);
+                        (true, items)
+                    }
+                    _ => (false, vec![]),
+                };
+                let rest_shapes = vec![Shape::Same; rest.len()];
+                let (rest, rest_shape) = self.finish_sequence(rest, rest_shapes);
+                rests.push((rest, Shape::Alternative(k, Box::new(rest_shape))));
+                shapes.push((sequence, shape));
+            }
+            let (rest, rest_shape) = self.finish_choice(rests, states);
+            factored.push((
+                CoreExpression::Sequence(vec![prefix, rest]),
+                Shape::Factored(Box::new(rest_shape), shapes),
+            ));
+        }
+        factored
+    }
+}
+
+/// Whether two expressions parse the same, which is the case when they only differ in the ids of their repetitions.
+fn same_expression(a: &CoreExpression, b: &CoreExpression) -> bool {
+    if a == b {
+        return true;
+    }
+    let (mut a, mut b) = (a.clone(), b.clone());
+    clear_repeat_ids(&mut a);
+    clear_repeat_ids(&mut b);
+    a == b
+}
+
+fn clear_repeat_ids(expr: &mut CoreExpression) {
+    match expr {
+        CoreExpression::Sequence(subexprs) | CoreExpression::Choice(subexprs, _) => {
+            subexprs.iter_mut().for_each(clear_repeat_ids)
+        }
+        CoreExpression::Repeat { subexpr, id, .. }
+        | CoreExpression::Counted { subexpr, id, .. } => {
+            *id = RepeatId(0);
+            clear_repeat_ids(subexpr);
+        }
+        CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _)
+        | CoreExpression::Error(subexpr, _) => clear_repeat_ids(subexpr),
+        CoreExpression::Name(_)
+        | CoreExpression::CharacterClass(_)
+        | CoreExpression::Literal(_)
+        | CoreExpression::Fail => {}
+    }
+}
+
+/// Whether the items of a sequence contain a counted repetition. Its count is found by the index of an item of
+/// the sequence, so the items of such a sequence are not flattened or factored out.
+fn counts(items: &[CoreExpression]) -> bool {
+    items
+        .iter()
+        .any(|item| matches!(item, CoreExpression::Counted { .. }))
+}
+
+/// Whether a sequence with this shape can be flattened into the sequence around it, which is the case
+/// when its items are given to a list again (possibly in a choice or sort).
+fn flattens(shape: &Shape) -> bool {
+    match shape {
+        Shape::Same | Shape::Sequence(_) => true,
+        Shape::Name(shape) | Shape::Alternative(_, shape) => flattens(shape),
+        _ => false,
+    }
+}
+
+/// The shape of a flattened sequence of `len` items with this shape, see [`flattens`].
+fn flatten(shape: Shape, len: usize) -> Shape {
+    match shape {
+        Shape::Same => Shape::Group(vec![Shape::Same; len]),
+        Shape::Sequence(shapes) => Shape::Group(shapes),
+        Shape::Name(shape) => Shape::Name(Box::new(flatten(*shape, len))),
+        Shape::Alternative(i, shape) => Shape::Alternative(i, Box::new(flatten(*shape, len))),
+        shape => unreachable!("{shape:?} can't be flattened"),
+    }
+}
+
+/// Finds the sorts that are used in an expression, and the states they can be parsed in.
+fn find_names(
+    expr: &CoreExpression,
+    states: LayoutStates,
+    found: &mut HashMap<SortId, LayoutStates>,
+) {
+    match expr {
+        CoreExpression::Name(sort) => {
+            let entry = found.entry(*sort).or_default();
+            *entry = entry.union(states);
+        }
+        CoreExpression::Sequence(subexprs) => {
+            subexprs.iter().fold(states, |states, e| {
+                find_names(e, states, found);
+                states.succeed(e)
+            });
+        }
+        CoreExpression::Repeat { subexpr, .. } | CoreExpression::Counted { subexpr, .. } => {
+            find_names(subexpr, states.union(states.succeed(subexpr)), found)
+        }
+        CoreExpression::Choice(subexprs, _) => {
+            for (i, subexpr) in subexprs.iter().enumerate() {
+                find_names(subexpr, if i == 0 { states } else { states.after() }, found);
+            }
+        }
+        CoreExpression::FlagNoLayout(subexpr) => find_names(subexpr, states.no_layout(), found),
+        CoreExpression::FlagNoErrors(subexpr, _) | CoreExpression::Error(subexpr, _) => {
+            find_names(subexpr, states, found)
+        }
+        CoreExpression::CharacterClass(_) | CoreExpression::Literal(_) | CoreExpression::Fail => {}
+    }
+}
+
+fn used_names(expr: &CoreExpression, names: &mut Vec<SortId>) {
+    match expr {
+        CoreExpression::Name(sort) => names.push(*sort),
+        CoreExpression::Sequence(subexprs) | CoreExpression::Choice(subexprs, _) => {
+            subexprs.iter().for_each(|e| used_names(e, names))
+        }
+        CoreExpression::Repeat { subexpr, .. }
+        | CoreExpression::Counted { subexpr, .. }
+        | CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _)
+        | CoreExpression::Error(subexpr, _) => used_names(subexpr, names),
+        CoreExpression::CharacterClass(_) | CoreExpression::Literal(_) | CoreExpression::Fail => {}
+    }
+}
+
+/// Whether the expression contains an error expression, which may succeed without moving past what it parsed.
+fn may_reset(expr: &CoreExpression, resetting: &HashSet<SortId>) -> bool {
+    match expr {
+        CoreExpression::Error(..) => true,
+        CoreExpression::Name(sort) => resetting.contains(sort),
+        CoreExpression::Sequence(subexprs) | CoreExpression::Choice(subexprs, _) => {
+            subexprs.iter().any(|e| may_reset(e, resetting))
+        }
+        CoreExpression::Repeat { subexpr, .. }
+        | CoreExpression::Counted { subexpr, .. }
+        | CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _) => may_reset(subexpr, resetting),
+        CoreExpression::CharacterClass(_) | CoreExpression::Literal(_) | CoreExpression::Fail => {
+            false
+        }
+    }
+}
+
+fn is_trivial(expr: &CoreExpression) -> bool {
+    match expr {
+        CoreExpression::Name(_)
+        | CoreExpression::CharacterClass(_)
+        | CoreExpression::Literal(_) => true,
+        CoreExpression::Repeat { subexpr, .. }
+        | CoreExpression::FlagNoLayout(subexpr)
+        | CoreExpression::FlagNoErrors(subexpr, _) => is_trivial(subexpr),
+        _ => false,
+    }
+}
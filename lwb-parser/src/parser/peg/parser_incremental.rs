@@ -0,0 +1,379 @@
+use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_options::{ParseAborted, ParseOptions};
+use crate::parser::peg::parse_outcome::ParseOutcome;
+use crate::parser::peg::parse_pair::ParsePairSort;
+use crate::parser::peg::parse_result::ParseResult;
+use crate::parser::peg::parser_core::{ParserContext, ParserState, ReuseInfo};
+use crate::parser::peg::parser_core_ast::{ParsePairRaw, SortId};
+use crate::parser::peg::parser_core_file::parse_file_context;
+use crate::parser::peg::parser_sugar::{desugar_ast, resugar_outcome};
+use crate::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use crate::sources::compact_span::CompactSpan;
+use crate::sources::source_file::SourceFile;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A change to the contents of a source file: the text in `range` is replaced by `text`.
+/// The range is in bytes of the [`contents`](SourceFile::contents) of the file, like the edits of an editor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn new(range: Range<usize>, text: impl Into<String>) -> Self {
+        Self {
+            range,
+            text: text.into(),
+        }
+    }
+
+    pub fn insert(offset: usize, text: impl Into<String>) -> Self {
+        Self::new(offset..offset, text)
+    }
+
+    pub fn delete(range: Range<usize>) -> Self {
+        Self::new(range, "")
+    }
+
+    /// Applies the edit to `contents`.
+    /// Panics when the range is out of bounds, or not on character boundaries.
+    pub fn apply(&self, contents: &mut String) {
+        contents.replace_range(self.range.clone(), &self.text);
+    }
+}
+
+/// The part of the contents that was changed by a list of edits. Everything before `start`
+/// is the same in the old and new contents, and so is everything after `old_end` in the
+/// old contents and `new_end` in the new contents.
+#[derive(Debug, Clone, Copy)]
+struct EditRegion {
+    start: usize,
+    old_end: usize,
+    new_end: usize,
+}
+
+impl EditRegion {
+    /// The region changed by edits which are applied one after another. None if there are no edits.
+    fn new(edits: &[TextEdit]) -> Option<Self> {
+        edits.iter().fold(None, |region, edit| {
+            let (a, b) = (edit.range.start, edit.range.end);
+            Some(match region {
+                None => Self {
+                    start: a,
+                    old_end: b,
+                    new_end: a + edit.text.len(),
+                },
+                //Offsets of the edit are in the contents after the previous edits
+                Some(r) => Self {
+                    start: r.start.min(a),
+                    old_end: r.old_end + b.saturating_sub(r.new_end),
+                    new_end: r.new_end.max(b) - (b - a) + edit.text.len(),
+                },
+            })
+        })
+    }
+
+    /// The offset in the old contents of an offset in the new contents, if it was not changed.
+    /// Also returns whether it is after the changed part.
+    fn old_offset(&self, offset: usize) -> Option<(usize, bool)> {
+        if offset < self.start {
+            Some((offset, false))
+        } else if offset >= self.new_end {
+            Some((offset - self.new_end + self.old_end, true))
+        } else {
+            None
+        }
+    }
+
+    /// Moves an offset in the old contents which is not in the changed part to the new contents.
+    fn shift(&self, offset: usize, after: bool) -> usize {
+        if after {
+            offset + self.new_end - self.old_end
+        } else {
+            offset
+        }
+    }
+}
+
+/// A cache entry of a previous parse. Unlike the spans in `result`, the offsets are
+/// in the contents of the file, so they can be moved along with the text edits.
+/// The ids of the sorts are the same in the next parse, since the grammar is desugared the same way.
+struct MemoEntry {
+    sort: SortId,
+    end: usize,
+    pos_err: usize,
+    ok: bool,
+    recovered: bool,
+    info: ReuseInfo,
+    result: Arc<ParsePairRaw>,
+}
+
+/// The reusable cache entries of the first attempt of a parse, by the offset they start at.
+pub(crate) struct Memo {
+    file: SourceFile,
+    entries: HashMap<usize, Vec<MemoEntry>>,
+}
+
+impl Memo {
+    pub(crate) fn new(file: &SourceFile, cache: &mut ParserState) -> Self {
+        let mut entries: HashMap<usize, Vec<MemoEntry>> = HashMap::new();
+        for ((position, sort), value, mut info) in cache.take_reusable() {
+            //The end of the examined input can be in the middle of a character that is wider in the original input
+            info.examined = match info.examined.checked_sub(1) {
+                Some(last) if info.examined > position => file.str_offset(last) + 1,
+                _ => file.str_offset(position),
+            };
+            entries
+                .entry(file.str_offset(position))
+                .or_default()
+                .push(MemoEntry {
+                    sort,
+                    end: file.str_offset(value.pos.position()),
+                    pos_err: file.str_offset(value.pos_err.position()),
+                    ok: value.ok,
+                    recovered: value.recovered,
+                    info,
+                    result: value.result,
+                });
+        }
+        Self {
+            file: file.clone(),
+            entries,
+        }
+    }
+}
+
+/// The memo of the previous parse, and how the file was changed since.
+pub(crate) struct Reuse<'a> {
+    memo: &'a Memo,
+    region: EditRegion,
+    /// How many bytes were dropped from the start of the previous file. Offsets in the region are in
+    /// the new file as if these bytes were still there.
+    removed: usize,
+    reused: Cell<usize>,
+}
+
+impl<'a> Reuse<'a> {
+    fn new(memo: &'a Memo, region: EditRegion) -> Self {
+        Self {
+            memo,
+            region,
+            removed: 0,
+            reused: Cell::new(0),
+        }
+    }
+
+    /// Reuses the memo of a parse of a window of a stream in the next window, which is the old window
+    /// without its first `removed` bytes, and with more input at the end.
+    pub(crate) fn for_window(memo: &'a Memo, removed: usize, window: &SourceFile) -> Self {
+        let old_len = memo.file.contents().len();
+        Self {
+            memo,
+            region: EditRegion {
+                start: old_len,
+                old_end: old_len,
+                new_end: removed + window.contents().len(),
+            },
+            removed,
+            reused: Cell::new(0),
+        }
+    }
+
+    /// How many results of the previous parse were reused.
+    pub(crate) fn reused(&self) -> usize {
+        self.reused.get()
+    }
+}
+
+/// The result of the sort `key.1` at `key.0` from the previous parse, if the input it looked at was not edited
+/// and it was parsed with the same flags. Results are reused without the errors they found, so only the first
+/// attempt of a parse reuses them, which skips alternatives and doesn't report errors either. When it fails, the
+/// errors are found by parsing from scratch, like any parse that fails.
+pub(crate) fn reuse_entry<'src>(
+    state: &ParserContext<'src>,
+    cache: &mut ParserState<'src>,
+    key: (usize, SortId),
+) -> Option<ParseResult<'src, Arc<ParsePairRaw>>> {
+    let reuse = state.reuse.as_ref()?;
+    if !cache.predict {
+        return None;
+    }
+    let region = reuse.region;
+    let (old, after) = region.old_offset(state.file.str_offset(key.0) + reuse.removed)?;
+    let no_layout = cache.no_layout_nest_count > 0;
+    let entry = reuse.memo.entries.get(&old)?.iter().find(|e| {
+        e.sort == key.1
+            && e.info.allow_layout == cache.allow_layout
+            && e.info.no_layout == no_layout
+    })?;
+    if !after && entry.info.examined > region.start {
+        return None;
+    }
+
+    let file = state.file;
+    let old_file = &reuse.memo.file;
+    let new_offset =
+        |offset: usize| file.original_offset(region.shift(offset, after) - reuse.removed);
+    //The input of the entry is the same in both files, so its spans move by the same number of bytes
+    let old_start = old_file.original_offset(old);
+    let result = relocate(&entry.result, &|o| o - old_start + key.0);
+    let res = ParseResult::new(
+        Arc::new(result),
+        file.iter_at(new_offset(entry.end)),
+        file.iter_at(new_offset(entry.pos_err)),
+        entry.ok,
+        entry.recovered,
+    );
+    let info = ReuseInfo {
+        examined: new_offset(entry.info.examined),
+        ..entry.info
+    };
+
+    cache.insert_reused(key, res.clone(), info);
+    cache.examine(info.examined);
+    cache.allow_layout = info.allow_layout_after;
+    reuse.reused.set(reuse.reused.get() + 1);
+    Some(res)
+}
+
+/// Moves a parse pair of the previous parse into the new file, using `offset` to move its spans.
+fn relocate(pair: &ParsePairRaw, offset: &impl Fn(usize) -> usize) -> ParsePairRaw {
+    let span = pair.span();
+    let span = CompactSpan::from_end(offset(span.start()), offset(span.end()));
+    match pair {
+        ParsePairRaw::Name(_, p) => ParsePairRaw::Name(span, Arc::new(relocate(p, offset))),
+        ParsePairRaw::List(_, ps) => {
+            ParsePairRaw::List(span, ps.iter().map(|p| relocate(p, offset)).collect())
+        }
+        ParsePairRaw::Choice(_, i, p) => {
+            ParsePairRaw::Choice(span, *i, Box::new(relocate(p, offset)))
+        }
+        ParsePairRaw::Empty(_) => ParsePairRaw::Empty(span),
+        ParsePairRaw::Error(_) => ParsePairRaw::Error(span),
+    }
+}
+
+/// A parsed source file, which can be [reparsed](reparse) after it is edited.
+/// A reparse reuses the results of the previous parse for the parts of the file
+/// that were not changed, and gives the same tree and errors as parsing the edited file from scratch.
+///
+/// ```
+/// # use lwb_parser::language::Language;
+/// # use lwb_parser::parser::peg::parser_incremental::{reparse, ParseTree, TextEdit};
+/// # use lwb_parser::parser::syntax_file::convert_syntax_file_ast::convert;
+/// # use lwb_parser::parser::syntax_file::SyntaxFile;
+/// # use lwb_parser::sources::source_file::SourceFile;
+/// let grammar = SourceFile::new_for_test(
+///     "list = item*;\nitem:\n    word = [a-z]+ \";\";\n    group = \"(\" list \")\";\nlayout = \" \";\nstart at list;\n",
+/// );
+/// let ast = convert(SyntaxFile::parse(&grammar)).unwrap();
+///
+/// let tree = ParseTree::new(&ast, SourceFile::new_for_test("a; b; c;"));
+/// let tree = reparse(&tree, &[TextEdit::insert(5, " bb;")]);
+/// assert_eq!(tree.file().contents(), "a; b; bb; c;");
+/// assert!(tree.errors().is_empty());
+/// assert!(tree.reused() > 0);
+/// ```
+pub struct ParseTree<'src> {
+    ast: &'src SyntaxFileAst,
+    file: SourceFile,
+    options: ParseOptions,
+    outcome: ParseOutcome<ParsePairSort<'src>>,
+    /// None when the parse was traced, since the memo is only kept when alternatives are skipped
+    memo: Option<Memo>,
+    reused: usize,
+}
+
+impl<'src> ParseTree<'src> {
+    pub fn new(ast: &'src SyntaxFileAst, file: SourceFile) -> Self {
+        Self::with_options(ast, file, ParseOptions::default())
+            .expect("parsing without limits is never aborted")
+    }
+
+    /// Like [`new`](ParseTree::new), but configured by the options. These are also used when reparsing.
+    pub fn with_options(
+        ast: &'src SyntaxFileAst,
+        file: SourceFile,
+        options: ParseOptions,
+    ) -> Result<Self, ParseAborted> {
+        Self::parse(ast, file, options, None)
+    }
+
+    fn parse(
+        ast: &'src SyntaxFileAst,
+        file: SourceFile,
+        options: ParseOptions,
+        previous: Option<(&Memo, EditRegion)>,
+    ) -> Result<Self, ParseAborted> {
+        let core_ast = desugar_ast(ast, &options);
+        let (outcome, memo, reused) = {
+            let mut state = ParserContext::new(&file, &core_ast, &options);
+            if options.stats() {
+                state.stats = Some(RefCell::default());
+            }
+            state.memo = Some(RefCell::default());
+            state.reuse = previous.map(|(memo, region)| Reuse::new(memo, region));
+            let outcome = parse_file_context(&mut state, &options)?;
+            let reused = state.reuse.as_ref().map_or(0, Reuse::reused);
+            (outcome, state.memo.and_then(RefCell::into_inner), reused)
+        };
+        Ok(Self {
+            outcome: resugar_outcome(ast, &file, &options, outcome),
+            ast,
+            file,
+            options,
+            memo,
+            reused,
+        })
+    }
+
+    pub fn file(&self) -> &SourceFile {
+        &self.file
+    }
+
+    pub fn tree(&self) -> &ParsePairSort<'src> {
+        &self.outcome.tree
+    }
+
+    pub fn errors(&self) -> &[PEGParseError] {
+        &self.outcome.errors
+    }
+
+    pub fn outcome(&self) -> &ParseOutcome<ParsePairSort<'src>> {
+        &self.outcome
+    }
+
+    pub fn into_outcome(self) -> ParseOutcome<ParsePairSort<'src>> {
+        self.outcome
+    }
+
+    /// How many results of the previous parse were reused to make this tree.
+    pub fn reused(&self) -> usize {
+        self.reused
+    }
+}
+
+/// Parses the file of `previous` after applying the edits to it, one after another.
+/// Results of the previous parse that only depend on unchanged input are reused.
+///
+/// Panics when the options of `previous` have limits which abort the parse, see [`try_reparse`].
+pub fn reparse<'src>(previous: &ParseTree<'src>, edits: &[TextEdit]) -> ParseTree<'src> {
+    try_reparse(previous, edits).expect("the options of the previous parse abort the reparse")
+}
+
+/// Like [`reparse`], but returns why the parse stopped when it exceeds the limits of the options.
+pub fn try_reparse<'src>(
+    previous: &ParseTree<'src>,
+    edits: &[TextEdit],
+) -> Result<ParseTree<'src>, ParseAborted> {
+    let file = edits.iter().fold(previous.file.clone(), |file, edit| {
+        file.edited(edit.range.clone(), &edit.text)
+    });
+
+    let previous_memo = previous.memo.as_ref().zip(EditRegion::new(edits));
+    ParseTree::parse(previous.ast, file, previous.options.clone(), previous_memo)
+}
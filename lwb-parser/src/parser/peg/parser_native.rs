@@ -0,0 +1,333 @@
+use crate::parser::peg::parse_result::ParseResult;
+use crate::parser::peg::parser_core::ParserContext;
+use crate::parser::peg::parser_core_ast::{ParsePairRaw, SortId};
+use crate::sources::compact_span::CompactSpan;
+use crate::sources::source_file::SourceFileIterator;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// The result of a generated parse function: the pair and the position after it, or `None` when it failed.
+pub type NativeResult<'src> = Option<(ParsePairRaw, SourceFileIterator<'src>)>;
+
+/// The result of a sort, which is shared with the cache of the parser
+type NativeSortResult<'src> = Option<(Arc<ParsePairRaw>, SourceFileIterator<'src>)>;
+
+/// A generated function which parses the expression of a sort.
+pub type NativeSortFn =
+    for<'p, 'src> fn(&mut NativeState<'p, 'src>, SourceFileIterator<'src>) -> NativeResult<'src>;
+
+/// A parser that was generated as Rust code, with a function for every sort of the grammar.
+/// It is generated when `parser = "native"` is set in `lwb.toml`, and gives the same trees as interpreting the grammar.
+///
+/// The generated functions only find out whether the input can be parsed. When it can't, the file is parsed again
+/// by interpreting the grammar, which finds the errors. Grammars with `error` constructors get no native parser,
+/// since those constructors only report errors. Parses which collect statistics, are traced, are reparsed
+/// incrementally or have a limit on the number of memoized results always interpret the grammar.
+#[derive(Debug)]
+pub struct NativeParser {
+    /// The sorts, by their [`SortId`]
+    sorts: &'static [NativeSort],
+    layout: SortId,
+}
+
+impl NativeParser {
+    pub const fn new(sorts: &'static [NativeSort], layout: SortId) -> Self {
+        Self { sorts, layout }
+    }
+
+    /// Parses the whole file as `sort`. Gives a failed result without an error when that is not possible,
+    /// see [`parse_file_attempt`](crate::parser::peg::parser_core_file).
+    pub(crate) fn parse_file<'src>(
+        &self,
+        state: &ParserContext<'src>,
+        sort: SortId,
+        pos: SourceFileIterator<'src>,
+    ) -> ParseResult<'src, Arc<ParsePairRaw>> {
+        let mut native = NativeState::new(self, state);
+        if let Some((ParsePairRaw::Name(_, result), end)) = &native.parse_sort(sort, pos) {
+            //If there is no input left, the parse succeeded. Skip layout first
+            let mut end = native.skip_layout(*end);
+            if end.peek().is_none() {
+                return ParseResult::new_ok(result.clone(), end, end, false);
+            }
+        }
+        let span = CompactSpan::from_length(pos.position(), 0);
+        ParseResult::new_err(Arc::new(ParsePairRaw::Error(span)), pos, pos)
+    }
+}
+
+/// A sort of a [`NativeParser`].
+#[derive(Debug)]
+pub struct NativeSort {
+    parse: NativeSortFn,
+    /// Whether the results of the sort are memoized, see [`Memoize`](crate::parser::peg::parser_core_ast::Memoize)
+    memoize: bool,
+}
+
+impl NativeSort {
+    pub const fn new(parse: NativeSortFn, memoize: bool) -> Self {
+        Self { parse, memoize }
+    }
+}
+
+/// The mutable data of a generated parser, like [`ParserState`](crate::parser::peg::parser_core::ParserState).
+/// The generated functions parse expressions with the methods of this type, which do exactly what the parser does
+/// for them while it skips alternatives that can't match and doesn't need the errors.
+pub struct NativeState<'p, 'src> {
+    parser: &'p NativeParser,
+    state: &'p ParserContext<'src>,
+    /// The results of the memoized sorts, and whether they were read while they were computed
+    cache: HashMap<(usize, SortId), (bool, NativeSortResult<'src>)>,
+    /// The keys of the cache, in the order they were inserted
+    cache_stack: Vec<(usize, SortId)>,
+    allow_layout: bool,
+    no_layout_nest_count: usize,
+    no_errors_nest_count: usize,
+    depth: usize,
+}
+
+impl<'p, 'src> NativeState<'p, 'src> {
+    fn new(parser: &'p NativeParser, state: &'p ParserContext<'src>) -> Self {
+        Self {
+            parser,
+            state,
+            cache: HashMap::new(),
+            cache_stack: vec![],
+            allow_layout: true,
+            no_layout_nest_count: 0,
+            no_errors_nest_count: 0,
+            depth: 0,
+        }
+    }
+
+    /// Counts a single step. Returns false if the parse should stop.
+    #[inline]
+    pub fn step(&self) -> bool {
+        self.state.limiter.step()
+    }
+
+    pub fn feature_enabled(&self, name: &str) -> bool {
+        self.state.options.feature_enabled(name)
+    }
+
+    fn parse_sort(&mut self, sort: SortId, pos: SourceFileIterator<'src>) -> NativeResult<'src> {
+        let NativeSort { parse, memoize } = self.parser.sorts[sort.0];
+        self.name(sort, memoize, pos, parse)
+    }
+
+    /// Parses a sort with its generated function, like [`parse_expression_name`](crate::parser::peg::parser_core_expression::parse_expression_name).
+    pub fn name(
+        &mut self,
+        sort: SortId,
+        memoize: bool,
+        pos: SourceFileIterator<'src>,
+        parse: impl Fn(&mut Self, SourceFileIterator<'src>) -> NativeResult<'src>,
+    ) -> NativeResult<'src> {
+        let key = (pos.position(), sort);
+        if memoize {
+            if let Some((read, cached)) = self.cache.get_mut(&key) {
+                *read = true;
+                return cached.clone().map(Self::name_pair);
+            }
+        }
+
+        let limiter = &self.state.limiter;
+        if self.depth >= limiter.max_depth() || limiter.stack_exceeded() {
+            return None;
+        }
+        self.depth += 1;
+
+        //A failed result is used when the sort is left recursive, see `parse_expression_name`
+        let cache_state = self.cache_stack.len();
+        if memoize {
+            self.insert(key, None);
+        }
+        let parse_arc = |native: &mut Self| parse(native, pos).map(|(r, end)| (Arc::new(r), end));
+        let mut res = parse_arc(self);
+        if let (Some((_, end)), true) = (&res, memoize && self.cache[&key].0) {
+            //There was left recursion, grow the seed until it doesn't get longer
+            let mut end = *end;
+            loop {
+                self.state_revert(cache_state);
+                self.insert(key, res.clone());
+                match parse_arc(self) {
+                    Some(new_res) if new_res.1.position() > end.position() => {
+                        end = new_res.1;
+                        res = Some(new_res);
+                    }
+                    _ => break,
+                }
+            }
+            self.insert(key, res.clone());
+        }
+
+        if memoize {
+            self.insert(key, res.clone());
+        }
+        self.depth -= 1;
+        res.map(Self::name_pair)
+    }
+
+    fn name_pair(
+        (result, end): (Arc<ParsePairRaw>, SourceFileIterator<'src>),
+    ) -> (ParsePairRaw, SourceFileIterator<'src>) {
+        (ParsePairRaw::Name(result.span(), result), end)
+    }
+
+    fn insert(&mut self, key: (usize, SortId), value: NativeSortResult<'src>) {
+        self.cache.insert(key, (false, value));
+        self.cache_stack.push(key);
+    }
+
+    /// Removes the entries that were inserted after the cache stack had `len` entries
+    fn state_revert(&mut self, len: usize) {
+        for key in self.cache_stack.drain(len..) {
+            self.cache.remove(&key);
+        }
+    }
+
+    /// Parses a character class, like [`parse_character_class`](crate::parser::peg::parser_core_expression).
+    #[inline]
+    pub fn character_class(
+        &mut self,
+        pos: SourceFileIterator<'src>,
+        accept: impl Fn(char) -> bool,
+    ) -> NativeResult<'src> {
+        let (pos, next) = self.accept_after_layout(pos, accept);
+        let next = next?;
+        if self.no_layout_nest_count > 0 {
+            self.allow_layout = false;
+        }
+        let span = CompactSpan::from_length(pos.position(), 1);
+        Some((ParsePairRaw::Empty(span), next))
+    }
+
+    /// Parses a literal, like [`parse_literal`](crate::parser::peg::parser_core_expression).
+    pub fn literal(&mut self, pos: SourceFileIterator<'src>, literal: &str) -> NativeResult<'src> {
+        let mut chars = literal.chars();
+        let Some(first) = chars.next() else {
+            let span = CompactSpan::from_length(pos.position(), 0);
+            return Some((ParsePairRaw::List(span, vec![]), pos));
+        };
+
+        self.no_layout_nest_count += 1;
+        let (start, next) = self.accept_after_layout(pos, |c| c == first);
+        let end = next.and_then(|mut next| {
+            self.allow_layout = false;
+            for c in chars {
+                if next.peek() != Some(&c) {
+                    return None;
+                }
+                next.advance();
+            }
+            Some(next)
+        });
+        self.exit_no_layout();
+
+        let end = end?;
+        let span = CompactSpan::from_end(start.position(), end.position());
+        Some((ParsePairRaw::List(span, vec![]), end))
+    }
+
+    /// Accepts the next character if it matches, skipping layout before it when layout is allowed.
+    /// Returns the position of the character, and the position after it if it was accepted.
+    #[inline]
+    fn accept_after_layout(
+        &mut self,
+        mut pos: SourceFileIterator<'src>,
+        accept: impl Fn(char) -> bool,
+    ) -> (SourceFileIterator<'src>, Option<SourceFileIterator<'src>>) {
+        loop {
+            let mut next = pos;
+            if matches!(next.peek(), Some(&c) if accept(c)) {
+                next.advance();
+                return (pos, Some(next));
+            }
+            if !self.allow_layout {
+                return (pos, None);
+            }
+            match self.skip_single_layout(pos) {
+                Some(after_layout_pos) => pos = after_layout_pos,
+                None => return (pos, None),
+            }
+        }
+    }
+
+    /// Returns false when an alternative with this first set can't match at `pos`, like
+    /// [`predict_alternative`](crate::parser::peg::parser_core_expression). The first set should not be nullable.
+    /// `layout_disjoint` is true when the first set of the layout sort is not nullable, and has no characters in common with it.
+    pub fn can_start(
+        &mut self,
+        pos: SourceFileIterator<'src>,
+        next_char_pos: &mut Option<SourceFileIterator<'src>>,
+        layout_disjoint: bool,
+        first: impl Fn(char) -> bool,
+    ) -> bool {
+        let mut next = if self.allow_layout {
+            if !layout_disjoint {
+                return true;
+            }
+            *next_char_pos.get_or_insert_with(|| self.skip_layout(pos))
+        } else {
+            pos
+        };
+        matches!(next.peek(), Some(&c) if first(c))
+    }
+
+    pub fn enter_no_layout(&mut self) {
+        self.no_layout_nest_count += 1;
+    }
+
+    pub fn exit_no_layout(&mut self) {
+        self.no_layout_nest_count -= 1;
+        if self.no_layout_nest_count == 0 {
+            self.allow_layout = true;
+        }
+    }
+
+    pub fn enter_no_errors(&mut self) {
+        self.no_errors_nest_count += 1;
+    }
+
+    pub fn exit_no_errors(&mut self) {
+        self.no_errors_nest_count -= 1;
+    }
+
+    /// The pair of a sequence or repeat, which starts where its first element starts.
+    pub fn list(
+        &self,
+        start: SourceFileIterator<'src>,
+        results: Vec<ParsePairRaw>,
+        end: SourceFileIterator<'src>,
+    ) -> ParsePairRaw {
+        let start = results
+            .first()
+            .map_or(start.position(), |pair| pair.span().start());
+        ParsePairRaw::List(CompactSpan::from_end(start, end.position()), results)
+    }
+
+    fn skip_single_layout(
+        &mut self,
+        pos: SourceFileIterator<'src>,
+    ) -> Option<SourceFileIterator<'src>> {
+        let prev_allow_layout = self.allow_layout;
+        self.no_layout_nest_count += 1;
+        self.no_errors_nest_count += 1;
+        self.allow_layout = false;
+
+        let layout = self.parser.sorts[self.parser.layout.0].parse;
+        let res = layout(self, pos);
+
+        self.no_layout_nest_count -= 1;
+        self.no_errors_nest_count -= 1;
+        self.allow_layout = prev_allow_layout;
+        res.map(|(_, end)| end)
+    }
+
+    fn skip_layout(&mut self, mut pos: SourceFileIterator<'src>) -> SourceFileIterator<'src> {
+        while let Some(after_layout_pos) = self.skip_single_layout(pos) {
+            pos = after_layout_pos;
+        }
+        pos
+    }
+}
@@ -0,0 +1,183 @@
+use crate::codegen_prelude::ParsePairSort;
+use crate::parser::peg::parse_error::PEGParseError;
+use crate::parser::peg::parse_options::ParseOptions;
+use crate::parser::peg::parser_compiled::CompiledParser;
+use crate::parser::peg::parser_core::{ParserContext, ParserState};
+use crate::parser::peg::parser_core_file::{parse_prefix_sub, skip_context_layout, skip_layout};
+use crate::parser::peg::parser_incremental::{Memo, Reuse};
+use crate::parser::peg::parser_sugar::resugar_sort;
+use crate::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use crate::parser::syntax_file::ParseError;
+use crate::sources::stream::ChunkedInput;
+use std::borrow::Cow;
+use std::io::Read;
+
+/// Parses a stream as a sequence of items, each of which is parsed with the starting
+/// sort of the syntax definition, and which may be separated by layout. For example,
+/// this parses newline delimited json when the starting sort is a json value.
+///
+/// Only the item that is currently being parsed is kept in memory.
+/// Spans in the result are relative to the window of the input the item was parsed
+/// from (see [`ChunkedInput::window`]).
+///
+/// After an item fails to parse, it is yielded together with the error and the iterator stops,
+/// since it is not known where the next item would start.
+pub fn parse_many<R: Read>(ast: &SyntaxFileAst, input: ChunkedInput<R>) -> ParseMany<'_, R> {
+    parse_many_with_options(ast, input, &ParseOptions::default())
+}
+
+/// Like [`parse_many`], but every item is parsed with the options. When the parse of an item
+/// exceeds the limits given in the options, the error is yielded and the iterator stops.
+/// The limits apply to each attempt at parsing an item, which is parsed again when more input is read.
+///
+/// Panics when the options give a starting sort which does not exist.
+pub fn parse_many_with_options<'src, R: Read>(
+    ast: &'src SyntaxFileAst,
+    input: ChunkedInput<R>,
+    options: &ParseOptions,
+) -> ParseMany<'src, R> {
+    let parser = CompiledParser::with_options(ast, options);
+    ParseMany::new(Cow::Owned(parser), input, options.clone())
+}
+
+/// Iterator over the items in a stream. See [`parse_many`].
+pub struct ParseMany<'src, R> {
+    parser: Cow<'src, CompiledParser<'src>>,
+    input: ChunkedInput<R>,
+    options: ParseOptions,
+    done: bool,
+    reused: usize,
+}
+
+impl<'src, R> ParseMany<'src, R> {
+    pub(crate) fn new(
+        parser: Cow<'src, CompiledParser<'src>>,
+        input: ChunkedInput<R>,
+        options: ParseOptions,
+    ) -> Self {
+        Self {
+            parser,
+            input,
+            options,
+            done: false,
+            reused: 0,
+        }
+    }
+
+    /// The input that is being parsed.
+    pub fn input(&self) -> &ChunkedInput<R> {
+        &self.input
+    }
+
+    /// How many results of parses were reused after more input was read, see [`ChunkedInput::read_more`].
+    pub fn reused(&self) -> usize {
+        self.reused
+    }
+}
+
+impl<'src, R: Read> Iterator for ParseMany<'src, R> {
+    type Item = Result<(ParsePairSort<'src>, Vec<PEGParseError>), ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let res = parse_next(
+            &self.parser,
+            &mut self.input,
+            &self.options,
+            &mut self.reused,
+        );
+        match &res {
+            Some(Ok((_, errs))) if errs.is_empty() => {}
+            _ => self.done = true,
+        }
+        res
+    }
+}
+
+/// Parses the next item in the input, reading more input as long as the parser
+/// tries to look past the end of the window. Returns `None` when there are no more items.
+///
+/// When more input is read, the results of the previous attempt that didn't look at the end of the window
+/// are reused, so a large item is not parsed again from its start for every chunk.
+fn parse_next<'src, R: Read>(
+    parser: &CompiledParser<'src>,
+    input: &mut ChunkedInput<R>,
+    options: &ParseOptions,
+    reused: &mut usize,
+) -> Option<Result<(ParsePairSort<'src>, Vec<PEGParseError>), ParseError>> {
+    let core_ast = parser.core_ast(options);
+    let ast = parser.ast();
+    let starting_sort = options.starting_sort().unwrap_or(&ast.starting_sort);
+    let sort = core_ast.sort_id(starting_sort).expect("sort exists");
+    let starting_sort = &ast.sorts[starting_sort];
+    //The memo of the previous attempt at this item, and how many bytes of its window were dropped since
+    let mut previous: Option<(Memo, usize)> = None;
+
+    loop {
+        let file = input.window().clone();
+        let mut state = ParserContext::new(&file, &core_ast, options);
+
+        //Skip the layout before the item, if we reach the end there are no more items
+        let mut pos = skip_layout(
+            &state,
+            &mut ParserState::new(),
+            file.iter_at(input.consumed()),
+        );
+        if pos.peek().is_none() {
+            if input.is_complete() {
+                input.consume_to(pos.position());
+                return None;
+            }
+            if let Err(e) = input.read_more() {
+                return Some(Err(e.into()));
+            }
+            continue;
+        }
+
+        let start = pos.position();
+        state.reuse = previous
+            .as_ref()
+            .map(|(memo, removed)| Reuse::for_window(memo, *removed, &file));
+        let (res, mut cache) = parse_prefix_sub(&state, sort, pos);
+        *reused += state.reuse.as_ref().map_or(0, Reuse::reused);
+        if let Some(aborted) = state.limiter.aborted() {
+            return Some(Err(aborted.into()));
+        }
+
+        //If the parser looked past the end of the window, more input could change the result
+        if cache.examined > file.bytes().len() && !input.is_complete() {
+            let memo = Memo::new(&file, &mut cache);
+            input.consume_to(start);
+            let removed = input.consumed();
+            if let Err(e) = input.read_more() {
+                return Some(Err(e.into()));
+            }
+            previous = Some((memo, removed));
+            continue;
+        }
+
+        let end = res.pos.position();
+        let mut errs = if res.ok {
+            vec![]
+        } else {
+            cache.best_error.into_iter().collect()
+        };
+        errs.iter_mut()
+            .for_each(|err| skip_context_layout(&state, err));
+        if errs.is_empty() && end == start {
+            //An empty item would be parsed forever
+            errs.push(PEGParseError::fail_loop(
+                res.result.span().to_span(state.file),
+            ));
+        }
+        input.consume_to(end);
+
+        return Some(Ok((
+            resugar_sort(ast, state.file, starting_sort, &res.result),
+            errs,
+        )));
+    }
+}
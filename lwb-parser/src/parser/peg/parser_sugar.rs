@@ -1,11 +1,24 @@
 use crate::codegen_prelude::{ParsePairExpression, ParsePairSort};
-use crate::parser::peg::parse_error::PEGParseError;
-use crate::parser::peg::parser_core_ast::{CoreAst, CoreExpression, CoreSort, ParsePairRaw};
-use crate::parser::peg::parser_core_file;
+use crate::parser::peg::parse_error::{Expect, PEGParseError};
+use crate::parser::peg::parse_options::{ParseAborted, ParseOptions};
+use crate::parser::peg::parse_outcome::ParseOutcome;
+use crate::parser::peg::parse_stats::ParseStats;
+use crate::parser::peg::parse_warning::ParseWarning;
+use crate::parser::peg::parser_compiled::CompiledParser;
+use crate::parser::peg::parser_core_ast::{
+    CoreAst, CoreExpression, CoreSort, Memoize, ParsePairRaw, RepeatId, SortId,
+};
+use crate::parser::peg::parser_core_first::{compute_first_sets, First};
+use crate::parser::peg::parser_core_memo::choose_memoization;
+use crate::parser::peg::parser_core_optimize::optimize_ast;
 use crate::parser::peg::parser_sugar_ast::{Annotation, Expression, Sort, SyntaxFileAst};
+use crate::parser::syntax_file::builtin_sorts::integer_format;
+use crate::parser::syntax_file::ParseError;
 use crate::sources::character_class::CharacterClass;
 use crate::sources::source_file::SourceFile;
+use crate::sources::span::Span;
 use itertools::Itertools;
+use std::cell::Cell;
 use std::collections::HashMap;
 
 /// Parse a file by:
@@ -16,54 +29,273 @@ pub fn parse_file<'src>(
     ast: &'src SyntaxFileAst,
     file: &'src SourceFile,
 ) -> (ParsePairSort<'src>, Vec<PEGParseError>) {
-    //Desugar
-    let core_ast = desugar_ast(ast);
+    parse_file_with_options(ast, file, &ParseOptions::default())
+        .expect("parsing without limits is never aborted")
+}
+
+/// Like [`parse_file`], but the parse stops early when it exceeds the limits given in the options.
+pub fn parse_file_with_options<'src>(
+    ast: &'src SyntaxFileAst,
+    file: &'src SourceFile,
+    options: &ParseOptions,
+) -> Result<(ParsePairSort<'src>, Vec<PEGParseError>), ParseAborted> {
+    let outcome = parse_file_outcome(ast, file, options)?;
+    Ok((outcome.tree, outcome.errors))
+}
 
-    //Parse
-    let (res, errs) = parser_core_file::parse_file(&core_ast, file);
+/// Like [`parse_file_with_options`], but also returns statistics about the parse, per sort.
+/// These show which sorts take the most time, and how well memoization works for them.
+///
+/// ```
+/// # use lwb_parser::language::Language;
+/// # use lwb_parser::parser::peg::parse_options::ParseOptions;
+/// # use lwb_parser::parser::peg::parser_sugar::parse_file_with_stats;
+/// # use lwb_parser::parser::syntax_file::convert_syntax_file_ast::convert;
+/// # use lwb_parser::parser::syntax_file::SyntaxFile;
+/// # use lwb_parser::sources::source_file::SourceFile;
+/// let sf = SourceFile::new("a = \"a\"*;\nstart at a;\n", "test.syntax");
+/// let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+///
+/// let input = SourceFile::new("aaa", "input");
+/// let (_, _, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+/// assert_eq!(stats.sort("a").invocations, 1);
+/// println!("{}", stats.render_table());
+/// ```
+pub fn parse_file_with_stats<'src>(
+    ast: &'src SyntaxFileAst,
+    file: &'src SourceFile,
+    options: &ParseOptions,
+) -> Result<(ParsePairSort<'src>, Vec<PEGParseError>, ParseStats), ParseAborted> {
+    let outcome = parse_file_outcome(ast, file, &options.clone().with_stats(true))?;
+    let stats = outcome.stats.expect("stats are collected");
+    Ok((outcome.tree, outcome.errors, stats))
+}
 
-    //Resugar
-    let starting_sort = ast.sorts.get(&ast.starting_sort).unwrap();
-    (resugar_sort(ast, starting_sort, res), errs)
+/// Parse a file, configured by the options. Returns the resugared tree together with the errors, warnings
+/// and (if requested) statistics of the parse. Even when there are errors, the tree contains
+/// everything that could be parsed.
+///
+/// Panics when the options give a starting sort which does not exist.
+pub fn parse_file_outcome<'src>(
+    ast: &'src SyntaxFileAst,
+    file: &'src SourceFile,
+    options: &ParseOptions,
+) -> Result<ParseOutcome<ParsePairSort<'src>>, ParseAborted> {
+    //The grammar is only used for this file, so it is desugared for exactly these options
+    CompiledParser::with_options(ast, options).parse_file_outcome(file, options)
 }
 
-fn desugar_ast(ast: &SyntaxFileAst) -> CoreAst {
-    let mut sorts = HashMap::new();
-    //Insert all sorts
-    ast.sorts.values().for_each(|s| {
-        sorts.insert(&s.name[..], desugar_sort(s));
-    });
+/// Resugars the tree of a parse with these options.
+pub(crate) fn resugar_outcome<'src>(
+    ast: &'src SyntaxFileAst,
+    file: &SourceFile,
+    options: &ParseOptions,
+    outcome: ParseOutcome<ParsePairRaw>,
+) -> ParseOutcome<ParsePairSort<'src>> {
+    let starting_sort = options.starting_sort().unwrap_or(&ast.starting_sort);
+    let starting_sort = ast.sorts.get(starting_sort).expect("sort exists");
+    outcome.map(|tree| resugar_sort(ast, file, starting_sort, &tree))
+}
+
+/// Collects the warnings of the constructors in the tree of a parse with these options.
+pub(crate) fn collect_warnings(
+    ast: &SyntaxFileAst,
+    file: &SourceFile,
+    options: &ParseOptions,
+    outcome: &mut ParseOutcome<ParsePairRaw>,
+) {
+    let starting_sort = options.starting_sort().unwrap_or(&ast.starting_sort);
+    let starting_sort = ast.sorts.get(starting_sort).expect("sort exists");
+
+    //Only constructors in the final tree give warnings, not the ones which were tried and then backtracked
+    collect_warnings_sort(
+        ast,
+        file,
+        starting_sort,
+        &outcome.tree,
+        &mut outcome.warnings,
+    );
+}
+
+/// Collects the warnings of the constructors in `pair`, which was parsed as `sort`.
+/// Like resugaring, this follows the expressions of the grammar to find the sorts in the tree.
+fn collect_warnings_sort(
+    ast: &SyntaxFileAst,
+    file: &SourceFile,
+    sort: &Sort,
+    pair: &ParsePairRaw,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    //Error pairs have no constructor
+    let ParsePairRaw::Choice(span, i, subpair) = pair else {
+        return;
+    };
+    let constructor = &sort.constructors[*i];
+    for annotation in &constructor.annotations {
+        let (message, deprecated) = match annotation {
+            Annotation::Warning(message) => (message, false),
+            Annotation::Deprecated(message) => (message, true),
+            _ => continue,
+        };
+        warnings.push(ParseWarning {
+            span: span.to_span(file),
+            sort: sort.name.clone(),
+            constructor: constructor.name.clone(),
+            message: message.clone(),
+            deprecated,
+        });
+    }
+    collect_warnings_expr(ast, file, &constructor.expression, subpair, warnings);
+}
+
+fn collect_warnings_expr(
+    ast: &SyntaxFileAst,
+    file: &SourceFile,
+    expr: &Expression,
+    pair: &ParsePairRaw,
+    warnings: &mut Vec<ParseWarning>,
+) {
+    let mut collect = |expr, pair| collect_warnings_expr(ast, file, expr, pair, warnings);
+    match (expr, pair) {
+        (Expression::Sort(name), ParsePairRaw::Name(_, val)) => {
+            collect_warnings_sort(ast, file, &ast.sorts[name], val, warnings)
+        }
+        (Expression::Sequence(exprs), ParsePairRaw::List(_, vals)) => {
+            exprs.iter().zip(vals).for_each(|(e, v)| collect(e, v))
+        }
+        (
+            Expression::Repeat { e, .. } | Expression::Counted { e, .. },
+            ParsePairRaw::List(_, vals),
+        ) => vals.iter().for_each(|v| collect(e, v)),
+        (Expression::Choice(constructors), ParsePairRaw::Choice(_, i, expr)) => {
+            collect(&constructors[*i], expr)
+        }
+        //Only the elements of a delimited list are in the resugared tree, see `resugar_expr`
+        (Expression::Delimited { e, .. }, ParsePairRaw::List(_, list)) => {
+            let Some(ParsePairRaw::Choice(_, 0, choice)) = list.first() else {
+                return;
+            };
+            let ParsePairRaw::List(_, seq) = &**choice else {
+                return;
+            };
+            if let Some(first) = seq.first() {
+                collect(e, first);
+            }
+            if let Some(ParsePairRaw::List(_, rest)) = seq.get(1) {
+                for pair in rest {
+                    if let ParsePairRaw::List(_, pair) = pair {
+                        if let Some(element) = pair.get(1) {
+                            collect(e, element);
+                        }
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Parse a single sort at byte `offset` in a file, without requiring that the rest of the file is parsed.
+/// Returns the result, the offset directly after the parsed part, and the errors that occurred.
+/// Useful to parse fragments of a language which are embedded in another language.
+///
+/// See [`parse_prefix`](crate::parser::peg::parser_core_file::parse_prefix) for details.
+pub fn parse_prefix<'src>(
+    ast: &'src SyntaxFileAst,
+    sort: &str,
+    file: &'src SourceFile,
+    offset: usize,
+) -> Result<(ParsePairSort<'src>, usize, Vec<PEGParseError>), ParseError> {
+    parse_prefix_with_options(ast, sort, file, offset, &ParseOptions::default())
+}
+
+/// Like [`parse_prefix`], but the parse stops early when it exceeds the limits given in the options.
+/// The starting sort of the options is replaced by `sort`.
+pub fn parse_prefix_with_options<'src>(
+    ast: &'src SyntaxFileAst,
+    sort: &str,
+    file: &'src SourceFile,
+    offset: usize,
+    options: &ParseOptions,
+) -> Result<(ParsePairSort<'src>, usize, Vec<PEGParseError>), ParseError> {
+    if !ast.sorts.contains_key(sort) {
+        return Err(ParseError::UnknownSort(sort.to_string()));
+    }
+    let options = options.clone().with_starting_sort(sort);
+    CompiledParser::with_options(ast, &options)
+        .parse_prefix_with_options(sort, file, offset, &options)
+}
+
+/// Desugars the AST to core syntax. Constructors with a feature that is not enabled in the options are disabled.
+pub(crate) fn desugar_ast<'src>(ast: &'src SyntaxFileAst, options: &ParseOptions) -> CoreAst<'src> {
+    //The sorts are numbered in order of their names, so the same grammar always gets the same ids
+    let mut names: Vec<&str> = ast.sorts.keys().map(|name| &name[..]).collect();
     //If there is no layout sort, insert one
-    if !sorts.contains_key("layout") {
-        sorts.insert(
-            "layout",
-            CoreSort {
+    if !ast.sorts.contains_key("layout") {
+        names.push("layout");
+    }
+    names.sort_unstable();
+    let sort_ids: HashMap<&str, SortId> = names
+        .iter()
+        .enumerate()
+        .map(|(i, &name)| (name, SortId(i)))
+        .collect();
+
+    //Like the sorts, the repetitions are numbered in order
+    let repeats = Cell::new(0);
+    let sorts = names
+        .iter()
+        .map(|&name| match ast.sorts.get(name) {
+            Some(sort) => desugar_sort(sort, &sort_ids, &repeats, options),
+            None => CoreSort {
+                id: sort_ids[name],
                 name: "layout",
-                expr: CoreExpression::CharacterClass(CharacterClass::Nothing),
+                constructors: vec![],
+                expr: CoreExpression::CharacterClass(CharacterClass::Nothing.compile()),
                 annotations: vec![],
+                optimized: None,
+                memo: Memoize::Always,
             },
-        );
-    }
+        })
+        .collect();
 
-    CoreAst {
+    let starting_sort = options.starting_sort().unwrap_or(&ast.starting_sort);
+    let mut core_ast = CoreAst {
         sorts,
-        starting_sort: &ast.starting_sort,
+        starting_sort: sort_ids[&ast.starting_sort[..]],
+        layout: sort_ids["layout"],
+        sort_ids,
+        layout_first: First::default(),
+    };
+    let starting_sort = core_ast.sort_id(starting_sort).expect("sort exists");
+    if options.optimize() {
+        optimize_ast(&mut core_ast, starting_sort);
     }
+    choose_memoization(&mut core_ast, starting_sort);
+    compute_first_sets(&mut core_ast);
+    core_ast
 }
 
-fn desugar_sort(sort: &Sort) -> CoreSort {
+fn desugar_sort<'src>(
+    sort: &'src Sort,
+    sort_ids: &HashMap<&str, SortId>,
+    repeats: &Cell<usize>,
+    options: &ParseOptions,
+) -> CoreSort<'src> {
     CoreSort {
+        id: sort_ids[&sort.name[..]],
         name: &sort.name,
+        constructors: sort.constructors.iter().map(|c| &c.name[..]).collect(),
         expr: CoreExpression::Choice(
             sort.constructors
                 .iter()
                 .map(|c| {
-                    let mut base = desugar_expr(&c.expression);
+                    let mut base = desugar_expr(&c.expression, sort_ids, repeats);
                     if c.annotations.contains(&Annotation::NoLayout) {
                         base = CoreExpression::FlagNoLayout(Box::new(base));
                         base = CoreExpression::FlagNoErrors(
                             Box::new(base),
-                            String::from_iter([&sort.name, ".", &c.name]),
+                            Expect::ExpectSort(String::from_iter([&sort.name, ".", &c.name])),
                         );
                     }
 
@@ -77,40 +309,69 @@ fn desugar_sort(sort: &Sort) -> CoreSort {
                         base = CoreExpression::Error(Box::new(base), e.to_string())
                     }
 
+                    //Disabled constructors stay in the choice, so the indices of the other constructors don't change
+                    if c.annotations.iter().any(|i| {
+                        matches!(i, Annotation::Feature(name) if !options.feature_enabled(name))
+                    }) {
+                        base = CoreExpression::Fail;
+                    }
+
                     base
                 })
                 .collect(),
+            vec![],
         ),
         annotations: sort.annotations.clone(),
+        optimized: None,
+        //Chosen after desugaring by `choose_memoization`
+        memo: Memoize::Always,
     }
 }
 
-fn desugar_expr(expr: &Expression) -> CoreExpression {
+/// Desugars an expression. `repeats` is the number of repetitions that were desugared before it, which is
+/// the id of the next repetition.
+fn desugar_expr<'src>(
+    expr: &'src Expression,
+    sort_ids: &HashMap<&str, SortId>,
+    repeats: &Cell<usize>,
+) -> CoreExpression<'src> {
+    let desugar = |e| desugar_expr(e, sort_ids, repeats);
+    let repeat_id = || RepeatId(repeats.replace(repeats.get() + 1));
     match expr {
-        Expression::Sort(name) => CoreExpression::Name(&name[..]),
-        Expression::Sequence(constructors) => {
-            CoreExpression::Sequence(constructors.iter().map(desugar_expr).collect_vec())
+        Expression::Sort(name) => {
+            CoreExpression::Name(*sort_ids.get(&name[..]).expect("used sorts exist"))
         }
+        Expression::Sequence(constructors) => CoreExpression::Sequence(
+            constructors
+                .iter()
+                .enumerate()
+                .map(|(i, c)| match c {
+                    //The count is the last item of the integer sort before the repetition
+                    Expression::Counted { e, count } => CoreExpression::Counted {
+                        subexpr: Box::new(desugar(e)),
+                        count: constructors[..i]
+                            .iter()
+                            .rposition(|c| matches!(c, Expression::Sort(name) if name == count))
+                            .expect("the count is parsed before the repetition"),
+                        integer: integer_format(count).expect("the count is an integer sort"),
+                        id: repeat_id(),
+                    },
+                    c => desugar(c),
+                })
+                .collect_vec(),
+        ),
         Expression::Repeat { e: c, min, max } => CoreExpression::Repeat {
-            subexpr: Box::new(desugar_expr(c)),
+            subexpr: Box::new(desugar(c)),
             min: *min,
             max: *max,
+            id: repeat_id(),
         },
-        Expression::CharacterClass(cc) => CoreExpression::CharacterClass(cc.clone()),
+        Expression::Counted { .. } => unreachable!("a counted repetition is an item of a sequence"),
+        Expression::CharacterClass(cc) => CoreExpression::CharacterClass(cc.compile()),
         Expression::Choice(constructors) => {
-            CoreExpression::Choice(constructors.iter().map(desugar_expr).collect_vec())
-        }
-        //Literals are desugared to a sequence of character classes
-        Expression::Literal(lit) => {
-            CoreExpression::FlagNoLayout(Box::new(CoreExpression::FlagNoErrors(
-                Box::new(CoreExpression::Sequence(
-                    lit.chars()
-                        .map(|c| CoreExpression::CharacterClass(c.into()))
-                        .collect_vec(),
-                )),
-                String::from_iter(["'", lit, "'"]),
-            )))
+            CoreExpression::Choice(constructors.iter().map(desugar).collect_vec(), vec![])
         }
+        Expression::Literal(lit) => CoreExpression::Literal(lit),
         Expression::Negative(_) => {
             todo!()
         }
@@ -124,8 +385,8 @@ fn desugar_expr(expr: &Expression) -> CoreExpression {
             max,
             trailing,
         } => {
-            let e = desugar_expr(e);
-            let delim = desugar_expr(delim);
+            let e = desugar(e);
+            let delim = desugar(delim);
 
             let mut options = vec![];
             //Can parse count > 0
@@ -136,6 +397,7 @@ fn desugar_expr(expr: &Expression) -> CoreExpression {
                         subexpr: Box::new(CoreExpression::Sequence(vec![delim.clone(), e.clone()])),
                         min: min.saturating_sub(1),
                         max: max.map(|max| max.saturating_sub(1)),
+                        id: repeat_id(),
                     },
                 ]));
             }
@@ -144,7 +406,7 @@ fn desugar_expr(expr: &Expression) -> CoreExpression {
                 options.push(CoreExpression::Sequence(vec![]));
             }
 
-            let choice = CoreExpression::Choice(options);
+            let choice = CoreExpression::Choice(options, vec![]);
             if *trailing {
                 CoreExpression::Sequence(vec![
                     choice,
@@ -152,6 +414,7 @@ fn desugar_expr(expr: &Expression) -> CoreExpression {
                         subexpr: Box::new(delim),
                         min: 0,
                         max: Some(1),
+                        id: repeat_id(),
                     },
                 ])
             } else {
@@ -161,121 +424,175 @@ fn desugar_expr(expr: &Expression) -> CoreExpression {
     }
 }
 
-fn resugar_sort<'src>(
+/// A step of resugaring a tree, see [`resugar_sort`].
+enum Resugar<'src, 'p> {
+    /// Resugars the pair of a sort. The span is given for a pair in an expression, which wraps it in a
+    /// [`ParsePairExpression::Sort`], otherwise it is the root of the tree.
+    Sort(&'src Sort, &'p ParsePairRaw, Option<Span>),
+    Expr(&'src Expression, &'p ParsePairRaw),
+    /// A pair that is already resugared
+    Done(ParsePairExpression<'src>),
+    /// Combines the last resugared pair into the pair of a constructor
+    Constructor(&'src str, &'src str, Option<Span>),
+    /// Combines this many of the last resugared pairs into a list
+    List(Span, usize),
+    /// Combines the last resugared pair into a choice
+    Choice(Span, usize),
+}
+
+/// Resugars the pair of a parse of `sort`. The tree is resugared without recursion,
+/// so deeply nested input which was parsed does not overflow the stack.
+pub(crate) fn resugar_sort<'src>(
     ast: &'src SyntaxFileAst,
+    file: &SourceFile,
     sort: &'src Sort,
-    pair: ParsePairRaw,
+    pair: &ParsePairRaw,
 ) -> ParsePairSort<'src> {
-    match pair {
-        ParsePairRaw::Choice(_, i, subpair) => ParsePairSort {
-            sort: &sort.name[..],
-            constructor_name: &sort.constructors[i].name[..],
-            constructor_value: resugar_expr(ast, &sort.constructors[i].expression, *subpair),
-        },
-        ParsePairRaw::Error(span) => ParsePairSort {
-            sort: &sort.name[..],
-            constructor_name: "ERROR",
-            constructor_value: ParsePairExpression::Error(span),
-        },
-        _ => unreachable!(),
+    let mut steps = vec![Resugar::Sort(sort, pair, None)];
+    let mut pairs: Vec<ParsePairExpression<'src>> = vec![];
+    let mut root = None;
+
+    while let Some(step) = steps.pop() {
+        match step {
+            Resugar::Sort(sort, pair, span) => match pair {
+                ParsePairRaw::Choice(_, i, subpair) => {
+                    let constructor = &sort.constructors[*i];
+                    steps.push(Resugar::Constructor(&sort.name, &constructor.name, span));
+                    steps.push(Resugar::Expr(&constructor.expression, subpair));
+                }
+                ParsePairRaw::Error(error) => {
+                    steps.push(Resugar::Constructor(&sort.name, "ERROR", span));
+                    steps.push(Resugar::Done(ParsePairExpression::Error(
+                        error.to_span(file),
+                    )));
+                }
+                _ => unreachable!(),
+            },
+            Resugar::Expr(expr, pair) => resugar_expr(ast, file, expr, pair, &mut steps),
+            Resugar::Done(pair) => pairs.push(pair),
+            Resugar::Constructor(sort, constructor_name, span) => {
+                let pair = ParsePairSort {
+                    sort,
+                    constructor_name,
+                    constructor_value: pairs.pop().expect("the constructor was resugared"),
+                };
+                match span {
+                    Some(span) => pairs.push(ParsePairExpression::Sort(span, Box::new(pair))),
+                    None => root = Some(pair),
+                }
+            }
+            Resugar::List(span, len) => {
+                let list = pairs.split_off(pairs.len() - len);
+                pairs.push(ParsePairExpression::List(span, list));
+            }
+            Resugar::Choice(span, i) => {
+                let pair = pairs.pop().expect("the alternative was resugared");
+                pairs.push(ParsePairExpression::Choice(span, i, Box::new(pair)));
+            }
+        }
     }
+    root.expect("the sort was resugared")
 }
 
-fn resugar_expr<'src>(
+/// Adds the steps that resugar the pair of `expr` to `steps`. The steps of the pairs in it are
+/// added last, so they are resugared first.
+fn resugar_expr<'src, 'p>(
     ast: &'src SyntaxFileAst,
-    sort: &'src Expression,
-    pair: ParsePairRaw,
-) -> ParsePairExpression<'src> {
-    match (sort, pair) {
-        (Expression::Sort(name), ParsePairRaw::Name(span, val)) => ParsePairExpression::Sort(
-            span,
-            Box::new(resugar_sort(ast, ast.sorts.get(name).unwrap(), *val)),
-        ),
-        (Expression::Sequence(exprs), ParsePairRaw::List(span, vals)) => ParsePairExpression::List(
-            span,
-            exprs
-                .iter()
-                .zip(vals.into_iter())
-                .map(|(e, v)| resugar_expr(ast, e, v))
-                .collect_vec(),
-        ),
-        (Expression::Repeat { e: c, .. }, ParsePairRaw::List(span, vals)) => {
-            ParsePairExpression::List(
-                span,
-                vals.into_iter()
-                    .map(|v| resugar_expr(ast, c, v))
-                    .collect_vec(),
-            )
+    file: &SourceFile,
+    expr: &'src Expression,
+    pair: &'p ParsePairRaw,
+    steps: &mut Vec<Resugar<'src, 'p>>,
+) {
+    match (expr, pair) {
+        (Expression::Sort(name), ParsePairRaw::Name(span, val)) => steps.push(Resugar::Sort(
+            ast.sorts.get(name).unwrap(),
+            val,
+            Some(span.to_span(file)),
+        )),
+        (Expression::Sequence(exprs), ParsePairRaw::List(span, vals)) => {
+            let items = exprs.iter().zip(vals).map(|(e, v)| Resugar::Expr(e, v));
+            push_list(steps, span.to_span(file), items.collect_vec());
         }
-        (Expression::CharacterClass(_), ParsePairRaw::Empty(span)) => {
-            ParsePairExpression::Empty(span)
+        (
+            Expression::Repeat { e: c, .. } | Expression::Counted { e: c, .. },
+            ParsePairRaw::List(span, vals),
+        ) => {
+            let items = vals.iter().map(|v| Resugar::Expr(c, v));
+            push_list(steps, span.to_span(file), items.collect_vec());
         }
+        (Expression::CharacterClass(_), ParsePairRaw::Empty(span)) => steps.push(Resugar::Done(
+            ParsePairExpression::Empty(span.to_span(file)),
+        )),
         (Expression::Choice(constructors), ParsePairRaw::Choice(span, i, expr)) => {
-            ParsePairExpression::Choice(
-                span,
-                i,
-                Box::new(resugar_expr(ast, &constructors[i], *expr)),
-            )
+            steps.push(Resugar::Choice(span.to_span(file), *i));
+            steps.push(Resugar::Expr(&constructors[*i], expr));
         }
-        (Expression::Literal(_), ParsePairRaw::List(span, _)) => ParsePairExpression::Empty(span),
+        (Expression::Literal(_), ParsePairRaw::List(span, _)) => steps.push(Resugar::Done(
+            ParsePairExpression::Empty(span.to_span(file)),
+        )),
         (Expression::Delimited { e, max, .. }, ParsePairRaw::List(span, list)) => {
-            //If max is 0, empty list
-            if !max.is_none() && max.unwrap() == 0 {
-                return ParsePairExpression::List(span, vec![]);
-            };
-            //Get choice
-            let (i, choice) =
-                if let ParsePairRaw::Choice(_, i, choice) = list.into_iter().next().unwrap() {
-                    (i, choice)
-                } else {
-                    return ParsePairExpression::Error(span);
-                };
-            //If choice was not 0, empty list
-            if i != 0 {
-                return ParsePairExpression::List(span, vec![]);
-            };
-            //Find elements inside choice
-            let seq = if let ParsePairRaw::List(_, seq) = *choice {
-                seq
-            } else {
-                return ParsePairExpression::Error(span);
-            };
-
-            let mut result = vec![];
-            let mut seq_iter = seq.into_iter();
-
-            //Inside choice is first an expr, then a repeat of seq (delim, expr)
-            //We first find the first expr
-            let seq0 = seq_iter.next().unwrap();
-            result.push(resugar_expr(ast, e, seq0));
-
-            //See if the rest of the expr is present
-            let next = seq_iter.next();
-            if next.is_none() {
-                return ParsePairExpression::List(span, result);
+            let span = span.to_span(file);
+            match resugar_delimited(file, e, *max, list) {
+                Some(items) => push_list(steps, span, items),
+                None => steps.push(Resugar::Done(ParsePairExpression::Error(span))),
             }
-            //It is present, lets get the list of them
-            let seq1 = if let ParsePairRaw::List(_, list) = next.unwrap() {
-                list
-            } else {
-                return ParsePairExpression::Error(span);
-            };
-            //Map each element in the list to get the expr
-            seq1.into_iter().for_each(|pair| {
-                result.push(if let ParsePairRaw::List(span, list) = pair {
-                    if list.len() < 2 {
-                        ParsePairExpression::Error(span)
-                    } else {
-                        resugar_expr(ast, e, list.into_iter().nth(1).unwrap())
-                    }
-                } else {
-                    ParsePairExpression::Error(pair.span())
-                });
-            });
-
-            ParsePairExpression::List(span, result)
         }
-        (_, ParsePairRaw::Error(span)) => ParsePairExpression::Error(span),
+        (_, ParsePairRaw::Error(span)) => steps.push(Resugar::Done(ParsePairExpression::Error(
+            span.to_span(file),
+        ))),
         (_, _) => unreachable!(),
     }
 }
+
+/// Adds the steps that resugar a list of these items.
+fn push_list<'src, 'p>(
+    steps: &mut Vec<Resugar<'src, 'p>>,
+    span: Span,
+    items: Vec<Resugar<'src, 'p>>,
+) {
+    steps.push(Resugar::List(span, items.len()));
+    steps.extend(items.into_iter().rev());
+}
+
+/// The steps that resugar the elements of a delimited list, without the delimiters.
+/// Returns `None` when the list was not parsed.
+fn resugar_delimited<'src, 'p>(
+    file: &SourceFile,
+    e: &'src Expression,
+    max: Option<u64>,
+    list: &'p [ParsePairRaw],
+) -> Option<Vec<Resugar<'src, 'p>>> {
+    //If max is 0, empty list
+    if max == Some(0) {
+        return Some(vec![]);
+    }
+    //Get choice
+    let ParsePairRaw::Choice(_, i, choice) = list.first().unwrap() else {
+        return None;
+    };
+    //If choice was not 0, empty list
+    if *i != 0 {
+        return Some(vec![]);
+    }
+    //Find elements inside choice
+    let ParsePairRaw::List(_, seq) = &**choice else {
+        return None;
+    };
+
+    //Inside choice is first an expr, then a repeat of seq (delim, expr)
+    //We first find the first expr
+    let mut result = vec![Resugar::Expr(e, &seq[0])];
+
+    //See if the rest of the expr is present, it is a list of them
+    let rest = match seq.get(1) {
+        None => return Some(result),
+        Some(ParsePairRaw::List(_, rest)) => rest,
+        Some(_) => return None,
+    };
+    //Map each element in the list to get the expr
+    result.extend(rest.iter().map(|pair| match pair {
+        ParsePairRaw::List(_, list) if list.len() >= 2 => Resugar::Expr(e, &list[1]),
+        pair => Resugar::Done(ParsePairExpression::Error(pair.span().to_span(file))),
+    }));
+    Some(result)
+}
@@ -25,6 +25,12 @@ pub enum Expression {
         min: u64,
         max: Option<u64>,
     },
+    /// Repeats `e` as many times as the value of the integer sort `count`, which is the last item of
+    /// that sort before this one in the sequence around it. Only binary grammars have integer sorts.
+    Counted {
+        e: Box<Expression>,
+        count: String,
+    },
     CharacterClass(CharacterClass),
     Choice(Vec<Expression>),
     Delimited {
@@ -66,6 +72,35 @@ pub enum Annotation {
     /// Says that one rule must generate its constructors as part of another rule
     #[display(fmt = "part-of: {}", _0)]
     PartOf(String),
+
+    /// This constructor only parses when the feature is enabled
+    #[display(fmt = "feature: {}", _0)]
+    Feature(String),
+
+    /// The name of this sort in errors
+    #[display(fmt = "display-name: {}", _0)]
+    DisplayName(String),
+
+    /// This constructor gives a warning when it is used
+    #[display(fmt = "warning: {}", _0)]
+    Warning(String),
+
+    /// This constructor is deprecated, using it gives a warning
+    #[display(fmt = "deprecated: {}", _0)]
+    Deprecated(String),
+
+    /// Whether the results of this sort are memoized by the parser
+    #[display(fmt = "memo: {}", _0)]
+    Memo(MemoPolicy),
+}
+
+/// The policy of the `memo` annotation
+#[derive(Debug, Clone, Copy, Display, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MemoPolicy {
+    #[display(fmt = "always")]
+    Always,
+    #[display(fmt = "never")]
+    Never,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -269,11 +304,11 @@ impl SyntaxFileAst {
         Ok(self)
     }
 
-    fn get_new_name(name: &String, merges: &HashMap<String, String>) -> String {
+    pub(crate) fn get_new_name(name: &str, merges: &HashMap<String, String>) -> String {
         if let Some(new_name) = merges.get(name) {
             Self::get_new_name(new_name, merges)
         } else {
-            name.clone()
+            name.to_string()
         }
     }
 
@@ -291,6 +326,10 @@ impl SyntaxFileAst {
                 min,
                 max,
             },
+            Expression::Counted { e, count } => Expression::Counted {
+                e: Box::new(Self::rewrite_expression(*e, merges)),
+                count,
+            },
             a @ Expression::CharacterClass(_) => a,
             Expression::Choice(s) => Expression::Choice(
                 s.into_iter()
@@ -20,7 +20,14 @@ pub enum SortOrMeta<M> {
 ///Other top-level constructs that are not sorts
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(crate = "self::serde")]
-pub struct Meta<M>(pub M, pub Identifier<M>);
+pub enum Meta<M> {
+    ///The starting rule is an indication for the parser
+    Start(M, Identifier<M>),
+    ///Makes this a grammar for binary formats, which parses the bytes of a source file
+    ///made with `SourceFile::new_bytes`. In a binary grammar, `\x` escapes match bytes, and
+    ///fixed-width integers like `u8` and `u16le` can be used without defining them.
+    Binary(M),
+}
 ///A sort is a group of constructors. See [`constructor`] for more details.
 ///
 ///There is one special sort, called `layout`. It can be used to denote
@@ -105,6 +112,10 @@ pub enum Expression<M> {
     RepeatRange(M, Box<Expression<M>>, Number<M>, Number<M>),
     ///Ranged repetition, without upper bound (or an infinite maximum)
     RepeatLower(M, Box<Expression<M>>, Number<M>),
+    ///Counted repetition, only in binary grammars. The expression is repeated as many times as the value of
+    ///an integer sort (like `u8` or `u16le`), which is the last time that sort was used before it in the same sequence.
+    ///For example, `u16le [\x00-\xff]{u16le}` is a length, followed by that many bytes.
+    RepeatCount(M, Box<Expression<M>>, Identifier<M>),
     ///Delimited expressions. Says that some expression should be repeatedly parsed,
     ///but between two parses, a delimiter should be parsed too. For example, comma seperated expressions.
     ///The final trailing keyword enables a trailing separator after the sequence. If not present, no trailing
@@ -160,7 +171,18 @@ pub enum Annotation<M> {
     ///has a slightly different effect. If none of the variants in the sort managed to parse
     ///then alongside an "expected ...", that message will be displayed as well.
     ///If this sort was the only possibility at a certain point, only the message will be displayed.
+    ///
+    ///`{text}` in the message is replaced by the text that caused the error.
     Error(M, String<M>),
+    ///Annotation for sorts. When this sort was expected but could not be parsed, errors say that the display name was expected,
+    ///instead of listing everything that could start this sort. For example, `{display-name: "an expression"}`.
+    DisplayName(M, String<M>),
+    ///Annotation for constructors. The constructor parses as usual, but a warning with the associated message
+    ///is given for every place where it was used.
+    Warning(M, String<M>),
+    ///Annotation for constructors. The constructor parses as usual, but a warning is given that it is
+    ///deprecated, with the associated message. For example, `{deprecated: "use `fn` instead"}`.
+    Deprecated(M, String<M>),
     ///Makes constructors of this rule generate as part of another rule.
     ///This has one major requirement. If a is part-of b then
     ///b must have a rule like `a=a;` (also written as just `a;`) to allow
@@ -168,6 +190,14 @@ pub enum Annotation<M> {
     ///
     ///Injections on rule a become injections into rule b too.
     PartOf(M, Identifier<M>),
+    ///Only enable this constructor when the feature with this name is enabled while parsing
+    ///(see `ParseOptions::with_feature`). When the feature is not enabled, the constructor never parses.
+    ///This makes it possible to have a single grammar for several versions or dialects of a language.
+    Feature(M, Identifier<M>),
+    ///Annotation for sorts. Whether the parser memoizes the results of this sort, `always` or `never`.
+    ///By default, small sorts that only use other small sorts (like the sorts of tokens) are not memoized,
+    ///since parsing them again is faster than looking them up. Recursive sorts are always memoized.
+    Memo(M, MemoPolicy<M>),
 }
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(crate = "self::serde")]
@@ -209,7 +239,17 @@ pub enum String<M> {
 pub struct CharacterClass<M>(pub M, pub bool, pub Vec<CharacterClassItem<M>>);
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(crate = "self::serde")]
+pub enum MemoPolicy<M> {
+    Always(M),
+    Never(M),
+}
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(crate = "self::serde")]
 pub enum StringChar<M> {
+    ///A single byte, written as two hexadecimal digits. In `binary` grammars
+    ///this matches exactly that byte. Other grammars only allow the ascii characters `\x00` to `\x7f`,
+    ///since any other character is more than one byte.
+    Byte(M, std::string::String, std::string::String),
     Escaped(M, std::string::String),
     Normal(M, std::string::String),
 }
@@ -222,6 +262,10 @@ pub enum CharacterClassItem<M> {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(crate = "self::serde")]
 pub enum EscapeClosingBracket<M> {
+    ///A single byte, written as two hexadecimal digits. In `binary` grammars
+    ///this matches exactly that byte. In other grammars it stands for the characters
+    ///`\`, `x` and the two digits, like any other text in a character class.
+    Byte(M, std::string::String, std::string::String),
     Escaped(M, std::string::String),
     Unescaped(M, std::string::String),
 }
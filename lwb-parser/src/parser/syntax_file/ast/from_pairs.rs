@@ -30,6 +30,32 @@ impl<M: AstInfo> FromPairs<M> for Program<M> {
         )
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for Program<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, _, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "program"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("program", span);
+        Self(
+            info,
+            if let ParsePairRaw::List(_, ref l) = *value {
+                l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { SortOrMeta :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "program") ; }) . collect ()
+            } else {
+                unreachable!(
+                    "expected different parse pair expression in pair to ast conversion of {}",
+                    "program"
+                );
+            },
+        )
+    }
+}
 impl<M: AstInfo> FromPairs<M> for SortOrMeta<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -64,6 +90,44 @@ impl<M: AstInfo> FromPairs<M> for SortOrMeta<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for SortOrMeta<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "sort-or-meta"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("sort-or-meta", span);
+        match constructor {
+            0 => {
+                Self::Meta(
+                    info,
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        Meta::from_raw_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort-or-meta");
+                    },
+                )
+            }
+            1 => {
+                Self::Sort(
+                    info,
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        Sort::from_raw_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort-or-meta");
+                    },
+                )
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for Meta<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -73,23 +137,62 @@ impl<M: AstInfo> FromPairs<M> for Meta<M> {
             vec!["meta"]
         );
         let info = generator.generate(&pair);
-        if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
-            Self(
-                info,
-                if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
-                    Identifier::from_pairs(s, generator)
+        match pair.constructor_name {
+            "start" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Start(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            Identifier::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "meta");
+                        },
+                    )
                 } else {
                     unreachable!(
                         "expected different parse pair expression in pair to ast conversion of {}",
                         "meta"
                     );
-                },
-            )
-        } else {
+                }
+            }
+            "binary" => Self::Binary(info),
+            a => unreachable!("{}", a),
+        }
+    }
+}
+impl<M: AstInfo> FromRawPairs<M> for Meta<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
             unreachable!(
-                "expected different parse pair expression in pair to ast conversion of {}",
+                "expected the choice of a constructor in pair to ast conversion of {}",
                 "meta"
             );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("meta", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Start(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "meta");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "meta"
+                    );
+                }
+            }
+            1 => Self::Binary(info),
+            a => unreachable!("{}", a),
         }
     }
 }
@@ -183,6 +286,100 @@ impl<M: AstInfo> FromPairs<M> for Sort<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for Sort<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "sort"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("sort", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::SortDocumented(
+                        info,
+                        if let ParsePairRaw::List(_, ref l) = l[0usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { DocComment :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[1usize] {
+                            Box::new(Sort::from_raw_pairs(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "sort"
+                    );
+                }
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Sort(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[2usize] {
+                            l . first () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { AnnotationList :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort") ; })
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[4usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { Constructor :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "sort"
+                    );
+                }
+            }
+            2 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::SortSingle(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[2usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { Expression :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[4usize] {
+                            l . first () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { AnnotationList :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort") ; })
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "sort");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "sort"
+                    );
+                }
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for Identifier<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -195,6 +392,22 @@ impl<M: AstInfo> FromPairs<M> for Identifier<M> {
         return Self(info, pair.constructor_value.span().as_str().to_string());
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for Identifier<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, _, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "identifier"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("identifier", span);
+        return Self(info, value.span().as_str(generator.file()).to_string());
+    }
+}
 impl<M: AstInfo> FromPairs<M> for DocComment<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -207,6 +420,22 @@ impl<M: AstInfo> FromPairs<M> for DocComment<M> {
         return Self(info, pair.constructor_value.span().as_str().to_string());
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for DocComment<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, _, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "doc-comment"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("doc-comment", span);
+        return Self(info, value.span().as_str(generator.file()).to_string());
+    }
+}
 impl<M: AstInfo> FromPairs<M> for AnnotationList<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -236,6 +465,29 @@ impl<M: AstInfo> FromPairs<M> for AnnotationList<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for AnnotationList<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, _, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "annotation-list"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("annotation-list", span);
+        if let ParsePairRaw::List(_, ref l) = *value {
+            Self (info , delimited_items (& l [1usize]) . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { Annotation :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation-list") ; }) . collect ())
+        } else {
+            unreachable!(
+                "expected different parse pair expression in pair to ast conversion of {}",
+                "annotation-list"
+            );
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for Constructor<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -321,13 +573,102 @@ impl<M: AstInfo> FromPairs<M> for Constructor<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for Constructor<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "constructor"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("constructor", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::ConstructorDocumented(
+                        info,
+                        if let ParsePairRaw::List(_, ref l) = l[0usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { DocComment :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[1usize] {
+                            Box::new(Constructor::from_raw_pairs(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "constructor"
+                    );
+                }
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Constructor(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[1usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[3usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { Expression :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[5usize] {
+                            l . first () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { AnnotationList :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor") ; })
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "constructor"
+                    );
+                }
+            }
+            2 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::ConstructorBare(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[1usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[3usize] {
+                            l . first () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { AnnotationList :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor") ; })
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "constructor");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "constructor"
+                    );
+                }
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for Expression<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
-            vec!["atom", "expression"].contains(&pair.sort),
+            vec!["expression", "atom"].contains(&pair.sort),
             "{} not in {:?}",
             pair.sort,
-            vec!["atom", "expression"]
+            vec!["expression", "atom"]
         );
         let info = generator.generate(&pair);
         match pair.constructor_name {
@@ -453,6 +794,28 @@ impl<M: AstInfo> FromPairs<M> for Expression<M> {
                     );
                 }
             }
+            "repeat-count" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::RepeatCount(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_pairs(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            Identifier::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
             "delimited" => {
                 if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
                     Self::Delimited(
@@ -568,46 +931,564 @@ impl<M: AstInfo> FromPairs<M> for Expression<M> {
         }
     }
 }
-impl<M: AstInfo> FromPairs<M> for Annotation<M> {
-    fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
-        assert!(
-            vec!["annotation"].contains(&pair.sort),
-            "{} not in {:?}",
-            pair.sort,
-            vec!["annotation"]
-        );
-        let info = generator.generate(&pair);
-        match pair.constructor_name {
-            "injection" => Self::Injection(info),
-            "no-pretty-print" => Self::NoPrettyPrint(info),
-            "single-string" => Self::SingleString(info),
-            "no-layout" => Self::NoLayout(info),
-            "hidden" => Self::Hidden(info),
-            "error" => {
-                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
-                    Self::Error(
+impl<M: AstInfo> FromRawPairs<M> for Expression<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "expression"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("expression", span);
+        match constructor {
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Star(
                         info,
-                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
-                            String::from_pairs(s, generator)
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
                         } else {
-                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
                         },
                     )
                 } else {
                     unreachable!(
                         "expected different parse pair expression in pair to ast conversion of {}",
-                        "annotation"
+                        "expression"
                     );
                 }
             }
-            "part-of" => {
-                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
-                    Self::PartOf(
+            2 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Plus(
                         info,
-                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
-                            Identifier::from_pairs(s, generator)
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
                         } else {
-                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            3 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Maybe(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            4 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::RepeatExact(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            5 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::RepeatRange(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[4usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            6 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::RepeatLower(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            7 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::RepeatCount(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            8 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Delimited(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Box::new(Expression::from_raw_pairs(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[4usize] {
+                            Box::new(Expression::from_raw_pairs(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[6usize] {
+                            DelimitedBound::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                        if let ParsePairRaw::List(_, ref l) = l[7usize] {
+                            l.first().is_some()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "expression");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            9 => {
+                *if let ParsePairRaw::Name(_, ref s) = *value {
+                    Box::new(Expression::from_raw_pairs_atom(s, generator))
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "expression"
+                    );
+                }
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
+impl<M: AstInfo> Expression<M> {
+    fn from_raw_pairs_atom<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "atom"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("atom", span);
+        match constructor {
+            1 => {
+                Self::Literal(
+                    info,
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        String::from_raw_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom");
+                    },
+                )
+            }
+            2 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Paren(
+                        info,
+                        if let ParsePairRaw::List(_, ref l) = l[1usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { Box :: new (Expression :: from_raw_pairs (s , generator)) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "atom"
+                    );
+                }
+            }
+            3 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Labelled(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Box::new(Expression::from_raw_pairs_atom(s, generator))
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "atom"
+                    );
+                }
+            }
+            4 => {
+                Self::Sort(
+                    info,
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        Identifier::from_raw_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom");
+                    },
+                )
+            }
+            5 => {
+                Self::Class(
+                    info,
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        CharacterClass::from_raw_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "atom");
+                    },
+                )
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
+impl<M: AstInfo> FromPairs<M> for Annotation<M> {
+    fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
+        assert!(
+            vec!["annotation"].contains(&pair.sort),
+            "{} not in {:?}",
+            pair.sort,
+            vec!["annotation"]
+        );
+        let info = generator.generate(&pair);
+        match pair.constructor_name {
+            "injection" => Self::Injection(info),
+            "no-pretty-print" => Self::NoPrettyPrint(info),
+            "single-string" => Self::SingleString(info),
+            "no-layout" => Self::NoLayout(info),
+            "hidden" => Self::Hidden(info),
+            "error" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Error(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            String::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            "display-name" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::DisplayName(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            String::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            "warning" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Warning(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            String::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            "deprecated" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Deprecated(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            String::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            "part-of" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::PartOf(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            Identifier::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            "feature" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Feature(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            Identifier::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            "memo" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Memo(
+                        info,
+                        if let ParsePairExpression::Sort(_, ref s) = l[2usize] {
+                            MemoPolicy::from_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
+impl<M: AstInfo> FromRawPairs<M> for Annotation<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "annotation"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("annotation", span);
+        match constructor {
+            0 => Self::Injection(info),
+            1 => Self::NoPrettyPrint(info),
+            2 => Self::SingleString(info),
+            3 => Self::NoLayout(info),
+            4 => Self::Hidden(info),
+            5 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Error(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            String::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            6 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::DisplayName(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            String::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            7 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Warning(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            String::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            8 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Deprecated(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            String::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            9 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::PartOf(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            10 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Feature(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Identifier::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "annotation"
+                    );
+                }
+            }
+            11 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Memo(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            MemoPolicy::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "annotation");
                         },
                     )
                 } else {
@@ -633,6 +1514,22 @@ impl<M: AstInfo> FromPairs<M> for Number<M> {
         return Self(info, pair.constructor_value.span().as_str().to_string());
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for Number<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, _, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "number"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("number", span);
+        return Self(info, value.span().as_str(generator.file()).to_string());
+    }
+}
 impl<M: AstInfo> FromPairs<M> for DelimitedBound<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -682,18 +1579,87 @@ impl<M: AstInfo> FromPairs<M> for DelimitedBound<M> {
                     );
                 }
             }
-            "num" => {
+            "num" => {
+                Self::Num(
+                    info,
+                    if let ParsePairExpression::Sort(_, ref s) = pair.constructor_value {
+                        Number::from_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "delimited-bound");
+                    },
+                )
+            }
+            "star" => Self::Star(info),
+            "plus" => Self::Plus(info),
+            a => unreachable!("{}", a),
+        }
+    }
+}
+impl<M: AstInfo> FromRawPairs<M> for DelimitedBound<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "delimited-bound"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("delimited-bound", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::NumNum(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "delimited-bound");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "delimited-bound");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "delimited-bound"
+                    );
+                }
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::NumInf(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            Number::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "delimited-bound");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "delimited-bound"
+                    );
+                }
+            }
+            2 => {
                 Self::Num(
                     info,
-                    if let ParsePairExpression::Sort(_, ref s) = pair.constructor_value {
-                        Number::from_pairs(s, generator)
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        Number::from_raw_pairs(s, generator)
                     } else {
                         unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "delimited-bound");
                     },
                 )
             }
-            "star" => Self::Star(info),
-            "plus" => Self::Plus(info),
+            3 => Self::Star(info),
+            4 => Self::Plus(info),
             a => unreachable!("{}", a),
         }
     }
@@ -746,6 +1712,58 @@ impl<M: AstInfo> FromPairs<M> for String<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for String<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "string"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("string", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Single(
+                        info,
+                        if let ParsePairRaw::List(_, ref l) = l[1usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { StringChar :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "string"
+                    );
+                }
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Double(
+                        info,
+                        if let ParsePairRaw::List(_, ref l) = l[1usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { StringChar :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "string"
+                    );
+                }
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for CharacterClass<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -783,6 +1801,83 @@ impl<M: AstInfo> FromPairs<M> for CharacterClass<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for CharacterClass<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, _, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "character-class"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("character-class", span);
+        if let ParsePairRaw::List(_, ref l) = *value {
+            Self(
+                info,
+                if let ParsePairRaw::List(_, ref l) = l[1usize] {
+                    l.first().is_some()
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "character-class"
+                    );
+                },
+                if let ParsePairRaw::List(_, ref l) = l[2usize] {
+                    l . iter () . map (| x | if let ParsePairRaw :: Name (_ , ref s) = x { CharacterClassItem :: from_raw_pairs (s , generator) } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "character-class") ; }) . collect ()
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "character-class"
+                    );
+                },
+            )
+        } else {
+            unreachable!(
+                "expected different parse pair expression in pair to ast conversion of {}",
+                "character-class"
+            );
+        }
+    }
+}
+impl<M: AstInfo> FromPairs<M> for MemoPolicy<M> {
+    fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
+        assert!(
+            vec!["memo-policy"].contains(&pair.sort),
+            "{} not in {:?}",
+            pair.sort,
+            vec!["memo-policy"]
+        );
+        let info = generator.generate(&pair);
+        match pair.constructor_name {
+            "always" => Self::Always(info),
+            "never" => Self::Never(info),
+            a => unreachable!("{}", a),
+        }
+    }
+}
+impl<M: AstInfo> FromRawPairs<M> for MemoPolicy<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "memo-policy"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("memo-policy", span);
+        match constructor {
+            0 => Self::Always(info),
+            1 => Self::Never(info),
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for StringChar<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -793,6 +1888,28 @@ impl<M: AstInfo> FromPairs<M> for StringChar<M> {
         );
         let info = generator.generate(&pair);
         match pair.constructor_name {
+            "byte" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Byte(
+                        info,
+                        if let ParsePairExpression::Empty(ref span) = l[1usize] {
+                            span.as_str().to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string-char");
+                        },
+                        if let ParsePairExpression::Empty(ref span) = l[2usize] {
+                            span.as_str().to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string-char");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "string-char"
+                    );
+                }
+            }
             "escaped" => {
                 if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
                     Self::Escaped(
@@ -824,6 +1941,73 @@ impl<M: AstInfo> FromPairs<M> for StringChar<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for StringChar<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "string-char"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("string-char", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Byte(
+                        info,
+                        if let ParsePairRaw::Empty(ref span) = l[1usize] {
+                            span.as_str(generator.file()).to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string-char");
+                        },
+                        if let ParsePairRaw::Empty(ref span) = l[2usize] {
+                            span.as_str(generator.file()).to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string-char");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "string-char"
+                    );
+                }
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Escaped(
+                        info,
+                        if let ParsePairRaw::Empty(ref span) = l[1usize] {
+                            span.as_str(generator.file()).to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string-char");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "string-char"
+                    );
+                }
+            }
+            2 => {
+                Self::Normal(
+                    info,
+                    if let ParsePairRaw::Empty(ref span) = *value {
+                        span.as_str(generator.file()).to_string()
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "string-char");
+                    },
+                )
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for CharacterClassItem<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -870,6 +2054,56 @@ impl<M: AstInfo> FromPairs<M> for CharacterClassItem<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for CharacterClassItem<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "character-class-item"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("character-class-item", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Range(
+                        info,
+                        if let ParsePairRaw::Name(_, ref s) = l[0usize] {
+                            EscapeClosingBracket::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "character-class-item");
+                        },
+                        if let ParsePairRaw::Name(_, ref s) = l[2usize] {
+                            EscapeClosingBracket::from_raw_pairs(s, generator)
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "character-class-item");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "character-class-item"
+                    );
+                }
+            }
+            1 => {
+                Self::SingleChar(
+                    info,
+                    if let ParsePairRaw::Name(_, ref s) = *value {
+                        EscapeClosingBracket::from_raw_pairs(s, generator)
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "character-class-item");
+                    },
+                )
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for EscapeClosingBracket<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -880,6 +2114,28 @@ impl<M: AstInfo> FromPairs<M> for EscapeClosingBracket<M> {
         );
         let info = generator.generate(&pair);
         match pair.constructor_name {
+            "byte" => {
+                if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
+                    Self::Byte(
+                        info,
+                        if let ParsePairExpression::Empty(ref span) = l[1usize] {
+                            span.as_str().to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "escape-closing-bracket");
+                        },
+                        if let ParsePairExpression::Empty(ref span) = l[2usize] {
+                            span.as_str().to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "escape-closing-bracket");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "escape-closing-bracket"
+                    );
+                }
+            }
             "escaped" => {
                 if let ParsePairExpression::List(_, ref l) = pair.constructor_value {
                     Self::Escaped(
@@ -911,6 +2167,73 @@ impl<M: AstInfo> FromPairs<M> for EscapeClosingBracket<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for EscapeClosingBracket<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "escape-closing-bracket"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("escape-closing-bracket", span);
+        match constructor {
+            0 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Byte(
+                        info,
+                        if let ParsePairRaw::Empty(ref span) = l[1usize] {
+                            span.as_str(generator.file()).to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "escape-closing-bracket");
+                        },
+                        if let ParsePairRaw::Empty(ref span) = l[2usize] {
+                            span.as_str(generator.file()).to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "escape-closing-bracket");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "escape-closing-bracket"
+                    );
+                }
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Escaped(
+                        info,
+                        if let ParsePairRaw::Empty(ref span) = l[1usize] {
+                            span.as_str(generator.file()).to_string()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "escape-closing-bracket");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "escape-closing-bracket"
+                    );
+                }
+            }
+            2 => {
+                Self::Unescaped(
+                    info,
+                    if let ParsePairRaw::Empty(ref span) = *value {
+                        span.as_str(generator.file()).to_string()
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "escape-closing-bracket");
+                    },
+                )
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
 impl<M: AstInfo> FromPairs<M> for Layout<M> {
     fn from_pairs<G: GenerateAstInfo<Result = M>>(pair: &ParsePairSort, generator: &mut G) -> Self {
         assert!(
@@ -952,3 +2275,48 @@ impl<M: AstInfo> FromPairs<M> for Layout<M> {
         }
     }
 }
+impl<M: AstInfo> FromRawPairs<M> for Layout<M> {
+    fn from_raw_pairs<G: GenerateRawAstInfo<Result = M>>(
+        pair: &ParsePairRaw,
+        generator: &mut G,
+    ) -> Self {
+        let ParsePairRaw::Choice(span, constructor, ref value) = *pair else {
+            unreachable!(
+                "expected the choice of a constructor in pair to ast conversion of {}",
+                "layout"
+            );
+        };
+        let value: &ParsePairRaw = value;
+        let info = generator.generate("layout", span);
+        match constructor {
+            0 => {
+                Self::Simple(
+                    info,
+                    if let ParsePairRaw::Empty(ref span) = *value {
+                        span.as_str(generator.file()).to_string()
+                    } else {
+                        unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "layout");
+                    },
+                )
+            }
+            1 => {
+                if let ParsePairRaw::List(_, ref l) = *value {
+                    Self::Comment(
+                        info,
+                        if let ParsePairRaw::List(_, ref l) = l[1usize] {
+                            l . iter () . map (| x | if let ParsePairRaw :: Empty (ref span) = x { span . as_str (generator . file ()) . to_string () } else { unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "layout") ; }) . collect ()
+                        } else {
+                            unreachable ! ("expected different parse pair expression in pair to ast conversion of {}" , "layout");
+                        },
+                    )
+                } else {
+                    unreachable!(
+                        "expected different parse pair expression in pair to ast conversion of {}",
+                        "layout"
+                    );
+                }
+            }
+            a => unreachable!("{}", a),
+        }
+    }
+}
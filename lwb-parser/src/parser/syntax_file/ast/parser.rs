@@ -7,4 +7,5 @@
 // |      CHANGES TO IT WILL BE DELETED WHEN REGENERATED.     |
 // | IN GENERAL, THIS FILE SHOULD NOT BE MODIFIED IN ANY WAY. |
 // |==========================================================|
-pub const PARSER: &[u8] = &[1, 0, 0, 0, 0, 0, 0, 0, 162, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 32, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 32, 105, 115, 32, 97, 110, 121, 32, 110, 97, 109, 101, 32, 111, 102, 32, 97, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 111, 114, 32, 115, 111, 114, 116, 44, 32, 97, 110, 100, 32, 105, 115, 32, 117, 115, 101, 100, 32, 105, 110, 32, 118, 97, 114, 105, 111, 117, 115, 32, 112, 108, 97, 99, 101, 115, 46, 88, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 100, 101, 110, 116, 105, 102, 105, 101, 114, 115, 32, 97, 108, 119, 97, 121, 115, 32, 115, 116, 97, 114, 116, 32, 119, 105, 116, 104, 32, 97, 32, 108, 101, 116, 116, 101, 114, 32, 40, 99, 97, 112, 105, 116, 97, 108, 32, 111, 114, 32, 110, 111, 116, 41, 32, 111, 114, 32, 97, 110, 32, 117, 110, 100, 101, 114, 115, 99, 111, 114, 101, 44, 32, 97, 110, 100, 32, 99, 97, 110, 32, 98, 101, 177, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 102, 111, 108, 108, 111, 119, 101, 100, 32, 98, 121, 32, 108, 101, 116, 116, 101, 114, 115, 32, 111, 114, 32, 110, 117, 109, 98, 101, 114, 115, 46, 32, 84, 104, 105, 115, 32, 105, 115, 32, 118, 101, 114, 121, 32, 115, 105, 109, 105, 108, 97, 114, 32, 116, 111, 32, 104, 111, 119, 32, 118, 97, 114, 105, 97, 98, 108, 101, 115, 32, 105, 110, 32, 109, 111, 115, 116, 32, 109, 97, 106, 111, 114, 9, 1, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 112, 114, 111, 103, 114, 97, 109, 109, 105, 110, 103, 32, 108, 97, 110, 103, 117, 97, 103, 101, 115, 32, 119, 111, 114, 107, 46, 2, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 2, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 1, 0, 0, 0, 57, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 58, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 58, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 60, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 122, 1, 0, 0, 0, 61, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 61, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 1, 0, 0, 0, 66, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 67, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 67, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 69, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 70, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 70, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 1, 0, 0, 0, 72, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 1, 0, 0, 0, 73, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 73, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 95, 1, 79, 1, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 80, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 91, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 131, 1, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 135, 1, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 145, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 145, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 146, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 11, 0, 0, 0, 150, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 150, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 151, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 151, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 1, 0, 0, 0, 153, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 153, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 154, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 154, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 155, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 155, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 156, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 156, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 0, 1, 0, 0, 0, 161, 1, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 165, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 117, 110, 101, 115, 99, 97, 112, 101, 100, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 179, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 179, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 0, 1, 0, 0, 0, 185, 1, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 185, 1, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 185, 1, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 45, 105, 116, 101, 109, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 211, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 114, 97, 110, 103, 101, 3, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 219, 1, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 219, 1, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 7, 0, 0, 0, 243, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 243, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 244, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 10, 0, 0, 0, 247, 1, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 247, 1, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 0, 1, 0, 0, 0, 15, 2, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 19, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 105, 110, 103, 108, 101, 45, 99, 104, 97, 114, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 33, 2, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 33, 2, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 1, 57, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 58, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 71, 2, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 71, 2, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 71, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 45, 99, 104, 97, 114, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 2, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 88, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 98, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 98, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 98, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 11, 0, 0, 0, 103, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 103, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 104, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 104, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 105, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 105, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 106, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 106, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 1, 0, 0, 0, 109, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 109, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 0, 1, 0, 0, 0, 113, 2, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 111, 114, 109, 97, 108, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 126, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 0, 0, 0, 0, 0, 0, 0, 126, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 128, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 128, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 1, 0, 0, 0, 129, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 129, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 0, 1, 0, 0, 0, 135, 2, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 135, 2, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 135, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 144, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 144, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 144, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 145, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 145, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 1, 0, 0, 0, 147, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 1, 152, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 153, 2, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 169, 2, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 169, 2, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 169, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 177, 2, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 181, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 105, 110, 103, 108, 101, 3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 190, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 190, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 139, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 191, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 194, 2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 194, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 194, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 45, 99, 104, 97, 114, 7, 0, 0, 0, 207, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 207, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 208, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 39, 1, 212, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 213, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 224, 2, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 228, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 100, 111, 117, 98, 108, 101, 3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 237, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 237, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 238, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 242, 2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 242, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 242, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 45, 99, 104, 97, 114, 7, 0, 0, 0, 255, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 255, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 1, 5, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 236, 2, 0, 0, 0, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 236, 2, 0, 0, 0, 0, 0, 0, 166, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 32, 114, 101, 112, 114, 101, 115, 101, 110, 116, 32, 97, 32, 115, 101, 108, 101, 99, 116, 105, 111, 110, 32, 111, 102, 32, 116, 101, 114, 109, 105, 110, 97, 108, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 46, 32, 84, 104, 105, 115, 32, 105, 115, 32, 115, 105, 109, 105, 108, 97, 114, 32, 116, 111, 105, 3, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 103, 101, 120, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 101, 115, 46, 32, 67, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 101, 115, 32, 99, 97, 110, 32, 98, 101, 32, 105, 110, 118, 101, 114, 116, 101, 100, 32, 98, 121, 32, 115, 116, 97, 114, 116, 105, 110, 103, 32, 116, 104, 101, 109, 32, 119, 105, 116, 104, 32, 97, 32, 96, 94, 96, 46, 197, 3, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 91, 94, 92, 110, 93, 96, 32, 109, 101, 97, 110, 115, 32, 105, 116, 32, 109, 97, 116, 99, 104, 101, 115, 32, 97, 110, 121, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 116, 104, 97, 116, 32, 105, 115, 32, 110, 111, 116, 32, 97, 32, 110, 101, 119, 108, 105, 110, 101, 46, 20, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 24, 4, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 67, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 101, 115, 32, 99, 97, 110, 32, 99, 111, 110, 116, 97, 105, 110, 32, 97, 32, 114, 97, 110, 103, 101, 32, 111, 102, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 46, 32, 69, 105, 116, 104, 101, 114, 32, 98, 121, 32, 108, 105, 115, 116, 105, 110, 103, 32, 101, 97, 99, 104, 32, 105, 110, 100, 105, 118, 105, 100, 117, 97, 108, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 44, 32, 111, 114, 32, 117, 115, 105, 110, 103, 135, 4, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 32, 100, 97, 115, 104, 32, 40, 96, 45, 96, 41, 46, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 91, 97, 45, 122, 93, 96, 32, 109, 101, 97, 110, 115, 32, 97, 110, 121, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 105, 110, 32, 116, 104, 101, 32, 114, 97, 110, 103, 101, 32, 97, 32, 116, 104, 114, 111, 117, 103, 104, 32, 122, 32, 40, 105, 110, 99, 108, 117, 115, 105, 118, 101, 33, 41, 44, 233, 4, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 100, 32, 96, 91, 97, 98, 99, 93, 96, 32, 109, 101, 97, 110, 115, 32, 97, 110, 32, 97, 44, 32, 98, 32, 111, 114, 32, 99, 12, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 16, 5, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 78, 111, 116, 101, 32, 116, 104, 97, 116, 32, 116, 111, 32, 117, 115, 101, 32, 97, 32, 99, 108, 111, 115, 105, 110, 103, 32, 115, 113, 117, 97, 114, 101, 32, 98, 114, 97, 99, 107, 101, 116, 32, 119, 105, 116, 104, 105, 110, 32, 97, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 44, 32, 121, 111, 117, 32, 110, 101, 101, 100, 32, 116, 111, 32, 101, 115, 99, 97, 112, 101, 32, 105, 116, 46, 111, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 115, 5, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 91, 94, 92, 93, 93, 96, 32, 109, 101, 97, 110, 115, 32, 97, 110, 121, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 116, 104, 97, 116, 32, 105, 115, 110, 39, 116, 32, 97, 32, 115, 113, 117, 97, 114, 101, 32, 98, 114, 97, 99, 107, 101, 116, 46, 1, 0, 0, 0, 176, 5, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 176, 5, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 193, 5, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 197, 5, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 181, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 99, 108, 97, 115, 115, 4, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 206, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 206, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 207, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 91, 2, 0, 0, 0, 210, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 210, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 210, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 188, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 211, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 215, 5, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 215, 5, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 215, 5, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 45, 105, 116, 101, 109, 7, 0, 0, 0, 237, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 237, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 238, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 1, 242, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 243, 5, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 199, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 1, 6, 6, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 202, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 7, 6, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 0, 16, 6, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 204, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 28, 6, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 32, 6, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 206, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 111, 117, 98, 108, 101, 45, 101, 113, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 44, 6, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 208, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 44, 6, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 45, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 210, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 49, 6, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 50, 6, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 57, 6, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 58, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 214, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 59, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 215, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 60, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 61, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 62, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 63, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 219, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 64, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 65, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 221, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 66, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 222, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 67, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 68, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 69, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 225, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 70, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 226, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 71, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 72, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 73, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 74, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 1, 0, 0, 0, 75, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 76, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 77, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 233, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 78, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 234, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 79, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 235, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 80, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 81, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 82, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 83, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 84, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 85, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 86, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 87, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 243, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 88, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 89, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 245, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 90, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 246, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 91, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 247, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 92, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 248, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 93, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 94, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 95, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 96, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 1, 0, 0, 0, 97, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 98, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 99, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 100, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 101, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 102, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 103, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 104, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 105, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 106, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 107, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 108, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 109, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 110, 6, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 119, 6, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 6, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 116, 99, 104, 101, 115, 32, 97, 32, 112, 105, 101, 99, 101, 32, 111, 102, 32, 116, 101, 120, 116, 32, 101, 120, 97, 99, 116, 108, 121, 46, 32, 76, 97, 121, 111, 117, 116, 32, 105, 115, 32, 112, 97, 114, 115, 101, 100, 32, 119, 105, 116, 104, 105, 110, 32, 97, 32, 108, 105, 116, 101, 114, 97, 108, 46, 1, 0, 0, 0, 191, 6, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 195, 6, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 108, 105, 116, 101, 114, 97, 108, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 205, 6, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 16, 1, 0, 0, 0, 0, 0, 0, 205, 6, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 17, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 218, 6, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 18, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 218, 6, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 19, 1, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 89, 111, 117, 32, 99, 97, 110, 32, 117, 115, 101, 32, 112, 97, 114, 101, 110, 116, 104, 101, 115, 101, 115, 32, 116, 111, 32, 103, 114, 111, 117, 112, 32, 112, 97, 114, 116, 115, 32, 111, 102, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 1, 0, 0, 0, 21, 7, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 20, 1, 0, 0, 0, 0, 0, 0, 25, 7, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 21, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 112, 97, 114, 101, 110, 3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 33, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 23, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 33, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 34, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 25, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 40, 1, 0, 0, 0, 37, 7, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 26, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 37, 7, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 27, 1, 0, 0, 0, 0, 0, 0, 37, 7, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 28, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 7, 0, 0, 0, 49, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 30, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 49, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 31, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 50, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 0, 1, 0, 0, 0, 55, 7, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 33, 1, 0, 0, 0, 0, 0, 0, 59, 7, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 34, 1, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 108, 97, 98, 101, 108, 108, 101, 100, 3, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 70, 7, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 36, 1, 0, 0, 0, 0, 0, 0, 70, 7, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 37, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 7, 0, 0, 0, 81, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 39, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 81, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 40, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 82, 7, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 10, 0, 0, 0, 85, 7, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 43, 1, 0, 0, 0, 0, 0, 0, 85, 7, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 44, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 0, 0, 0, 0, 0, 96, 7, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 96, 7, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 46, 1, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 102, 101, 114, 101, 110, 99, 101, 32, 97, 110, 111, 116, 104, 101, 114, 32, 115, 111, 114, 116, 32, 119, 105, 116, 104, 105, 110, 32, 116, 104, 105, 115, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 46, 151, 7, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 47, 1, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 97, 116, 32, 115, 111, 114, 116, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 32, 105, 110, 32, 116, 104, 105, 115, 32, 112, 111, 115, 105, 116, 105, 111, 110, 32, 105, 110, 32, 116, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 46, 1, 0, 0, 0, 218, 7, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 48, 1, 0, 0, 0, 0, 0, 0, 222, 7, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 49, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 229, 7, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 51, 1, 0, 0, 0, 0, 0, 0, 229, 7, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 52, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 0, 0, 0, 0, 0, 246, 7, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 53, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 246, 7, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 91, 96, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 96, 93, 40, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 41, 32, 40, 114, 97, 110, 103, 101, 32, 111, 102, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 41, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 32, 104, 101, 114, 101, 46, 1, 0, 0, 0, 78, 8, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 82, 8, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 56, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 99, 108, 97, 115, 115, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 90, 8, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 58, 1, 0, 0, 0, 0, 0, 0, 90, 8, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 59, 1, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 0, 1, 0, 0, 0, 108, 8, 0, 0, 0, 0, 0, 0, 224, 5, 0, 0, 0, 0, 0, 0, 60, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 8, 0, 0, 0, 0, 0, 0, 224, 5, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 108, 8, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 62, 1, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 87, 105, 116, 104, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 44, 32, 121, 111, 117, 32, 99, 97, 110, 32, 103, 105, 118, 101, 32, 116, 104, 101, 32, 115, 121, 110, 116, 97, 120, 32, 114, 117, 108, 101, 115, 32, 111, 102, 32, 97, 32, 115, 105, 110, 103, 108, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 46, 185, 8, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 32, 99, 97, 110, 32, 98, 101, 32, 110, 101, 115, 116, 101, 100, 32, 97, 110, 100, 32, 99, 111, 109, 98, 105, 110, 101, 100, 46, 1, 0, 0, 0, 229, 8, 0, 0, 0, 0, 0, 0, 103, 5, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 229, 8, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 65, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 241, 8, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 66, 1, 0, 0, 0, 0, 0, 0, 245, 8, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 67, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 111, 117, 98, 108, 101, 45, 101, 113, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 69, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 70, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 71, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 6, 9, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 72, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 7, 9, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 73, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 14, 9, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 15, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 75, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 16, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 76, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 17, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 77, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 18, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 78, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 19, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 79, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 20, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 80, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 21, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 81, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 22, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 82, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 23, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 83, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 24, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 84, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 25, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 85, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 26, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 86, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 27, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 87, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 28, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 88, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 29, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 89, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 30, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 90, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 31, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 91, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 1, 0, 0, 0, 32, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 33, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 34, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 94, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 35, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 95, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 36, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 37, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 38, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 39, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 40, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 41, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 42, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 43, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 44, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 45, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 46, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 106, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 47, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 48, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 49, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 50, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 51, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 52, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 53, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 113, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 1, 0, 0, 0, 54, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 55, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 56, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 57, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 58, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 118, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 59, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 60, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 120, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 61, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 62, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 122, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 63, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 64, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 124, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 65, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 66, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 126, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 67, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 127, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 76, 9, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 128, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 76, 9, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 129, 1, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 112, 101, 97, 116, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 122, 101, 114, 111, 32, 111, 114, 32, 109, 111, 114, 101, 32, 116, 105, 109, 101, 115, 126, 9, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 130, 1, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 32, 116, 111, 32, 96, 60, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 62, 32, 123, 48, 44, 125, 96, 1, 0, 0, 0, 164, 9, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 131, 1, 0, 0, 0, 0, 0, 0, 168, 9, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 132, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 116, 97, 114, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 175, 9, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 134, 1, 0, 0, 0, 0, 0, 0, 175, 9, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 135, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 7, 0, 0, 0, 180, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 137, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 180, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 138, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 181, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 139, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 190, 9, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 140, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 190, 9, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 141, 1, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 112, 101, 97, 116, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 111, 110, 101, 32, 111, 114, 32, 109, 111, 114, 101, 32, 116, 105, 109, 101, 115, 239, 9, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 142, 1, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 32, 116, 111, 32, 96, 60, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 62, 32, 123, 49, 44, 125, 96, 1, 0, 0, 0, 21, 10, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 143, 1, 0, 0, 0, 0, 0, 0, 25, 10, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 144, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 112, 108, 117, 115, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 32, 10, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 146, 1, 0, 0, 0, 0, 0, 0, 32, 10, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 147, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 7, 0, 0, 0, 37, 10, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 149, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 37, 10, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 150, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 38, 10, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 151, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 47, 10, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 152, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 47, 10, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 153, 1, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 112, 116, 105, 111, 110, 97, 108, 108, 121, 32, 104, 97, 118, 101, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 46, 88, 10, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 154, 1, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 32, 116, 111, 32, 96, 60, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 62, 32, 123, 48, 44, 49, 125, 96, 1, 0, 0, 0, 127, 10, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 155, 1, 0, 0, 0, 0, 0, 0, 131, 10, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 156, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 109, 97, 121, 98, 101, 2, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 139, 10, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 158, 1, 0, 0, 0, 0, 0, 0, 139, 10, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 159, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 7, 0, 0, 0, 144, 10, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 161, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 144, 10, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 162, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 145, 10, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 163, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 154, 10, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 164, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 154, 10, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 165, 1, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 120, 97, 99, 116, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 46, 32, 84, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 105, 115, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 97, 110, 32, 101, 120, 97, 99, 116, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 116, 105, 109, 101, 115, 46, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 244, 10, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 166, 1, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 111, 32, 114, 97, 110, 103, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 32, 119, 105, 116, 104, 32, 97, 110, 32, 101, 113, 117, 97, 108, 32, 108, 111, 119, 101, 114, 32, 97, 110, 100, 32, 117, 112, 112, 101, 114, 32, 98, 111, 117, 110, 100, 46, 1, 0, 0, 0, 50, 11, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 167, 1, 0, 0, 0, 0, 0, 0, 54, 11, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 168, 1, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 101, 120, 97, 99, 116, 4, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 69, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 170, 1, 0, 0, 0, 0, 0, 0, 69, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 171, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 7, 0, 0, 0, 74, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 173, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 74, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 174, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 75, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 175, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 10, 0, 0, 0, 78, 11, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 78, 11, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 178, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 7, 0, 0, 0, 85, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 180, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 85, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 181, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 86, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 182, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 94, 11, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 94, 11, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 184, 1, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 97, 110, 103, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 46, 32, 84, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 109, 97, 121, 32, 98, 101, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 97, 110, 121, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 116, 105, 109, 101, 115, 44, 32, 119, 105, 116, 104, 105, 110, 32, 116, 104, 101, 32, 114, 97, 110, 103, 101, 46, 191, 11, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 185, 1, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 66, 111, 116, 104, 32, 98, 111, 117, 110, 100, 115, 32, 97, 114, 101, 32, 105, 110, 99, 108, 117, 115, 105, 118, 101, 46, 1, 0, 0, 0, 222, 11, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 226, 11, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 187, 1, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 114, 97, 110, 103, 101, 6, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 241, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 189, 1, 0, 0, 0, 0, 0, 0, 241, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 190, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 7, 0, 0, 0, 246, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 246, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 193, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 247, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 194, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 10, 0, 0, 0, 250, 11, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 196, 1, 0, 0, 0, 0, 0, 0, 250, 11, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 197, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 7, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 199, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 200, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 2, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 201, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 10, 0, 0, 0, 5, 12, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 203, 1, 0, 0, 0, 0, 0, 0, 5, 12, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 204, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 7, 0, 0, 0, 12, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 206, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 12, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 13, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 208, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 21, 12, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 209, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 21, 12, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 210, 1, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 97, 110, 103, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 44, 32, 119, 105, 116, 104, 111, 117, 116, 32, 117, 112, 112, 101, 114, 32, 98, 111, 117, 110, 100, 32, 40, 111, 114, 32, 97, 110, 32, 105, 110, 102, 105, 110, 105, 116, 101, 32, 109, 97, 120, 105, 109, 117, 109, 41, 1, 0, 0, 0, 89, 12, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 211, 1, 0, 0, 0, 0, 0, 0, 93, 12, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 212, 1, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 108, 111, 119, 101, 114, 6, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 108, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 214, 1, 0, 0, 0, 0, 0, 0, 108, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 215, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 7, 0, 0, 0, 113, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 217, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 113, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 218, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 114, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 219, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 10, 0, 0, 0, 117, 12, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 221, 1, 0, 0, 0, 0, 0, 0, 117, 12, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 222, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 7, 0, 0, 0, 124, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 224, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 124, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 225, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 125, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 226, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 7, 0, 0, 0, 128, 12, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 228, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 128, 12, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 229, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 129, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 230, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 130, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 231, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 131, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 232, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 7, 0, 0, 0, 134, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 234, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 134, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 235, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 135, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 236, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 144, 12, 0, 0, 0, 0, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 237, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 144, 12, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 238, 1, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 68, 101, 108, 105, 109, 105, 116, 101, 100, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 32, 83, 97, 121, 115, 32, 116, 104, 97, 116, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 114, 101, 112, 101, 97, 116, 101, 100, 108, 121, 32, 112, 97, 114, 115, 101, 100, 44, 230, 12, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 239, 1, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 98, 117, 116, 32, 98, 101, 116, 119, 101, 101, 110, 32, 116, 119, 111, 32, 112, 97, 114, 115, 101, 115, 44, 32, 97, 32, 100, 101, 108, 105, 109, 105, 116, 101, 114, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 32, 116, 111, 111, 46, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 99, 111, 109, 109, 97, 32, 115, 101, 112, 101, 114, 97, 116, 101, 100, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 82, 13, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 101, 32, 102, 105, 110, 97, 108, 32, 116, 114, 97, 105, 108, 105, 110, 103, 32, 107, 101, 121, 119, 111, 114, 100, 32, 101, 110, 97, 98, 108, 101, 115, 32, 97, 32, 116, 114, 97, 105, 108, 105, 110, 103, 32, 115, 101, 112, 97, 114, 97, 116, 111, 114, 32, 97, 102, 116, 101, 114, 32, 116, 104, 101, 32, 115, 101, 113, 117, 101, 110, 99, 101, 46, 32, 73, 102, 32, 110, 111, 116, 32, 112, 114, 101, 115, 101, 110, 116, 44, 32, 110, 111, 32, 116, 114, 97, 105, 108, 105, 110, 103, 194, 13, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 241, 1, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 115, 101, 112, 97, 114, 97, 116, 111, 114, 32, 105, 115, 32, 97, 108, 108, 111, 119, 101, 100, 46, 1, 0, 0, 0, 220, 13, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 242, 1, 0, 0, 0, 0, 0, 0, 224, 13, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 243, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 101, 108, 105, 109, 105, 116, 101, 100, 9, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 236, 13, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 245, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 236, 13, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 246, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 237, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 247, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 238, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 248, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 239, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 249, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 240, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 250, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 241, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 251, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 1, 0, 0, 0, 242, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 243, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 253, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 244, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 254, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 245, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 255, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 7, 0, 0, 0, 248, 13, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 248, 13, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 2, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 249, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 40, 10, 0, 0, 0, 252, 13, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 5, 2, 0, 0, 0, 0, 0, 0, 252, 13, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 7, 0, 0, 0, 7, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 7, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 9, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 8, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 10, 0, 0, 0, 11, 14, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 12, 2, 0, 0, 0, 0, 0, 0, 11, 14, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 7, 0, 0, 0, 22, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 15, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 22, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 16, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 23, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 17, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 10, 0, 0, 0, 26, 14, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 19, 2, 0, 0, 0, 0, 0, 0, 26, 14, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 20, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 100, 101, 108, 105, 109, 105, 116, 101, 100, 45, 98, 111, 117, 110, 100, 2, 0, 0, 0, 42, 14, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 21, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 42, 14, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 22, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 43, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 43, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 25, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 44, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 26, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 7, 0, 0, 0, 47, 14, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 28, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 47, 14, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 29, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 48, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 30, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 49, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 31, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 50, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 51, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 33, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 52, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 53, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 35, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 54, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 36, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 55, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 37, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 7, 0, 0, 0, 60, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 39, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 60, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 40, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 61, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 0, 2, 0, 0, 0, 66, 14, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 42, 2, 0, 0, 0, 0, 0, 0, 70, 14, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 43, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 0, 1, 0, 0, 0, 77, 14, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 0, 44, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 14, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 77, 14, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 46, 2, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 100, 101, 108, 105, 109, 105, 116, 101, 100, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 99, 97, 110, 32, 98, 101, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 106, 117, 115, 116, 32, 108, 105, 107, 101, 32, 110, 111, 114, 109, 97, 108, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 161, 14, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 2, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 111, 32, 100, 101, 110, 111, 116, 101, 32, 116, 104, 105, 115, 44, 32, 121, 111, 117, 32, 99, 97, 110, 32, 117, 115, 101, 32, 97, 32, 100, 101, 108, 105, 109, 105, 116, 97, 116, 105, 111, 110, 32, 98, 111, 117, 110, 100, 46, 1, 0, 0, 0, 215, 14, 0, 0, 0, 0, 0, 0, 102, 1, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 0, 0, 0, 215, 14, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 49, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 100, 101, 108, 105, 109, 105, 116, 101, 100, 45, 98, 111, 117, 110, 100, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 14, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 50, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 236, 14, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 51, 2, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 87, 105, 116, 104, 105, 110, 32, 97, 32, 114, 97, 110, 103, 101, 32, 111, 114, 32, 112, 111, 115, 115, 105, 98, 108, 101, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 46, 1, 0, 0, 0, 24, 15, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 52, 2, 0, 0, 0, 0, 0, 0, 28, 15, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 53, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 45, 110, 117, 109, 3, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 38, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 55, 2, 0, 0, 0, 0, 0, 0, 38, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 56, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 7, 0, 0, 0, 45, 15, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 58, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 45, 15, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 46, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 60, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 10, 0, 0, 0, 49, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 62, 2, 0, 0, 0, 0, 0, 0, 49, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 63, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 0, 0, 0, 0, 0, 61, 15, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 64, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 15, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 116, 32, 108, 101, 97, 115, 116, 32, 115, 111, 109, 101, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 44, 32, 98, 117, 116, 32, 110, 111, 32, 117, 112, 112, 101, 114, 32, 98, 111, 117, 110, 100, 46, 1, 0, 0, 0, 122, 15, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 66, 2, 0, 0, 0, 0, 0, 0, 126, 15, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 67, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 45, 105, 110, 102, 3, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 136, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 69, 2, 0, 0, 0, 0, 0, 0, 136, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 70, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 7, 0, 0, 0, 143, 15, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 72, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 143, 15, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 73, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 144, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 74, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 7, 0, 0, 0, 147, 15, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 76, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 147, 15, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 77, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 148, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 78, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 149, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 79, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 150, 15, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 80, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 159, 15, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 81, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 159, 15, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 82, 2, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 120, 97, 99, 116, 108, 121, 32, 116, 104, 105, 115, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 46, 1, 0, 0, 0, 199, 15, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 83, 2, 0, 0, 0, 0, 0, 0, 203, 15, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 84, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 1, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 209, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 86, 2, 0, 0, 0, 0, 0, 0, 209, 15, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 87, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 0, 1, 0, 0, 0, 218, 15, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 88, 2, 0, 0, 0, 0, 0, 0, 255, 15, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 89, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 116, 97, 114, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 6, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 91, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 92, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 7, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 16, 16, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 94, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 16, 16, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 95, 2, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 110, 101, 32, 111, 114, 32, 109, 111, 114, 101, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 46, 1, 0, 0, 0, 45, 16, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 0, 0, 0, 49, 16, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 112, 108, 117, 115, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 56, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 56, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 57, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 43, 0, 1, 0, 0, 0, 62, 16, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 102, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 16, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 103, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 62, 16, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 115, 32, 97, 114, 101, 32, 116, 97, 103, 115, 32, 116, 104, 97, 116, 32, 109, 111, 100, 105, 102, 121, 32, 97, 32, 115, 112, 101, 99, 105, 102, 105, 99, 32, 115, 111, 114, 116, 32, 111, 114, 32, 109, 111, 114, 101, 32, 111, 102, 116, 101, 110, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 46, 2, 0, 0, 0, 142, 16, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 0, 0, 0, 142, 16, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 106, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 160, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 160, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 161, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 6, 0, 0, 0, 164, 16, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 174, 16, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 113, 2, 0, 0, 0, 0, 0, 0, 174, 16, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 7, 0, 0, 0, 186, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 186, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 187, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 118, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 4, 0, 0, 0, 191, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 2, 0, 0, 0, 0, 0, 0, 1, 7, 0, 0, 0, 204, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 204, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 122, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 205, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 1, 0, 0, 0, 209, 16, 0, 0, 0, 0, 0, 0, 5, 9, 0, 0, 0, 0, 0, 0, 124, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 209, 16, 0, 0, 0, 0, 0, 0, 5, 9, 0, 0, 0, 0, 0, 0, 125, 2, 0, 0, 0, 0, 0, 0, 209, 16, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 126, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 225, 16, 0, 0, 0, 0, 0, 0, 35, 2, 0, 0, 0, 0, 0, 0, 127, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 225, 16, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 128, 2, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 114, 107, 32, 97, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 97, 115, 32, 98, 101, 105, 110, 103, 32, 97, 32, 109, 97, 112, 112, 105, 110, 103, 32, 102, 114, 111, 109, 32, 115, 111, 114, 116, 32, 120, 32, 116, 111, 32, 115, 111, 114, 116, 32, 120, 46, 38, 17, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 129, 2, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 32, 101, 120, 97, 109, 112, 108, 101, 32, 105, 115, 32, 97, 32, 112, 97, 114, 101, 110, 116, 104, 101, 115, 105, 115, 32, 114, 117, 108, 101, 58, 80, 17, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 130, 2, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 96, 96, 108, 119, 98, 44, 110, 111, 95, 114, 117, 110, 102, 17, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 131, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 101, 120, 112, 114, 58, 116, 17, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 132, 2, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 32, 32, 32, 32, 112, 97, 114, 101, 110, 32, 61, 32, 34, 40, 34, 32, 101, 120, 112, 114, 32, 34, 41, 34, 149, 17, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 133, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 96, 96, 161, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 134, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 169, 17, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 135, 2, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 110, 32, 116, 104, 97, 116, 32, 99, 97, 115, 101, 32, 121, 111, 117, 32, 100, 111, 110, 39, 116, 32, 119, 97, 110, 116, 32, 97, 32, 118, 97, 114, 105, 97, 110, 116, 32, 105, 110, 32, 116, 104, 101, 32, 101, 120, 112, 114, 32, 114, 117, 108, 101, 32, 116, 104, 97, 116, 39, 115, 32, 99, 97, 108, 108, 101, 100, 32, 34, 112, 97, 114, 101, 110, 34, 46, 255, 17, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 136, 2, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 110, 115, 116, 101, 97, 100, 44, 32, 98, 121, 32, 97, 100, 100, 105, 110, 103, 32, 116, 104, 101, 32, 96, 105, 110, 106, 101, 99, 116, 105, 111, 110, 96, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 121, 111, 117, 32, 116, 101, 108, 108, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 32, 116, 104, 97, 116, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 32, 105, 115, 32, 112, 117, 114, 101, 108, 121, 32, 116, 111, 32, 99, 114, 101, 97, 116, 101, 32, 97, 32, 110, 101, 119, 32, 112, 114, 105, 111, 114, 105, 116, 121, 32, 108, 101, 118, 101, 108, 44, 130, 18, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 137, 2, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 98, 117, 116, 32, 116, 111, 32, 117, 115, 101, 32, 116, 104, 101, 32, 105, 110, 110, 101, 114, 32, 101, 120, 112, 114, 32, 97, 115, 32, 116, 104, 101, 32, 114, 101, 115, 117, 108, 116, 32, 111, 102, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 46, 32, 84, 104, 117, 115, 32, 116, 104, 101, 114, 101, 32, 119, 105, 108, 108, 32, 98, 101, 32, 110, 111, 32, 114, 117, 108, 101, 32, 99, 97, 108, 108, 101, 100, 32, 34, 112, 97, 114, 101, 110, 34, 46, 1, 0, 0, 0, 231, 18, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 138, 2, 0, 0, 0, 0, 0, 0, 235, 18, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 139, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 105, 110, 106, 101, 99, 116, 105, 111, 110, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 247, 18, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 141, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 247, 18, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 142, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 248, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 143, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 249, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 144, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 250, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 145, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 106, 1, 0, 0, 0, 251, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 146, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 252, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 147, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 253, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 148, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 254, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 149, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 255, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 150, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 151, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 9, 19, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 152, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 9, 19, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 153, 2, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 100, 105, 115, 97, 98, 108, 101, 32, 112, 114, 101, 116, 116, 121, 32, 112, 114, 105, 110, 116, 105, 110, 103, 46, 32, 68, 111, 101, 115, 110, 39, 116, 32, 119, 111, 114, 107, 32, 119, 101, 108, 108, 32, 97, 110, 121, 119, 97, 121, 32, 115, 111, 32, 100, 111, 110, 39, 116, 32, 98, 111, 116, 104, 101, 114, 32, 119, 105, 116, 104, 32, 116, 104, 105, 115, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 1, 0, 0, 0, 100, 19, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 154, 2, 0, 0, 0, 0, 0, 0, 104, 19, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 155, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 110, 111, 45, 112, 114, 101, 116, 116, 121, 45, 112, 114, 105, 110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 122, 19, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 157, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 122, 19, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 158, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 123, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 159, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 124, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 160, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 125, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 161, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 126, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 162, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 1, 0, 0, 0, 127, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 163, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 128, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 164, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 129, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 165, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 130, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 166, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 131, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 167, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 132, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 168, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 133, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 169, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 1, 0, 0, 0, 134, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 170, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 135, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 171, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 136, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 172, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 137, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 173, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 146, 19, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 174, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 146, 19, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 175, 2, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 109, 97, 114, 107, 32, 97, 32, 114, 117, 108, 101, 32, 116, 111, 32, 97, 112, 112, 101, 97, 114, 32, 97, 115, 32, 106, 117, 115, 116, 32, 97, 32, 115, 116, 114, 105, 110, 103, 32, 105, 110, 32, 116, 104, 101, 32, 65, 83, 84, 46, 32, 87, 104, 97, 116, 101, 118, 101, 114, 32, 115, 116, 114, 117, 99, 116, 117, 114, 101, 32, 105, 115, 32, 102, 111, 117, 110, 100, 32, 119, 105, 116, 104, 105, 110, 44, 32, 116, 104, 114, 111, 119, 32, 105, 116, 32, 97, 119, 97, 121, 32, 97, 110, 100, 32, 106, 117, 115, 116, 32, 115, 116, 111, 114, 101, 14, 20, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 176, 2, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 119, 104, 97, 116, 101, 118, 101, 114, 32, 119, 97, 115, 32, 112, 97, 114, 115, 101, 100, 46, 43, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 177, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 51, 20, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 178, 2, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 78, 111, 116, 101, 32, 116, 104, 97, 116, 32, 97, 110, 121, 32, 65, 83, 84, 32, 110, 111, 100, 101, 32, 104, 97, 115, 32, 116, 104, 101, 32, 46, 97, 115, 95, 115, 116, 114, 40, 41, 32, 109, 101, 116, 104, 111, 100, 32, 116, 111, 32, 114, 101, 113, 117, 101, 115, 116, 32, 116, 104, 105, 115, 32, 115, 116, 114, 105, 110, 103, 32, 114, 101, 112, 114, 101, 115, 101, 110, 116, 97, 116, 105, 111, 110, 32, 111, 102, 32, 116, 104, 101, 32, 110, 111, 100, 101, 46, 32, 70, 111, 114, 162, 20, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 179, 2, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 115, 105, 110, 103, 108, 101, 45, 115, 116, 114, 105, 110, 103, 32, 114, 117, 108, 101, 115, 32, 116, 104, 105, 115, 32, 105, 115, 32, 115, 105, 109, 112, 108, 121, 32, 116, 104, 101, 32, 100, 101, 102, 97, 117, 108, 116, 46, 1, 0, 0, 0, 214, 20, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 180, 2, 0, 0, 0, 0, 0, 0, 218, 20, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 181, 2, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 115, 105, 110, 103, 108, 101, 45, 115, 116, 114, 105, 110, 103, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 234, 20, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 234, 20, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 184, 2, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 235, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 185, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 236, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 186, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 237, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 187, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 238, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 188, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 0, 239, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 189, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 240, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 190, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 241, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 191, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 242, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 192, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 243, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 193, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 244, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 194, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 245, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 195, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 246, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 196, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 247, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 197, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 198, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 199, 2, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 100, 111, 110, 39, 116, 32, 97, 99, 99, 101, 112, 116, 32, 97, 110, 121, 32, 108, 97, 121, 111, 117, 116, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 32, 119, 104, 105, 108, 101, 32, 112, 97, 114, 115, 105, 110, 103, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 1, 0, 0, 0, 63, 21, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 200, 2, 0, 0, 0, 0, 0, 0, 67, 21, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 201, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 110, 111, 45, 108, 97, 121, 111, 117, 116, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 79, 21, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 203, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 79, 21, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 204, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 80, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 205, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 81, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 206, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 82, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 207, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 83, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 208, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 84, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 209, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 85, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 210, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 86, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 211, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 87, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 212, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 88, 21, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 213, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 97, 21, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 214, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 97, 21, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 215, 2, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 115, 111, 114, 116, 115, 46, 32, 84, 104, 105, 115, 32, 115, 111, 114, 116, 32, 119, 105, 108, 108, 32, 110, 111, 116, 32, 97, 112, 112, 101, 97, 114, 32, 105, 110, 32, 97, 110, 121, 32, 111, 102, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 105, 116, 39, 115, 32, 117, 115, 101, 100, 32, 105, 110, 46, 194, 21, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 117, 115, 101, 102, 117, 108, 32, 102, 111, 114, 32, 102, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 32, 116, 104, 101, 32, 91, 96, 110, 101, 119, 108, 105, 110, 101, 96, 93, 32, 114, 117, 108, 101, 32, 105, 110, 32, 116, 104, 105, 115, 32, 102, 105, 108, 101, 46, 1, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 217, 2, 0, 0, 0, 0, 0, 0, 4, 22, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 218, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 104, 105, 100, 100, 101, 110, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 13, 22, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 220, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 13, 22, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 221, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 14, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 222, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 15, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 223, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 16, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 224, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 17, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 225, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 18, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 226, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 19, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 227, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 28, 22, 0, 0, 0, 0, 0, 0, 87, 2, 0, 0, 0, 0, 0, 0, 228, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 28, 22, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 229, 2, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 105, 102, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 32, 109, 97, 110, 97, 103, 101, 115, 32, 116, 111, 32, 112, 97, 114, 115, 101, 44, 32, 100, 105, 115, 112, 108, 97, 121, 32, 97, 110, 32, 101, 114, 114, 111, 114, 32, 119, 105, 116, 104, 32, 116, 104, 101, 32, 97, 115, 115, 111, 99, 105, 97, 116, 101, 100, 32, 109, 101, 115, 115, 97, 103, 101, 112, 22, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 230, 2, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 105, 115, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 99, 97, 110, 32, 98, 101, 32, 112, 108, 97, 99, 101, 100, 32, 111, 110, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 40, 116, 104, 101, 115, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 116, 104, 101, 110, 32, 119, 111, 110, 39, 116, 32, 97, 99, 116, 117, 97, 108, 108, 121, 32, 101, 120, 105, 115, 116, 32, 105, 110, 32, 116, 104, 101, 32, 65, 83, 84, 44, 224, 22, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 231, 2, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 105, 102, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 105, 115, 32, 99, 104, 111, 115, 101, 110, 32, 97, 110, 32, 101, 114, 114, 111, 114, 32, 105, 115, 32, 101, 109, 105, 116, 116, 101, 100, 41, 44, 32, 111, 114, 32, 111, 110, 32, 115, 111, 114, 116, 115, 46, 32, 79, 110, 32, 97, 32, 115, 111, 114, 116, 32, 116, 104, 101, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 65, 23, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 232, 2, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 104, 97, 115, 32, 97, 32, 115, 108, 105, 103, 104, 116, 108, 121, 32, 100, 105, 102, 102, 101, 114, 101, 110, 116, 32, 101, 102, 102, 101, 99, 116, 46, 32, 73, 102, 32, 110, 111, 110, 101, 32, 111, 102, 32, 116, 104, 101, 32, 118, 97, 114, 105, 97, 110, 116, 115, 32, 105, 110, 32, 116, 104, 101, 32, 115, 111, 114, 116, 32, 109, 97, 110, 97, 103, 101, 100, 32, 116, 111, 32, 112, 97, 114, 115, 101, 159, 23, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 233, 2, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 104, 101, 110, 32, 97, 108, 111, 110, 103, 115, 105, 100, 101, 32, 97, 110, 32, 34, 101, 120, 112, 101, 99, 116, 101, 100, 32, 46, 46, 46, 34, 44, 32, 116, 104, 97, 116, 32, 109, 101, 115, 115, 97, 103, 101, 32, 119, 105, 108, 108, 32, 98, 101, 32, 100, 105, 115, 112, 108, 97, 121, 101, 100, 32, 97, 115, 32, 119, 101, 108, 108, 46, 241, 23, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 234, 2, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 102, 32, 116, 104, 105, 115, 32, 115, 111, 114, 116, 32, 119, 97, 115, 32, 116, 104, 101, 32, 111, 110, 108, 121, 32, 112, 111, 115, 115, 105, 98, 105, 108, 105, 116, 121, 32, 97, 116, 32, 97, 32, 99, 101, 114, 116, 97, 105, 110, 32, 112, 111, 105, 110, 116, 44, 32, 111, 110, 108, 121, 32, 116, 104, 101, 32, 109, 101, 115, 115, 97, 103, 101, 32, 119, 105, 108, 108, 32, 98, 101, 32, 100, 105, 115, 112, 108, 97, 121, 101, 100, 46, 1, 0, 0, 0, 83, 24, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 235, 2, 0, 0, 0, 0, 0, 0, 87, 24, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 236, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 101, 114, 114, 111, 114, 3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 95, 24, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 238, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 24, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 239, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 96, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 240, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 97, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 241, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 98, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 242, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 99, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 243, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 100, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 244, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 7, 0, 0, 0, 103, 24, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 246, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 103, 24, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 247, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 104, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 248, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 10, 0, 0, 0, 107, 24, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 250, 2, 0, 0, 0, 0, 0, 0, 107, 24, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 251, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 120, 24, 0, 0, 0, 0, 0, 0, 94, 1, 0, 0, 0, 0, 0, 0, 252, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 120, 24, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 253, 2, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 107, 101, 115, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 111, 102, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 32, 103, 101, 110, 101, 114, 97, 116, 101, 32, 97, 115, 32, 112, 97, 114, 116, 32, 111, 102, 32, 97, 110, 111, 116, 104, 101, 114, 32, 114, 117, 108, 101, 46, 194, 24, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 254, 2, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 105, 115, 32, 104, 97, 115, 32, 111, 110, 101, 32, 109, 97, 106, 111, 114, 32, 114, 101, 113, 117, 105, 114, 101, 109, 101, 110, 116, 46, 32, 73, 102, 32, 97, 32, 105, 115, 32, 112, 97, 114, 116, 45, 111, 102, 32, 98, 32, 116, 104, 101, 110, 1, 25, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 255, 2, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 98, 32, 109, 117, 115, 116, 32, 104, 97, 118, 101, 32, 97, 32, 114, 117, 108, 101, 32, 108, 105, 107, 101, 32, 96, 97, 61, 97, 59, 96, 32, 40, 97, 108, 115, 111, 32, 119, 114, 105, 116, 116, 101, 110, 32, 97, 115, 32, 106, 117, 115, 116, 32, 96, 97, 59, 96, 41, 32, 116, 111, 32, 97, 108, 108, 111, 119, 77, 25, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 121, 32, 97, 32, 116, 111, 32, 97, 112, 112, 101, 97, 114, 32, 105, 110, 32, 98, 106, 25, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 114, 25, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 110, 106, 101, 99, 116, 105, 111, 110, 115, 32, 111, 110, 32, 114, 117, 108, 101, 32, 97, 32, 98, 101, 99, 111, 109, 101, 32, 105, 110, 106, 101, 99, 116, 105, 111, 110, 115, 32, 105, 110, 116, 111, 32, 114, 117, 108, 101, 32, 98, 32, 116, 111, 111, 46, 1, 0, 0, 0, 174, 25, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 178, 25, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 112, 97, 114, 116, 45, 111, 102, 3, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 188, 25, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 188, 25, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 189, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 1, 0, 0, 0, 190, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 9, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 191, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 10, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 192, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 11, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 193, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 12, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 194, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 13, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 195, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 14, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 7, 0, 0, 0, 198, 25, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 16, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 198, 25, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 17, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 199, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 10, 0, 0, 0, 202, 25, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 20, 3, 0, 0, 0, 0, 0, 0, 202, 25, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 21, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 0, 1, 0, 0, 0, 215, 25, 0, 0, 0, 0, 0, 0, 168, 2, 0, 0, 0, 0, 0, 0, 22, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 215, 25, 0, 0, 0, 0, 0, 0, 168, 2, 0, 0, 0, 0, 0, 0, 23, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 215, 25, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 24, 3, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 91, 96, 115, 111, 114, 116, 96, 93, 32, 99, 111, 110, 115, 105, 115, 116, 115, 32, 111, 102, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 46, 32, 65, 32, 115, 111, 114, 116, 32, 119, 105, 108, 108, 32, 116, 114, 121, 32, 101, 97, 99, 104, 32, 111, 102, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 41, 26, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 25, 3, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 102, 114, 111, 109, 32, 116, 111, 112, 32, 116, 111, 32, 98, 111, 116, 116, 111, 109, 44, 32, 97, 110, 100, 32, 117, 115, 101, 32, 116, 104, 101, 32, 102, 105, 114, 115, 116, 32, 111, 110, 101, 32, 116, 104, 97, 116, 32, 115, 117, 99, 99, 101, 115, 115, 102, 117, 108, 108, 121, 32, 112, 97, 114, 115, 101, 115, 32, 116, 104, 101, 32, 105, 110, 112, 117, 116, 32, 115, 116, 114, 105, 110, 103, 46, 130, 26, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 26, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 134, 26, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 27, 3, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 99, 111, 110, 115, 105, 115, 116, 115, 32, 111, 102, 32, 97, 32, 110, 97, 109, 101, 44, 32, 102, 111, 108, 108, 111, 119, 101, 100, 32, 98, 121, 32, 97, 110, 32, 91, 96, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 96, 93, 202, 26, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 28, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 206, 26, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 29, 3, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 67, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 99, 97, 110, 32, 104, 97, 118, 101, 32, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 115, 32, 117, 115, 105, 110, 103, 32, 116, 114, 105, 112, 108, 101, 32, 115, 108, 97, 115, 104, 101, 115, 46, 1, 0, 0, 0, 11, 27, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 0, 0, 0, 30, 3, 0, 0, 0, 0, 0, 0, 11, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 31, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 1, 24, 27, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 32, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 25, 27, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 33, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 32, 27, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 34, 3, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 33, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 35, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 1, 0, 0, 0, 34, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 36, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 35, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 37, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 0, 36, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 38, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 37, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 39, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 38, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 40, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 39, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 40, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 42, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 41, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 43, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 42, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 43, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 44, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 46, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 45, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 46, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 47, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 49, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 48, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 50, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 49, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 51, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 50, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 52, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 51, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 53, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 52, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 54, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 53, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 55, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 54, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 56, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 55, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 56, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 57, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 58, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 60, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 59, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 60, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 62, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 61, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 63, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 62, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 64, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 63, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 64, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 66, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 65, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 67, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 1, 0, 0, 0, 66, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 68, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 67, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 69, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 68, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 70, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 69, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 71, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 70, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 72, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 71, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 73, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 72, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 74, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 73, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 75, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 1, 0, 0, 0, 74, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 76, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 75, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 77, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 76, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 78, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 77, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 79, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 78, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 80, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 79, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 81, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 80, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 82, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 81, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 83, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 82, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 84, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 83, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 85, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 84, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 86, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 85, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 87, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 86, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 88, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 87, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 89, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 88, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 90, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 89, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 91, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 90, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 91, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 92, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 94, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 1, 0, 0, 0, 93, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 95, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 94, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 95, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 96, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 97, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 52, 1, 0, 0, 0, 98, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 99, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 100, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 1, 0, 0, 0, 101, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 102, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 1, 0, 0, 0, 103, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 104, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 106, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 105, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 107, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 46, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 109, 27, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 108, 3, 0, 0, 0, 0, 0, 0, 113, 27, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 109, 3, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 45, 100, 111, 99, 117, 109, 101, 110, 116, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 138, 27, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 110, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 138, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 111, 3, 0, 0, 0, 0, 0, 0, 138, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 112, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 10, 0, 0, 0, 151, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 114, 3, 0, 0, 0, 0, 0, 0, 151, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 0, 1, 0, 0, 0, 164, 27, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 116, 3, 0, 0, 0, 0, 0, 0, 168, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 117, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 7, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 182, 27, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 119, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 182, 27, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 120, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 183, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 184, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 122, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 185, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 186, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 124, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 10, 0, 0, 0, 189, 27, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 126, 3, 0, 0, 0, 0, 0, 0, 189, 27, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 127, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 7, 0, 0, 0, 200, 27, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 129, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 200, 27, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 130, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 201, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 131, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 204, 27, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 132, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 204, 27, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 133, 3, 0, 0, 0, 0, 0, 0, 204, 27, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 134, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 7, 0, 0, 0, 216, 27, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 136, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 216, 27, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 137, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 217, 27, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 138, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 220, 27, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 139, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 220, 27, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 140, 3, 0, 0, 0, 0, 0, 0, 220, 27, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 141, 3, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 10, 0, 0, 0, 237, 27, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 143, 3, 0, 0, 0, 0, 0, 0, 237, 27, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 144, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 0, 0, 0, 0, 250, 27, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 145, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 250, 27, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 146, 3, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 117, 115, 105, 110, 103, 32, 96, 116, 101, 115, 116, 59, 96, 32, 97, 115, 32, 97, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 100, 101, 115, 117, 103, 97, 114, 115, 32, 116, 111, 32, 96, 116, 101, 115, 116, 32, 61, 32, 116, 101, 115, 116, 59, 96, 1, 0, 0, 0, 56, 28, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 147, 3, 0, 0, 0, 0, 0, 0, 60, 28, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 148, 3, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 45, 98, 97, 114, 101, 5, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 79, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 150, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 79, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 151, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 80, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 152, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 81, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 153, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 82, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 154, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 83, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 155, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 10, 0, 0, 0, 86, 28, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 157, 3, 0, 0, 0, 0, 0, 0, 86, 28, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 158, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 7, 0, 0, 0, 97, 28, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 160, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 97, 28, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 161, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 98, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 162, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 101, 28, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 163, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 101, 28, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 164, 3, 0, 0, 0, 0, 0, 0, 101, 28, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 165, 3, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 10, 0, 0, 0, 118, 28, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 167, 3, 0, 0, 0, 0, 0, 0, 118, 28, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 168, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 1, 0, 0, 0, 128, 28, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 169, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 128, 28, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 170, 3, 0, 0, 0, 0, 0, 0, 128, 28, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 171, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 1, 137, 28, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 172, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 138, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 173, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 146, 28, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 174, 3, 0, 0, 0, 0, 0, 0, 150, 28, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 175, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 117, 110, 105, 120, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 157, 28, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 177, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 157, 28, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 178, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 28, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 179, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 1, 0, 0, 0, 163, 28, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 180, 3, 0, 0, 0, 0, 0, 0, 167, 28, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 181, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 119, 105, 110, 100, 111, 119, 115, 1, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 177, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 177, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 184, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 28, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 185, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 180, 28, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 186, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 1, 0, 0, 0, 186, 28, 0, 0, 0, 0, 0, 0, 180, 2, 0, 0, 0, 0, 0, 0, 187, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 28, 0, 0, 0, 0, 0, 0, 180, 2, 0, 0, 0, 0, 0, 0, 188, 3, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 186, 28, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 189, 3, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 115, 111, 114, 116, 32, 105, 115, 32, 97, 32, 103, 114, 111, 117, 112, 32, 111, 102, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 46, 32, 83, 101, 101, 32, 91, 96, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 96, 93, 32, 102, 111, 114, 32, 109, 111, 114, 101, 32, 100, 101, 116, 97, 105, 108, 115, 46, 7, 29, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 190, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 11, 29, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 101, 114, 101, 32, 105, 115, 32, 111, 110, 101, 32, 115, 112, 101, 99, 105, 97, 108, 32, 115, 111, 114, 116, 44, 32, 99, 97, 108, 108, 101, 100, 32, 96, 108, 97, 121, 111, 117, 116, 96, 46, 32, 73, 116, 32, 99, 97, 110, 32, 98, 101, 32, 117, 115, 101, 100, 32, 116, 111, 32, 100, 101, 110, 111, 116, 101, 84, 29, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 192, 3, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 104, 97, 116, 32, 97, 32, 103, 114, 97, 109, 109, 97, 114, 32, 115, 104, 111, 117, 108, 100, 32, 102, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 32, 105, 103, 110, 111, 114, 101, 32, 99, 101, 114, 116, 97, 105, 110, 32, 119, 104, 105, 116, 101, 115, 112, 97, 99, 101, 32, 111, 114, 32, 99, 111, 109, 109, 101, 110, 116, 115, 46, 161, 29, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 193, 3, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 66, 101, 116, 119, 101, 101, 110, 32, 101, 118, 101, 114, 121, 32, 112, 97, 114, 116, 32, 111, 102, 32, 97, 110, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 44, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 32, 119, 105, 108, 108, 32, 97, 116, 116, 101, 109, 112, 116, 32, 116, 111, 32, 112, 97, 114, 115, 101, 32, 116, 104, 101, 239, 29, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 194, 3, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 108, 97, 121, 111, 117, 116, 32, 115, 111, 114, 116, 32, 48, 32, 111, 114, 32, 109, 111, 114, 101, 32, 116, 105, 109, 101, 115, 44, 32, 97, 110, 100, 32, 116, 104, 114, 111, 119, 32, 97, 119, 97, 121, 32, 119, 104, 97, 116, 101, 118, 101, 114, 32, 105, 116, 32, 112, 97, 114, 115, 101, 115, 46, 51, 30, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 195, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 55, 30, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 196, 3, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 83, 111, 114, 116, 115, 32, 99, 97, 110, 32, 104, 97, 118, 101, 32, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 115, 32, 117, 115, 105, 110, 103, 32, 116, 114, 105, 112, 108, 101, 32, 115, 108, 97, 115, 104, 101, 115, 46, 1, 0, 0, 0, 109, 30, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 197, 3, 0, 0, 0, 0, 0, 0, 109, 30, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 198, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 115, 30, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 199, 3, 0, 0, 0, 0, 0, 0, 119, 30, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 200, 3, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 100, 111, 99, 117, 109, 101, 110, 116, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 137, 30, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 201, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 137, 30, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 202, 3, 0, 0, 0, 0, 0, 0, 137, 30, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 203, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 10, 0, 0, 0, 150, 30, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 205, 3, 0, 0, 0, 0, 0, 0, 150, 30, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 206, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 0, 1, 0, 0, 0, 156, 30, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 207, 3, 0, 0, 0, 0, 0, 0, 160, 30, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 208, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 5, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 167, 30, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 210, 3, 0, 0, 0, 0, 0, 0, 167, 30, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 211, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 7, 0, 0, 0, 178, 30, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 213, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 178, 30, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 214, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 179, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 215, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 2, 0, 0, 0, 182, 30, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 216, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 182, 30, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 217, 3, 0, 0, 0, 0, 0, 0, 182, 30, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 218, 3, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 10, 0, 0, 0, 199, 30, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 220, 3, 0, 0, 0, 0, 0, 0, 199, 30, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 221, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 0, 0, 0, 207, 30, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 222, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 207, 30, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 223, 3, 0, 0, 0, 0, 0, 0, 207, 30, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 224, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 0, 0, 0, 0, 0, 226, 30, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 225, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 226, 30, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 226, 3, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 87, 104, 101, 110, 32, 97, 32, 115, 111, 114, 116, 32, 104, 97, 115, 32, 111, 110, 108, 121, 32, 111, 110, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 44, 32, 105, 116, 32, 104, 97, 115, 32, 115, 105, 109, 112, 108, 101, 114, 32, 115, 121, 110, 116, 97, 120, 46, 1, 0, 0, 0, 35, 31, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 227, 3, 0, 0, 0, 0, 0, 0, 39, 31, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 228, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 115, 105, 110, 103, 108, 101, 6, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 53, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 230, 3, 0, 0, 0, 0, 0, 0, 53, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 231, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 7, 0, 0, 0, 64, 31, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 233, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 64, 31, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 234, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 65, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 235, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 68, 31, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 236, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 68, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 237, 3, 0, 0, 0, 0, 0, 0, 68, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 238, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 7, 0, 0, 0, 80, 31, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 240, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 80, 31, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 241, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 81, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 242, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 84, 31, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 243, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 84, 31, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 244, 3, 0, 0, 0, 0, 0, 0, 84, 31, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 245, 3, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 10, 0, 0, 0, 101, 31, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 247, 3, 0, 0, 0, 0, 0, 0, 101, 31, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 248, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 1, 0, 0, 0, 111, 31, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 249, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 31, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 250, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 31, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 251, 3, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 116, 104, 101, 114, 32, 116, 111, 112, 45, 108, 101, 118, 101, 108, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 115, 32, 116, 104, 97, 116, 32, 97, 114, 101, 32, 110, 111, 116, 32, 115, 111, 114, 116, 115, 1, 0, 0, 0, 161, 31, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 252, 3, 0, 0, 0, 0, 0, 0, 161, 31, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 253, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 109, 101, 116, 97, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 31, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 254, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 171, 31, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 255, 3, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 101, 32, 115, 116, 97, 114, 116, 105, 110, 103, 32, 114, 117, 108, 101, 32, 105, 115, 32, 97, 110, 32, 105, 110, 100, 105, 99, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 1, 0, 0, 0, 225, 31, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 229, 31, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 115, 116, 97, 114, 116, 4, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 237, 31, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 237, 31, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 238, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 239, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 240, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 7, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 241, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 8, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 242, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 9, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 7, 0, 0, 0, 245, 31, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 11, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 245, 31, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 12, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 246, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 13, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 247, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 14, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 10, 0, 0, 0, 250, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 16, 4, 0, 0, 0, 0, 0, 0, 250, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 17, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 7, 0, 0, 0, 5, 32, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 19, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 5, 32, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 20, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 6, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 21, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 0, 1, 0, 0, 0, 11, 32, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 22, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 11, 32, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 23, 4, 0, 0, 0, 0, 0, 0, 11, 32, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 24, 4, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 111, 114, 45, 109, 101, 116, 97, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 25, 32, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 25, 4, 0, 0, 0, 0, 0, 0, 29, 32, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 26, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 109, 101, 116, 97, 0, 2, 0, 0, 0, 35, 32, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 27, 4, 0, 0, 0, 0, 0, 0, 39, 32, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 28, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 0, 1, 0, 0, 0, 46, 32, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 29, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 46, 32, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 30, 4, 0, 0, 0, 0, 0, 0, 46, 32, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 31, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 112, 114, 111, 103, 114, 97, 109, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 32, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 32, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 56, 32, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 33, 4, 0, 0, 0, 0, 0, 0, 56, 32, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 34, 4, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 111, 114, 45, 109, 101, 116, 97, 0, 1, 0, 0, 0, 72, 32, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 35, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 32, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 36, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 72, 32, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 37, 4, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 100, 111, 99, 117, 109, 101, 110, 116, 97, 116, 105, 111, 110, 32, 99, 111, 109, 109, 101, 110, 116, 32, 40, 100, 111, 99, 32, 99, 111, 109, 109, 101, 110, 116, 41, 32, 105, 115, 32, 97, 108, 119, 97, 121, 115, 32, 97, 115, 115, 111, 99, 105, 97, 116, 101, 100, 32, 119, 105, 116, 104, 32, 97, 32, 115, 111, 114, 116, 147, 32, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 38, 4, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 111, 114, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 46, 32, 73, 116, 32, 100, 111, 99, 117, 109, 101, 110, 116, 115, 32, 119, 104, 97, 116, 32, 105, 116, 32, 100, 111, 101, 115, 46, 32, 68, 111, 99, 32, 99, 111, 109, 109, 101, 110, 116, 115, 32, 119, 105, 108, 108, 32, 98, 101, 32, 105, 110, 116, 101, 114, 112, 114, 101, 116, 101, 100, 227, 32, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 39, 4, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 100, 32, 119, 105, 108, 108, 32, 98, 101, 32, 112, 117, 116, 32, 111, 110, 32, 116, 104, 101, 32, 103, 101, 110, 101, 114, 97, 116, 101, 100, 32, 116, 121, 112, 101, 115, 32, 100, 117, 114, 105, 110, 103, 32, 99, 111, 100, 101, 103, 101, 110, 46, 2, 0, 0, 0, 30, 33, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 40, 4, 0, 0, 0, 0, 0, 0, 30, 33, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 41, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 44, 33, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 43, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 44, 33, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 44, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 45, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 1, 0, 0, 0, 46, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 46, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 1, 0, 0, 0, 47, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 50, 33, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 48, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 50, 33, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 49, 4, 0, 0, 0, 0, 0, 0, 50, 33, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 50, 4, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 52, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 51, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 52, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 58, 33, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 53, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 59, 33, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 54, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 70, 33, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 55, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 86, 33, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 56, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 86, 33, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 57, 4, 0, 0, 0, 0, 0, 0, 86, 33, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 58, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 108, 97, 121, 111, 117, 116, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 94, 33, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 59, 4, 0, 0, 0, 0, 0, 0, 98, 33, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 60, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 105, 109, 112, 108, 101, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 107, 33, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 62, 4, 0, 0, 0, 0, 0, 0, 107, 33, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 63, 4, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 108, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 64, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 65, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 110, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 66, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 110, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 67, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 112, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 68, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 112, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 69, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 114, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 70, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 114, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 71, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 0, 1, 0, 0, 0, 118, 33, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 72, 4, 0, 0, 0, 0, 0, 0, 122, 33, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 73, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 99, 111, 109, 109, 101, 110, 116, 2, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 132, 33, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 75, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 132, 33, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 76, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 133, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 77, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 1, 0, 0, 0, 134, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 78, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 137, 33, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 79, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 137, 33, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 80, 4, 0, 0, 0, 0, 0, 0, 137, 33, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 81, 4, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 139, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 82, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 33, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 83, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 146, 33, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 84, 4, 0, 0, 0, 0, 0, 0, 146, 33, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 85, 4, 0, 0, 0, 0, 0, 0, 155, 33, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 86, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 112, 114, 111, 103, 114, 97, 109];
\ No newline at end of file
+pub const NATIVE_PARSER: Option<&super::prelude::NativeParser> = None;
+pub const PARSER: &[u8] = &[1, 0, 0, 0, 0, 0, 0, 0, 158, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 32, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 32, 105, 115, 32, 97, 110, 121, 32, 110, 97, 109, 101, 32, 111, 102, 32, 97, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 111, 114, 32, 115, 111, 114, 116, 44, 32, 97, 110, 100, 32, 105, 115, 32, 117, 115, 101, 100, 32, 105, 110, 32, 118, 97, 114, 105, 111, 117, 115, 32, 112, 108, 97, 99, 101, 115, 46, 88, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 100, 101, 110, 116, 105, 102, 105, 101, 114, 115, 32, 97, 108, 119, 97, 121, 115, 32, 115, 116, 97, 114, 116, 32, 119, 105, 116, 104, 32, 97, 32, 108, 101, 116, 116, 101, 114, 32, 40, 99, 97, 112, 105, 116, 97, 108, 32, 111, 114, 32, 110, 111, 116, 41, 32, 111, 114, 32, 97, 110, 32, 117, 110, 100, 101, 114, 115, 99, 111, 114, 101, 44, 32, 97, 110, 100, 32, 99, 97, 110, 32, 98, 101, 177, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 102, 111, 108, 108, 111, 119, 101, 100, 32, 98, 121, 32, 108, 101, 116, 116, 101, 114, 115, 32, 111, 114, 32, 110, 117, 109, 98, 101, 114, 115, 46, 32, 84, 104, 105, 115, 32, 105, 115, 32, 118, 101, 114, 121, 32, 115, 105, 109, 105, 108, 97, 114, 32, 116, 111, 32, 104, 111, 119, 32, 118, 97, 114, 105, 97, 98, 108, 101, 115, 32, 105, 110, 32, 109, 111, 115, 116, 32, 109, 97, 106, 111, 114, 9, 1, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 112, 114, 111, 103, 114, 97, 109, 109, 105, 110, 103, 32, 108, 97, 110, 103, 117, 97, 103, 101, 115, 32, 119, 111, 114, 107, 46, 2, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 2, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 57, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 58, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 58, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 60, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 122, 1, 0, 0, 0, 61, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 61, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 66, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 67, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 67, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 69, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 70, 1, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 70, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 72, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 1, 0, 0, 0, 73, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 73, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 95, 1, 79, 1, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 80, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 91, 1, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 108, 1, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 135, 1, 0, 0, 0, 0, 0, 0, 27, 1, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 135, 1, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 115, 105, 110, 103, 108, 101, 32, 98, 121, 116, 101, 44, 32, 119, 114, 105, 116, 116, 101, 110, 32, 97, 115, 32, 116, 119, 111, 32, 104, 101, 120, 97, 100, 101, 99, 105, 109, 97, 108, 32, 100, 105, 103, 105, 116, 115, 46, 32, 73, 110, 32, 96, 98, 105, 110, 97, 114, 121, 96, 32, 103, 114, 97, 109, 109, 97, 114, 115, 214, 1, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 104, 105, 115, 32, 109, 97, 116, 99, 104, 101, 115, 32, 101, 120, 97, 99, 116, 108, 121, 32, 116, 104, 97, 116, 32, 98, 121, 116, 101, 46, 32, 73, 110, 32, 111, 116, 104, 101, 114, 32, 103, 114, 97, 109, 109, 97, 114, 115, 32, 105, 116, 32, 115, 116, 97, 110, 100, 115, 32, 102, 111, 114, 32, 116, 104, 101, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 45, 2, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 92, 96, 44, 32, 96, 120, 96, 32, 97, 110, 100, 32, 116, 104, 101, 32, 116, 119, 111, 32, 100, 105, 103, 105, 116, 115, 44, 32, 108, 105, 107, 101, 32, 97, 110, 121, 32, 111, 116, 104, 101, 114, 32, 116, 101, 120, 116, 32, 105, 110, 32, 97, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 46, 1, 0, 0, 0, 120, 2, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 124, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 98, 121, 116, 101, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 131, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 131, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 132, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 2, 0, 0, 0, 134, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 120, 12, 0, 0, 0, 137, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 137, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 138, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 138, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 140, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 141, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 141, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 143, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 144, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 144, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 146, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 70, 12, 0, 0, 0, 149, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 149, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 150, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 152, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 153, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 153, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 155, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 156, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 156, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 158, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 70, 0, 1, 0, 0, 0, 162, 2, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 166, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 176, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 176, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 177, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 12, 0, 0, 0, 181, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 181, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 182, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 182, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 1, 0, 0, 0, 184, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 184, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 185, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 185, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 186, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 186, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 187, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 187, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 0, 1, 0, 0, 0, 192, 2, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 96, 0, 0, 0, 0, 0, 0, 0, 196, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 117, 110, 101, 115, 99, 97, 112, 101, 100, 1, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 208, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 208, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 210, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 210, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 0, 1, 0, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 45, 105, 116, 101, 109, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 238, 2, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 242, 2, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 114, 97, 110, 103, 101, 3, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 250, 2, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 250, 2, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 8, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 112, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 113, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 19, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 11, 0, 0, 0, 22, 3, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 0, 0, 22, 3, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 0, 1, 0, 0, 0, 46, 3, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 50, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 105, 110, 103, 108, 101, 45, 99, 104, 97, 114, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 64, 3, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 64, 3, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 122, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 45, 99, 108, 111, 115, 105, 110, 103, 45, 98, 114, 97, 99, 107, 101, 116, 1, 88, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 3, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 124, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 102, 3, 0, 0, 0, 0, 0, 0, 90, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 102, 3, 0, 0, 0, 0, 0, 0, 90, 1, 0, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 102, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 45, 99, 104, 97, 114, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 3, 0, 0, 0, 0, 0, 0, 23, 1, 0, 0, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 119, 3, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 129, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 115, 105, 110, 103, 108, 101, 32, 98, 121, 116, 101, 44, 32, 119, 114, 105, 116, 116, 101, 110, 32, 97, 115, 32, 116, 119, 111, 32, 104, 101, 120, 97, 100, 101, 99, 105, 109, 97, 108, 32, 100, 105, 103, 105, 116, 115, 46, 32, 73, 110, 32, 96, 98, 105, 110, 97, 114, 121, 96, 32, 103, 114, 97, 109, 109, 97, 114, 115, 198, 3, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 104, 105, 115, 32, 109, 97, 116, 99, 104, 101, 115, 32, 101, 120, 97, 99, 116, 108, 121, 32, 116, 104, 97, 116, 32, 98, 121, 116, 101, 46, 32, 79, 116, 104, 101, 114, 32, 103, 114, 97, 109, 109, 97, 114, 115, 32, 111, 110, 108, 121, 32, 97, 108, 108, 111, 119, 32, 116, 104, 101, 32, 97, 115, 99, 105, 105, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 32, 96, 92, 120, 48, 48, 96, 32, 116, 111, 32, 96, 92, 120, 55, 102, 96, 44, 47, 4, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 115, 105, 110, 99, 101, 32, 97, 110, 121, 32, 111, 116, 104, 101, 114, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 105, 115, 32, 109, 111, 114, 101, 32, 116, 104, 97, 110, 32, 111, 110, 101, 32, 98, 121, 116, 101, 46, 1, 0, 0, 0, 100, 4, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 104, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 98, 121, 116, 101, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 111, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 111, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 112, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 137, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 2, 0, 0, 0, 114, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 120, 12, 0, 0, 0, 117, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 117, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 141, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 118, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 142, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 118, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 120, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 121, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 121, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 146, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 123, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 124, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 148, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 124, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 126, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 70, 12, 0, 0, 0, 129, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 129, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 130, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 132, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 156, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 133, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 133, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 135, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 136, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 136, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 138, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 70, 0, 1, 0, 0, 0, 142, 4, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 146, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 101, 115, 99, 97, 112, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 156, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 166, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 156, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 157, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 12, 0, 0, 0, 161, 4, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 161, 4, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 162, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 162, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 163, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 163, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 164, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 164, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 165, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 165, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 1, 0, 0, 0, 167, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 167, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 181, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 0, 1, 0, 0, 0, 171, 4, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 175, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 111, 114, 109, 97, 108, 1, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 184, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 184, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 186, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 187, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 186, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 188, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 1, 0, 0, 0, 187, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 187, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 190, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 92, 0, 1, 0, 0, 0, 193, 4, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 193, 4, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 193, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 202, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 202, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 202, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 197, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 203, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 198, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 205, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 199, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 57, 1, 210, 4, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 200, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 211, 4, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 201, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 227, 4, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 202, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 227, 4, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 203, 0, 0, 0, 0, 0, 0, 0, 227, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 204, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 235, 4, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 205, 0, 0, 0, 0, 0, 0, 0, 239, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 206, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 105, 110, 103, 108, 101, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 248, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 208, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 248, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 209, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 249, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 210, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 252, 4, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 252, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 252, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 213, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 45, 99, 104, 97, 114, 8, 0, 0, 0, 9, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 215, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 9, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 10, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 39, 1, 14, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 15, 5, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 219, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 26, 5, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 30, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 221, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 100, 111, 117, 98, 108, 101, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 39, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 223, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 39, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 224, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 40, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 225, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 44, 5, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 226, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 44, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 44, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 45, 99, 104, 97, 114, 8, 0, 0, 0, 57, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 57, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 58, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 34, 1, 63, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 233, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 64, 5, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 234, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 76, 5, 0, 0, 0, 0, 0, 0, 236, 2, 0, 0, 0, 0, 0, 0, 0, 235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 5, 0, 0, 0, 0, 0, 0, 236, 2, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 76, 5, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 32, 114, 101, 112, 114, 101, 115, 101, 110, 116, 32, 97, 32, 115, 101, 108, 101, 99, 116, 105, 111, 110, 32, 111, 102, 32, 116, 101, 114, 109, 105, 110, 97, 108, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 46, 32, 84, 104, 105, 115, 32, 105, 115, 32, 115, 105, 109, 105, 108, 97, 114, 32, 116, 111, 163, 5, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 91, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 103, 101, 120, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 101, 115, 46, 32, 67, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 101, 115, 32, 99, 97, 110, 32, 98, 101, 32, 105, 110, 118, 101, 114, 116, 101, 100, 32, 98, 121, 32, 115, 116, 97, 114, 116, 105, 110, 103, 32, 116, 104, 101, 109, 32, 119, 105, 116, 104, 32, 97, 32, 96, 94, 96, 46, 255, 5, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 91, 94, 92, 110, 93, 96, 32, 109, 101, 97, 110, 115, 32, 105, 116, 32, 109, 97, 116, 99, 104, 101, 115, 32, 97, 110, 121, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 116, 104, 97, 116, 32, 105, 115, 32, 110, 111, 116, 32, 97, 32, 110, 101, 119, 108, 105, 110, 101, 46, 78, 6, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 82, 6, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 67, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 101, 115, 32, 99, 97, 110, 32, 99, 111, 110, 116, 97, 105, 110, 32, 97, 32, 114, 97, 110, 103, 101, 32, 111, 102, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 46, 32, 69, 105, 116, 104, 101, 114, 32, 98, 121, 32, 108, 105, 115, 116, 105, 110, 103, 32, 101, 97, 99, 104, 32, 105, 110, 100, 105, 118, 105, 100, 117, 97, 108, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 44, 32, 111, 114, 32, 117, 115, 105, 110, 103, 193, 6, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 32, 100, 97, 115, 104, 32, 40, 96, 45, 96, 41, 46, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 91, 97, 45, 122, 93, 96, 32, 109, 101, 97, 110, 115, 32, 97, 110, 121, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 105, 110, 32, 116, 104, 101, 32, 114, 97, 110, 103, 101, 32, 97, 32, 116, 104, 114, 111, 117, 103, 104, 32, 122, 32, 40, 105, 110, 99, 108, 117, 115, 105, 118, 101, 33, 41, 44, 35, 7, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 243, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 100, 32, 96, 91, 97, 98, 99, 93, 96, 32, 109, 101, 97, 110, 115, 32, 97, 110, 32, 97, 44, 32, 98, 32, 111, 114, 32, 99, 70, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 74, 7, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 245, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 78, 111, 116, 101, 32, 116, 104, 97, 116, 32, 116, 111, 32, 117, 115, 101, 32, 97, 32, 99, 108, 111, 115, 105, 110, 103, 32, 115, 113, 117, 97, 114, 101, 32, 98, 114, 97, 99, 107, 101, 116, 32, 119, 105, 116, 104, 105, 110, 32, 97, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 44, 32, 121, 111, 117, 32, 110, 101, 101, 100, 32, 116, 111, 32, 101, 115, 99, 97, 112, 101, 32, 105, 116, 46, 169, 7, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 246, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 173, 7, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 247, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 91, 94, 92, 93, 93, 96, 32, 109, 101, 97, 110, 115, 32, 97, 110, 121, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 116, 104, 97, 116, 32, 105, 115, 110, 39, 116, 32, 97, 32, 115, 113, 117, 97, 114, 101, 32, 98, 114, 97, 99, 107, 101, 116, 46, 1, 0, 0, 0, 234, 7, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 248, 0, 0, 0, 0, 0, 0, 0, 234, 7, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 251, 7, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0, 0, 0, 0, 0, 0, 255, 7, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 99, 108, 97, 115, 115, 4, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 253, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 8, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 9, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 91, 2, 0, 0, 0, 12, 8, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 12, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 12, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 13, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 17, 8, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 4, 1, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 17, 8, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 5, 1, 0, 0, 0, 0, 0, 0, 17, 8, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 6, 1, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 45, 105, 116, 101, 109, 8, 0, 0, 0, 39, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 8, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 39, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 9, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 40, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 10, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 93, 1, 44, 8, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 11, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 45, 8, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 12, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 58, 8, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 58, 8, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 0, 14, 1, 0, 0, 0, 0, 0, 0, 58, 8, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 15, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 1, 64, 8, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 16, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 65, 8, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 17, 1, 0, 0, 0, 0, 0, 0, 74, 8, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 18, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 6, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 86, 8, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 19, 1, 0, 0, 0, 0, 0, 0, 90, 8, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 20, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 111, 117, 98, 108, 101, 45, 101, 113, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 102, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 22, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 102, 8, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 23, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 103, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 24, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 107, 8, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 25, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 108, 8, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 26, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 115, 8, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 27, 1, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 116, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 28, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 117, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 29, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 118, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 30, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 119, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 31, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 120, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 32, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 2, 0, 0, 0, 121, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 33, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 122, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 34, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 123, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 35, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 124, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 36, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 125, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 37, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 126, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 38, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 127, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 39, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 128, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 40, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 129, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 41, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 130, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 42, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 131, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 43, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 132, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 44, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 2, 0, 0, 0, 133, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 45, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 134, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 46, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 135, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 47, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 136, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 48, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 137, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 49, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 138, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 50, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 139, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 51, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 140, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 52, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 141, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 53, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 142, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 54, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 143, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 55, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 144, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 56, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 145, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 57, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 146, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 58, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 147, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 59, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 148, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 60, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 149, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 150, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 62, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 151, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 63, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 152, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 64, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 153, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 65, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 154, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 66, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 2, 0, 0, 0, 155, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 67, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 156, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 68, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 157, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 69, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 158, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 70, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 159, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 71, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 160, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 72, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 161, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 73, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 162, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 74, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 163, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 75, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 164, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 76, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 165, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 77, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 166, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 78, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 167, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 79, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 168, 8, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 80, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 177, 8, 0, 0, 0, 0, 0, 0, 94, 0, 0, 0, 0, 0, 0, 0, 0, 81, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 177, 8, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 82, 1, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 116, 99, 104, 101, 115, 32, 97, 32, 112, 105, 101, 99, 101, 32, 111, 102, 32, 116, 101, 120, 116, 32, 101, 120, 97, 99, 116, 108, 121, 46, 32, 76, 97, 121, 111, 117, 116, 32, 105, 115, 32, 112, 97, 114, 115, 101, 100, 32, 119, 105, 116, 104, 105, 110, 32, 97, 32, 108, 105, 116, 101, 114, 97, 108, 46, 1, 0, 0, 0, 249, 8, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 83, 1, 0, 0, 0, 0, 0, 0, 253, 8, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 84, 1, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 108, 105, 116, 101, 114, 97, 108, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 7, 9, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 86, 1, 0, 0, 0, 0, 0, 0, 7, 9, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 87, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 20, 9, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 88, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 20, 9, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 89, 1, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 89, 111, 117, 32, 99, 97, 110, 32, 117, 115, 101, 32, 112, 97, 114, 101, 110, 116, 104, 101, 115, 101, 115, 32, 116, 111, 32, 103, 114, 111, 117, 112, 32, 112, 97, 114, 116, 115, 32, 111, 102, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 1, 0, 0, 0, 79, 9, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 90, 1, 0, 0, 0, 0, 0, 0, 83, 9, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 91, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 112, 97, 114, 101, 110, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 91, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 93, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 91, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 94, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 92, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 95, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 40, 1, 0, 0, 0, 95, 9, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 96, 1, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 95, 9, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 97, 1, 0, 0, 0, 0, 0, 0, 95, 9, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 98, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 8, 0, 0, 0, 107, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 100, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 107, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 101, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 108, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 102, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 0, 1, 0, 0, 0, 113, 9, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 103, 1, 0, 0, 0, 0, 0, 0, 117, 9, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 104, 1, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 108, 97, 98, 101, 108, 108, 101, 100, 3, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 128, 9, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 106, 1, 0, 0, 0, 0, 0, 0, 128, 9, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 107, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 139, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 109, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 139, 9, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 140, 9, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 111, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 143, 9, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 113, 1, 0, 0, 0, 0, 0, 0, 143, 9, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 0, 0, 0, 0, 0, 154, 9, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 115, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 154, 9, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 102, 101, 114, 101, 110, 99, 101, 32, 97, 110, 111, 116, 104, 101, 114, 32, 115, 111, 114, 116, 32, 119, 105, 116, 104, 105, 110, 32, 116, 104, 105, 115, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 46, 209, 9, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 117, 1, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 97, 116, 32, 115, 111, 114, 116, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 32, 105, 110, 32, 116, 104, 105, 115, 32, 112, 111, 115, 105, 116, 105, 111, 110, 32, 105, 110, 32, 116, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 46, 1, 0, 0, 0, 20, 10, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 118, 1, 0, 0, 0, 0, 0, 0, 24, 10, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 119, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 31, 10, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 121, 1, 0, 0, 0, 0, 0, 0, 31, 10, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 122, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 0, 0, 0, 0, 0, 48, 10, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 123, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 48, 10, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 0, 124, 1, 0, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 91, 96, 99, 104, 97, 114, 97, 99, 116, 101, 114, 32, 99, 108, 97, 115, 115, 96, 93, 40, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 41, 32, 40, 114, 97, 110, 103, 101, 32, 111, 102, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 41, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 32, 104, 101, 114, 101, 46, 1, 0, 0, 0, 136, 10, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 125, 1, 0, 0, 0, 0, 0, 0, 140, 10, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 126, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 99, 108, 97, 115, 115, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 148, 10, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 128, 1, 0, 0, 0, 0, 0, 0, 148, 10, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 129, 1, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 99, 104, 97, 114, 97, 99, 116, 101, 114, 45, 99, 108, 97, 115, 115, 0, 1, 0, 0, 0, 166, 10, 0, 0, 0, 0, 0, 0, 78, 7, 0, 0, 0, 0, 0, 0, 0, 130, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 166, 10, 0, 0, 0, 0, 0, 0, 78, 7, 0, 0, 0, 0, 0, 0, 0, 131, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 166, 10, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 132, 1, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 87, 105, 116, 104, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 44, 32, 121, 111, 117, 32, 99, 97, 110, 32, 103, 105, 118, 101, 32, 116, 104, 101, 32, 115, 121, 110, 116, 97, 120, 32, 114, 117, 108, 101, 115, 32, 111, 102, 32, 97, 32, 115, 105, 110, 103, 108, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 46, 243, 10, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 133, 1, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 32, 99, 97, 110, 32, 98, 101, 32, 110, 101, 115, 116, 101, 100, 32, 97, 110, 100, 32, 99, 111, 109, 98, 105, 110, 101, 100, 46, 1, 0, 0, 0, 31, 11, 0, 0, 0, 0, 0, 0, 213, 6, 0, 0, 0, 0, 0, 0, 0, 134, 1, 0, 0, 0, 0, 0, 0, 31, 11, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 135, 1, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 0, 10, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 43, 11, 0, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0, 0, 0, 0, 0, 136, 1, 0, 0, 0, 0, 0, 0, 47, 11, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 137, 1, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 111, 117, 98, 108, 101, 45, 101, 113, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 59, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 139, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 59, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 140, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 60, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 141, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 64, 11, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 142, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 65, 11, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 143, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 72, 11, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 144, 1, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 73, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 145, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 74, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 146, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 75, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 147, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 76, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 148, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 77, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 149, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 2, 0, 0, 0, 78, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 150, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 79, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 151, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 80, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 152, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 81, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 153, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 82, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 154, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 83, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 155, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 84, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 156, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 85, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 157, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 86, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 158, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 87, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 159, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 88, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 160, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 89, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 161, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 2, 0, 0, 0, 90, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 162, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 91, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 163, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 92, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 164, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 93, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 165, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 94, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 166, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 95, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 167, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 96, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 168, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 97, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 169, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 98, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 170, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 99, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 171, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 100, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 172, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 101, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 173, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 102, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 174, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 103, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 175, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 104, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 176, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 105, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 106, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 178, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 107, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 179, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 108, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 180, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 109, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 181, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 110, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 182, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 111, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 183, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 2, 0, 0, 0, 112, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 184, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 113, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 185, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 114, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 186, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 115, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 187, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 116, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 188, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 117, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 189, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 118, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 190, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 119, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 191, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 120, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 192, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 121, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 193, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 122, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 194, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 123, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 195, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 124, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 196, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 125, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 197, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 0, 0, 0, 0, 134, 11, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 198, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 134, 11, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 199, 1, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 112, 101, 97, 116, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 122, 101, 114, 111, 32, 111, 114, 32, 109, 111, 114, 101, 32, 116, 105, 109, 101, 115, 184, 11, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 200, 1, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 32, 116, 111, 32, 96, 60, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 62, 32, 123, 48, 44, 125, 96, 1, 0, 0, 0, 222, 11, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 201, 1, 0, 0, 0, 0, 0, 0, 226, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 202, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 116, 97, 114, 2, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 233, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 204, 1, 0, 0, 0, 0, 0, 0, 233, 11, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 205, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 238, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 207, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 238, 11, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 208, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 239, 11, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 209, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 248, 11, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 0, 0, 0, 0, 0, 210, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 248, 11, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 211, 1, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 101, 112, 101, 97, 116, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 111, 110, 101, 32, 111, 114, 32, 109, 111, 114, 101, 32, 116, 105, 109, 101, 115, 41, 12, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 212, 1, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 32, 116, 111, 32, 96, 60, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 62, 32, 123, 49, 44, 125, 96, 1, 0, 0, 0, 79, 12, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 213, 1, 0, 0, 0, 0, 0, 0, 83, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 214, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 112, 108, 117, 115, 2, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 90, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 216, 1, 0, 0, 0, 0, 0, 0, 90, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 217, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 95, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 219, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 220, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 96, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 221, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 105, 12, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 222, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 105, 12, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 223, 1, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 112, 116, 105, 111, 110, 97, 108, 108, 121, 32, 104, 97, 118, 101, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 46, 146, 12, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 224, 1, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 32, 116, 111, 32, 96, 60, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 62, 32, 123, 48, 44, 49, 125, 96, 1, 0, 0, 0, 185, 12, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 225, 1, 0, 0, 0, 0, 0, 0, 189, 12, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 226, 1, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 109, 97, 121, 98, 101, 2, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 197, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 228, 1, 0, 0, 0, 0, 0, 0, 197, 12, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 229, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 202, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 231, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 202, 12, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 232, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 203, 12, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 233, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 212, 12, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 234, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 212, 12, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 235, 1, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 120, 97, 99, 116, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 46, 32, 84, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 105, 115, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 97, 110, 32, 101, 120, 97, 99, 116, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 116, 105, 109, 101, 115, 46, 32, 69, 113, 117, 105, 118, 97, 108, 101, 110, 116, 46, 13, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 236, 1, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 111, 32, 114, 97, 110, 103, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 32, 119, 105, 116, 104, 32, 97, 110, 32, 101, 113, 117, 97, 108, 32, 108, 111, 119, 101, 114, 32, 97, 110, 100, 32, 117, 112, 112, 101, 114, 32, 98, 111, 117, 110, 100, 46, 1, 0, 0, 0, 108, 13, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 237, 1, 0, 0, 0, 0, 0, 0, 112, 13, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 238, 1, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 101, 120, 97, 99, 116, 4, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 127, 13, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 0, 127, 13, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 241, 1, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 132, 13, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 243, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 132, 13, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 244, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 133, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 245, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 11, 0, 0, 0, 136, 13, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 247, 1, 0, 0, 0, 0, 0, 0, 136, 13, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 248, 1, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 8, 0, 0, 0, 143, 13, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 250, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 143, 13, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 251, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 144, 13, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 252, 1, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 152, 13, 0, 0, 0, 0, 0, 0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 253, 1, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 152, 13, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 254, 1, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 97, 110, 103, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 46, 32, 84, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 109, 97, 121, 32, 98, 101, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 97, 110, 121, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 116, 105, 109, 101, 115, 44, 32, 119, 105, 116, 104, 105, 110, 32, 116, 104, 101, 32, 114, 97, 110, 103, 101, 46, 249, 13, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 255, 1, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 66, 111, 116, 104, 32, 98, 111, 117, 110, 100, 115, 32, 97, 114, 101, 32, 105, 110, 99, 108, 117, 115, 105, 118, 101, 46, 1, 0, 0, 0, 24, 14, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 28, 14, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 114, 97, 110, 103, 101, 6, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 43, 14, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 3, 2, 0, 0, 0, 0, 0, 0, 43, 14, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 4, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 48, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 6, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 48, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 7, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 49, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 8, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 11, 0, 0, 0, 52, 14, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 10, 2, 0, 0, 0, 0, 0, 0, 52, 14, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 11, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 8, 0, 0, 0, 59, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 13, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 59, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 14, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 60, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 15, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 11, 0, 0, 0, 63, 14, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 17, 2, 0, 0, 0, 0, 0, 0, 63, 14, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 18, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 8, 0, 0, 0, 70, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 20, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 70, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 21, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 71, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 22, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 79, 14, 0, 0, 0, 0, 0, 0, 118, 0, 0, 0, 0, 0, 0, 0, 0, 23, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 79, 14, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 24, 2, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 82, 97, 110, 103, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 44, 32, 119, 105, 116, 104, 111, 117, 116, 32, 117, 112, 112, 101, 114, 32, 98, 111, 117, 110, 100, 32, 40, 111, 114, 32, 97, 110, 32, 105, 110, 102, 105, 110, 105, 116, 101, 32, 109, 97, 120, 105, 109, 117, 109, 41, 1, 0, 0, 0, 147, 14, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0, 0, 0, 25, 2, 0, 0, 0, 0, 0, 0, 151, 14, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 26, 2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 108, 111, 119, 101, 114, 6, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 166, 14, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 28, 2, 0, 0, 0, 0, 0, 0, 166, 14, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 29, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 171, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 31, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 171, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 172, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 33, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 11, 0, 0, 0, 175, 14, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 35, 2, 0, 0, 0, 0, 0, 0, 175, 14, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 36, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 8, 0, 0, 0, 182, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 38, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 182, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 39, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 183, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 40, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 8, 0, 0, 0, 186, 14, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 42, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 186, 14, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 43, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 187, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 44, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 188, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 189, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 46, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 8, 0, 0, 0, 192, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 48, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 192, 14, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 49, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 193, 14, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 50, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 201, 14, 0, 0, 0, 0, 0, 0, 106, 1, 0, 0, 0, 0, 0, 0, 0, 51, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 201, 14, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 52, 2, 0, 0, 0, 0, 0, 0, 105, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 67, 111, 117, 110, 116, 101, 100, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 44, 32, 111, 110, 108, 121, 32, 105, 110, 32, 98, 105, 110, 97, 114, 121, 32, 103, 114, 97, 109, 109, 97, 114, 115, 46, 32, 84, 104, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 105, 115, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 97, 115, 32, 109, 97, 110, 121, 32, 116, 105, 109, 101, 115, 32, 97, 115, 32, 116, 104, 101, 32, 118, 97, 108, 117, 101, 32, 111, 102, 55, 15, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 53, 2, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 32, 105, 110, 116, 101, 103, 101, 114, 32, 115, 111, 114, 116, 32, 40, 108, 105, 107, 101, 32, 96, 117, 56, 96, 32, 111, 114, 32, 96, 117, 49, 54, 108, 101, 96, 41, 44, 32, 119, 104, 105, 99, 104, 32, 105, 115, 32, 116, 104, 101, 32, 108, 97, 115, 116, 32, 116, 105, 109, 101, 32, 116, 104, 97, 116, 32, 115, 111, 114, 116, 32, 119, 97, 115, 32, 117, 115, 101, 100, 32, 98, 101, 102, 111, 114, 101, 32, 105, 116, 32, 105, 110, 32, 116, 104, 101, 32, 115, 97, 109, 101, 32, 115, 101, 113, 117, 101, 110, 99, 101, 46, 177, 15, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 54, 2, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 117, 49, 54, 108, 101, 32, 91, 92, 120, 48, 48, 45, 92, 120, 102, 102, 93, 123, 117, 49, 54, 108, 101, 125, 96, 32, 105, 115, 32, 97, 32, 108, 101, 110, 103, 116, 104, 44, 32, 102, 111, 108, 108, 111, 119, 101, 100, 32, 98, 121, 32, 116, 104, 97, 116, 32, 109, 97, 110, 121, 32, 98, 121, 116, 101, 115, 46, 1, 0, 0, 0, 7, 16, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 55, 2, 0, 0, 0, 0, 0, 0, 11, 16, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 56, 2, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 114, 101, 112, 101, 97, 116, 45, 99, 111, 117, 110, 116, 4, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 26, 16, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 58, 2, 0, 0, 0, 0, 0, 0, 26, 16, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 8, 0, 0, 0, 31, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 61, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 31, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 62, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 32, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 63, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 11, 0, 0, 0, 35, 16, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 65, 2, 0, 0, 0, 0, 0, 0, 35, 16, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 66, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 46, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 68, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 46, 16, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 69, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 47, 16, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 70, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 56, 16, 0, 0, 0, 0, 0, 0, 177, 1, 0, 0, 0, 0, 0, 0, 0, 71, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 56, 16, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 72, 2, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 68, 101, 108, 105, 109, 105, 116, 101, 100, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 32, 83, 97, 121, 115, 32, 116, 104, 97, 116, 32, 115, 111, 109, 101, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 114, 101, 112, 101, 97, 116, 101, 100, 108, 121, 32, 112, 97, 114, 115, 101, 100, 44, 142, 16, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 73, 2, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 98, 117, 116, 32, 98, 101, 116, 119, 101, 101, 110, 32, 116, 119, 111, 32, 112, 97, 114, 115, 101, 115, 44, 32, 97, 32, 100, 101, 108, 105, 109, 105, 116, 101, 114, 32, 115, 104, 111, 117, 108, 100, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 32, 116, 111, 111, 46, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 99, 111, 109, 109, 97, 32, 115, 101, 112, 101, 114, 97, 116, 101, 100, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 250, 16, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 74, 2, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 101, 32, 102, 105, 110, 97, 108, 32, 116, 114, 97, 105, 108, 105, 110, 103, 32, 107, 101, 121, 119, 111, 114, 100, 32, 101, 110, 97, 98, 108, 101, 115, 32, 97, 32, 116, 114, 97, 105, 108, 105, 110, 103, 32, 115, 101, 112, 97, 114, 97, 116, 111, 114, 32, 97, 102, 116, 101, 114, 32, 116, 104, 101, 32, 115, 101, 113, 117, 101, 110, 99, 101, 46, 32, 73, 102, 32, 110, 111, 116, 32, 112, 114, 101, 115, 101, 110, 116, 44, 32, 110, 111, 32, 116, 114, 97, 105, 108, 105, 110, 103, 106, 17, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 75, 2, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 115, 101, 112, 97, 114, 97, 116, 111, 114, 32, 105, 115, 32, 97, 108, 108, 111, 119, 101, 100, 46, 1, 0, 0, 0, 132, 17, 0, 0, 0, 0, 0, 0, 101, 0, 0, 0, 0, 0, 0, 0, 0, 76, 2, 0, 0, 0, 0, 0, 0, 136, 17, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 77, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 100, 101, 108, 105, 109, 105, 116, 101, 100, 9, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 148, 17, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 79, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 148, 17, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 80, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 149, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 81, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 150, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 82, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 151, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 83, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 152, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 84, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 153, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 85, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 154, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 86, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 155, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 87, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 156, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 88, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 157, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 89, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 8, 0, 0, 0, 160, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 91, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 160, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 92, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 161, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 93, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 40, 11, 0, 0, 0, 164, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 95, 2, 0, 0, 0, 0, 0, 0, 164, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 8, 0, 0, 0, 175, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 98, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 175, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 176, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 11, 0, 0, 0, 179, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 102, 2, 0, 0, 0, 0, 0, 0, 179, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 103, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 8, 0, 0, 0, 190, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 190, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 106, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 191, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 11, 0, 0, 0, 194, 17, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 0, 0, 0, 194, 17, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 100, 101, 108, 105, 109, 105, 116, 101, 100, 45, 98, 111, 117, 110, 100, 2, 0, 0, 0, 210, 17, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 210, 17, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 211, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 211, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 212, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 8, 0, 0, 0, 215, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 118, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 215, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 119, 2, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 216, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 120, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 217, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 218, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 122, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 219, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 123, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 220, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 124, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 221, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 125, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 222, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 126, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 223, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 127, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 0, 228, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 129, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 228, 17, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 130, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 229, 17, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 131, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 41, 0, 2, 0, 0, 0, 234, 17, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 132, 2, 0, 0, 0, 0, 0, 0, 238, 17, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 133, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 97, 116, 111, 109, 0, 1, 0, 0, 0, 245, 17, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 0, 0, 134, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 245, 17, 0, 0, 0, 0, 0, 0, 240, 1, 0, 0, 0, 0, 0, 0, 0, 135, 2, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 245, 17, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 0, 136, 2, 0, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 100, 101, 108, 105, 109, 105, 116, 101, 100, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 32, 99, 97, 110, 32, 98, 101, 32, 114, 101, 112, 101, 97, 116, 101, 100, 32, 106, 117, 115, 116, 32, 108, 105, 107, 101, 32, 110, 111, 114, 109, 97, 108, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 115, 46, 73, 18, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 137, 2, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 111, 32, 100, 101, 110, 111, 116, 101, 32, 116, 104, 105, 115, 44, 32, 121, 111, 117, 32, 99, 97, 110, 32, 117, 115, 101, 32, 97, 32, 100, 101, 108, 105, 109, 105, 116, 97, 116, 105, 111, 110, 32, 98, 111, 117, 110, 100, 46, 1, 0, 0, 0, 127, 18, 0, 0, 0, 0, 0, 0, 102, 1, 0, 0, 0, 0, 0, 0, 0, 138, 2, 0, 0, 0, 0, 0, 0, 127, 18, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 139, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 100, 101, 108, 105, 109, 105, 116, 101, 100, 45, 98, 111, 117, 110, 100, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 148, 18, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 140, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 148, 18, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 141, 2, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 87, 105, 116, 104, 105, 110, 32, 97, 32, 114, 97, 110, 103, 101, 32, 111, 114, 32, 112, 111, 115, 115, 105, 98, 108, 101, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 46, 1, 0, 0, 0, 192, 18, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 142, 2, 0, 0, 0, 0, 0, 0, 196, 18, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 143, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 45, 110, 117, 109, 3, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 206, 18, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 145, 2, 0, 0, 0, 0, 0, 0, 206, 18, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 146, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 8, 0, 0, 0, 213, 18, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 148, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 213, 18, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 149, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 214, 18, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 150, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 11, 0, 0, 0, 217, 18, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 152, 2, 0, 0, 0, 0, 0, 0, 217, 18, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 153, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 0, 0, 0, 0, 0, 229, 18, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 154, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 229, 18, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 155, 2, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 116, 32, 108, 101, 97, 115, 116, 32, 115, 111, 109, 101, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 44, 32, 98, 117, 116, 32, 110, 111, 32, 117, 112, 112, 101, 114, 32, 98, 111, 117, 110, 100, 46, 1, 0, 0, 0, 34, 19, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 156, 2, 0, 0, 0, 0, 0, 0, 38, 19, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 157, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 45, 105, 110, 102, 3, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 48, 19, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 159, 2, 0, 0, 0, 0, 0, 0, 48, 19, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 160, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 8, 0, 0, 0, 55, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 162, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 55, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 163, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 56, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 164, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 8, 0, 0, 0, 59, 19, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 166, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 59, 19, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 167, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 60, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 168, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 61, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 169, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 62, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 170, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 71, 19, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 171, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 71, 19, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 172, 2, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 69, 120, 97, 99, 116, 108, 121, 32, 116, 104, 105, 115, 32, 110, 117, 109, 98, 101, 114, 32, 111, 102, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 46, 1, 0, 0, 0, 111, 19, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 173, 2, 0, 0, 0, 0, 0, 0, 115, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 174, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 1, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 121, 19, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 176, 2, 0, 0, 0, 0, 0, 0, 121, 19, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 177, 2, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 110, 117, 109, 98, 101, 114, 0, 1, 0, 0, 0, 130, 19, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 178, 2, 0, 0, 0, 0, 0, 0, 167, 19, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 179, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 116, 97, 114, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 174, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 181, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 174, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 182, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 175, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 183, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 184, 19, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 184, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 184, 19, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 185, 2, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 110, 101, 32, 111, 114, 32, 109, 111, 114, 101, 32, 114, 101, 112, 101, 116, 105, 116, 105, 111, 110, 115, 46, 1, 0, 0, 0, 213, 19, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 186, 2, 0, 0, 0, 0, 0, 0, 217, 19, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 187, 2, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 112, 108, 117, 115, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 224, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 189, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 224, 19, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 190, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 225, 19, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 191, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 43, 0, 1, 0, 0, 0, 230, 19, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 192, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 19, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 193, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 230, 19, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 194, 2, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 115, 32, 97, 114, 101, 32, 116, 97, 103, 115, 32, 116, 104, 97, 116, 32, 109, 111, 100, 105, 102, 121, 32, 97, 32, 115, 112, 101, 99, 105, 102, 105, 99, 32, 115, 111, 114, 116, 32, 111, 114, 32, 109, 111, 114, 101, 32, 111, 102, 116, 101, 110, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 46, 2, 0, 0, 0, 54, 20, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 195, 2, 0, 0, 0, 0, 0, 0, 54, 20, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 196, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 72, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 198, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 72, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 199, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 73, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 200, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 123, 7, 0, 0, 0, 76, 20, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 201, 2, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 86, 20, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 203, 2, 0, 0, 0, 0, 0, 0, 86, 20, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 204, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 8, 0, 0, 0, 98, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 206, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 98, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 207, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 99, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 208, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 44, 4, 0, 0, 0, 103, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 209, 2, 0, 0, 0, 0, 0, 0, 1, 8, 0, 0, 0, 116, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 211, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 116, 20, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 212, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 117, 20, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 213, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 125, 0, 1, 0, 0, 0, 121, 20, 0, 0, 0, 0, 0, 0, 247, 14, 0, 0, 0, 0, 0, 0, 0, 214, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 121, 20, 0, 0, 0, 0, 0, 0, 247, 14, 0, 0, 0, 0, 0, 0, 0, 215, 2, 0, 0, 0, 0, 0, 0, 121, 20, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 216, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 137, 20, 0, 0, 0, 0, 0, 0, 35, 2, 0, 0, 0, 0, 0, 0, 0, 217, 2, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 137, 20, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 218, 2, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 114, 107, 32, 97, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 97, 115, 32, 98, 101, 105, 110, 103, 32, 97, 32, 109, 97, 112, 112, 105, 110, 103, 32, 102, 114, 111, 109, 32, 115, 111, 114, 116, 32, 120, 32, 116, 111, 32, 115, 111, 114, 116, 32, 120, 46, 206, 20, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 219, 2, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 32, 101, 120, 97, 109, 112, 108, 101, 32, 105, 115, 32, 97, 32, 112, 97, 114, 101, 110, 116, 104, 101, 115, 105, 115, 32, 114, 117, 108, 101, 58, 248, 20, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 220, 2, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 96, 96, 108, 119, 98, 44, 110, 111, 95, 114, 117, 110, 14, 21, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 221, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 101, 120, 112, 114, 58, 28, 21, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 222, 2, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 32, 32, 32, 32, 112, 97, 114, 101, 110, 32, 61, 32, 34, 40, 34, 32, 101, 120, 112, 114, 32, 34, 41, 34, 61, 21, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 223, 2, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 96, 96, 73, 21, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 224, 2, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 81, 21, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 225, 2, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 110, 32, 116, 104, 97, 116, 32, 99, 97, 115, 101, 32, 121, 111, 117, 32, 100, 111, 110, 39, 116, 32, 119, 97, 110, 116, 32, 97, 32, 118, 97, 114, 105, 97, 110, 116, 32, 105, 110, 32, 116, 104, 101, 32, 101, 120, 112, 114, 32, 114, 117, 108, 101, 32, 116, 104, 97, 116, 39, 115, 32, 99, 97, 108, 108, 101, 100, 32, 34, 112, 97, 114, 101, 110, 34, 46, 167, 21, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 226, 2, 0, 0, 0, 0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 110, 115, 116, 101, 97, 100, 44, 32, 98, 121, 32, 97, 100, 100, 105, 110, 103, 32, 116, 104, 101, 32, 96, 105, 110, 106, 101, 99, 116, 105, 111, 110, 96, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 121, 111, 117, 32, 116, 101, 108, 108, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 32, 116, 104, 97, 116, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 32, 105, 115, 32, 112, 117, 114, 101, 108, 121, 32, 116, 111, 32, 99, 114, 101, 97, 116, 101, 32, 97, 32, 110, 101, 119, 32, 112, 114, 105, 111, 114, 105, 116, 121, 32, 108, 101, 118, 101, 108, 44, 42, 22, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 227, 2, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 98, 117, 116, 32, 116, 111, 32, 117, 115, 101, 32, 116, 104, 101, 32, 105, 110, 110, 101, 114, 32, 101, 120, 112, 114, 32, 97, 115, 32, 116, 104, 101, 32, 114, 101, 115, 117, 108, 116, 32, 111, 102, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 46, 32, 84, 104, 117, 115, 32, 116, 104, 101, 114, 101, 32, 119, 105, 108, 108, 32, 98, 101, 32, 110, 111, 32, 114, 117, 108, 101, 32, 99, 97, 108, 108, 101, 100, 32, 34, 112, 97, 114, 101, 110, 34, 46, 1, 0, 0, 0, 143, 22, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 228, 2, 0, 0, 0, 0, 0, 0, 147, 22, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 229, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 105, 110, 106, 101, 99, 116, 105, 111, 110, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 159, 22, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 231, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 159, 22, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 232, 2, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 160, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 233, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 161, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 234, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 162, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 235, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 106, 2, 0, 0, 0, 163, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 236, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 164, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 237, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 165, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 238, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 166, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 239, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 167, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 240, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 168, 22, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 241, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 177, 22, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 0, 0, 0, 242, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 177, 22, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 0, 243, 2, 0, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 100, 105, 115, 97, 98, 108, 101, 32, 112, 114, 101, 116, 116, 121, 32, 112, 114, 105, 110, 116, 105, 110, 103, 46, 32, 68, 111, 101, 115, 110, 39, 116, 32, 119, 111, 114, 107, 32, 119, 101, 108, 108, 32, 97, 110, 121, 119, 97, 121, 32, 115, 111, 32, 100, 111, 110, 39, 116, 32, 98, 111, 116, 104, 101, 114, 32, 119, 105, 116, 104, 32, 116, 104, 105, 115, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 1, 0, 0, 0, 12, 23, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 244, 2, 0, 0, 0, 0, 0, 0, 16, 23, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 245, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 110, 111, 45, 112, 114, 101, 116, 116, 121, 45, 112, 114, 105, 110, 116, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 34, 23, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 247, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 34, 23, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 248, 2, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 35, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 249, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 36, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 250, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 37, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 251, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 38, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 252, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 39, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 253, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 40, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 254, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 41, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 255, 2, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 42, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 43, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 44, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 45, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 3, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 46, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 4, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 47, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 5, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 48, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 6, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 49, 23, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 7, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 58, 23, 0, 0, 0, 0, 0, 0, 105, 1, 0, 0, 0, 0, 0, 0, 0, 8, 3, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 58, 23, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 9, 3, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 109, 97, 114, 107, 32, 97, 32, 114, 117, 108, 101, 32, 116, 111, 32, 97, 112, 112, 101, 97, 114, 32, 97, 115, 32, 106, 117, 115, 116, 32, 97, 32, 115, 116, 114, 105, 110, 103, 32, 105, 110, 32, 116, 104, 101, 32, 65, 83, 84, 46, 32, 87, 104, 97, 116, 101, 118, 101, 114, 32, 115, 116, 114, 117, 99, 116, 117, 114, 101, 32, 105, 115, 32, 102, 111, 117, 110, 100, 32, 119, 105, 116, 104, 105, 110, 44, 32, 116, 104, 114, 111, 119, 32, 105, 116, 32, 97, 119, 97, 121, 32, 97, 110, 100, 32, 106, 117, 115, 116, 32, 115, 116, 111, 114, 101, 182, 23, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 10, 3, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 119, 104, 97, 116, 101, 118, 101, 114, 32, 119, 97, 115, 32, 112, 97, 114, 115, 101, 100, 46, 211, 23, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 11, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 219, 23, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 0, 12, 3, 0, 0, 0, 0, 0, 0, 106, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 78, 111, 116, 101, 32, 116, 104, 97, 116, 32, 97, 110, 121, 32, 65, 83, 84, 32, 110, 111, 100, 101, 32, 104, 97, 115, 32, 116, 104, 101, 32, 46, 97, 115, 95, 115, 116, 114, 40, 41, 32, 109, 101, 116, 104, 111, 100, 32, 116, 111, 32, 114, 101, 113, 117, 101, 115, 116, 32, 116, 104, 105, 115, 32, 115, 116, 114, 105, 110, 103, 32, 114, 101, 112, 114, 101, 115, 101, 110, 116, 97, 116, 105, 111, 110, 32, 111, 102, 32, 116, 104, 101, 32, 110, 111, 100, 101, 46, 32, 70, 111, 114, 74, 24, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 13, 3, 0, 0, 0, 0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 115, 105, 110, 103, 108, 101, 45, 115, 116, 114, 105, 110, 103, 32, 114, 117, 108, 101, 115, 32, 116, 104, 105, 115, 32, 105, 115, 32, 115, 105, 109, 112, 108, 121, 32, 116, 104, 101, 32, 100, 101, 102, 97, 117, 108, 116, 46, 1, 0, 0, 0, 126, 24, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 14, 3, 0, 0, 0, 0, 0, 0, 130, 24, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 15, 3, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 115, 105, 110, 103, 108, 101, 45, 115, 116, 114, 105, 110, 103, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 146, 24, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 17, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 146, 24, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 18, 3, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 147, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 19, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 148, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 20, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 149, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 21, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 150, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 22, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 2, 0, 0, 0, 151, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 23, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 152, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 24, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 153, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 25, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 154, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 26, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 155, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 27, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 156, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 28, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 157, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 29, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 158, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 30, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 159, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 31, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 168, 24, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 32, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 168, 24, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 33, 3, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 100, 111, 110, 39, 116, 32, 97, 99, 99, 101, 112, 116, 32, 97, 110, 121, 32, 108, 97, 121, 111, 117, 116, 32, 99, 104, 97, 114, 97, 99, 116, 101, 114, 115, 32, 119, 104, 105, 108, 101, 32, 112, 97, 114, 115, 105, 110, 103, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 1, 0, 0, 0, 231, 24, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 34, 3, 0, 0, 0, 0, 0, 0, 235, 24, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 35, 3, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 110, 111, 45, 108, 97, 121, 111, 117, 116, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 247, 24, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 37, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 247, 24, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 38, 3, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 248, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 39, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 249, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 40, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 250, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 41, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 251, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 42, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 252, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 43, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 253, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 44, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 254, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 45, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 255, 24, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 46, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 47, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 0, 0, 9, 25, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 0, 48, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 9, 25, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 49, 3, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 115, 111, 114, 116, 115, 46, 32, 84, 104, 105, 115, 32, 115, 111, 114, 116, 32, 119, 105, 108, 108, 32, 110, 111, 116, 32, 97, 112, 112, 101, 97, 114, 32, 105, 110, 32, 97, 110, 121, 32, 111, 102, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 105, 116, 39, 115, 32, 117, 115, 101, 100, 32, 105, 110, 46, 106, 25, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 50, 3, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 117, 115, 101, 102, 117, 108, 32, 102, 111, 114, 32, 102, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 32, 116, 104, 101, 32, 91, 96, 110, 101, 119, 108, 105, 110, 101, 96, 93, 32, 114, 117, 108, 101, 32, 105, 110, 32, 116, 104, 105, 115, 32, 102, 105, 108, 101, 46, 1, 0, 0, 0, 168, 25, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 51, 3, 0, 0, 0, 0, 0, 0, 172, 25, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 52, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 104, 105, 100, 100, 101, 110, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 181, 25, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 54, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 181, 25, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 55, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 182, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 56, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 183, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 57, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 184, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 58, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 185, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 59, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 186, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 60, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 187, 25, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 61, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 196, 25, 0, 0, 0, 0, 0, 0, 174, 2, 0, 0, 0, 0, 0, 0, 0, 62, 3, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 196, 25, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 63, 3, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 105, 102, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 32, 109, 97, 110, 97, 103, 101, 115, 32, 116, 111, 32, 112, 97, 114, 115, 101, 44, 32, 100, 105, 115, 112, 108, 97, 121, 32, 97, 110, 32, 101, 114, 114, 111, 114, 32, 119, 105, 116, 104, 32, 116, 104, 101, 32, 97, 115, 115, 111, 99, 105, 97, 116, 101, 100, 32, 109, 101, 115, 115, 97, 103, 101, 24, 26, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 64, 3, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 105, 115, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 99, 97, 110, 32, 98, 101, 32, 112, 108, 97, 99, 101, 100, 32, 111, 110, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 40, 116, 104, 101, 115, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 116, 104, 101, 110, 32, 119, 111, 110, 39, 116, 32, 97, 99, 116, 117, 97, 108, 108, 121, 32, 101, 120, 105, 115, 116, 32, 105, 110, 32, 116, 104, 101, 32, 65, 83, 84, 44, 136, 26, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 0, 65, 3, 0, 0, 0, 0, 0, 0, 92, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 105, 102, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 105, 115, 32, 99, 104, 111, 115, 101, 110, 32, 97, 110, 32, 101, 114, 114, 111, 114, 32, 105, 115, 32, 101, 109, 105, 116, 116, 101, 100, 41, 44, 32, 111, 114, 32, 111, 110, 32, 115, 111, 114, 116, 115, 46, 32, 79, 110, 32, 97, 32, 115, 111, 114, 116, 32, 116, 104, 101, 32, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 233, 26, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 66, 3, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 104, 97, 115, 32, 97, 32, 115, 108, 105, 103, 104, 116, 108, 121, 32, 100, 105, 102, 102, 101, 114, 101, 110, 116, 32, 101, 102, 102, 101, 99, 116, 46, 32, 73, 102, 32, 110, 111, 110, 101, 32, 111, 102, 32, 116, 104, 101, 32, 118, 97, 114, 105, 97, 110, 116, 115, 32, 105, 110, 32, 116, 104, 101, 32, 115, 111, 114, 116, 32, 109, 97, 110, 97, 103, 101, 100, 32, 116, 111, 32, 112, 97, 114, 115, 101, 71, 27, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 67, 3, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 104, 101, 110, 32, 97, 108, 111, 110, 103, 115, 105, 100, 101, 32, 97, 110, 32, 34, 101, 120, 112, 101, 99, 116, 101, 100, 32, 46, 46, 46, 34, 44, 32, 116, 104, 97, 116, 32, 109, 101, 115, 115, 97, 103, 101, 32, 119, 105, 108, 108, 32, 98, 101, 32, 100, 105, 115, 112, 108, 97, 121, 101, 100, 32, 97, 115, 32, 119, 101, 108, 108, 46, 153, 27, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 68, 3, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 102, 32, 116, 104, 105, 115, 32, 115, 111, 114, 116, 32, 119, 97, 115, 32, 116, 104, 101, 32, 111, 110, 108, 121, 32, 112, 111, 115, 115, 105, 98, 105, 108, 105, 116, 121, 32, 97, 116, 32, 97, 32, 99, 101, 114, 116, 97, 105, 110, 32, 112, 111, 105, 110, 116, 44, 32, 111, 110, 108, 121, 32, 116, 104, 101, 32, 109, 101, 115, 115, 97, 103, 101, 32, 119, 105, 108, 108, 32, 98, 101, 32, 100, 105, 115, 112, 108, 97, 121, 101, 100, 46, 255, 27, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 69, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 7, 28, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 70, 3, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 96, 123, 116, 101, 120, 116, 125, 96, 32, 105, 110, 32, 116, 104, 101, 32, 109, 101, 115, 115, 97, 103, 101, 32, 105, 115, 32, 114, 101, 112, 108, 97, 99, 101, 100, 32, 98, 121, 32, 116, 104, 101, 32, 116, 101, 120, 116, 32, 116, 104, 97, 116, 32, 99, 97, 117, 115, 101, 100, 32, 116, 104, 101, 32, 101, 114, 114, 111, 114, 46, 1, 0, 0, 0, 82, 28, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 71, 3, 0, 0, 0, 0, 0, 0, 86, 28, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 72, 3, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 101, 114, 114, 111, 114, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 94, 28, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 74, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 94, 28, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 75, 3, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 95, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 76, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 96, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 77, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 97, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 78, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 98, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 79, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 99, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 80, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 8, 0, 0, 0, 102, 28, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 82, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 102, 28, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 83, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 103, 28, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 84, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 106, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 86, 3, 0, 0, 0, 0, 0, 0, 106, 28, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 87, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 119, 28, 0, 0, 0, 0, 0, 0, 30, 1, 0, 0, 0, 0, 0, 0, 0, 88, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 119, 28, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 89, 3, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 115, 111, 114, 116, 115, 46, 32, 87, 104, 101, 110, 32, 116, 104, 105, 115, 32, 115, 111, 114, 116, 32, 119, 97, 115, 32, 101, 120, 112, 101, 99, 116, 101, 100, 32, 98, 117, 116, 32, 99, 111, 117, 108, 100, 32, 110, 111, 116, 32, 98, 101, 32, 112, 97, 114, 115, 101, 100, 44, 32, 101, 114, 114, 111, 114, 115, 32, 115, 97, 121, 32, 116, 104, 97, 116, 32, 116, 104, 101, 32, 100, 105, 115, 112, 108, 97, 121, 32, 110, 97, 109, 101, 32, 119, 97, 115, 32, 101, 120, 112, 101, 99, 116, 101, 100, 44, 249, 28, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 0, 90, 3, 0, 0, 0, 0, 0, 0, 109, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 105, 110, 115, 116, 101, 97, 100, 32, 111, 102, 32, 108, 105, 115, 116, 105, 110, 103, 32, 101, 118, 101, 114, 121, 116, 104, 105, 110, 103, 32, 116, 104, 97, 116, 32, 99, 111, 117, 108, 100, 32, 115, 116, 97, 114, 116, 32, 116, 104, 105, 115, 32, 115, 111, 114, 116, 46, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 123, 100, 105, 115, 112, 108, 97, 121, 45, 110, 97, 109, 101, 58, 32, 34, 97, 110, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 34, 125, 96, 46, 1, 0, 0, 0, 103, 29, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 91, 3, 0, 0, 0, 0, 0, 0, 107, 29, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 92, 3, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 100, 105, 115, 112, 108, 97, 121, 45, 110, 97, 109, 101, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 122, 29, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 94, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 122, 29, 0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 95, 3, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 123, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 96, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 124, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 97, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 125, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 98, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 126, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 99, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 127, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 100, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 128, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 101, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 129, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 102, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 130, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 103, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 131, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 104, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 132, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 105, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 133, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 106, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 134, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 107, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 8, 0, 0, 0, 137, 29, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 109, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 137, 29, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 110, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 138, 29, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 111, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 141, 29, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 113, 3, 0, 0, 0, 0, 0, 0, 141, 29, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 114, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 154, 29, 0, 0, 0, 0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 115, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 154, 29, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 0, 116, 3, 0, 0, 0, 0, 0, 0, 107, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 46, 32, 84, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 112, 97, 114, 115, 101, 115, 32, 97, 115, 32, 117, 115, 117, 97, 108, 44, 32, 98, 117, 116, 32, 97, 32, 119, 97, 114, 110, 105, 110, 103, 32, 119, 105, 116, 104, 32, 116, 104, 101, 32, 97, 115, 115, 111, 99, 105, 97, 116, 101, 100, 32, 109, 101, 115, 115, 97, 103, 101, 10, 30, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 0, 117, 3, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 105, 115, 32, 103, 105, 118, 101, 110, 32, 102, 111, 114, 32, 101, 118, 101, 114, 121, 32, 112, 108, 97, 99, 101, 32, 119, 104, 101, 114, 101, 32, 105, 116, 32, 119, 97, 115, 32, 117, 115, 101, 100, 46, 1, 0, 0, 0, 58, 30, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 118, 3, 0, 0, 0, 0, 0, 0, 62, 30, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 119, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 119, 97, 114, 110, 105, 110, 103, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 72, 30, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 121, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 72, 30, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 122, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 73, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 123, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 2, 0, 0, 0, 74, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 124, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 75, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 125, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 76, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 126, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 77, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 127, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 78, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 128, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 79, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 129, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 103, 8, 0, 0, 0, 82, 30, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 131, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 82, 30, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 132, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 83, 30, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 133, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 86, 30, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 135, 3, 0, 0, 0, 0, 0, 0, 86, 30, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 136, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 99, 30, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 137, 3, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 99, 30, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 138, 3, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 46, 32, 84, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 112, 97, 114, 115, 101, 115, 32, 97, 115, 32, 117, 115, 117, 97, 108, 44, 32, 98, 117, 116, 32, 97, 32, 119, 97, 114, 110, 105, 110, 103, 32, 105, 115, 32, 103, 105, 118, 101, 110, 32, 116, 104, 97, 116, 32, 105, 116, 32, 105, 115, 203, 30, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 0, 139, 3, 0, 0, 0, 0, 0, 0, 93, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 100, 101, 112, 114, 101, 99, 97, 116, 101, 100, 44, 32, 119, 105, 116, 104, 32, 116, 104, 101, 32, 97, 115, 115, 111, 99, 105, 97, 116, 101, 100, 32, 109, 101, 115, 115, 97, 103, 101, 46, 32, 70, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 44, 32, 96, 123, 100, 101, 112, 114, 101, 99, 97, 116, 101, 100, 58, 32, 34, 117, 115, 101, 32, 96, 102, 110, 96, 32, 105, 110, 115, 116, 101, 97, 100, 34, 125, 96, 46, 1, 0, 0, 0, 41, 31, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 140, 3, 0, 0, 0, 0, 0, 0, 45, 31, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 141, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 100, 101, 112, 114, 101, 99, 97, 116, 101, 100, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 58, 31, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 143, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 58, 31, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 144, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 59, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 145, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 60, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 146, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 61, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 147, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 62, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 148, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 63, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 149, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 64, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 150, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 65, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 151, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 66, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 152, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 67, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 153, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 68, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 154, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 8, 0, 0, 0, 71, 31, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 156, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 71, 31, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 157, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 72, 31, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 158, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 75, 31, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 160, 3, 0, 0, 0, 0, 0, 0, 75, 31, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 161, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 116, 114, 105, 110, 103, 0, 0, 0, 0, 0, 88, 31, 0, 0, 0, 0, 0, 0, 94, 1, 0, 0, 0, 0, 0, 0, 0, 162, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 88, 31, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 0, 163, 3, 0, 0, 0, 0, 0, 0, 69, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 107, 101, 115, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 111, 102, 32, 116, 104, 105, 115, 32, 114, 117, 108, 101, 32, 103, 101, 110, 101, 114, 97, 116, 101, 32, 97, 115, 32, 112, 97, 114, 116, 32, 111, 102, 32, 97, 110, 111, 116, 104, 101, 114, 32, 114, 117, 108, 101, 46, 162, 31, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 164, 3, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 105, 115, 32, 104, 97, 115, 32, 111, 110, 101, 32, 109, 97, 106, 111, 114, 32, 114, 101, 113, 117, 105, 114, 101, 109, 101, 110, 116, 46, 32, 73, 102, 32, 97, 32, 105, 115, 32, 112, 97, 114, 116, 45, 111, 102, 32, 98, 32, 116, 104, 101, 110, 225, 31, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 165, 3, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 98, 32, 109, 117, 115, 116, 32, 104, 97, 118, 101, 32, 97, 32, 114, 117, 108, 101, 32, 108, 105, 107, 101, 32, 96, 97, 61, 97, 59, 96, 32, 40, 97, 108, 115, 111, 32, 119, 114, 105, 116, 116, 101, 110, 32, 97, 115, 32, 106, 117, 115, 116, 32, 96, 97, 59, 96, 41, 32, 116, 111, 32, 97, 108, 108, 111, 119, 45, 32, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 166, 3, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 121, 32, 97, 32, 116, 111, 32, 97, 112, 112, 101, 97, 114, 32, 105, 110, 32, 98, 74, 32, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 167, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 82, 32, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 168, 3, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 73, 110, 106, 101, 99, 116, 105, 111, 110, 115, 32, 111, 110, 32, 114, 117, 108, 101, 32, 97, 32, 98, 101, 99, 111, 109, 101, 32, 105, 110, 106, 101, 99, 116, 105, 111, 110, 115, 32, 105, 110, 116, 111, 32, 114, 117, 108, 101, 32, 98, 32, 116, 111, 111, 46, 1, 0, 0, 0, 142, 32, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 169, 3, 0, 0, 0, 0, 0, 0, 146, 32, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 170, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 112, 97, 114, 116, 45, 111, 102, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 156, 32, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 172, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 156, 32, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 173, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 157, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 174, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 158, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 175, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 159, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 176, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 160, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 177, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 161, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 178, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 45, 2, 0, 0, 0, 162, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 179, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 163, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 180, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 8, 0, 0, 0, 166, 32, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 182, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 166, 32, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 183, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 167, 32, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 184, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 170, 32, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 186, 3, 0, 0, 0, 0, 0, 0, 170, 32, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 187, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 0, 0, 0, 0, 0, 187, 32, 0, 0, 0, 0, 0, 0, 85, 1, 0, 0, 0, 0, 0, 0, 0, 188, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 187, 32, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 189, 3, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 110, 108, 121, 32, 101, 110, 97, 98, 108, 101, 32, 116, 104, 105, 115, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 119, 104, 101, 110, 32, 116, 104, 101, 32, 102, 101, 97, 116, 117, 114, 101, 32, 119, 105, 116, 104, 32, 116, 104, 105, 115, 32, 110, 97, 109, 101, 32, 105, 115, 32, 101, 110, 97, 98, 108, 101, 100, 32, 119, 104, 105, 108, 101, 32, 112, 97, 114, 115, 105, 110, 103, 25, 33, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 0, 190, 3, 0, 0, 0, 0, 0, 0, 102, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 40, 115, 101, 101, 32, 96, 80, 97, 114, 115, 101, 79, 112, 116, 105, 111, 110, 115, 58, 58, 119, 105, 116, 104, 95, 102, 101, 97, 116, 117, 114, 101, 96, 41, 46, 32, 87, 104, 101, 110, 32, 116, 104, 101, 32, 102, 101, 97, 116, 117, 114, 101, 32, 105, 115, 32, 110, 111, 116, 32, 101, 110, 97, 98, 108, 101, 100, 44, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 110, 101, 118, 101, 114, 32, 112, 97, 114, 115, 101, 115, 46, 132, 33, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 0, 191, 3, 0, 0, 0, 0, 0, 0, 99, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 105, 115, 32, 109, 97, 107, 101, 115, 32, 105, 116, 32, 112, 111, 115, 115, 105, 98, 108, 101, 32, 116, 111, 32, 104, 97, 118, 101, 32, 97, 32, 115, 105, 110, 103, 108, 101, 32, 103, 114, 97, 109, 109, 97, 114, 32, 102, 111, 114, 32, 115, 101, 118, 101, 114, 97, 108, 32, 118, 101, 114, 115, 105, 111, 110, 115, 32, 111, 114, 32, 100, 105, 97, 108, 101, 99, 116, 115, 32, 111, 102, 32, 97, 32, 108, 97, 110, 103, 117, 97, 103, 101, 46, 1, 0, 0, 0, 232, 33, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 192, 3, 0, 0, 0, 0, 0, 0, 236, 33, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 193, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 102, 101, 97, 116, 117, 114, 101, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 246, 33, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 195, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 246, 33, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 196, 3, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 247, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 197, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 102, 2, 0, 0, 0, 248, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 198, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 249, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 199, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 250, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 200, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 251, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 201, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 252, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 202, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 253, 33, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 203, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 8, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 205, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 206, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 1, 34, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 207, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 4, 34, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 209, 3, 0, 0, 0, 0, 0, 0, 4, 34, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 210, 3, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 0, 0, 0, 0, 0, 21, 34, 0, 0, 0, 0, 0, 0, 91, 1, 0, 0, 0, 0, 0, 0, 0, 211, 3, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 21, 34, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 0, 212, 3, 0, 0, 0, 0, 0, 0, 100, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 110, 110, 111, 116, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 115, 111, 114, 116, 115, 46, 32, 87, 104, 101, 116, 104, 101, 114, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 32, 109, 101, 109, 111, 105, 122, 101, 115, 32, 116, 104, 101, 32, 114, 101, 115, 117, 108, 116, 115, 32, 111, 102, 32, 116, 104, 105, 115, 32, 115, 111, 114, 116, 44, 32, 96, 97, 108, 119, 97, 121, 115, 96, 32, 111, 114, 32, 96, 110, 101, 118, 101, 114, 96, 46, 126, 34, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 0, 213, 3, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 66, 121, 32, 100, 101, 102, 97, 117, 108, 116, 44, 32, 115, 109, 97, 108, 108, 32, 115, 111, 114, 116, 115, 32, 116, 104, 97, 116, 32, 111, 110, 108, 121, 32, 117, 115, 101, 32, 111, 116, 104, 101, 114, 32, 115, 109, 97, 108, 108, 32, 115, 111, 114, 116, 115, 32, 40, 108, 105, 107, 101, 32, 116, 104, 101, 32, 115, 111, 114, 116, 115, 32, 111, 102, 32, 116, 111, 107, 101, 110, 115, 41, 32, 97, 114, 101, 32, 110, 111, 116, 32, 109, 101, 109, 111, 105, 122, 101, 100, 44, 235, 34, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 0, 214, 3, 0, 0, 0, 0, 0, 0, 97, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 115, 105, 110, 99, 101, 32, 112, 97, 114, 115, 105, 110, 103, 32, 116, 104, 101, 109, 32, 97, 103, 97, 105, 110, 32, 105, 115, 32, 102, 97, 115, 116, 101, 114, 32, 116, 104, 97, 110, 32, 108, 111, 111, 107, 105, 110, 103, 32, 116, 104, 101, 109, 32, 117, 112, 46, 32, 82, 101, 99, 117, 114, 115, 105, 118, 101, 32, 115, 111, 114, 116, 115, 32, 97, 114, 101, 32, 97, 108, 119, 97, 121, 115, 32, 109, 101, 109, 111, 105, 122, 101, 100, 46, 1, 0, 0, 0, 77, 35, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 215, 3, 0, 0, 0, 0, 0, 0, 81, 35, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 216, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 109, 101, 109, 111, 3, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 88, 35, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 218, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 88, 35, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 219, 3, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 89, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 220, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 90, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 221, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 91, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 222, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 92, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 223, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 8, 0, 0, 0, 95, 35, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 225, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 95, 35, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 226, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 96, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 227, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 11, 0, 0, 0, 99, 35, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 229, 3, 0, 0, 0, 0, 0, 0, 99, 35, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 230, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 109, 101, 109, 111, 45, 112, 111, 108, 105, 99, 121, 0, 1, 0, 0, 0, 113, 35, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 231, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 113, 35, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 232, 3, 0, 0, 0, 0, 0, 0, 113, 35, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 233, 3, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 109, 101, 109, 111, 45, 112, 111, 108, 105, 99, 121, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 126, 35, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 234, 3, 0, 0, 0, 0, 0, 0, 130, 35, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 235, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 97, 108, 119, 97, 121, 115, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 139, 35, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 237, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 139, 35, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 238, 3, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 140, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 239, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 141, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 240, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 108, 2, 0, 0, 0, 142, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 241, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 2, 0, 0, 0, 143, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 242, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 144, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 243, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 2, 0, 0, 0, 145, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 244, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 0, 1, 0, 0, 0, 149, 35, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 245, 3, 0, 0, 0, 0, 0, 0, 153, 35, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 246, 3, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 110, 101, 118, 101, 114, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 161, 35, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 248, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 161, 35, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 249, 3, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 162, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 250, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 163, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 251, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 164, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 252, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 118, 2, 0, 0, 0, 165, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 253, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 166, 35, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 254, 3, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 0, 1, 0, 0, 0, 171, 35, 0, 0, 0, 0, 0, 0, 168, 2, 0, 0, 0, 0, 0, 0, 0, 255, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 171, 35, 0, 0, 0, 0, 0, 0, 168, 2, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 171, 35, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 1, 4, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 91, 96, 115, 111, 114, 116, 96, 93, 32, 99, 111, 110, 115, 105, 115, 116, 115, 32, 111, 102, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 46, 32, 65, 32, 115, 111, 114, 116, 32, 119, 105, 108, 108, 32, 116, 114, 121, 32, 101, 97, 99, 104, 32, 111, 102, 32, 116, 104, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 253, 35, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 2, 4, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 102, 114, 111, 109, 32, 116, 111, 112, 32, 116, 111, 32, 98, 111, 116, 116, 111, 109, 44, 32, 97, 110, 100, 32, 117, 115, 101, 32, 116, 104, 101, 32, 102, 105, 114, 115, 116, 32, 111, 110, 101, 32, 116, 104, 97, 116, 32, 115, 117, 99, 99, 101, 115, 115, 102, 117, 108, 108, 121, 32, 112, 97, 114, 115, 101, 115, 32, 116, 104, 101, 32, 105, 110, 112, 117, 116, 32, 115, 116, 114, 105, 110, 103, 46, 86, 36, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 90, 36, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 4, 4, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 99, 111, 110, 115, 105, 115, 116, 115, 32, 111, 102, 32, 97, 32, 110, 97, 109, 101, 44, 32, 102, 111, 108, 108, 111, 119, 101, 100, 32, 98, 121, 32, 97, 110, 32, 91, 96, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 96, 93, 158, 36, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 5, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 162, 36, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 6, 4, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 67, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 32, 99, 97, 110, 32, 104, 97, 118, 101, 32, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 115, 32, 117, 115, 105, 110, 103, 32, 116, 114, 105, 112, 108, 101, 32, 115, 108, 97, 115, 104, 101, 115, 46, 1, 0, 0, 0, 223, 36, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 0, 0, 0, 0, 7, 4, 0, 0, 0, 0, 0, 0, 223, 36, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 8, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 1, 236, 36, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 9, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 237, 36, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 10, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 244, 36, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 11, 4, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 245, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 12, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 109, 2, 0, 0, 0, 246, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 13, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 247, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 14, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 107, 2, 0, 0, 0, 248, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 15, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 249, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 16, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 250, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 17, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 251, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 18, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 252, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 19, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 253, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 20, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 254, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 21, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 255, 36, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 22, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 23, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 1, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 24, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 2, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 25, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 3, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 26, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 4, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 27, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 5, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 28, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 6, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 29, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 7, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 30, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 8, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 31, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 9, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 32, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 10, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 33, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 117, 2, 0, 0, 0, 11, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 34, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 12, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 35, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 13, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 36, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 111, 2, 0, 0, 0, 14, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 37, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 15, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 38, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 16, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 39, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 17, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 40, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 18, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 41, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 19, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 42, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 20, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 43, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 21, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 44, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 2, 0, 0, 0, 22, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 45, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 23, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 46, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 24, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 47, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 25, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 48, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 26, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 49, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 27, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 50, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 28, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 51, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 29, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 52, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 30, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 53, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 96, 2, 0, 0, 0, 31, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 54, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 32, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 55, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 33, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 56, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 34, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 57, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 35, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 58, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 36, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 59, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 37, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 60, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 38, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 61, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 39, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 62, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 40, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 63, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 41, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 64, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 42, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 65, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 43, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 66, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 44, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 67, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 45, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 68, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 46, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 69, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 100, 2, 0, 0, 0, 47, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 70, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 48, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 71, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 119, 2, 0, 0, 0, 49, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 72, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 50, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 73, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 51, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 74, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 104, 2, 0, 0, 0, 52, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 75, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 53, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 76, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 52, 2, 0, 0, 0, 54, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 77, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 55, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 78, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 56, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 79, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 112, 2, 0, 0, 0, 57, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 80, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 58, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 81, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 99, 2, 0, 0, 0, 59, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 82, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 101, 2, 0, 0, 0, 60, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 83, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 61, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 84, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 46, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 65, 37, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 85, 4, 0, 0, 0, 0, 0, 0, 69, 37, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 86, 4, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 45, 100, 111, 99, 117, 109, 101, 110, 116, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 94, 37, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 87, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 94, 37, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 88, 4, 0, 0, 0, 0, 0, 0, 94, 37, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 89, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 11, 0, 0, 0, 107, 37, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 91, 4, 0, 0, 0, 0, 0, 0, 107, 37, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 92, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 0, 1, 0, 0, 0, 120, 37, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 93, 4, 0, 0, 0, 0, 0, 0, 124, 37, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 94, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 7, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 138, 37, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 96, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 138, 37, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 97, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 139, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 98, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 140, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 99, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 141, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 100, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 142, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 101, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 11, 0, 0, 0, 145, 37, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 103, 4, 0, 0, 0, 0, 0, 0, 145, 37, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 104, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 156, 37, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 106, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 156, 37, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 107, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 157, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 108, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 160, 37, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 109, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 160, 37, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 110, 4, 0, 0, 0, 0, 0, 0, 160, 37, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 111, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 8, 0, 0, 0, 172, 37, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 113, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 172, 37, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 114, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 173, 37, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 115, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 176, 37, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 116, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 176, 37, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 117, 4, 0, 0, 0, 0, 0, 0, 176, 37, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 118, 4, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 11, 0, 0, 0, 193, 37, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 120, 4, 0, 0, 0, 0, 0, 0, 193, 37, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 121, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 0, 0, 0, 0, 206, 37, 0, 0, 0, 0, 0, 0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 122, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 206, 37, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 123, 4, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 117, 115, 105, 110, 103, 32, 96, 116, 101, 115, 116, 59, 96, 32, 97, 115, 32, 97, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 32, 100, 101, 115, 117, 103, 97, 114, 115, 32, 116, 111, 32, 96, 116, 101, 115, 116, 32, 61, 32, 116, 101, 115, 116, 59, 96, 1, 0, 0, 0, 12, 38, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 124, 4, 0, 0, 0, 0, 0, 0, 16, 38, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 125, 4, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 45, 98, 97, 114, 101, 5, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 35, 38, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 127, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 35, 38, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 128, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 36, 38, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 129, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 37, 38, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 130, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 38, 38, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 131, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 2, 0, 0, 0, 39, 38, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 132, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 11, 0, 0, 0, 42, 38, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 134, 4, 0, 0, 0, 0, 0, 0, 42, 38, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 135, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 53, 38, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 137, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 53, 38, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 138, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 54, 38, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 139, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 57, 38, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 140, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 57, 38, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 141, 4, 0, 0, 0, 0, 0, 0, 57, 38, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 142, 4, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 11, 0, 0, 0, 74, 38, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 144, 4, 0, 0, 0, 0, 0, 0, 74, 38, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 145, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 1, 0, 0, 0, 84, 38, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 146, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 84, 38, 0, 0, 0, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 147, 4, 0, 0, 0, 0, 0, 0, 84, 38, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 148, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 1, 93, 38, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 149, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 94, 38, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 150, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 102, 38, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 151, 4, 0, 0, 0, 0, 0, 0, 106, 38, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 152, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 117, 110, 105, 120, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 113, 38, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 154, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 113, 38, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 155, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 114, 38, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 156, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 1, 0, 0, 0, 119, 38, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 157, 4, 0, 0, 0, 0, 0, 0, 123, 38, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 158, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 119, 105, 110, 100, 111, 119, 115, 1, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 133, 38, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 160, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 133, 38, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 161, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 134, 38, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 162, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 136, 38, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 163, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 1, 0, 0, 0, 142, 38, 0, 0, 0, 0, 0, 0, 180, 2, 0, 0, 0, 0, 0, 0, 0, 164, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 38, 0, 0, 0, 0, 0, 0, 180, 2, 0, 0, 0, 0, 0, 0, 0, 165, 4, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 142, 38, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 166, 4, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 115, 111, 114, 116, 32, 105, 115, 32, 97, 32, 103, 114, 111, 117, 112, 32, 111, 102, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 115, 46, 32, 83, 101, 101, 32, 91, 96, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 96, 93, 32, 102, 111, 114, 32, 109, 111, 114, 101, 32, 100, 101, 116, 97, 105, 108, 115, 46, 219, 38, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 167, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 223, 38, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 168, 4, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 101, 114, 101, 32, 105, 115, 32, 111, 110, 101, 32, 115, 112, 101, 99, 105, 97, 108, 32, 115, 111, 114, 116, 44, 32, 99, 97, 108, 108, 101, 100, 32, 96, 108, 97, 121, 111, 117, 116, 96, 46, 32, 73, 116, 32, 99, 97, 110, 32, 98, 101, 32, 117, 115, 101, 100, 32, 116, 111, 32, 100, 101, 110, 111, 116, 101, 40, 39, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 169, 4, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 116, 104, 97, 116, 32, 97, 32, 103, 114, 97, 109, 109, 97, 114, 32, 115, 104, 111, 117, 108, 100, 32, 102, 111, 114, 32, 101, 120, 97, 109, 112, 108, 101, 32, 105, 103, 110, 111, 114, 101, 32, 99, 101, 114, 116, 97, 105, 110, 32, 119, 104, 105, 116, 101, 115, 112, 97, 99, 101, 32, 111, 114, 32, 99, 111, 109, 109, 101, 110, 116, 115, 46, 117, 39, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 170, 4, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 66, 101, 116, 119, 101, 101, 110, 32, 101, 118, 101, 114, 121, 32, 112, 97, 114, 116, 32, 111, 102, 32, 97, 110, 32, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 44, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 32, 119, 105, 108, 108, 32, 97, 116, 116, 101, 109, 112, 116, 32, 116, 111, 32, 112, 97, 114, 115, 101, 32, 116, 104, 101, 195, 39, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 171, 4, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 108, 97, 121, 111, 117, 116, 32, 115, 111, 114, 116, 32, 48, 32, 111, 114, 32, 109, 111, 114, 101, 32, 116, 105, 109, 101, 115, 44, 32, 97, 110, 100, 32, 116, 104, 114, 111, 119, 32, 97, 119, 97, 121, 32, 119, 104, 97, 116, 101, 118, 101, 114, 32, 105, 116, 32, 112, 97, 114, 115, 101, 115, 46, 7, 40, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 172, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 11, 40, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 173, 4, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 83, 111, 114, 116, 115, 32, 99, 97, 110, 32, 104, 97, 118, 101, 32, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 115, 32, 117, 115, 105, 110, 103, 32, 116, 114, 105, 112, 108, 101, 32, 115, 108, 97, 115, 104, 101, 115, 46, 1, 0, 0, 0, 65, 40, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 174, 4, 0, 0, 0, 0, 0, 0, 65, 40, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 175, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 0, 3, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 71, 40, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 176, 4, 0, 0, 0, 0, 0, 0, 75, 40, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 177, 4, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 100, 111, 99, 117, 109, 101, 110, 116, 101, 100, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 93, 40, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 178, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 93, 40, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 179, 4, 0, 0, 0, 0, 0, 0, 93, 40, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 180, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 11, 0, 0, 0, 106, 40, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 182, 4, 0, 0, 0, 0, 0, 0, 106, 40, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 183, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 0, 1, 0, 0, 0, 112, 40, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 184, 4, 0, 0, 0, 0, 0, 0, 116, 40, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 185, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 5, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 123, 40, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 187, 4, 0, 0, 0, 0, 0, 0, 123, 40, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 188, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 134, 40, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 190, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 134, 40, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 191, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 135, 40, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 192, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 58, 2, 0, 0, 0, 138, 40, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 193, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 138, 40, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 194, 4, 0, 0, 0, 0, 0, 0, 138, 40, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 195, 4, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 11, 0, 0, 0, 155, 40, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 197, 4, 0, 0, 0, 0, 0, 0, 155, 40, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 198, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 0, 0, 0, 163, 40, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 199, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 163, 40, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 200, 4, 0, 0, 0, 0, 0, 0, 163, 40, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 201, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 0, 0, 0, 0, 0, 182, 40, 0, 0, 0, 0, 0, 0, 140, 0, 0, 0, 0, 0, 0, 0, 0, 202, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 182, 40, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 203, 4, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 87, 104, 101, 110, 32, 97, 32, 115, 111, 114, 116, 32, 104, 97, 115, 32, 111, 110, 108, 121, 32, 111, 110, 101, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 44, 32, 105, 116, 32, 104, 97, 115, 32, 115, 105, 109, 112, 108, 101, 114, 32, 115, 121, 110, 116, 97, 120, 46, 1, 0, 0, 0, 247, 40, 0, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0, 0, 0, 0, 0, 204, 4, 0, 0, 0, 0, 0, 0, 251, 40, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 205, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 115, 105, 110, 103, 108, 101, 6, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 9, 41, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 207, 4, 0, 0, 0, 0, 0, 0, 9, 41, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 208, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 20, 41, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 210, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 20, 41, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 211, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 21, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 212, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 61, 1, 0, 0, 0, 24, 41, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 213, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 24, 41, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 214, 4, 0, 0, 0, 0, 0, 0, 24, 41, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 215, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 101, 120, 112, 114, 101, 115, 115, 105, 111, 110, 8, 0, 0, 0, 36, 41, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 217, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 36, 41, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 218, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 37, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 219, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 2, 0, 0, 0, 40, 41, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 220, 4, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 40, 41, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 221, 4, 0, 0, 0, 0, 0, 0, 40, 41, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 222, 4, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 97, 110, 110, 111, 116, 97, 116, 105, 111, 110, 45, 108, 105, 115, 116, 11, 0, 0, 0, 57, 41, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 224, 4, 0, 0, 0, 0, 0, 0, 57, 41, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 225, 4, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 110, 101, 119, 108, 105, 110, 101, 0, 1, 0, 0, 0, 67, 41, 0, 0, 0, 0, 0, 0, 195, 1, 0, 0, 0, 0, 0, 0, 0, 226, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 41, 0, 0, 0, 0, 0, 0, 195, 1, 0, 0, 0, 0, 0, 0, 0, 227, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 67, 41, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 228, 4, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 79, 116, 104, 101, 114, 32, 116, 111, 112, 45, 108, 101, 118, 101, 108, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 115, 32, 116, 104, 97, 116, 32, 97, 114, 101, 32, 110, 111, 116, 32, 115, 111, 114, 116, 115, 1, 0, 0, 0, 117, 41, 0, 0, 0, 0, 0, 0, 145, 1, 0, 0, 0, 0, 0, 0, 0, 229, 4, 0, 0, 0, 0, 0, 0, 117, 41, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 230, 4, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 109, 101, 116, 97, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 127, 41, 0, 0, 0, 0, 0, 0, 95, 0, 0, 0, 0, 0, 0, 0, 0, 231, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 127, 41, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 232, 4, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 84, 104, 101, 32, 115, 116, 97, 114, 116, 105, 110, 103, 32, 114, 117, 108, 101, 32, 105, 115, 32, 97, 110, 32, 105, 110, 100, 105, 99, 97, 116, 105, 111, 110, 32, 102, 111, 114, 32, 116, 104, 101, 32, 112, 97, 114, 115, 101, 114, 1, 0, 0, 0, 181, 41, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 233, 4, 0, 0, 0, 0, 0, 0, 185, 41, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 234, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 115, 116, 97, 114, 116, 4, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 193, 41, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 236, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 193, 41, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 237, 4, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 194, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 238, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 115, 2, 0, 0, 0, 195, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 239, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 2, 0, 0, 0, 196, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 240, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 197, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 241, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 198, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 242, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 8, 0, 0, 0, 201, 41, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 244, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 201, 41, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 245, 4, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 202, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 246, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 203, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 247, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 11, 0, 0, 0, 206, 41, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 249, 4, 0, 0, 0, 0, 0, 0, 206, 41, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 250, 4, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 105, 100, 101, 110, 116, 105, 102, 105, 101, 114, 8, 0, 0, 0, 217, 41, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 252, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 217, 41, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 253, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 218, 41, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 254, 4, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 226, 41, 0, 0, 0, 0, 0, 0, 36, 1, 0, 0, 0, 0, 0, 0, 0, 255, 4, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 226, 41, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 77, 97, 107, 101, 115, 32, 116, 104, 105, 115, 32, 97, 32, 103, 114, 97, 109, 109, 97, 114, 32, 102, 111, 114, 32, 98, 105, 110, 97, 114, 121, 32, 102, 111, 114, 109, 97, 116, 115, 44, 32, 119, 104, 105, 99, 104, 32, 112, 97, 114, 115, 101, 115, 32, 116, 104, 101, 32, 98, 121, 116, 101, 115, 32, 111, 102, 32, 97, 32, 115, 111, 117, 114, 99, 101, 32, 102, 105, 108, 101, 59, 42, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 1, 5, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 109, 97, 100, 101, 32, 119, 105, 116, 104, 32, 96, 83, 111, 117, 114, 99, 101, 70, 105, 108, 101, 58, 58, 110, 101, 119, 95, 98, 121, 116, 101, 115, 96, 46, 32, 73, 110, 32, 97, 32, 98, 105, 110, 97, 114, 121, 32, 103, 114, 97, 109, 109, 97, 114, 44, 32, 96, 92, 120, 96, 32, 101, 115, 99, 97, 112, 101, 115, 32, 109, 97, 116, 99, 104, 32, 98, 121, 116, 101, 115, 44, 32, 97, 110, 100, 153, 42, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 2, 5, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 102, 105, 120, 101, 100, 45, 119, 105, 100, 116, 104, 32, 105, 110, 116, 101, 103, 101, 114, 115, 32, 108, 105, 107, 101, 32, 96, 117, 56, 96, 32, 97, 110, 100, 32, 96, 117, 49, 54, 108, 101, 96, 32, 99, 97, 110, 32, 98, 101, 32, 117, 115, 101, 100, 32, 119, 105, 116, 104, 111, 117, 116, 32, 100, 101, 102, 105, 110, 105, 110, 103, 32, 116, 104, 101, 109, 46, 1, 0, 0, 0, 235, 42, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 3, 5, 0, 0, 0, 0, 0, 0, 239, 42, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 4, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 98, 105, 110, 97, 114, 121, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 248, 42, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 6, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 248, 42, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 7, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 249, 42, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 8, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 98, 2, 0, 0, 0, 250, 42, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 9, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 105, 2, 0, 0, 0, 251, 42, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 10, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 2, 0, 0, 0, 252, 42, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 11, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 97, 2, 0, 0, 0, 253, 42, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 12, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 2, 0, 0, 0, 254, 42, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 13, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 121, 8, 0, 0, 0, 1, 43, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 15, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 43, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 16, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 2, 43, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 17, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 59, 0, 1, 0, 0, 0, 7, 43, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 18, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 7, 43, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 19, 5, 0, 0, 0, 0, 0, 0, 7, 43, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 20, 5, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 111, 114, 45, 109, 101, 116, 97, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 21, 43, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 21, 5, 0, 0, 0, 0, 0, 0, 25, 43, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 22, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 109, 101, 116, 97, 0, 2, 0, 0, 0, 31, 43, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 23, 5, 0, 0, 0, 0, 0, 0, 35, 43, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 24, 5, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 0, 1, 0, 0, 0, 42, 43, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 25, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 42, 43, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 26, 5, 0, 0, 0, 0, 0, 0, 42, 43, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 27, 5, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 112, 114, 111, 103, 114, 97, 109, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 43, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 28, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 52, 43, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 29, 5, 0, 0, 0, 0, 0, 0, 52, 43, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 0, 30, 5, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 0, 0, 115, 111, 114, 116, 45, 111, 114, 45, 109, 101, 116, 97, 0, 1, 0, 0, 0, 68, 43, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 0, 31, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 43, 0, 0, 0, 0, 0, 0, 13, 1, 0, 0, 0, 0, 0, 0, 0, 32, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 68, 43, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 33, 5, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 65, 32, 100, 111, 99, 117, 109, 101, 110, 116, 97, 116, 105, 111, 110, 32, 99, 111, 109, 109, 101, 110, 116, 32, 40, 100, 111, 99, 32, 99, 111, 109, 109, 101, 110, 116, 41, 32, 105, 115, 32, 97, 108, 119, 97, 121, 115, 32, 97, 115, 115, 111, 99, 105, 97, 116, 101, 100, 32, 119, 105, 116, 104, 32, 97, 32, 115, 111, 114, 116, 143, 43, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 34, 5, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 111, 114, 32, 99, 111, 110, 115, 116, 114, 117, 99, 116, 111, 114, 46, 32, 73, 116, 32, 100, 111, 99, 117, 109, 101, 110, 116, 115, 32, 119, 104, 97, 116, 32, 105, 116, 32, 100, 111, 101, 115, 46, 32, 68, 111, 99, 32, 99, 111, 109, 109, 101, 110, 116, 115, 32, 119, 105, 108, 108, 32, 98, 101, 32, 105, 110, 116, 101, 114, 112, 114, 101, 116, 101, 100, 223, 43, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 35, 5, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 47, 47, 47, 32, 97, 110, 100, 32, 119, 105, 108, 108, 32, 98, 101, 32, 112, 117, 116, 32, 111, 110, 32, 116, 104, 101, 32, 103, 101, 110, 101, 114, 97, 116, 101, 100, 32, 116, 121, 112, 101, 115, 32, 100, 117, 114, 105, 110, 103, 32, 99, 111, 100, 101, 103, 101, 110, 46, 2, 0, 0, 0, 26, 44, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 36, 5, 0, 0, 0, 0, 0, 0, 26, 44, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 37, 5, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 100, 111, 99, 45, 99, 111, 109, 109, 101, 110, 116, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 40, 44, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 39, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 40, 44, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 40, 5, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 41, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 41, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 2, 0, 0, 0, 42, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 42, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 2, 0, 0, 0, 43, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 43, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 46, 44, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 44, 5, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 46, 44, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 45, 5, 0, 0, 0, 0, 0, 0, 46, 44, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 46, 5, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 48, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 47, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 48, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 48, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 54, 44, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 49, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 55, 44, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 50, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 66, 44, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 51, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 82, 44, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 52, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 82, 44, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 53, 5, 0, 0, 0, 0, 0, 0, 82, 44, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 54, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 108, 97, 121, 111, 117, 116, 0, 2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 90, 44, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 55, 5, 0, 0, 0, 0, 0, 0, 94, 44, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 56, 5, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 115, 105, 109, 112, 108, 101, 1, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 103, 44, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 58, 5, 0, 0, 0, 0, 0, 0, 103, 44, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 59, 5, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 104, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 60, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 104, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 61, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 1, 0, 0, 0, 106, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 62, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 106, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 63, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 114, 1, 0, 0, 0, 108, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 64, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 108, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 65, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 116, 1, 0, 0, 0, 110, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 66, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 110, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 67, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 32, 0, 1, 0, 0, 0, 114, 44, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 68, 5, 0, 0, 0, 0, 0, 0, 118, 44, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 69, 5, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 99, 111, 109, 109, 101, 110, 116, 2, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 128, 44, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 71, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 128, 44, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 72, 5, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 129, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 73, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 2, 0, 0, 0, 130, 44, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 74, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 133, 44, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 75, 5, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 133, 44, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 76, 5, 0, 0, 0, 0, 0, 0, 133, 44, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 77, 5, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 135, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 78, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 135, 44, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 79, 5, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 110, 0, 0, 0, 0, 0, 142, 44, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 80, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 142, 44, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 81, 5, 0, 0, 0, 0, 0, 0, 151, 44, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 82, 5, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 112, 114, 111, 103, 114, 97, 109];
\ No newline at end of file
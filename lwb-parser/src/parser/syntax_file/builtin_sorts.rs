@@ -0,0 +1,133 @@
+use crate::parser::peg::parser_sugar_ast::{
+    Annotation, Constructor, Expression, Sort, SyntaxFileAst,
+};
+use crate::sources::character_class::CharacterClass;
+use std::collections::HashSet;
+
+/// Fixed-width integer sorts that can be used in `binary` grammars
+/// without defining them. The name says how many bytes are parsed, and in what
+/// order they should be interpreted. Each entry is the name of the sort, its
+/// width in bytes, the matching rust type and a description.
+const INTEGER_SORTS: &[(&str, u64, &str, &str)] = &[
+    ("u8", 1, "u8", "An unsigned 8-bit integer"),
+    ("i8", 1, "i8", "A signed 8-bit integer"),
+    ("u16le", 2, "u16", "A little-endian unsigned 16-bit integer"),
+    ("u16be", 2, "u16", "A big-endian unsigned 16-bit integer"),
+    ("i16le", 2, "i16", "A little-endian signed 16-bit integer"),
+    ("i16be", 2, "i16", "A big-endian signed 16-bit integer"),
+    ("u32le", 4, "u32", "A little-endian unsigned 32-bit integer"),
+    ("u32be", 4, "u32", "A big-endian unsigned 32-bit integer"),
+    ("i32le", 4, "i32", "A little-endian signed 32-bit integer"),
+    ("i32be", 4, "i32", "A big-endian signed 32-bit integer"),
+    ("u64le", 8, "u64", "A little-endian unsigned 64-bit integer"),
+    ("u64be", 8, "u64", "A big-endian unsigned 64-bit integer"),
+    ("i64le", 8, "i64", "A little-endian signed 64-bit integer"),
+    ("i64be", 8, "i64", "A big-endian signed 64-bit integer"),
+];
+
+/// How the bytes of an integer sort are interpreted, see [`integer_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntegerFormat {
+    /// The number of bytes
+    pub width: usize,
+    pub big_endian: bool,
+    pub signed: bool,
+}
+
+impl IntegerFormat {
+    /// The value of the integer in these bytes, if it is not negative.
+    /// Returns `None` when there are not exactly [`width`](IntegerFormat::width) bytes.
+    pub fn decode(&self, bytes: &[u8]) -> Option<u64> {
+        if bytes.len() != self.width {
+            return None;
+        }
+        let push = |value: u64, &byte: &u8| value << 8 | u64::from(byte);
+        let (value, most_significant) = if self.big_endian {
+            (bytes.iter().fold(0, push), bytes[0])
+        } else {
+            (bytes.iter().rev().fold(0, push), bytes[self.width - 1])
+        };
+        (!self.signed || most_significant & 0x80 == 0).then_some(value)
+    }
+}
+
+/// The format of the builtin integer sort with this name, like `u16le`.
+pub fn integer_format(name: &str) -> Option<IntegerFormat> {
+    INTEGER_SORTS
+        .iter()
+        .find(|(sort, ..)| *sort == name)
+        .map(|&(name, width, rust_type, _)| IntegerFormat {
+            width: width as usize,
+            big_endian: name.ends_with("be"),
+            signed: rust_type.starts_with('i'),
+        })
+}
+
+/// Adds the definitions of builtin sorts (like `u16le`) that are referenced,
+/// but not defined, in the syntax definition. A definition in the syntax file
+/// always takes precedence over a builtin sort. Only binary grammars have builtin sorts,
+/// in other grammars these names are free to use.
+pub fn add_builtin_sorts(ast: &mut SyntaxFileAst) {
+    let mut referenced = HashSet::new();
+    for sort in ast.sorts.values() {
+        for constructor in &sort.constructors {
+            referenced_sorts(&constructor.expression, &mut referenced);
+        }
+    }
+
+    for &(name, width, rust_type, description) in INTEGER_SORTS {
+        if !referenced.contains(name) || ast.sorts.contains_key(name) {
+            continue;
+        }
+
+        let annotations = vec![Annotation::NoLayout, Annotation::SingleString];
+        ast.sorts.insert(
+            name.to_string(),
+            Sort {
+                documentation: Some(format!(
+                    "{description}. Use `{rust_type}::from_{}_bytes` on the bytes of its span to get its value.",
+                    if name.ends_with("be") { "be" } else { "le" }
+                )),
+                name: name.to_string(),
+                constructors: vec![Constructor {
+                    documentation: None,
+                    name: name.to_string(),
+                    expression: Expression::Repeat {
+                        e: Box::new(Expression::CharacterClass(CharacterClass::RangeInclusive {
+                            from: '\x00',
+                            to: '\u{ff}',
+                        })),
+                        min: width,
+                        max: Some(width),
+                    },
+                    annotations: annotations.clone(),
+                    dont_put_in_ast: false,
+                }],
+                annotations,
+            },
+        );
+    }
+}
+
+fn referenced_sorts(expression: &Expression, res: &mut HashSet<String>) {
+    match expression {
+        Expression::Sort(name) => {
+            res.insert(name.clone());
+        }
+        Expression::Sequence(es) | Expression::Choice(es) => {
+            es.iter().for_each(|e| referenced_sorts(e, res));
+        }
+        Expression::Repeat { e, .. } | Expression::Negative(e) | Expression::Positive(e) => {
+            referenced_sorts(e, res)
+        }
+        Expression::Counted { e, count } => {
+            res.insert(count.clone());
+            referenced_sorts(e, res);
+        }
+        Expression::Delimited { e, delim, .. } => {
+            referenced_sorts(e, res);
+            referenced_sorts(delim, res);
+        }
+        Expression::Literal(_) | Expression::CharacterClass(_) => {}
+    }
+}
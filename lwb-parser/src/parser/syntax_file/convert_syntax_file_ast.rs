@@ -2,6 +2,7 @@ use crate::codegen_prelude::AstInfo;
 use crate::parser::peg::parser_sugar_ast::*;
 use crate::parser::syntax_file::ast;
 use crate::parser::syntax_file::ast::{CharacterClassItem, EscapeClosingBracket, SortOrMeta};
+use crate::parser::syntax_file::builtin_sorts::{add_builtin_sorts, integer_format};
 use crate::parser::syntax_file::convert_syntax_file_ast::AstConversionError::{
     DuplicateStartingRule, NoStartingSort,
 };
@@ -24,6 +25,15 @@ pub enum AstConversionError {
 
     #[error("no starting sort in syntax file definition")]
     NoStartingSort,
+
+    #[error("`\\x{0}` in a string is not a single character of text, byte escapes above `\\x7f` can only be used in binary grammars")]
+    ByteInText(String),
+
+    #[error("`{{{0}}}` counts a repetition with an integer sort, which can only be used in binary grammars")]
+    CountInText(String),
+
+    #[error("`{{{0}}}` counts a repetition with an integer sort like `u8` or `u16le`, which has to be used before it in the same sequence")]
+    BadCount(String),
 }
 
 pub type ConversionResult<T> = Result<T, AstConversionError>;
@@ -32,29 +42,39 @@ pub fn convert<M: AstInfo>(inp: ast::AST_ROOT<M>) -> ConversionResult<SyntaxFile
     let ast::Program(_, sort_or_metas) = inp;
     let mut sorts = HashMap::new();
     let mut start = None;
+    //Whether the grammar is binary changes what the sorts mean, so it is found first
+    let binary = sort_or_metas
+        .iter()
+        .any(|i| matches!(i, SortOrMeta::Meta(_, ast::Meta::Binary(_))));
 
     for i in sort_or_metas {
         match i {
-            SortOrMeta::Meta(_, m) => {
+            SortOrMeta::Meta(_, ast::Meta::Start(_, name)) => {
                 if start.is_some() {
                     return Err(DuplicateStartingRule);
                 } else {
-                    start = Some(convert_identifier(&m.1))
+                    start = Some(convert_identifier(&name))
                 }
             }
+            SortOrMeta::Meta(_, ast::Meta::Binary(_)) => {}
             SortOrMeta::Sort(_, sort) => {
-                let converted = convert_sort(sort)?;
+                let converted = convert_sort(sort, binary)?;
                 sorts.insert(converted.name.clone(), converted);
             }
         }
     }
 
-    Ok(SyntaxFileAst {
+    let mut res = SyntaxFileAst {
         sorts,
         starting_sort: start.ok_or(NoStartingSort)?,
         merges: Default::default(),
         old_sort_names: vec![],
-    })
+    };
+    if binary {
+        add_builtin_sorts(&mut res);
+    }
+
+    Ok(res)
 }
 
 fn convert_identifier<M: AstInfo>(inp: &ast::Identifier<M>) -> String {
@@ -67,25 +87,50 @@ fn convert_number<M: AstInfo>(inp: ast::Number<M>) -> ConversionResult<u64> {
         .map_err(AstConversionError::NumberConversion)
 }
 
-fn convert_escape_closing_bracket<M: AstInfo>(inp: ast::EscapeClosingBracket<M>) -> char {
+/// Converts two hexadecimal digits (as written in `\xNN`) to the character
+/// with that code point. In binary grammars every such character stands for exactly one byte.
+fn convert_hex_byte(high: &str, low: &str) -> char {
+    let byte = u8::from_str_radix(&format!("{high}{low}"), 16)
+        .expect("grammar only allows hexadecimal digits here");
+    char::from(byte)
+}
+
+/// The characters an item of a character class stands for. Only in binary grammars,
+/// `\xNN` is a single byte, otherwise it is just the text.
+fn convert_escape_closing_bracket<M: AstInfo>(
+    inp: ast::EscapeClosingBracket<M>,
+    binary: bool,
+) -> Vec<char> {
     match inp {
-        EscapeClosingBracket::Escaped(_, c) => match c.as_str() {
+        EscapeClosingBracket::Byte(_, high, low) if binary => vec![convert_hex_byte(&high, &low)],
+        EscapeClosingBracket::Byte(_, high, low) => "\\x"
+            .chars()
+            .chain(high.chars())
+            .chain(low.chars())
+            .collect(),
+        EscapeClosingBracket::Escaped(_, c) => vec![match c.as_str() {
             "n" => '\n',
             "r" => '\r',
             "t" => '\t',
             "\\" => '\\',
             "]" => ']',
             a => unreachable!("grammar shouldn't allow {} here", a),
-        },
+        }],
         EscapeClosingBracket::Unescaped(_, c) => {
-            let c = c.chars().next().expect("only one character");
-            c
+            vec![c.chars().next().expect("only one character")]
         }
     }
 }
 
-fn convert_string_char<M>(inp: &ast::StringChar<M>) -> char {
-    match inp {
+fn convert_string_char<M>(inp: &ast::StringChar<M>, binary: bool) -> ConversionResult<char> {
+    Ok(match inp {
+        StringChar::Byte(_, high, low) => {
+            let c = convert_hex_byte(high, low);
+            if !binary && !c.is_ascii() {
+                return Err(AstConversionError::ByteInText(format!("{high}{low}")));
+            }
+            c
+        }
         StringChar::Escaped(_, c) => match c.as_str() {
             "n" => '\n',
             "r" => '\r',
@@ -98,27 +143,36 @@ fn convert_string_char<M>(inp: &ast::StringChar<M>) -> char {
             let c = c.chars().next().expect("only one character");
             c
         }
-    }
+    })
 }
 
 fn convert_character_class<M: AstInfo>(
     inp: ast::CharacterClass<M>,
+    binary: bool,
 ) -> ConversionResult<CharacterClass> {
     let ast::CharacterClass(_, inverted, items) = inp;
     let mut res = CharacterClass::Nothing;
+    let single = |c| CharacterClass::RangeInclusive { from: c, to: c };
 
     for i in items {
         match i {
+            //When an end of the range is more than one character, the range is between the characters next to the `-`
             CharacterClassItem::Range(_, from, to_inclusive) => {
+                let mut from = convert_escape_closing_bracket(from, binary);
+                let to_inclusive = convert_escape_closing_bracket(to_inclusive, binary);
+                let last = from.pop().expect("at least one character");
+                for c in from.into_iter().chain(to_inclusive[1..].iter().copied()) {
+                    res = res.combine(single(c));
+                }
                 res = res.combine(CharacterClass::RangeInclusive {
-                    from: convert_escape_closing_bracket(from),
-                    to: convert_escape_closing_bracket(to_inclusive),
+                    from: last,
+                    to: to_inclusive[0],
                 })
             }
             CharacterClassItem::SingleChar(_, c) => {
-                let c = convert_escape_closing_bracket(c);
-
-                res = res.combine(CharacterClass::RangeInclusive { from: c, to: c })
+                for c in convert_escape_closing_bracket(c, binary) {
+                    res = res.combine(single(c));
+                }
             }
         }
     }
@@ -130,7 +184,7 @@ fn convert_character_class<M: AstInfo>(
     Ok(res)
 }
 
-fn convert_sort<M: AstInfo>(inp: ast::Sort<M>) -> ConversionResult<Sort> {
+fn convert_sort<M: AstInfo>(inp: ast::Sort<M>, binary: bool) -> ConversionResult<Sort> {
     Ok(match inp {
         ast::Sort::Sort(_, name, annos, constructors) => Sort {
             documentation: None,
@@ -141,7 +195,7 @@ fn convert_sort<M: AstInfo>(inp: ast::Sort<M>) -> ConversionResult<Sort> {
                 //     ast::Constructor::ConstructorDocumented(_, _, c) => true,
                 //     ast::Constructor::Constructor(_, n, _, _) => n.1 != "double-eq",
                 // })
-                .map(|i| convert_constructor(i))
+                .map(|i| convert_constructor(i, binary))
                 .collect::<Result<_, _>>()?,
             annotations: annos.map_or(Ok(vec![]), |a| convert_annotations(&a))?,
         },
@@ -153,7 +207,7 @@ fn convert_sort<M: AstInfo>(inp: ast::Sort<M>) -> ConversionResult<Sort> {
                 constructors: vec![Constructor {
                     documentation: None,
                     name,
-                    expression: convert_expressions(expressions)?,
+                    expression: convert_expressions(expressions, binary)?,
                     annotations: annotations
                         .as_ref()
                         .map_or(Ok(vec![]), |a| convert_annotations(a))?,
@@ -164,10 +218,12 @@ fn convert_sort<M: AstInfo>(inp: ast::Sort<M>) -> ConversionResult<Sort> {
                     .map_or(Ok(vec![]), |a| convert_annotations(a))?,
             }
         }
-        ast::Sort::SortDocumented(_, comments, sort) => convert_sort(*sort).and_then(|mut i| {
-            i.documentation = Some(convert_comments(comments)?);
-            Ok(i)
-        })?,
+        ast::Sort::SortDocumented(_, comments, sort) => {
+            convert_sort(*sort, binary).and_then(|mut i| {
+                i.documentation = Some(convert_comments(comments)?);
+                Ok(i)
+            })?
+        }
     })
 }
 
@@ -179,41 +235,54 @@ fn convert_comments<M: AstInfo>(inp: Vec<ast::DocComment<M>>) -> ConversionResul
         .join("\n"))
 }
 
-fn convert_expression<M: AstInfo>(inp: ast::Expression<M>) -> ConversionResult<Expression> {
+fn convert_expression<M: AstInfo>(
+    inp: ast::Expression<M>,
+    binary: bool,
+) -> ConversionResult<Expression> {
     Ok(match inp {
         ast::Expression::Star(_, exp) => Expression::Repeat {
-            e: Box::new(convert_expression(*exp)?),
+            e: Box::new(convert_nested(*exp, binary)?),
             min: 0,
             max: None,
         },
         ast::Expression::Plus(_, exp) => Expression::Repeat {
-            e: Box::new(convert_expression(*exp)?),
+            e: Box::new(convert_nested(*exp, binary)?),
             min: 1,
             max: None,
         },
         ast::Expression::Maybe(_, exp) => Expression::Repeat {
-            e: Box::new(convert_expression(*exp)?),
+            e: Box::new(convert_nested(*exp, binary)?),
             min: 0,
             max: Some(1),
         },
         ast::Expression::RepeatExact(_, exp, num) => {
             let converted_num = convert_number(num)?;
             Expression::Repeat {
-                e: Box::new(convert_expression(*exp)?),
+                e: Box::new(convert_nested(*exp, binary)?),
                 min: converted_num,
                 max: Some(converted_num),
             }
         }
         ast::Expression::RepeatRange(_, exp, min, max) => Expression::Repeat {
-            e: Box::new(convert_expression(*exp)?),
+            e: Box::new(convert_nested(*exp, binary)?),
             min: convert_number(min)?,
             max: Some(convert_number(max)?),
         },
         ast::Expression::RepeatLower(_, exp, num) => Expression::Repeat {
-            e: Box::new(convert_expression(*exp)?),
+            e: Box::new(convert_nested(*exp, binary)?),
             min: convert_number(num)?,
             max: None,
         },
+        ast::Expression::RepeatCount(_, exp, count) => {
+            let count = convert_identifier(&count);
+            if !binary {
+                return Err(AstConversionError::CountInText(count));
+            }
+            Expression::Counted {
+                e: Box::new(convert_nested(*exp, binary)?),
+                count,
+            }
+        }
         ast::Expression::Delimited(_, exp, delim, bound, trailing) => {
             let (min, max) = match bound {
                 DelimitedBound::NumNum(_, min, max) => {
@@ -225,45 +294,84 @@ fn convert_expression<M: AstInfo>(inp: ast::Expression<M>) -> ConversionResult<E
                 DelimitedBound::Plus(_) => (1, None),
             };
             Expression::Delimited {
-                e: Box::new(convert_expression(*exp)?),
-                delim: Box::new(convert_expression(*delim)?),
+                e: Box::new(convert_nested(*exp, binary)?),
+                delim: Box::new(convert_nested(*delim, binary)?),
                 min,
                 max,
                 trailing,
             }
         }
-        ast::Expression::Literal(_, l) => Expression::Literal(l.to_string()),
+        ast::Expression::Literal(_, l) => Expression::Literal(convert_string(&l, binary)?),
         ast::Expression::Sort(_, s) => Expression::Sort(convert_identifier(&s)),
-        ast::Expression::Class(_, cc) => Expression::CharacterClass(convert_character_class(cc)?),
+        ast::Expression::Class(_, cc) => {
+            Expression::CharacterClass(convert_character_class(cc, binary)?)
+        }
         ast::Expression::Paren(_, exp) => {
-            convert_expressions(exp.into_iter().map(|i| *i).collect())?
+            convert_expressions(exp.into_iter().map(|i| *i).collect(), binary)?
         }
         ast::Expression::Labelled(_, _, _) => todo!(),
     })
 }
 
+/// Converts an expression in another expression. It is not an item of a sequence, so there is nothing
+/// before it that can count it.
+fn convert_nested<M: AstInfo>(
+    inp: ast::Expression<M>,
+    binary: bool,
+) -> ConversionResult<Expression> {
+    match convert_expression(inp, binary)? {
+        Expression::Counted { count, .. } => Err(AstConversionError::BadCount(count)),
+        e => Ok(e),
+    }
+}
+
 fn convert_expressions<M: AstInfo>(
     mut inp: Vec<ast::Expression<M>>,
+    binary: bool,
 ) -> ConversionResult<Expression> {
     // 0 not possible due to grammar constraints
-    if inp.len() == 1 {
-        convert_expression(inp.pop().unwrap())
+    let items = if inp.len() == 1 {
+        vec![convert_expression(inp.pop().unwrap(), binary)?]
     } else {
-        Ok(Expression::Sequence(
-            inp.into_iter()
-                .map(|i| convert_expression(i))
-                .collect::<Result<_, _>>()?,
-        ))
+        inp.into_iter()
+            .map(|i| convert_expression(i, binary))
+            .collect::<Result<_, _>>()?
+    };
+
+    //The count of a counted repetition is parsed before it, in the same sequence
+    for (i, item) in items.iter().enumerate() {
+        if let Expression::Counted { count, .. } = item {
+            let counted = items[..i]
+                .iter()
+                .any(|e| matches!(e, Expression::Sort(name) if name == count));
+            if !counted || integer_format(count).is_none() {
+                return Err(AstConversionError::BadCount(count.clone()));
+            }
+        }
     }
+
+    Ok(if items.len() == 1 {
+        items.into_iter().next().unwrap()
+    } else {
+        Expression::Sequence(items)
+    })
+}
+
+fn convert_string<M>(inp: &ast::String<M>, binary: bool) -> ConversionResult<String> {
+    let chars = match inp {
+        ast::String::Single(_, s) => s,
+        ast::String::Double(_, s) => s,
+    };
+    chars
+        .iter()
+        .map(|i| convert_string_char(i, binary))
+        .collect()
 }
 
+/// Strings in annotations are messages, in which `\xNN` is the character with that code point.
 impl<M> ToString for ast::String<M> {
     fn to_string(&self) -> String {
-        let chars = match self {
-            ast::String::Single(_, s) => s,
-            ast::String::Double(_, s) => s,
-        };
-        chars.iter().map(|i| convert_string_char(i)).collect()
+        convert_string(self, true).expect("every byte escape is a character")
     }
 }
 
@@ -282,17 +390,28 @@ fn convert_annotations<M: AstInfo>(
                 ast::Annotation::Hidden(_) => Annotation::Hidden,
                 ast::Annotation::Error(_, msg) => Annotation::Error(msg.to_string()),
                 ast::Annotation::PartOf(_, name) => Annotation::PartOf(convert_identifier(name)),
+                ast::Annotation::Feature(_, name) => Annotation::Feature(convert_identifier(name)),
+                ast::Annotation::DisplayName(_, name) => Annotation::DisplayName(name.to_string()),
+                ast::Annotation::Warning(_, msg) => Annotation::Warning(msg.to_string()),
+                ast::Annotation::Deprecated(_, msg) => Annotation::Deprecated(msg.to_string()),
+                ast::Annotation::Memo(_, policy) => Annotation::Memo(match policy {
+                    ast::MemoPolicy::Always(_) => MemoPolicy::Always,
+                    ast::MemoPolicy::Never(_) => MemoPolicy::Never,
+                }),
             })
         })
         .collect::<Result<_, _>>()
 }
 
-fn convert_constructor<M: AstInfo>(inp: ast::Constructor<M>) -> ConversionResult<Constructor> {
+fn convert_constructor<M: AstInfo>(
+    inp: ast::Constructor<M>,
+    binary: bool,
+) -> ConversionResult<Constructor> {
     Ok(match inp {
         ast::Constructor::Constructor(_, name, expressions, annotations) => Constructor {
             documentation: None,
             name: convert_identifier(&name),
-            expression: convert_expressions(expressions)?,
+            expression: convert_expressions(expressions, binary)?,
             annotations: if let Some(a) = annotations {
                 convert_annotations(&a)?
             } else {
@@ -301,7 +420,7 @@ fn convert_constructor<M: AstInfo>(inp: ast::Constructor<M>) -> ConversionResult
             dont_put_in_ast: false,
         },
         ast::Constructor::ConstructorDocumented(_, comments, constructor) => {
-            convert_constructor(*constructor).and_then(|mut i| {
+            convert_constructor(*constructor, binary).and_then(|mut i| {
                 i.documentation = Some(convert_comments(comments)?);
                 Ok(i)
             })?
@@ -1,14 +1,26 @@
 use crate::error::display_miette_error;
-use crate::parser::ast::generate_ast::{generate_ast, BasicAstInfo, BasicAstNode};
+use crate::parser::ast::generate_ast::{
+    try_generate_ast, try_generate_ast_from_raw, BasicAstInfo, BasicAstNode,
+};
 use crate::parser::peg::parse_error::PEGParseError;
-use crate::parser::peg::parser_sugar::parse_file;
+use crate::parser::peg::parse_options::{Budget, ParseAborted, ParseOptions};
+use crate::parser::peg::parse_outcome::ParseOutcome;
+use crate::parser::peg::parser_compiled::CompiledParser;
+use crate::parser::peg::parser_native::NativeParser;
+use crate::parser::peg::parser_stream::ParseMany;
+use crate::parser::peg::parser_sugar_ast::SyntaxFileAst;
 use crate::parser::syntax_file::convert_syntax_file_ast::{convert, AstConversionError};
 use crate::sources::source_file::SourceFile;
+use crate::sources::stream::ChunkedInput;
 use itertools::Itertools;
+use std::io::Read;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
 use thiserror::Error;
 
 #[rustfmt::skip]
 pub mod ast;
+pub mod builtin_sorts;
 pub mod convert_syntax_file_ast;
 
 language!(pub SyntaxFile at mod ast);
@@ -23,26 +35,233 @@ pub enum ParseError {
 
     #[error("PEG Errors: \n{}", _0.iter().map(display_miette_error).join("\n"))]
     PEG(Vec<PEGParseError>),
+
+    #[error("failed to read input: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("sort {0} does not exist")]
+    UnknownSort(String),
+
+    #[error("the AST of the language is generated for its starting sort, which is not {0}")]
+    StartingSort(String),
+
+    #[error("offset {0} is not at the start of a character in the input")]
+    InvalidOffset(usize),
+
+    #[error("parsing was cancelled")]
+    Cancelled,
+
+    #[error("parsing exceeded {0}")]
+    BudgetExceeded(Budget),
+}
+
+impl From<ParseAborted> for ParseError {
+    fn from(aborted: ParseAborted) -> Self {
+        match aborted {
+            ParseAborted::Cancelled => ParseError::Cancelled,
+            ParseAborted::BudgetExceeded(budget) => ParseError::BudgetExceeded(budget),
+        }
+    }
 }
 
 pub fn parse_language<AST: BasicAstNode>(
     input: &SourceFile,
     parser: &[u8],
 ) -> Result<AST, ParseError> {
-    // let syntax_file_ast: ast::AST_ROOT<BasicAstInfo> = bincode::deserialize(SERIALIZED_AST).unwrap();
-    // let legacy_ast = convert(syntax_file_ast)?; // TODO: make peg parser use new version of ast
-    let syntax_file_ast: ast::AST_ROOT<BasicAstInfo> = bincode::deserialize(parser)?;
-    let legacy_ast = convert(syntax_file_ast)?; // TODO: make peg parser use new version of ast
+    parse_language_with_options(input, parser, &ParseOptions::default())
+}
+
+/// Like [`parse_language`], but configured by the options. The parse stops early when it exceeds the limits given in the options.
+pub fn parse_language_with_options<AST: BasicAstNode>(
+    input: &SourceFile,
+    parser: &[u8],
+    options: &ParseOptions,
+) -> Result<AST, ParseError> {
+    let outcome = parse_language_outcome(input, parser, options)?;
+    match outcome.tree {
+        Some(ast) => Ok(ast),
+        None => Err(ParseError::PEG(outcome.errors)),
+    }
+}
+
+/// Like [`parse_language_with_options`], but returns the errors, warnings and (if requested) statistics
+/// together with the AST, instead of failing on the first problem. The AST is only generated when
+/// there were no errors.
+///
+/// The parser is loaded for every call. To parse many files, load it once with [`load_parser`] and
+/// use [`parse_compiled_language_outcome`].
+///
+/// Note that `AST` should be the type which was generated for the starting sort, or for
+/// the sort given with [`ParseOptions::with_starting_sort`].
+pub fn parse_language_outcome<AST: BasicAstNode>(
+    input: &SourceFile,
+    parser: &[u8],
+    options: &ParseOptions,
+) -> Result<ParseOutcome<Option<AST>>, ParseError> {
+    let legacy_ast = load_parser(parser)?;
+    check_sort(&legacy_ast, options.starting_sort())?;
+    let parser = CompiledParser::with_options(&legacy_ast, options);
+    parse_compiled_language_outcome(input, &parser, options)
+}
+
+/// Like [`parse_language_outcome`], but parses with a parser that was already compiled.
+pub fn parse_compiled_language_outcome<AST: BasicAstNode>(
+    input: &SourceFile,
+    parser: &CompiledParser,
+    options: &ParseOptions,
+) -> Result<ParseOutcome<Option<AST>>, ParseError> {
+    check_sort(parser.ast(), options.starting_sort())?;
+
+    let mut outcome = parser.parse_raw_outcome(input, options)?;
+    if !outcome.is_ok() {
+        return Ok(outcome.map(|_| None));
+    }
+    match try_generate_ast_from_raw(&outcome.tree, input, options) {
+        Ok(ast) => Ok(outcome.map(|_| Some(ast))),
+        Err(e) => {
+            outcome.errors.push(e);
+            Ok(outcome.map(|_| None))
+        }
+    }
+}
+
+/// Like [`parse_compiled_language_outcome`], for the AST of the starting sort of the grammar, see [`language!`].
+/// Options that start at another sort give [`ParseError::StartingSort`], since that sort has another AST type.
+#[doc(hidden)]
+pub fn __parse_compiled_language_root_outcome<AST: BasicAstNode>(
+    input: &SourceFile,
+    parser: &CompiledParser,
+    options: &ParseOptions,
+) -> Result<ParseOutcome<Option<AST>>, ParseError> {
+    match options.starting_sort() {
+        Some(sort) if sort != parser.ast().starting_sort => {
+            Err(ParseError::StartingSort(sort.to_string()))
+        }
+        _ => parse_compiled_language_outcome(input, parser, options),
+    }
+}
 
-    // let sf = SourceFile::open("rust-lwb-bootstrap/syntax-file.syntax").expect("open error");
-    // let legacy_ast = bootstrap::parse(&sf).expect("should parse");
+/// Like [`parse_language`], but only parses a single `sort`, starting at byte `offset`
+/// in the input. The rest of the input does not need to be consumed.
+/// Returns the AST and the offset directly after the part that was parsed.
+///
+/// Note that `AST` should be the type which was generated for `sort`.
+pub fn parse_language_prefix<AST: BasicAstNode>(
+    sort: &str,
+    input: &SourceFile,
+    offset: usize,
+    parser: &[u8],
+) -> Result<(AST, usize), ParseError> {
+    let legacy_ast = load_parser(parser)?;
+    check_sort(&legacy_ast, Some(sort))?;
+    let parser =
+        CompiledParser::with_options(&legacy_ast, &ParseOptions::new().with_starting_sort(sort));
+    parse_compiled_language_prefix(sort, input, offset, &parser)
+}
 
-    let (pairs, errs) = parse_file(&legacy_ast, input);
+/// Like [`parse_language_prefix`], but parses with a parser that was already compiled.
+pub fn parse_compiled_language_prefix<AST: BasicAstNode>(
+    sort: &str,
+    input: &SourceFile,
+    offset: usize,
+    parser: &CompiledParser,
+) -> Result<(AST, usize), ParseError> {
+    let (pair, end, errs) = parser.parse_raw_prefix(sort, input, offset)?;
     if !errs.is_empty() {
         return Err(ParseError::PEG(errs));
     }
 
-    let ast = generate_ast(&pairs);
+    match try_generate_ast_from_raw(&pair, input, &ParseOptions::default()) {
+        Ok(ast) => Ok((ast, end)),
+        Err(e) => Err(ParseError::PEG(vec![e])),
+    }
+}
 
-    Ok(ast)
+/// Like [`parse_language`], but parses a stream which contains many items, and
+/// produces an AST for each of them. See [`parse_many`](crate::parser::peg::parser_stream::parse_many).
+pub fn parse_language_many<'p, AST, R: Read>(
+    input: ChunkedInput<R>,
+    parser: &'p CompiledParser<'p>,
+) -> ParseLanguageMany<'p, AST, R> {
+    ParseLanguageMany {
+        items: Ok(parser.parse_many(input)),
+        phantom: PhantomData,
+    }
+}
+
+/// Iterator over the ASTs of the items in a stream. See [`parse_language_many`].
+pub struct ParseLanguageMany<'p, AST, R> {
+    /// When the parser could not be loaded, holds the error until it is returned
+    items: Result<ParseMany<'p, R>, Option<ParseError>>,
+    phantom: PhantomData<AST>,
+}
+
+/// An iterator that only returns the error, for when the parser could not be loaded.
+impl<AST, R> From<ParseError> for ParseLanguageMany<'_, AST, R> {
+    fn from(e: ParseError) -> Self {
+        ParseLanguageMany {
+            items: Err(Some(e)),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<AST: BasicAstNode, R: Read> Iterator for ParseLanguageMany<'_, AST, R> {
+    type Item = Result<AST, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let items = match &mut self.items {
+            Ok(items) => items,
+            Err(e) => return e.take().map(Err),
+        };
+
+        // After an error the items stop, since it is not known where the next item starts
+        Some(match items.next()? {
+            Ok((pairs, errs)) if errs.is_empty() => {
+                try_generate_ast(&pairs, &ParseOptions::default())
+                    .map_err(|e| ParseError::PEG(vec![e]))
+            }
+            Ok((_, errs)) => Err(ParseError::PEG(errs)),
+            Err(e) => Err(e),
+        })
+    }
+}
+
+/// Loads a serialized parser, like the `PARSER` that is generated for a language.
+/// It can then be compiled with [`CompiledParser::new`].
+pub fn load_parser(parser: &[u8]) -> Result<SyntaxFileAst, ParseError> {
+    // TODO: make peg parser use new version of ast
+    let syntax_file_ast: ast::AST_ROOT<BasicAstInfo> = bincode::deserialize(parser)?;
+    Ok(convert(syntax_file_ast)?)
+}
+
+/// Loads and compiles the parser of a language the first time it is used, see [`language!`].
+#[doc(hidden)]
+pub fn __compiled_language_parser(
+    syntax: &'static OnceLock<SyntaxFileAst>,
+    compiled: &'static OnceLock<CompiledParser<'static>>,
+    parser: &[u8],
+    native: Option<&'static NativeParser>,
+) -> Result<&'static CompiledParser<'static>, ParseError> {
+    if let Some(compiled) = compiled.get() {
+        return Ok(compiled);
+    }
+    // When loading fails it is tried again the next time, so every parse gets the error
+    let legacy_ast = match syntax.get() {
+        Some(legacy_ast) => legacy_ast,
+        None => {
+            let legacy_ast = load_parser(parser)?;
+            syntax.get_or_init(|| legacy_ast)
+        }
+    };
+    Ok(compiled.get_or_init(|| CompiledParser::new(legacy_ast).with_native(native)))
+}
+
+fn check_sort(legacy_ast: &SyntaxFileAst, sort: Option<&str>) -> Result<(), ParseError> {
+    match sort {
+        Some(sort) if !legacy_ast.sorts.contains_key(sort) => {
+            Err(ParseError::UnknownSort(sort.to_string()))
+        }
+        _ => Ok(()),
+    }
 }
@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt::{Display, Formatter};
 use std::ops::{Range, RangeInclusive};
 
@@ -147,6 +148,226 @@ impl CharacterClass {
     pub fn combine(self, other: CharacterClass) -> CharacterClass {
         CharacterClass::Choice(vec![self, other])
     }
+
+    /// Compiles this character class into lookup tables, see [`CompiledCharacterClass`].
+    ///
+    /// ```
+    /// # use lwb_parser::sources::character_class::CharacterClass;
+    /// let c = CharacterClass::from('a'..='z')
+    ///     .combine(CharacterClass::from("_é😀"))
+    ///     .invert()
+    ///     .compile();
+    /// assert!(c.contains('0'));
+    /// assert!(c.contains('ü'));
+    /// assert!(!c.contains('q'));
+    /// assert!(!c.contains('é'));
+    /// assert!(!c.contains('😀'));
+    /// ```
+    pub fn compile(&self) -> CompiledCharacterClass {
+        CompiledCharacterClass {
+            set: CharacterSet::from_ranges(self.ranges()),
+            class: self.clone(),
+        }
+    }
+
+    /// The characters in this class, as sorted inclusive ranges of code points which don't overlap or touch.
+    fn ranges(&self) -> Vec<(u32, u32)> {
+        let ranges = match self {
+            CharacterClass::RangeInclusive { from, to } => vec![(*from as u32, *to as u32)],
+            CharacterClass::Range { from, to } if from < to => {
+                vec![(*from as u32, *to as u32 - 1)]
+            }
+            CharacterClass::Range { .. } => vec![],
+            CharacterClass::Contained(chars) => {
+                chars.iter().map(|&c| (c as u32, c as u32)).collect()
+            }
+            CharacterClass::Choice(parts) => parts.iter().flat_map(|p| p.ranges()).collect(),
+            CharacterClass::Not(cls) => {
+                //The gaps between the ranges of the inverted class
+                let mut ranges = vec![];
+                let mut start = 0;
+                for (from, to) in cls.ranges() {
+                    if from > start {
+                        ranges.push((start, from - 1));
+                    }
+                    start = to + 1;
+                }
+                if start <= char::MAX as u32 {
+                    ranges.push((start, char::MAX as u32));
+                }
+                return ranges;
+            }
+            CharacterClass::Nothing => vec![],
+        };
+        merge_ranges(ranges)
+    }
+}
+
+/// Sorts inclusive ranges, and merges the ones that overlap or touch.
+fn merge_ranges(mut ranges: Vec<(u32, u32)>) -> Vec<(u32, u32)> {
+    ranges.retain(|&(from, to)| from <= to);
+    ranges.sort_unstable();
+    let mut merged: Vec<(u32, u32)> = Vec::with_capacity(ranges.len());
+    for (from, to) in ranges {
+        match merged.last_mut() {
+            Some((_, last)) if from <= *last + 1 => *last = (*last).max(to),
+            _ => merged.push((from, to)),
+        }
+    }
+    merged
+}
+
+/// A set of characters stored as lookup tables, so checking whether it contains a character
+/// takes a single bit test for ascii characters, and a binary search for all others.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CharacterSet {
+    /// Bit `c` is set when ascii character `c` is in the set
+    ascii: u128,
+    /// The characters above ascii that are in the set, as sorted inclusive ranges
+    ranges: Vec<(u32, u32)>,
+}
+
+impl CharacterSet {
+    /// The set of the characters in sorted inclusive ranges which don't overlap or touch.
+    fn from_ranges(ranges: Vec<(u32, u32)>) -> Self {
+        let mut ascii = 0u128;
+        for &(from, to) in &ranges {
+            for c in from..=to.min(127) {
+                ascii |= 1 << c;
+            }
+        }
+        let ranges = ranges
+            .into_iter()
+            .filter(|&(_, to)| to >= 128)
+            .map(|(from, to)| (from.max(128), to))
+            .collect();
+        Self { ascii, ranges }
+    }
+
+    /// Returns true when a character is in this set.
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        let c = c as u32;
+        if c < 128 {
+            self.ascii & (1 << c) != 0
+        } else {
+            self.ranges
+                .binary_search_by(|&(from, to)| {
+                    if to < c {
+                        Ordering::Less
+                    } else if from > c {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                })
+                .is_ok()
+        }
+    }
+
+    /// The characters in this set, as sorted inclusive ranges of code points which don't overlap.
+    /// Used to generate code which matches the characters of the set.
+    pub fn ranges(&self) -> Vec<(u32, u32)> {
+        let mut ranges = vec![];
+        let mut start = None;
+        for c in 0..=128 {
+            match (c < 128 && self.ascii & (1 << c) != 0, start) {
+                (true, None) => start = Some(c),
+                (false, Some(from)) => {
+                    ranges.push((from, c - 1));
+                    start = None;
+                }
+                _ => {}
+            }
+        }
+        ranges.extend_from_slice(&self.ranges);
+        merge_ranges(ranges)
+    }
+
+    /// Adds all characters of `other` to this set.
+    pub fn extend(&mut self, other: &CharacterSet) {
+        self.ascii |= other.ascii;
+        if !other.ranges.is_empty() {
+            self.ranges.extend_from_slice(&other.ranges);
+            self.ranges = merge_ranges(std::mem::take(&mut self.ranges));
+        }
+    }
+
+    /// Returns true when a character is in both sets.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::character_class::CharacterClass;
+    /// let letters = CharacterClass::from('a'..='z').compile();
+    /// let layout = CharacterClass::from(" \n").compile();
+    /// assert!(!letters.set().intersects(layout.set()));
+    /// assert!(letters.set().intersects(CharacterClass::from("xyz").compile().set()));
+    /// assert!(CharacterClass::Nothing.invert().compile().set().intersects(layout.set()));
+    /// ```
+    pub fn intersects(&self, other: &CharacterSet) -> bool {
+        if self.ascii & other.ascii != 0 {
+            return true;
+        }
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(a_from, a_to)), Some(&&(b_from, b_to))) = (a.peek(), b.peek()) {
+            if a_to < b_from {
+                a.next();
+            } else if b_to < a_from {
+                b.next();
+            } else {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+impl From<char> for CharacterSet {
+    fn from(c: char) -> Self {
+        Self::from_ranges(vec![(c as u32, c as u32)])
+    }
+}
+
+/// A character class compiled into a [`CharacterSet`].
+/// The parser compiles every character class of a grammar when it is desugared.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CompiledCharacterClass {
+    set: CharacterSet,
+    /// The class this was compiled from, used in error messages
+    class: CharacterClass,
+}
+
+impl CompiledCharacterClass {
+    /// Returns true when a character is included in this character class,
+    /// like [`CharacterClass::contains`].
+    #[inline]
+    pub fn contains(&self, c: char) -> bool {
+        self.set.contains(c)
+    }
+
+    /// The characters in this class
+    pub fn set(&self) -> &CharacterSet {
+        &self.set
+    }
+
+    /// The character class this was compiled from
+    pub fn class(&self) -> &CharacterClass {
+        &self.class
+    }
+}
+
+impl Display for CompiledCharacterClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.class.fmt(f)
+    }
+}
+
+impl From<CharacterClass> for CompiledCharacterClass {
+    fn from(class: CharacterClass) -> Self {
+        class.compile()
+    }
 }
 
 impl From<RangeInclusive<char>> for CharacterClass {
@@ -0,0 +1,85 @@
+use crate::sources::source_file::SourceFile;
+use crate::sources::span::Span;
+use serde::{Deserialize, Serialize};
+
+/// A [`Span`] that takes 8 bytes and can be copied, because it does not refer to its source file.
+/// The parser stores these in its results, which always belong to the file that was parsed,
+/// and turns them into a full [`Span`] with that file when one is needed.
+///
+/// Offsets are in bytes, like in [`Span`], and have to fit in a `u32`, so source files can be at most 4 GiB.
+///
+/// ```
+/// # use lwb_parser::sources::compact_span::CompactSpan;
+/// # use lwb_parser::sources::source_file::SourceFile;
+/// let sf = SourceFile::new_for_test("abcdef");
+/// let span = CompactSpan::from_length(1, 3);
+///
+/// assert_eq!(span.end(), 4);
+/// assert_eq!(span.as_str(&sf), "bcd");
+/// assert_eq!(span.to_span(&sf).as_str(), "bcd");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CompactSpan {
+    pub position: u32,
+    pub length: u32,
+}
+
+fn to_u32(offset: usize) -> u32 {
+    u32::try_from(offset).expect("source files larger than 4 GiB are not supported")
+}
+
+impl CompactSpan {
+    /// Creates a new span, given a starting position and the length that the span should be.
+    pub fn from_length(position: usize, length: usize) -> Self {
+        Self {
+            position: to_u32(position),
+            length: to_u32(length),
+        }
+    }
+
+    /// Creates a new span, given a starting position and end position.
+    pub fn from_end(position: usize, end: usize) -> Self {
+        assert!(end >= position);
+        Self::from_length(position, end - position)
+    }
+
+    pub fn start(&self) -> usize {
+        self.position as usize
+    }
+
+    pub fn end(&self) -> usize {
+        self.start() + self.length as usize
+    }
+
+    pub fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Turns this into a full [`Span`] in `source`, which should be the file this span was made for.
+    pub fn to_span(&self, source: &SourceFile) -> Span {
+        Span::from_length(source, self.start(), self.len())
+    }
+
+    /// The text of `source` at this span, like [`Span::as_str`].
+    pub fn as_str<'a>(&self, source: &'a SourceFile) -> &'a str {
+        let start = source.str_offset(self.start());
+        let end = source.str_offset(self.end());
+        &source.contents()[start..end]
+    }
+}
+
+impl From<&Span> for CompactSpan {
+    fn from(span: &Span) -> Self {
+        Self::from_length(span.position, span.length)
+    }
+}
+
+impl From<Span> for CompactSpan {
+    fn from(span: Span) -> Self {
+        Self::from(&span)
+    }
+}
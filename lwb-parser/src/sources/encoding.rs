@@ -0,0 +1,255 @@
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The encoding a source file was read in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SourceEncoding {
+    Utf8,
+    Utf16Le,
+    Utf16Be,
+    /// Binary source files, in which every byte is one character
+    Bytes,
+}
+
+impl SourceEncoding {
+    fn bom(&self) -> &'static [u8] {
+        match self {
+            SourceEncoding::Utf8 => &[0xef, 0xbb, 0xbf],
+            SourceEncoding::Utf16Le => &[0xff, 0xfe],
+            SourceEncoding::Utf16Be => &[0xfe, 0xff],
+            SourceEncoding::Bytes => &[],
+        }
+    }
+
+    /// Appends `c` encoded in this encoding to `out`, and returns how many bytes it took.
+    /// Panics for characters above `\xff` in binary files, since these are not a single byte.
+    pub(crate) fn encode(&self, c: char, out: &mut Vec<u8>) -> usize {
+        let len = out.len();
+        match self {
+            SourceEncoding::Utf8 => out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            SourceEncoding::Utf16Le => c
+                .encode_utf16(&mut [0; 2])
+                .iter()
+                .for_each(|u| out.extend_from_slice(&u.to_le_bytes())),
+            SourceEncoding::Utf16Be => c
+                .encode_utf16(&mut [0; 2])
+                .iter()
+                .for_each(|u| out.extend_from_slice(&u.to_be_bytes())),
+            SourceEncoding::Bytes => {
+                out.push(u8::try_from(c).expect("binary files only contain characters up to \\xff"))
+            }
+        }
+        out.len() - len
+    }
+
+    /// The encoding given by the byte order mark at the start of `bytes`, if there is one.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        [Self::Utf8, Self::Utf16Le, Self::Utf16Be]
+            .into_iter()
+            .find(|e| bytes.starts_with(e.bom()))
+    }
+}
+
+/// Options for [`SourceFile::decode`](crate::sources::source_file::SourceFile::decode).
+#[derive(Debug, Clone, Default)]
+pub struct DecodeOptions {
+    encoding: Option<SourceEncoding>,
+    normalize_newlines: bool,
+}
+
+impl DecodeOptions {
+    /// Detect the encoding from the byte order mark, and use utf-8 when there is none.
+    /// Newlines are kept as they are.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode as this encoding when the input has no byte order mark.
+    pub fn with_encoding(mut self, encoding: SourceEncoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Turn every `\r\n` into `\n`, so grammars only have to deal with one kind of newline.
+    /// Spans still count both bytes of the original `\r\n`.
+    pub fn with_normalized_newlines(mut self, normalize: bool) -> Self {
+        self.normalize_newlines = normalize;
+        self
+    }
+}
+
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("invalid utf-8 at byte {0}")]
+    InvalidUtf8(usize),
+
+    #[error("invalid utf-16 at byte {0}")]
+    InvalidUtf16(usize),
+
+    #[error("utf-16 input has an odd number of bytes")]
+    OddUtf16Length,
+
+    #[error("binary input can't be decoded as text, use `SourceFile::new_bytes` instead")]
+    Binary,
+}
+
+/// A character which takes up a different number of bytes in the original input
+/// than in the (utf-8) contents of the source file.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct WidthException {
+    /// Where the character starts in the contents
+    pub(crate) str_offset: usize,
+    /// Where the character starts in the original input
+    pub(crate) offset: usize,
+    /// How many bytes the character takes in the contents
+    pub(crate) str_width: usize,
+    /// How many bytes the character takes in the original input
+    pub(crate) width: usize,
+}
+
+/// Converts between byte offsets in the original input (which spans use) and
+/// byte offsets in the contents of a source file. Only the characters which
+/// have a different width in both are stored, so for utf-8 input it is cheap.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct OffsetMap {
+    /// Where the contents start in the original input, after the byte order mark
+    pub(crate) start: usize,
+    /// In ascending order
+    pub(crate) exceptions: Vec<WidthException>,
+}
+
+impl OffsetMap {
+    /// Converts an offset in the original input to one in the contents.
+    /// Offsets in the middle of a character are rounded down to the start of it.
+    pub(crate) fn to_str(&self, offset: usize) -> usize {
+        let offset = offset.max(self.start);
+        let i = self.exceptions.partition_point(|e| e.offset <= offset);
+        match i.checked_sub(1).map(|i| self.exceptions[i]) {
+            None => offset - self.start,
+            Some(e) if offset < e.offset + e.width => e.str_offset,
+            Some(e) => e.str_offset + e.str_width + (offset - e.offset - e.width),
+        }
+    }
+
+    /// Converts an offset in the contents to one in the original input.
+    pub(crate) fn to_original(&self, str_offset: usize) -> usize {
+        let i = self
+            .exceptions
+            .partition_point(|e| e.str_offset <= str_offset);
+        match i.checked_sub(1).map(|i| self.exceptions[i]) {
+            None => self.start + str_offset,
+            Some(e) if str_offset < e.str_offset + e.str_width => e.offset,
+            Some(e) => e.offset + e.width + (str_offset - e.str_offset - e.str_width),
+        }
+    }
+
+    /// The exceptions at or after an offset in the original input.
+    pub(crate) fn exceptions_from(&self, offset: usize) -> &[WidthException] {
+        &self.exceptions[self.exceptions.partition_point(|e| e.offset < offset)..]
+    }
+}
+
+/// Builds the contents of a source file character by character, keeping track of the offset map.
+#[derive(Default)]
+pub(crate) struct Decoder {
+    pub(crate) contents: String,
+    pub(crate) map: OffsetMap,
+    offset: usize,
+}
+
+impl Decoder {
+    pub(crate) fn new(start: usize) -> Self {
+        Self {
+            contents: String::new(),
+            map: OffsetMap {
+                start,
+                exceptions: vec![],
+            },
+            offset: start,
+        }
+    }
+
+    /// Adds a character which took `width` bytes in the original input.
+    pub(crate) fn push(&mut self, c: char, width: usize) {
+        if width != c.len_utf8() {
+            self.map.exceptions.push(WidthException {
+                str_offset: self.contents.len(),
+                offset: self.offset,
+                str_width: c.len_utf8(),
+                width,
+            });
+        }
+        self.contents.push(c);
+        self.offset += width;
+    }
+
+    /// Adds characters with their width in the original input, turning `\r\n` into `\n` if asked to.
+    fn extend(&mut self, chars: impl Iterator<Item = (char, usize)>, normalize_newlines: bool) {
+        let mut chars = chars.peekable();
+        while let Some((c, width)) = chars.next() {
+            match chars.peek() {
+                Some(&('\n', newline_width)) if normalize_newlines && c == '\r' => {
+                    chars.next();
+                    self.push('\n', width + newline_width);
+                }
+                _ => self.push(c, width),
+            }
+        }
+    }
+
+    /// Decodes a binary input, in which every byte is one character.
+    pub(crate) fn bytes(bytes: &[u8]) -> Self {
+        let mut decoder = Self::new(0);
+        decoder.contents.reserve(bytes.len());
+        bytes.iter().for_each(|&b| decoder.push(char::from(b), 1));
+        decoder
+    }
+
+    /// Decodes text, returning the contents, the offset map and the encoding that was used.
+    pub(crate) fn text(
+        bytes: &[u8],
+        options: &DecodeOptions,
+    ) -> Result<(Self, SourceEncoding), DecodeError> {
+        let (encoding, start) = match SourceEncoding::detect(bytes) {
+            Some(encoding) => (encoding, encoding.bom().len()),
+            None => (options.encoding.unwrap_or(SourceEncoding::Utf8), 0),
+        };
+        let input = &bytes[start..];
+        let mut decoder = Self::new(start);
+
+        match encoding {
+            SourceEncoding::Utf8 => {
+                let text = std::str::from_utf8(input)
+                    .map_err(|e| DecodeError::InvalidUtf8(start + e.valid_up_to()))?;
+                if options.normalize_newlines {
+                    decoder.contents.reserve(text.len());
+                    decoder.extend(text.chars().map(|c| (c, c.len_utf8())), true);
+                } else {
+                    //Every character has the same width, only the byte order mark has to be skipped
+                    decoder.contents = text.to_string();
+                }
+            }
+            SourceEncoding::Utf16Le | SourceEncoding::Utf16Be => {
+                if !input.len().is_multiple_of(2) {
+                    return Err(DecodeError::OddUtf16Length);
+                }
+                let units = input.chunks(2).map(|unit| match encoding {
+                    SourceEncoding::Utf16Le => u16::from_le_bytes([unit[0], unit[1]]),
+                    _ => u16::from_be_bytes([unit[0], unit[1]]),
+                });
+
+                let mut chars = Vec::with_capacity(input.len() / 2);
+                let mut offset = start;
+                for c in char::decode_utf16(units) {
+                    let c = c.map_err(|_| DecodeError::InvalidUtf16(offset))?;
+                    chars.push((c, c.len_utf16() * 2));
+                    offset += c.len_utf16() * 2;
+                }
+                decoder.extend(chars.into_iter(), options.normalize_newlines);
+            }
+            SourceEncoding::Bytes => return Err(DecodeError::Binary),
+        }
+
+        Ok((decoder, encoding))
+    }
+}
@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A position in a file as a line and a column, both starting at 0 like in the
+/// language server protocol. In which unit the column is counted depends on
+/// where the position came from, see [`ColumnUnit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct LineCol {
+    pub line: usize,
+    pub col: usize,
+}
+
+/// Displays the position starting at 1, the way editors show it.
+impl Display for LineCol {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line + 1, self.col + 1)
+    }
+}
+
+/// The unit in which columns are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColumnUnit {
+    /// Bytes, the same unit as the offsets in spans
+    Byte,
+    /// UTF-16 code units, which is what the language server protocol uses by default
+    Utf16,
+    /// Unicode code points (chars)
+    Utf32,
+}
+
+impl ColumnUnit {
+    fn width(&self, c: char) -> usize {
+        match self {
+            ColumnUnit::Byte => c.len_utf8(),
+            ColumnUnit::Utf16 => c.len_utf16(),
+            ColumnUnit::Utf32 => 1,
+        }
+    }
+}
+
+/// The byte offsets at which each line of a file starts.
+/// Lines are ended by `\n`, so a `\r` before it is counted as part of the line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineIndex {
+    line_starts: Vec<usize>,
+    len: usize,
+}
+
+impl LineIndex {
+    /// Builds the index for a file with these bytes.
+    pub fn new(bytes: &[u8]) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(
+                bytes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &b)| b == b'\n')
+                    .map(|(i, _)| i + 1),
+            )
+            .collect();
+        Self {
+            line_starts,
+            len: bytes.len(),
+        }
+    }
+
+    /// Builds the index from the offsets at which lines start, the first of which is 0.
+    pub(crate) fn from_line_starts(line_starts: Vec<usize>, len: usize) -> Self {
+        Self { line_starts, len }
+    }
+
+    /// The number of lines. A file which ends with a newline has an empty last line.
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// The line that the byte offset is on. Offsets past the end are on the last line.
+    pub fn line(&self, offset: usize) -> usize {
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// The byte offsets where the line starts and ends, not including the `\n` that ends it.
+    /// Returns `None` when there is no such line.
+    pub fn line_range(&self, line: usize) -> Option<(usize, usize)> {
+        let start = *self.line_starts.get(line)?;
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.len, |next| next - 1);
+        Some((start, end))
+    }
+
+    /// Counts the columns in `text`, which is the start of a line.
+    pub(crate) fn columns(text: &str, unit: ColumnUnit) -> usize {
+        match unit {
+            ColumnUnit::Byte => text.len(),
+            _ => text.chars().map(|c| unit.width(c)).sum(),
+        }
+    }
+
+    /// The length in bytes of the start of `line_text` which is `col` columns wide.
+    /// Columns past the end of the line are clamped to the end, and columns in the
+    /// middle of a character are rounded down to the start of it.
+    pub(crate) fn column_bytes(line_text: &str, col: usize, unit: ColumnUnit) -> usize {
+        let mut width = 0;
+        for (i, c) in line_text.char_indices() {
+            width += unit.width(c);
+            if width > col {
+                return i;
+            }
+        }
+        line_text.len()
+    }
+
+    /// The column of the end of `text` when it is displayed, where tabs go to the next multiple of `tab_width`.
+    pub(crate) fn display_columns(text: &str, tab_width: usize) -> usize {
+        text.chars().fold(0, |col, c| match c {
+            '\t' if tab_width > 0 => (col / tab_width + 1) * tab_width,
+            _ => col + 1,
+        })
+    }
+}
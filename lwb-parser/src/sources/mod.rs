@@ -1,8 +1,21 @@
+/// Spans that only store offsets and an index of their file, to keep syntax trees small.
+pub mod compact_span;
+/// Decoding source files from utf-8 and utf-16, with byte order marks and normalized newlines.
+pub mod encoding;
+/// Lines and columns in source files, including the
+/// UTF-16 columns that the language server protocol uses.
+pub mod line_index;
 /// Source files (stored together with a name).
 pub mod source_file;
+/// Sets of source files with stable ids, for programs that consist of multiple files.
+pub mod source_map;
 /// Code spans (which reference to a source file)
 pub mod span;
 
 /// Character classes describe sets of characters
 /// (with ranges, or actual sets, or logical relations between sets).
 pub mod character_class;
+
+/// Input that is read from a stream in chunks, for inputs
+/// that are too large to read into a source file at once.
+pub mod stream;
@@ -1,11 +1,15 @@
-use crate::sources::character_class::CharacterClass;
+use crate::sources::character_class::{CharacterClass, CompiledCharacterClass};
+use crate::sources::encoding::{
+    DecodeError, DecodeOptions, Decoder, OffsetMap, SourceEncoding, WidthException,
+};
+use crate::sources::line_index::{ColumnUnit, LineCol, LineIndex};
+use crate::sources::source_map::FileId;
 use miette::{MietteError, SourceCode, SourceSpan, SpanContents};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::io;
-use std::io::Read;
-use std::iter::Peekable;
+use std::ops::Range;
 use std::path::Path;
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 #[doc(hidden)]
 #[derive(Debug, Serialize, Deserialize)]
@@ -13,6 +17,25 @@ struct Inner {
     contents: String,
     contents_for_display: String,
     name: String,
+    encoding: SourceEncoding,
+    /// The original bytes, when they are not the utf-8 encoding of `contents`.
+    /// This is the case for binary source files, and for text that was decoded
+    /// (see [`SourceFile::decode`]).
+    original: Option<Vec<u8>>,
+    /// Converts between offsets in the original bytes and in `contents`
+    offsets: OffsetMap,
+    /// Built the first time a line or column is asked for
+    #[serde(skip)]
+    line_index: OnceLock<LineIndex>,
+    /// Set when the file is part of a [`SourceMap`](crate::sources::source_map::SourceMap)
+    #[serde(skip)]
+    id: Option<FileId>,
+    /// The source map the file belongs to, since ids are only unique within a source map
+    #[serde(skip)]
+    map: Option<u64>,
+    /// True for the empty stand-ins made by [`SourceFile::detached`]
+    #[serde(skip)]
+    detached: bool,
 }
 
 /// SourceFile represents a source into which spans
@@ -21,36 +44,104 @@ struct Inner {
 #[derive(Clone, Debug)]
 pub struct SourceFile(Arc<Inner>);
 
+/// Only the [`FileId`] of a source file is serialized, not its contents.
+/// Deserializing gives a [detached](SourceFile::is_detached) file, which
+/// can be swapped for the real one with [`SourceMap::resolve`](crate::sources::source_map::SourceMap::resolve).
 impl Serialize for SourceFile {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        serializer.serialize_unit()
+        self.id().serialize(serializer)
     }
 }
 
 impl<'de> Deserialize<'de> for SourceFile {
-    fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        Ok(SourceFile::new("", "dummmy"))
+        Ok(Self::detached(Option::<FileId>::deserialize(deserializer)?))
     }
 }
 
 impl SourceFile {
+    /// Opens a text file. The encoding is detected from the byte order mark, see [`SourceFile::decode`].
     pub fn open(name: impl AsRef<Path>) -> io::Result<Self> {
-        let mut f = std::fs::File::open(&name)?;
-        let mut contents = String::new();
+        Self::open_decoded(name, &DecodeOptions::default())
+    }
+
+    /// Opens a text file, decoding it as configured by the options. See [`SourceFile::decode`].
+    pub fn open_decoded(name: impl AsRef<Path>, options: &DecodeOptions) -> io::Result<Self> {
+        let bytes = std::fs::read(&name)?;
+
+        Self::decode(bytes, name.as_ref().to_string_lossy(), options)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Creates a source file from text in utf-8 or utf-16. A byte order mark at the start
+    /// selects the encoding and is removed, without one the encoding from the options (by default utf-8)
+    /// is used. `\r\n` can be turned into `\n` with [`DecodeOptions::with_normalized_newlines`].
+    ///
+    /// Spans are byte offsets into the original input, so they still point at the right place
+    /// when the input is shown to users. Use [`str_offset`](SourceFile::str_offset) to index the contents.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::encoding::{DecodeOptions, SourceEncoding};
+    /// // "a\r\nb" in utf-16le, with a byte order mark
+    /// let bytes = [0xff, 0xfe, b'a', 0, b'\r', 0, b'\n', 0, b'b', 0];
+    /// let options = DecodeOptions::new().with_normalized_newlines(true);
+    /// let sf = SourceFile::decode(bytes, "test", &options).unwrap();
+    ///
+    /// assert_eq!(sf.contents(), "a\nb");
+    /// assert_eq!(sf.encoding(), SourceEncoding::Utf16Le);
+    ///
+    /// let mut sfi = sf.iter();
+    /// assert_eq!(sfi.position(), 2);
+    /// assert!(sfi.accept_str("a\n"));
+    /// assert_eq!(sfi.position(), 8);
+    /// assert_eq!(sf.str_offset(8), 2);
+    /// ```
+    pub fn decode(
+        bytes: impl AsRef<[u8]>,
+        name: impl AsRef<str>,
+        options: &DecodeOptions,
+    ) -> Result<Self, DecodeError> {
+        let bytes = bytes.as_ref();
+        let (decoder, encoding) = Decoder::text(bytes, options)?;
+        //Keep the original only when the offsets are different
+        let original =
+            (decoder.map.start > 0 || !decoder.map.exceptions.is_empty()).then(|| bytes.to_vec());
+
+        Ok(Self::from_parts(decoder, name.as_ref(), encoding, original))
+    }
+
+    fn from_parts(
+        decoder: Decoder,
+        name: &str,
+        encoding: SourceEncoding,
+        original: Option<Vec<u8>>,
+    ) -> Self {
+        Self(Arc::new(Inner {
+            contents_for_display: decoder.contents.clone() + "        ",
+            contents: decoder.contents,
+            name: name.to_string(),
+            encoding,
+            original,
+            offsets: decoder.map,
+            line_index: OnceLock::new(),
+            id: None,
+            map: None,
+            detached: false,
+        }))
+    }
 
-        f.read_to_string(&mut contents)?;
+    /// Opens a file as a binary source file. See [`SourceFile::new_bytes`].
+    pub fn open_bytes(name: impl AsRef<Path>) -> io::Result<Self> {
+        let bytes = std::fs::read(&name)?;
 
-        Ok(Self(Arc::new(Inner {
-            contents: contents.clone(),
-            contents_for_display: contents + "        ",
-            name: name.as_ref().to_string_lossy().to_string(),
-        })))
+        Ok(Self::new_bytes(bytes, name.as_ref().to_string_lossy()))
     }
 
     /// Create a new SourceFile
@@ -59,18 +150,242 @@ impl SourceFile {
             contents: contents.as_ref().to_string(),
             contents_for_display: contents.as_ref().to_string() + "        ",
             name: name.as_ref().to_string(),
+            encoding: SourceEncoding::Utf8,
+            original: None,
+            offsets: OffsetMap::default(),
+            line_index: OnceLock::new(),
+            id: None,
+            map: None,
+            detached: false,
         }))
     }
 
+    /// Create a new SourceFile from raw bytes, for parsing binary formats.
+    ///
+    /// The parser sees every byte as a single character with the same value
+    /// (`\x00` to `\xff`), so `binary` grammars can match bytes with character classes
+    /// like `[\x00-\x7f]`. Spans are byte offsets into the original bytes.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// let sf = SourceFile::new_bytes([0x7f, b'E', 0xff], "test.bin");
+    /// let mut sfi = sf.iter();
+    ///
+    /// assert!(sfi.accept(&'\x7f'.into()));
+    /// assert!(sfi.accept(&'E'.into()));
+    /// assert!(sfi.accept(&'\u{ff}'.into()));
+    /// assert_eq!(sfi.position(), 3);
+    /// assert!(sfi.exhausted());
+    /// ```
+    pub fn new_bytes(bytes: impl AsRef<[u8]>, name: impl AsRef<str>) -> Self {
+        let bytes = bytes.as_ref();
+        Self::from_parts(
+            Decoder::bytes(bytes),
+            name.as_ref(),
+            SourceEncoding::Bytes,
+            Some(bytes.to_vec()),
+        )
+    }
+
+    /// An empty stand-in for a file that is not available, like the file of a deserialized span.
+    /// See [`SourceMap::resolve`](crate::sources::source_map::SourceMap::resolve).
+    pub fn detached(id: Option<FileId>) -> Self {
+        let name = match id {
+            Some(id) => format!("<detached {id}>"),
+            None => "<detached>".to_string(),
+        };
+        let mut file = Self::new("", name);
+        let inner = Arc::get_mut(&mut file.0).expect("a new file is not shared");
+        inner.detached = true;
+        inner.id = id;
+        file
+    }
+
     pub fn new_for_test(s: impl AsRef<str>) -> Self {
         Self::new(s.as_ref(), "test")
     }
 
     pub fn iter(&self) -> SourceFileIterator {
+        self.iter_at(0)
+    }
+
+    /// Returns an iterator which starts at the given byte offset.
+    /// Panics when the offset is not on a character boundary.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// let sf = SourceFile::new_for_test("test");
+    /// let mut sfi = sf.iter_at(2);
+    ///
+    /// assert_eq!(sfi.position(), 2);
+    /// assert!(sfi.accept_str("st"));
+    /// assert!(sfi.exhausted());
+    /// ```
+    ///
+    /// Panics when `offset` is not [at the start of a character](SourceFile::is_char_boundary).
+    pub fn iter_at(&self, offset: usize) -> SourceFileIterator<'_> {
+        let contents = &self.0.contents[..];
+        let str_offset = self.str_offset(offset);
         SourceFileIterator {
-            inner_iter: self.0.contents.chars().peekable(),
-            index: 0,
+            contents,
+            str_offset,
+            next: decode_at(contents, str_offset),
+            index: offset.max(self.0.offsets.start),
+            exceptions: self.0.offsets.exceptions_from(offset),
+        }
+    }
+
+    /// returns true if this source file was created from raw bytes
+    pub fn is_binary(&self) -> bool {
+        self.0.encoding == SourceEncoding::Bytes
+    }
+
+    /// The encoding this source file was read in
+    pub fn encoding(&self) -> SourceEncoding {
+        self.0.encoding
+    }
+
+    /// returns the bytes of this source file. For binary and decoded
+    /// source files these are the original bytes, otherwise the utf-8
+    /// encoding of the contents.
+    pub fn bytes(&self) -> &[u8] {
+        match &self.0.original {
+            Some(original) => original,
+            None => self.0.contents.as_bytes(),
+        }
+    }
+
+    /// Converts a byte offset (like the ones in spans) into an offset
+    /// into [`contents`](SourceFile::contents). These are only different
+    /// for binary and decoded source files.
+    pub fn str_offset(&self, offset: usize) -> usize {
+        match &self.0.original {
+            Some(_) => self.0.offsets.to_str(offset),
+            None => offset,
+        }
+    }
+
+    /// Converts an offset into [`contents`](SourceFile::contents) back into a
+    /// byte offset like the ones in spans. The opposite of [`str_offset`](SourceFile::str_offset).
+    pub fn original_offset(&self, str_offset: usize) -> usize {
+        match &self.0.original {
+            Some(_) => self.0.offsets.to_original(str_offset),
+            None => str_offset,
+        }
+    }
+
+    /// Returns true if `offset` is the byte offset of a character of this file, or of its end.
+    /// Only at these offsets the file can be read with [`iter_at`](SourceFile::iter_at).
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// let sf = SourceFile::new_for_test("aé");
+    /// assert!(sf.is_char_boundary(1));
+    /// assert!(!sf.is_char_boundary(2));
+    /// assert!(sf.is_char_boundary(3));
+    /// assert!(!sf.is_char_boundary(4));
+    /// ```
+    pub fn is_char_boundary(&self, offset: usize) -> bool {
+        let str_offset = self.str_offset(offset);
+        self.contents().is_char_boundary(str_offset) && self.original_offset(str_offset) == offset
+    }
+
+    /// The id of this file in the [`SourceMap`](crate::sources::source_map::SourceMap) it belongs to, if any.
+    pub fn id(&self) -> Option<FileId> {
+        self.0.id
+    }
+
+    /// Gives a newly created file its id in the source map with key `map`.
+    pub(crate) fn with_id(mut self, id: FileId, map: u64) -> Self {
+        let inner =
+            Arc::get_mut(&mut self.0).expect("only new source files are added to a source map");
+        inner.id = Some(id);
+        inner.map = Some(map);
+        self
+    }
+
+    /// Gives a newly created file the id of `other`, making it a new version of that file.
+    pub(crate) fn with_id_of(mut self, other: &SourceFile) -> Self {
+        let inner = Arc::get_mut(&mut self.0).expect("only new source files get an id");
+        inner.id = other.0.id;
+        inner.map = other.0.map;
+        self
+    }
+
+    /// A new file in which the text at `range` of the [`contents`](SourceFile::contents) is replaced by `text`.
+    /// The text is encoded like the rest of the file, and the characters that are not replaced keep the width
+    /// they have in the original input, so spans before the range stay the same. The new file is a new
+    /// version of this one, with the same [`id`](SourceFile::id).
+    ///
+    /// Panics when the range is out of bounds or not on character boundaries, or when `text` has
+    /// characters above `\xff` for a binary file.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::encoding::{DecodeOptions, SourceEncoding};
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// // "a\r\nb" in utf-16le, with a byte order mark
+    /// let bytes = [0xff, 0xfe, b'a', 0, b'\r', 0, b'\n', 0, b'b', 0];
+    /// let options = DecodeOptions::new().with_normalized_newlines(true);
+    /// let sf = SourceFile::decode(bytes, "test", &options).unwrap();
+    ///
+    /// let edited = sf.edited(2..3, "cd");
+    /// assert_eq!(edited.contents(), "a\ncd");
+    /// assert_eq!(edited.encoding(), SourceEncoding::Utf16Le);
+    /// assert_eq!(edited.original_offset(2), 8);
+    /// assert_eq!(edited.bytes()[8..], [b'c', 0, b'd', 0]);
+    /// ```
+    pub fn edited(&self, range: Range<usize>, text: &str) -> Self {
+        let contents = self.contents();
+        let (before, after) = (&contents[..range.start], &contents[range.end..]);
+        let Some(original) = &self.0.original else {
+            return Self::new(format!("{before}{text}{after}"), self.name()).with_id_of(self);
+        };
+
+        //The characters that are kept take their bytes from the original input, so they keep their width
+        let (start, end) = (
+            self.original_offset(range.start),
+            self.original_offset(range.end),
+        );
+        let mut bytes = original[..start].to_vec();
+        let mut decoder = Decoder::new(self.0.offsets.start);
+        for (i, c) in before.char_indices() {
+            let width = self.original_offset(i + c.len_utf8()) - self.original_offset(i);
+            decoder.push(c, width);
+        }
+        for c in text.chars() {
+            let width = self.0.encoding.encode(c, &mut bytes);
+            decoder.push(c, width);
         }
+        bytes.extend_from_slice(&original[end..]);
+        for (i, c) in after.char_indices() {
+            let i = range.end + i;
+            let width = self.original_offset(i + c.len_utf8()) - self.original_offset(i);
+            decoder.push(c, width);
+        }
+
+        Self::from_parts(decoder, self.name(), self.0.encoding, Some(bytes)).with_id_of(self)
+    }
+
+    /// True when this is an empty stand-in for a file that is not available, see [`SourceFile::detached`].
+    pub fn is_detached(&self) -> bool {
+        self.0.detached
+    }
+
+    /// True when both are the same file: either clones of each other, or versions of the file with the same id
+    /// in the same [`SourceMap`](crate::sources::source_map::SourceMap). [Detached](SourceFile::is_detached) files
+    /// are the same file when they have the same id.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// let a = SourceFile::new_for_test("a");
+    /// let b = SourceFile::new_for_test("a");
+    ///
+    /// assert!(a.same_file(&a.clone()));
+    /// assert!(!a.same_file(&b));
+    /// ```
+    pub fn same_file(&self, other: &SourceFile) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+            || (self.id().is_some() && self.id() == other.id() && self.0.map == other.0.map)
     }
 
     /// returns the name of this source file
@@ -82,6 +397,10 @@ impl SourceFile {
     /// string. When parsing you likely often want to
     /// use `.iter()` instead as the source file iterator
     /// has a number of methods useful for parsing.
+    ///
+    /// For binary source files, this is the latin-1 decoding
+    /// of the bytes, and for decoded source files the decoded text. Use [`str_offset`](SourceFile::str_offset)
+    /// to index it with byte offsets.
     pub fn contents(&self) -> &str {
         &self.0.contents
     }
@@ -89,19 +408,161 @@ impl SourceFile {
     pub fn contents_for_display(&self) -> &str {
         &self.0.contents_for_display
     }
+
+    /// The start of every line in this file. Built the first time it is used.
+    pub fn line_index(&self) -> &LineIndex {
+        self.0.line_index.get_or_init(|| match self.0.encoding {
+            SourceEncoding::Utf16Le | SourceEncoding::Utf16Be => LineIndex::from_line_starts(
+                std::iter::once(0)
+                    .chain(
+                        self.contents()
+                            .match_indices('\n')
+                            .map(|(i, _)| self.original_offset(i + 1)),
+                    )
+                    .collect(),
+                self.bytes().len(),
+            ),
+            //A newline is the same byte in the original as in the contents, also when `\r\n` was normalized
+            _ => LineIndex::new(self.bytes()),
+        })
+    }
+
+    /// The text from the start of the line up to the byte offset, which is clamped to the
+    /// end of the file and rounded down to the start of a character.
+    fn line_prefix(&self, offset: usize) -> (LineCol, &str) {
+        let index = self.line_index();
+        let offset = offset.min(self.bytes().len());
+        let line = index.line(offset);
+        let (start, _) = index.line_range(line).expect("line exists");
+
+        let contents = self.contents();
+        let mut end = self.str_offset(offset);
+        while !contents.is_char_boundary(end) {
+            end -= 1;
+        }
+        (
+            LineCol {
+                line,
+                col: offset - start,
+            },
+            &contents[self.str_offset(start)..end],
+        )
+    }
+
+    /// The line and column of a byte offset, both starting at 0. The column is in bytes.
+    /// Offsets past the end of the file are clamped to the end.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::line_index::LineCol;
+    /// let sf = SourceFile::new_for_test("ab\ncd");
+    /// assert_eq!(sf.line_col(0), LineCol { line: 0, col: 0 });
+    /// assert_eq!(sf.line_col(4), LineCol { line: 1, col: 1 });
+    /// assert_eq!(sf.line_col(4).to_string(), "2:2");
+    /// ```
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        self.line_col_in(offset, ColumnUnit::Byte)
+    }
+
+    /// Like [`line_col`](SourceFile::line_col), but the column is counted in `unit`.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::line_index::{ColumnUnit, LineCol};
+    /// let sf = SourceFile::new_for_test("é😀x");
+    /// let offset = "é😀".len();
+    /// assert_eq!(sf.line_col_in(offset, ColumnUnit::Byte).col, 6);
+    /// assert_eq!(sf.line_col_in(offset, ColumnUnit::Utf16).col, 3);
+    /// assert_eq!(sf.line_col_in(offset, ColumnUnit::Utf32).col, 2);
+    /// ```
+    pub fn line_col_in(&self, offset: usize, unit: ColumnUnit) -> LineCol {
+        let (line_col, prefix) = self.line_prefix(offset);
+        match unit {
+            //For binary files the prefix is not in bytes
+            ColumnUnit::Byte => line_col,
+            _ => LineCol {
+                line: line_col.line,
+                col: LineIndex::columns(prefix, unit),
+            },
+        }
+    }
+
+    /// The byte offset of a line and a column in bytes, both starting at 0.
+    /// Returns `None` when the line does not exist. Columns past the end of the line
+    /// are clamped to the end of it.
+    pub fn offset(&self, line: usize, col: usize) -> Option<usize> {
+        self.offset_in(LineCol { line, col }, ColumnUnit::Byte)
+    }
+
+    /// Like [`offset`](SourceFile::offset), but the column is counted in `unit`.
+    /// Columns in the middle of a character give the offset of the start of that character.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::line_index::{ColumnUnit, LineCol};
+    /// let sf = SourceFile::new_for_test("a\n😀b");
+    /// let position = LineCol { line: 1, col: 2 };
+    /// assert_eq!(sf.offset_in(position, ColumnUnit::Utf16), Some(6));
+    /// assert_eq!(sf.offset_in(position, ColumnUnit::Utf32), Some(7));
+    /// assert_eq!(sf.offset_in(LineCol { line: 2, col: 0 }, ColumnUnit::Utf16), None);
+    /// ```
+    pub fn offset_in(&self, position: LineCol, unit: ColumnUnit) -> Option<usize> {
+        let (start, end) = self.line_index().line_range(position.line)?;
+        if unit == ColumnUnit::Byte {
+            return Some(start + position.col.min(end - start));
+        }
+
+        let line_start = self.str_offset(start);
+        let line_text = &self.contents()[line_start..self.str_offset(end)];
+        let bytes = LineIndex::column_bytes(line_text, position.col, unit);
+        Some(self.original_offset(line_start + bytes))
+    }
+
+    /// The column (starting at 0) at which a byte offset is displayed, when tabs
+    /// go to the next multiple of `tab_width` and every other character is one column wide.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// let sf = SourceFile::new_for_test("\tab\tc");
+    /// assert_eq!(sf.display_column(1, 4), 4);
+    /// assert_eq!(sf.display_column(4, 4), 8);
+    /// assert_eq!(sf.display_column(4, 8), 16);
+    /// ```
+    pub fn display_column(&self, offset: usize, tab_width: usize) -> usize {
+        let (_, prefix) = self.line_prefix(offset);
+        LineIndex::display_columns(prefix, tab_width)
+    }
 }
 
-#[derive(Clone)]
+/// The character at an offset in a string, which has to be on a character boundary.
+#[inline]
+fn decode_at(contents: &str, str_offset: usize) -> Option<char> {
+    match *contents.as_bytes().get(str_offset)? {
+        b if b.is_ascii() => Some(char::from(b)),
+        _ => contents[str_offset..].chars().next(),
+    }
+}
+
+/// A position in a source file, which can be used to read the file from there on.
+/// It is a cheap `Copy` cursor, so the parser can keep as many positions around as it needs.
+#[derive(Clone, Copy)]
 pub struct SourceFileIterator<'a> {
-    inner_iter: Peekable<std::str::Chars<'a>>,
+    contents: &'a str,
+    /// The offset of the next character in `contents`
+    str_offset: usize,
+    /// The next character, decoded ahead so it can be peeked at
+    next: Option<char>,
+    /// The offset of the next character in the original bytes, see [`SourceFile::str_offset`]
     index: usize,
+    /// The characters ahead that have a different width in the original bytes than in utf-8
+    exceptions: &'a [WidthException],
 }
 
 impl<'a> SourceFileIterator<'a> {
     /// Peek at the next character that can be obtained
     /// by calling [`next`] or [`accept`].
     pub fn peek(&mut self) -> Option<&char> {
-        self.inner_iter.peek()
+        self.next.as_ref()
     }
 
     /// Advance to the next character, discarding any
@@ -147,6 +608,18 @@ impl<'a> SourceFileIterator<'a> {
         self.accept_option(c).is_some()
     }
 
+    /// Like [`accept`](SourceFileIterator::accept), but for a compiled character class.
+    #[inline]
+    pub fn accept_compiled(&mut self, c: &CompiledCharacterClass) -> bool {
+        match self.peek() {
+            Some(&next) if c.contains(next) => {
+                self.advance();
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Like accepts but returns an option
     pub fn accept_option(&mut self, c: &CharacterClass) -> Option<char> {
         if let Some(true) = self.peek().map(|&i| c.contains(i)) {
@@ -179,11 +652,12 @@ impl<'a> SourceFileIterator<'a> {
     /// assert!(sfi.exhausted());
     /// ```
     pub fn accept_str(&mut self, s: &str) -> bool {
-        let mut self_clone = self.clone();
+        let mut self_clone = *self;
         for c in s.chars() {
-            if !self_clone.accept(&c.into()) {
+            if self_clone.peek() != Some(&c) {
                 return false;
             }
+            self_clone.advance();
         }
 
         *self = self_clone;
@@ -216,7 +690,7 @@ impl<'a> SourceFileIterator<'a> {
     /// assert!(sfi.accept_skip_layout(&'t'.into(), &' '.into()));
     /// ```
     pub fn accept_skip_layout(&mut self, c: &CharacterClass, layout: &CharacterClass) -> bool {
-        let mut self_clone = self.clone();
+        let mut self_clone = *self;
         self_clone.skip_layout(layout);
         if self_clone.accept(c) {
             *self = self_clone;
@@ -237,7 +711,7 @@ impl<'a> SourceFileIterator<'a> {
     /// assert!(sfi.accept_str_skip_layout("test", &' '.into()));
     /// ```
     pub fn accept_str_skip_layout(&mut self, s: &str, layout: &CharacterClass) -> bool {
-        let mut self_clone = self.clone();
+        let mut self_clone = *self;
         self_clone.skip_layout(layout);
         if self_clone.accept_str(s) {
             *self = self_clone;
@@ -304,10 +778,19 @@ impl<'a> SourceFileIterator<'a> {
 impl<'a> Iterator for SourceFileIterator<'a> {
     type Item = char;
 
+    #[inline]
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.inner_iter.next();
+        let next = self.next;
         if let Some(next) = next {
-            self.index += next.len_utf8();
+            match self.exceptions.split_first() {
+                Some((exception, rest)) if exception.offset == self.index => {
+                    self.index += exception.width;
+                    self.exceptions = rest;
+                }
+                _ => self.index += next.len_utf8(),
+            }
+            self.str_offset += next.len_utf8();
+            self.next = decode_at(self.contents, self.str_offset);
         }
         next
     }
@@ -322,9 +805,18 @@ impl SourceCode for SourceFile {
     ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
         <str as SourceCode>::read_span(
             self.contents(),
-            span,
+            &self.str_span(span),
             context_lines_before,
             context_lines_after,
         )
     }
 }
+
+impl SourceFile {
+    /// Converts a span with byte offsets to one that can index [`contents`](SourceFile::contents).
+    pub(crate) fn str_span(&self, span: &SourceSpan) -> SourceSpan {
+        let start = self.str_offset(span.offset());
+        let end = self.str_offset(span.offset() + span.len());
+        SourceSpan::from((start, end - start))
+    }
+}
@@ -0,0 +1,264 @@
+use crate::sources::encoding::{DecodeError, DecodeOptions};
+use crate::sources::source_file::SourceFile;
+use crate::sources::span::Span;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use thiserror::Error;
+
+/// Identifies a file in a [`SourceMap`]. The id of a path never changes, also
+/// not when the file is reloaded or gets an overlay, so it can be stored in
+/// (serialized) spans and syntax trees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct FileId(u32);
+
+impl FileId {
+    /// The position of the file in the source map, starting at 0.
+    pub fn index(&self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Display for FileId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "file {}", self.0)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum SourceMapError {
+    #[error("{0} is not in this source map")]
+    UnknownFile(FileId),
+
+    #[error("span is not in a file from a source map")]
+    NoFileId,
+
+    #[error("span at {position} with length {length} is not in the current version of {id}")]
+    OutOfBounds {
+        id: FileId,
+        position: usize,
+        length: usize,
+    },
+
+    #[error("couldn't read {path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+
+    #[error("couldn't decode {path}: {source}")]
+    Decode { path: PathBuf, source: DecodeError },
+}
+
+#[derive(Debug)]
+struct Entry {
+    path: PathBuf,
+    /// The contents of an unsaved editor buffer, which are used instead of the file on disk
+    overlay: Option<String>,
+    /// None until the file is used for the first time, and after it is reloaded
+    file: Option<SourceFile>,
+}
+
+#[derive(Debug, Default)]
+struct Files {
+    entries: Vec<Entry>,
+    ids: HashMap<PathBuf, FileId>,
+}
+
+impl Files {
+    fn id(&mut self, path: &Path) -> FileId {
+        if let Some(&id) = self.ids.get(path) {
+            return id;
+        }
+
+        let id = FileId(self.entries.len() as u32);
+        self.entries.push(Entry {
+            path: path.to_path_buf(),
+            overlay: None,
+            file: None,
+        });
+        self.ids.insert(path.to_path_buf(), id);
+        id
+    }
+
+    fn entry(&mut self, id: FileId) -> Result<&mut Entry, SourceMapError> {
+        self.entries
+            .get_mut(id.index())
+            .ok_or(SourceMapError::UnknownFile(id))
+    }
+}
+
+/// All source files of a program that consists of multiple files, like a language with modules.
+///
+/// Every path gets a [`FileId`], which spans in these files can be looked up with.
+/// Files are read from disk the first time they are used. Editors can set an overlay
+/// with the contents of an unsaved buffer, which is then used instead of the file on disk.
+///
+/// ```
+/// # use lwb_parser::sources::source_map::SourceMap;
+/// # use lwb_parser::sources::span::Span;
+/// let map = SourceMap::new();
+/// let main = map.set_overlay("main.lang", "import util;");
+/// let util = map.set_overlay("util.lang", "fn f() {}");
+/// assert_ne!(main, util);
+///
+/// let file = map.file(main).unwrap();
+/// assert_eq!(file.contents(), "import util;");
+/// assert_eq!(file.id(), Some(main));
+///
+/// // the id stays the same when the buffer is edited
+/// assert_eq!(map.set_overlay("main.lang", "import util2;"), main);
+///
+/// // old spans are moved to the newest version of their file
+/// let span = Span::from_length(&file, 7, 4);
+/// assert_eq!(map.resolve(&span).unwrap().as_str(), "util");
+/// ```
+#[derive(Debug)]
+pub struct SourceMap {
+    files: Mutex<Files>,
+    options: DecodeOptions,
+    /// Different for every source map, so files with the same id in different maps are not the same file
+    key: u64,
+}
+
+impl Default for SourceMap {
+    fn default() -> Self {
+        static KEYS: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            files: Default::default(),
+            options: Default::default(),
+            key: KEYS.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how files that are read from disk are decoded.
+    pub fn with_decode_options(mut self, options: DecodeOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// The id of the file at this path. The file is not read until it is used.
+    pub fn id(&self, path: impl AsRef<Path>) -> FileId {
+        self.files.lock().unwrap().id(path.as_ref())
+    }
+
+    /// The id of the file at this path, if it is in the source map.
+    pub fn lookup(&self, path: impl AsRef<Path>) -> Option<FileId> {
+        self.files.lock().unwrap().ids.get(path.as_ref()).copied()
+    }
+
+    /// The path of a file.
+    pub fn path(&self, id: FileId) -> Option<PathBuf> {
+        let files = self.files.lock().unwrap();
+        files.entries.get(id.index()).map(|e| e.path.clone())
+    }
+
+    /// The ids of all files, in the order they were added.
+    pub fn ids(&self) -> Vec<FileId> {
+        (0..self.len()).map(|i| FileId(i as u32)).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.lock().unwrap().entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The current version of a file. It is read from disk if it wasn't yet, unless it has an overlay.
+    pub fn file(&self, id: FileId) -> Result<SourceFile, SourceMapError> {
+        let mut files = self.files.lock().unwrap();
+        let entry = files.entry(id)?;
+        if let Some(file) = &entry.file {
+            return Ok(file.clone());
+        }
+
+        let file = match &entry.overlay {
+            Some(overlay) => SourceFile::new(overlay, entry.path.to_string_lossy()),
+            None => {
+                let bytes = std::fs::read(&entry.path).map_err(|source| SourceMapError::Io {
+                    path: entry.path.clone(),
+                    source,
+                })?;
+                SourceFile::decode(bytes, entry.path.to_string_lossy(), &self.options).map_err(
+                    |source| SourceMapError::Decode {
+                        path: entry.path.clone(),
+                        source,
+                    },
+                )?
+            }
+        }
+        .with_id(id, self.key);
+
+        entry.file = Some(file.clone());
+        Ok(file)
+    }
+
+    /// The file at this path, which is added to the source map if it isn't in it yet.
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<SourceFile, SourceMapError> {
+        self.file(self.id(path))
+    }
+
+    /// Uses `contents` instead of the file on disk, for example because it is open in an editor with unsaved
+    /// changes. The path does not have to exist, so this also adds files that only exist in memory.
+    pub fn set_overlay(&self, path: impl AsRef<Path>, contents: impl Into<String>) -> FileId {
+        let mut files = self.files.lock().unwrap();
+        let id = files.id(path.as_ref());
+        let entry = files.entry(id).expect("the id was just added");
+        entry.overlay = Some(contents.into());
+        entry.file = None;
+        id
+    }
+
+    /// Goes back to using the file on disk. It is read again the next time it is used.
+    pub fn remove_overlay(&self, path: impl AsRef<Path>) {
+        let mut files = self.files.lock().unwrap();
+        if let Some(&id) = files.ids.get(path.as_ref()) {
+            let entry = files.entry(id).expect("ids are never removed");
+            entry.overlay = None;
+            entry.file = None;
+        }
+    }
+
+    /// Forgets the current version of a file, so it is read from disk again the next time it is used.
+    /// This does nothing for files with an overlay.
+    pub fn reload(&self, id: FileId) -> Result<(), SourceMapError> {
+        let mut files = self.files.lock().unwrap();
+        let entry = files.entry(id)?;
+        if entry.overlay.is_none() {
+            entry.file = None;
+        }
+        Ok(())
+    }
+
+    /// The same span in the current version of its file. This gives the contents back to spans
+    /// from a deserialized syntax tree (whose files are [detached](SourceFile::is_detached)),
+    /// and moves spans from an old version of a file to the new one. The offsets are not changed,
+    /// so spans that are not in the current version of the file give [`SourceMapError::OutOfBounds`].
+    pub fn resolve(&self, span: &Span) -> Result<Span, SourceMapError> {
+        let id = span.source.id().ok_or(SourceMapError::NoFileId)?;
+        let file = self.file(id)?;
+
+        let end = span.position.checked_add(span.length);
+        let in_bounds = end
+            .is_some_and(|end| file.is_char_boundary(span.position) && file.is_char_boundary(end));
+        if !in_bounds {
+            return Err(SourceMapError::OutOfBounds {
+                id,
+                position: span.position,
+                length: span.length,
+            });
+        }
+
+        Ok(Span::from_length(&file, span.position, span.length))
+    }
+}
@@ -1,3 +1,4 @@
+use crate::sources::line_index::LineCol;
 use crate::sources::source_file::SourceFile;
 use miette::{MietteError, SourceCode, SourceSpan, SpanContents};
 use serde::{Deserialize, Serialize};
@@ -45,24 +46,98 @@ impl Span {
         self.position + self.length
     }
 
+    /// The line and column (in bytes) where this span starts. See [`SourceFile::line_col`].
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::span::Span;
+    /// # use lwb_parser::sources::line_index::LineCol;
+    /// let sf = SourceFile::new_for_test("a\nbcd\ne");
+    /// let span = Span::from_length(&sf, 3, 4);
+    ///
+    /// assert_eq!(span.start_line_col(), LineCol { line: 1, col: 1 });
+    /// assert_eq!(span.end_line_col(), LineCol { line: 2, col: 1 });
+    /// ```
+    pub fn start_line_col(&self) -> LineCol {
+        self.source.line_col(self.position)
+    }
+
+    /// The line and column (in bytes) where this span ends. See [`SourceFile::line_col`].
+    pub fn end_line_col(&self) -> LineCol {
+        self.source.line_col(self.end())
+    }
+
     /// Get a string from the source file, described by this span.
     /// ```
     /// // TODO
     /// ```
     pub fn as_str(&self) -> &str {
-        &self.source.contents()[self.position..self.position + self.length]
+        let start = self.source.str_offset(self.position);
+        let end = self.source.str_offset(self.end());
+        &self.source.contents()[start..end]
+    }
+
+    /// Get the bytes from the source file described by this span.
+    /// For binary source files, these are the original bytes.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::span::Span;
+    /// let sf = SourceFile::new_bytes([0x00, 0x01, 0xff, 0x03], "test.bin");
+    /// let span = Span::from_length(&sf, 1, 2);
+    ///
+    /// assert_eq!(span.as_bytes(), &[0x01, 0xff]);
+    /// assert_eq!(u16::from_le_bytes(span.as_bytes().try_into().unwrap()), 0xff01);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.source.bytes()[self.position..self.end()]
     }
 
-    /// Merge two spans.
+    /// Merge two spans. Both spans have to be in the same file, see [`SourceFile::same_file`].
+    /// Spans in [detached](SourceFile::is_detached) files without an id can't be checked, so they are always merged.
+    /// Panics when the spans are in different files, use [`try_merge`](Span::try_merge) to check this.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::span::Span;
+    /// let sf = SourceFile::new_for_test("abcdef");
+    /// let span = Span::from_length(&sf, 1, 2).merge(&Span::from_length(&sf, 4, 1));
+    ///
+    /// assert_eq!(span.as_str(), "bcde");
+    /// ```
     pub fn merge(&self, other: &Span) -> Self {
-        // TODO: add unique ids to source files to make this comparison
-        // assert!(self.source == other.source)
+        self.try_merge(other).unwrap_or_else(|| {
+            panic!(
+                "can't merge spans in {} and {}",
+                self.source.name(),
+                other.source.name()
+            )
+        })
+    }
 
-        Self::from_end(
+    /// Like [`merge`](Span::merge), but returns `None` when the spans are in different files.
+    ///
+    /// ```
+    /// # use lwb_parser::sources::source_file::SourceFile;
+    /// # use lwb_parser::sources::span::Span;
+    /// let a = SourceFile::new_for_test("abc");
+    /// let b = SourceFile::new_for_test("abc");
+    ///
+    /// assert!(Span::from_length(&a, 0, 1).try_merge(&Span::from_length(&b, 1, 1)).is_none());
+    /// ```
+    pub fn try_merge(&self, other: &Span) -> Option<Self> {
+        let unknown = |s: &SourceFile| s.is_detached() && s.id().is_none();
+        let same = self.source.same_file(&other.source)
+            || (unknown(&self.source) && unknown(&other.source));
+        if !same {
+            return None;
+        }
+
+        Some(Self::from_end(
             &self.source,
             self.position.min(other.position),
             self.end().max(other.end()),
-        )
+        ))
     }
 }
 
@@ -75,7 +150,7 @@ impl SourceCode for Span {
     ) -> Result<Box<dyn SpanContents<'a> + 'a>, MietteError> {
         <str as SourceCode>::read_span(
             self.source.contents_for_display(),
-            span,
+            &self.source.str_span(span),
             context_lines_before,
             context_lines_after,
         )
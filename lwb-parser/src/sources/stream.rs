@@ -0,0 +1,128 @@
+use crate::sources::source_file::SourceFile;
+use std::io;
+use std::io::{ErrorKind, Read};
+
+/// The default number of bytes that is read from a stream at once.
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Input that is read in chunks from an [`io::Read`], so it never has to be in memory completely.
+///
+/// Only a window of the input is kept in memory. The window starts at the first byte
+/// that was not consumed yet, and grows whenever more input is read. Once a parser knows
+/// that it will never look back before some point (for example, because a top level
+/// item was parsed completely), it can consume the input up to that point, after which it is
+/// dropped from the window when more input is read.
+///
+/// ```
+/// # use lwb_parser::sources::stream::ChunkedInput;
+/// let mut input = ChunkedInput::new("ab".as_bytes(), "stream").with_chunk_size(1);
+/// assert_eq!(input.window().contents(), "");
+///
+/// input.read_more().unwrap();
+/// assert_eq!(input.window().contents(), "a");
+///
+/// input.consume_to(1);
+/// input.read_more().unwrap();
+/// assert_eq!(input.window().contents(), "b");
+/// assert_eq!(input.window_offset(), 1);
+/// ```
+pub struct ChunkedInput<R> {
+    reader: R,
+    name: String,
+    /// All bytes from the start of the window that were read so far.
+    /// This may end in an incomplete utf-8 sequence, which is not part of the window yet.
+    buffer: Vec<u8>,
+    window: SourceFile,
+    /// Offset into the window up to which the input is consumed
+    consumed: usize,
+    /// Offset of the start of the window in the stream
+    window_offset: usize,
+    eof: bool,
+    chunk_size: usize,
+}
+
+impl<R: Read> ChunkedInput<R> {
+    /// Creates a new chunked input, which reads from `reader`. Nothing is read until
+    /// [`read_more`](ChunkedInput::read_more) is called.
+    pub fn new(reader: R, name: impl AsRef<str>) -> Self {
+        Self {
+            reader,
+            name: name.as_ref().to_string(),
+            buffer: Vec::new(),
+            window: SourceFile::new("", name),
+            consumed: 0,
+            window_offset: 0,
+            eof: false,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets the (minimum) number of bytes that is read at once.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(1);
+        self
+    }
+
+    /// The part of the input that is currently in memory. Offsets into
+    /// this source file are relative to [`window_offset`](ChunkedInput::window_offset).
+    pub fn window(&self) -> &SourceFile {
+        &self.window
+    }
+
+    /// The offset of the start of the window in the stream.
+    pub fn window_offset(&self) -> usize {
+        self.window_offset
+    }
+
+    /// The offset into the window up to which the input is consumed.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Marks everything in the window up to `offset` as consumed.
+    /// It will be dropped the next time more input is read.
+    pub fn consume_to(&mut self, offset: usize) {
+        self.consumed = self.consumed.max(offset);
+    }
+
+    /// returns true when the window contains the rest of the input.
+    pub fn is_complete(&self) -> bool {
+        self.eof
+    }
+
+    /// Reads more input from the reader into the window, dropping the consumed part.
+    /// To make sure large items don't need to be read many times, at least as many
+    /// bytes are requested as there are already in the window.
+    ///
+    /// Returns an error when the input could not be read, or is not valid utf-8.
+    pub fn read_more(&mut self) -> io::Result<()> {
+        self.buffer.drain(..self.consumed);
+        self.window_offset += self.consumed;
+        self.consumed = 0;
+
+        let start = self.buffer.len();
+        self.buffer.resize(start + self.chunk_size.max(start), 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[start..]) {
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                res => break res,
+            }
+        };
+        self.buffer.truncate(start + *read.as_ref().unwrap_or(&0));
+        if read? == 0 {
+            self.eof = true;
+        }
+
+        let valid = match std::str::from_utf8(&self.buffer) {
+            Ok(s) => s,
+            // Only the last character is incomplete, the rest of it has not been read yet
+            Err(e) if e.error_len().is_none() && !self.eof => {
+                std::str::from_utf8(&self.buffer[..e.valid_up_to()]).unwrap()
+            }
+            Err(e) => return Err(io::Error::new(ErrorKind::InvalidData, e)),
+        };
+        self.window = SourceFile::new(valid, &self.name);
+
+        Ok(())
+    }
+}
@@ -21,6 +21,8 @@ fn main() {
                 non_exhaustive: false,
                 serde: true,
                 mode: Mode::Custom("crate".to_string()),
+                diagnostics: Default::default(),
+                parser: Default::default(),
                 write_serialized_ast: false,
             },
             language: LanguageConfig {
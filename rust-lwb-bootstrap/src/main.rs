@@ -1,5 +1,5 @@
 use crate::bootstrap_config::{from_root, temporary_location, unwrap};
-use miette::GraphicalReportHandler;
+use rust_lwb::diagnostics::{DiagnosticFormat, Diagnostics};
 use rust_lwb::language::Language;
 use rust_lwb::parser::ast::generate_ast::generate_ast;
 use rust_lwb::parser::peg::parser_sugar::parse_file;
@@ -18,8 +18,22 @@ mod codegen_prelude {
 #[rustfmt::skip]
 mod temp;
 
+/// The format errors are written in, given as `--diagnostics <human|json|sarif>`.
+fn diagnostic_format() -> DiagnosticFormat {
+    let args = std::env::args().collect::<Vec<_>>();
+    match args.iter().position(|a| a == "--diagnostics") {
+        Some(i) => unwrap(
+            args.get(i + 1)
+                .expect("--diagnostics needs a format")
+                .parse(),
+        ),
+        None => DiagnosticFormat::Human,
+    }
+}
+
 fn main() -> Result<(), Box<dyn Error>> {
     let config = bootstrap_config::load("bootstrap.toml");
+    let format = diagnostic_format();
 
     // parse the syntax definition again with the old parse
     println!("parsing {}", config.input_location);
@@ -32,15 +46,10 @@ fn main() -> Result<(), Box<dyn Error>> {
         config.input_location
     );
     let (bootstrapped_syntax_file_ast_pairs, errs) = parse_file(&legacy_ast, &sf);
-    let mut s = String::new();
-    for err in &errs {
-        GraphicalReportHandler::new()
-            .with_links(true)
-            .render_report(&mut s, err)
-            .unwrap();
-    }
     if !errs.is_empty() {
-        panic!("{}", s);
+        let mut diagnostics = Diagnostics::new();
+        diagnostics.extend(&errs);
+        panic!("{}", diagnostics.render(format));
     }
 
     println!("generating ast from pairs");
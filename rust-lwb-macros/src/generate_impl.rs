@@ -1,7 +1,7 @@
 use proc_macro::TokenStream;
 use quote::quote;
 use rust_lwb::codegen::manager::__codegen_tokenstream;
-use rust_lwb::config::{Config, LanguageConfig, Mode, SyntaxConfig};
+use rust_lwb::config::{Config, LanguageConfig, Mode, ParserKind, SyntaxConfig};
 use rust_lwb::sources::source_file::SourceFile;
 use syn::parse::{Parse, ParseStream};
 use syn::{parse_macro_input, LitBool, LitStr, Token};
@@ -10,6 +10,8 @@ struct MacroInput {
     grammar: LitStr,
     non_exhaustive: LitBool,
     serde: LitBool,
+    /// `"interpreted"` or `"native"`, like `parser` in `lwb.toml`
+    parser: Option<LitStr>,
 }
 
 impl Parse for MacroInput {
@@ -19,17 +21,35 @@ impl Parse for MacroInput {
         let non_exhaustive = input.parse()?;
         input.parse::<Token![,]>()?;
         let serde = input.parse()?;
+        let parser = if input.parse::<Option<Token![,]>>()?.is_some() {
+            Some(input.parse()?)
+        } else {
+            None
+        };
 
         Ok(Self {
             grammar,
             non_exhaustive,
             serde,
+            parser,
         })
     }
 }
 
 pub fn generate(input: TokenStream) -> TokenStream {
     let i = parse_macro_input!(input as MacroInput);
+    let parser = match i.parser.as_ref().map(LitStr::value).as_deref() {
+        None | Some("interpreted") => ParserKind::Interpreted,
+        Some("native") => ParserKind::Native,
+        Some(_) => {
+            return syn::Error::new(
+                i.parser.unwrap().span(),
+                "expected \"interpreted\" or \"native\"",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
 
     let sf = SourceFile::new(i.grammar.value(), "test.syntax");
     let cfg = Config {
@@ -39,6 +59,8 @@ pub fn generate(input: TokenStream) -> TokenStream {
             non_exhaustive: i.non_exhaustive.value,
             serde: i.serde.value,
             mode: Mode::Lwb,
+            diagnostics: Default::default(),
+            parser,
             write_serialized_ast: true,
         },
         language: LanguageConfig {
@@ -52,7 +74,7 @@ pub fn generate(input: TokenStream) -> TokenStream {
         Err(e) => {
             let e = e.to_string();
             quote!(
-                compile_error!("{}", #e);
+                compile_error!(#e);
             )
             .into()
         }
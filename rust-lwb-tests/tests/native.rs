@@ -0,0 +1,159 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::ast::generate_ast::BasicAstNode;
+use rust_lwb::parser::peg::parse_error::Expect;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::syntax_file::parse_language_outcome;
+use rust_lwb::sources::source_file::SourceFile;
+use std::fmt::Debug;
+
+mod statements {
+    use rust_lwb::language;
+
+    pub mod grammar {
+        use rust_lwb_macros::generate;
+
+        generate!(
+            r#"
+program = statement*;
+statement:
+    let = "let" name "=" expr ";";
+    print = "print" expr ";";
+    yield = "yield" expr ";"; {feature: generators}
+    block = "{" statement* "}";
+expr:
+    add = expr "+" term;
+    sub = expr "-" term;
+    term = term;
+term:
+    mul = term "*" atom;
+    atom = atom;
+atom:
+    number = [0-9]+;
+    text = "\"" [^"]* "\""; {no-layout}
+    name = name;
+    paren = "(" expr ")";
+name = [a-z]+ [0-9]*; {no-layout}
+layout = [ \n];
+start at program;
+"#,
+            false,
+            false,
+            "native"
+        );
+    }
+
+    language!(pub Statements at mod grammar);
+}
+
+mod error_constructors {
+    use rust_lwb::language;
+
+    pub mod grammar {
+        use rust_lwb_macros::generate;
+
+        generate!(
+            r#"
+program = statement*;
+statement:
+    print = "print" number ";";
+    bare = number ";"; {error: "`{text}` does nothing"}
+number = [0-9]+; {no-layout}
+layout = [ \n];
+start at program;
+"#,
+            false,
+            false,
+            "native"
+        );
+    }
+
+    language!(pub ErrorConstructors at mod grammar);
+}
+
+use error_constructors::ErrorConstructors;
+use statements::Statements;
+
+/// Parses with the language, and checks that the outcome is the same as interpreting the grammar.
+/// Returns whether the input parsed without errors.
+fn check<L: Language>(parser: &[u8], input: &str, options: &ParseOptions) -> bool
+where
+    L::Ast: BasicAstNode + Debug,
+{
+    let sf = SourceFile::new(input, "input.language");
+    let native = L::parse_outcome(&sf, options).unwrap();
+    let interpreted = parse_language_outcome::<L::Ast>(&sf, parser, options).unwrap();
+    assert_eq!(
+        format!("{:?}", native.tree),
+        format!("{:?}", interpreted.tree),
+        "{input}"
+    );
+    assert_eq!(
+        format!("{:?}", native.errors),
+        format!("{:?}", interpreted.errors),
+        "{input}"
+    );
+    native.errors.is_empty()
+}
+
+#[test]
+fn test_native() {
+    assert!(statements::grammar::NATIVE_PARSER.is_some());
+    let parser = statements::grammar::PARSER;
+
+    let valid = [
+        "",
+        "let x = 1 + 2 * 3 - 4;",
+        "print (a1 + b) * \"c d\";\n{ let y = x; { print y; } }",
+        // left recursion, nested in both operands
+        "print 1 - (2 - 3) * 4 * (5 + 6) - 7;",
+    ];
+    let invalid = [
+        "let x = ;",
+        "let x y = 1;",
+        // `name` has no layout, so the number can't be part of it
+        "print a 1;",
+        "print \"a;",
+        "{ print 1;",
+        "yield 1;",
+    ];
+    for options in [
+        ParseOptions::new(),
+        ParseOptions::new().with_recovery(false),
+    ] {
+        for input in valid {
+            assert!(check::<Statements>(parser, input, &options), "{input}");
+        }
+        for input in invalid {
+            assert!(!check::<Statements>(parser, input, &options), "{input}");
+        }
+    }
+
+    // constructors with a feature only parse when it is enabled
+    let generators = ParseOptions::new().with_feature("generators");
+    assert!(check::<Statements>(parser, "yield 1;", &generators));
+    assert!(check::<Statements>(parser, "{ yield a + 1; }", &generators));
+}
+
+#[test]
+fn test_native_error_constructors() {
+    // error constructors only report errors, which are found by interpreting the grammar
+    assert!(error_constructors::grammar::NATIVE_PARSER.is_none());
+    let parser = error_constructors::grammar::PARSER;
+
+    let options = ParseOptions::new();
+    assert!(check::<ErrorConstructors>(
+        parser,
+        "print 1; print 2;",
+        &options
+    ));
+    assert!(!check::<ErrorConstructors>(parser, "print 1; 2;", &options));
+
+    let sf = SourceFile::new("print 1; 2;", "input.language");
+    let errors = ErrorConstructors::parse_outcome(&sf, &options)
+        .unwrap()
+        .errors;
+    assert!(errors[0]
+        .expected
+        .iter()
+        .any(|e| matches!(e, Expect::Custom(msg) if msg == "` 2;` does nothing")));
+}
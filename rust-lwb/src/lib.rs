@@ -35,3 +35,6 @@ pub mod typechecker;
 pub use lwb_parser::config;
 
 pub use lwb_parser::error;
+
+/// Exporting errors and warnings in machine-readable formats (JSON and SARIF)
+pub use lwb_parser::diagnostics;
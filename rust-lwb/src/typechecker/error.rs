@@ -1,4 +1,6 @@
+use crate::diagnostics::FileDiagnostic;
 use crate::error::display_miette_error;
+use crate::sources::source_file::SourceFile;
 use crate::sources::span::Span;
 use crate::typechecker::constraints::KnownVariable;
 use crate::typechecker::Type;
@@ -82,6 +84,16 @@ impl<TYPE: Type> Diagnostic for GeneratedTypeError<TYPE> {
     }
 }
 
+impl<TYPE: Type> FileDiagnostic for GeneratedTypeError<TYPE> {
+    fn file(&self) -> Option<&SourceFile> {
+        match self {
+            GeneratedTypeError::CantUnify(Some(a), _, _, _, _, _) => Some(&a.source),
+            GeneratedTypeError::CantUnify(_, _, _, Some(a), _, _) => Some(&a.source),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum TypeError<TYPE: Type> {
     #[error("{}", display_miette_error(_0))]
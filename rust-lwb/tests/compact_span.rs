@@ -0,0 +1,45 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::ast::{AstNode, SpannedAstInfo};
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::compact_span::CompactSpan;
+use rust_lwb::sources::source_file::SourceFile;
+use rust_lwb::sources::span::Span;
+use std::mem::size_of;
+
+#[test]
+fn test_size() {
+    assert_eq!(size_of::<CompactSpan>(), 8);
+    assert!(size_of::<CompactSpan>() < size_of::<Span>());
+}
+
+#[test]
+fn test_round_trip() {
+    let sf = SourceFile::new_for_test("abcdef");
+    let span = Span::from_length(&sf, 2, 3);
+    let compact = CompactSpan::from(&span);
+    assert_eq!(compact.start(), 2);
+    assert_eq!(compact.end(), 5);
+    assert_eq!(compact.as_str(&sf), "cde");
+
+    let back = compact.to_span(&sf);
+    assert!(back.source.same_file(&sf));
+    assert_eq!(back.as_str(), "cde");
+}
+
+#[test]
+fn test_decoded_offsets() {
+    // "é" takes two bytes in the file, but only one character in the contents
+    let sf = SourceFile::new_bytes([b'a', 0xe9, b'b'], "test.bin");
+    assert_eq!(CompactSpan::from_length(1, 2).as_str(&sf), "\u{e9}b");
+}
+
+#[test]
+fn test_ast_outlives_file() {
+    // the AST keeps its spans, also when the file it was parsed from is not used anymore
+    let ast = {
+        let sf = SourceFile::new("a = \"a\";\nstart at a;\n", "test.syntax");
+        SyntaxFile::parse(&sf)
+    };
+    assert_eq!(ast.as_str(), "a = \"a\";\nstart at a;");
+    assert_eq!(ast.ast_info().span().source.name(), "test.syntax");
+}
@@ -0,0 +1,114 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::ast::generate_ast::try_generate_ast_from_raw;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parser_compiled::CompiledParser;
+use rust_lwb::parser::peg::parser_sugar::parse_file_outcome;
+use rust_lwb::parser::syntax_file::{load_parser, ParseError};
+use rust_lwb::sources::source_file::SourceFile;
+use std::thread;
+
+mod arrays {
+    use rust_lwb::language;
+
+    pub mod grammar {
+        use rust_lwb_macros::generate;
+
+        generate!(
+            r#"
+value:
+    array = "[" delimited(value, ",", *) "]";
+    number = [0-9]+;
+layout = [ ];
+start at value;
+"#,
+            false,
+            false,
+            "native"
+        );
+    }
+
+    language!(pub Arrays at mod grammar);
+}
+
+use arrays::grammar::Value;
+use arrays::Arrays;
+
+fn nested(depth: usize) -> SourceFile {
+    SourceFile::new("[".repeat(depth) + &"]".repeat(depth), "test")
+}
+
+/// Runs `f` on a thread with a stack of `size` bytes.
+fn with_stack<T: Send>(size: usize, f: impl FnOnce() -> T + Send) -> T {
+    thread::scope(|s| {
+        thread::Builder::new()
+            .stack_size(size)
+            .spawn_scoped(s, f)
+            .unwrap()
+            .join()
+            .unwrap()
+    })
+}
+
+#[test]
+fn test_nesting() {
+    assert!(Arrays::try_parse(&nested(32)).is_ok());
+
+    // by default, the parser may use most of the stack of the thread, like the 8 MiB of a main thread
+    let depth = if cfg!(debug_assertions) { 200 } else { 500 };
+    let ast = load_parser(arrays::grammar::PARSER).unwrap();
+    with_stack(8 * 1024 * 1024, || {
+        let file = nested(depth);
+        assert!(Arrays::try_parse(&file).is_ok());
+        let interpreted = parse_file_outcome(&ast, &file, &ParseOptions::new());
+        assert!(interpreted.unwrap().is_ok());
+    });
+
+    // too deep to parse, but this should not overflow the stack
+    match Arrays::try_parse(&nested(100_000)) {
+        Err(ParseError::PEG(errs)) => {
+            assert_eq!(errs.len(), 1);
+            assert!(errs[0].fail_nesting);
+        }
+        _ => panic!("expected a nesting error"),
+    }
+
+    let options = ParseOptions::new().with_max_depth(3);
+    assert!(Arrays::try_parse_with_options(&nested(3), &options).is_ok());
+    match Arrays::try_parse_with_options(&nested(4), &options) {
+        Err(ParseError::PEG(errs)) => {
+            assert!(errs[0].fail_nesting);
+            assert_eq!(errs[0].span.position, 3);
+        }
+        _ => panic!("expected a nesting error"),
+    }
+}
+
+#[test]
+fn test_deep_results() {
+    const DEPTH: usize = 5_000;
+
+    let ast = load_parser(arrays::grammar::PARSER).unwrap();
+    let parser = CompiledParser::new(&ast).with_native(arrays::grammar::NATIVE_PARSER);
+    let file = nested(DEPTH);
+    let options = ParseOptions::new().with_max_stack(usize::MAX);
+
+    // only parsing needs a large stack, the results are converted and dropped without recursion
+    let outcome = with_stack(1 << 30, || {
+        parser.parse_raw_outcome(&file, &options).unwrap()
+    });
+    assert!(outcome.is_ok());
+    assert!(with_stack(1 << 30, || parse_file_outcome(
+        &ast, &file, &options
+    )
+    .unwrap()
+    .is_ok()));
+
+    with_stack(1024 * 1024, || {
+        let options = ParseOptions::new().with_max_stack(128 * 1024);
+        let err =
+            try_generate_ast_from_raw::<Value<_>>(&outcome.tree, &file, &options).unwrap_err();
+        assert!(err.fail_nesting);
+
+        drop(outcome);
+    });
+}
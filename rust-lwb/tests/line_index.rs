@@ -0,0 +1,81 @@
+use rust_lwb::sources::line_index::{ColumnUnit, LineCol};
+use rust_lwb::sources::source_file::SourceFile;
+use rust_lwb::sources::span::Span;
+
+#[test]
+fn test_line_col_round_trip() {
+    let contents = "fn main() {\r\n    let x = \"héllo 😀\";\n\n\tx\n";
+    let sf = SourceFile::new(contents, "test");
+    assert_eq!(sf.line_index().line_count(), 5);
+
+    for unit in [ColumnUnit::Byte, ColumnUnit::Utf16, ColumnUnit::Utf32] {
+        for (offset, _) in contents.char_indices() {
+            let position = sf.line_col_in(offset, unit);
+            assert_eq!(
+                sf.offset_in(position, unit),
+                Some(offset),
+                "{unit:?} {offset}"
+            );
+        }
+        // the end of the file is on the empty last line
+        assert_eq!(
+            sf.line_col_in(contents.len(), unit),
+            LineCol { line: 4, col: 0 }
+        );
+    }
+}
+
+#[test]
+fn test_line_col_lines() {
+    let sf = SourceFile::new("ab\r\ncd\n\nef", "test");
+
+    // the \r is part of the first line
+    assert_eq!(sf.line_col(2), LineCol { line: 0, col: 2 });
+    assert_eq!(sf.line_col(4), LineCol { line: 1, col: 0 });
+    assert_eq!(sf.line_col(7), LineCol { line: 2, col: 0 });
+    assert_eq!(sf.line_col(100), LineCol { line: 3, col: 2 });
+    assert_eq!(sf.line_index().line_range(0), Some((0, 3)));
+    assert_eq!(sf.line_index().line_range(2), Some((7, 7)));
+
+    // columns past the end of a line are clamped
+    assert_eq!(sf.offset(1, 100), Some(6));
+    assert_eq!(sf.offset(3, 1), Some(9));
+    assert_eq!(sf.offset(4, 0), None);
+}
+
+#[test]
+fn test_line_col_utf16() {
+    let sf = SourceFile::new("x = \"😀\" + y", "test");
+    let y = sf.contents().find('y').unwrap();
+
+    assert_eq!(sf.line_col_in(y, ColumnUnit::Byte).col, 13);
+    assert_eq!(sf.line_col_in(y, ColumnUnit::Utf16).col, 11);
+    assert_eq!(sf.line_col_in(y, ColumnUnit::Utf32).col, 10);
+
+    // a column in the middle of a surrogate pair points at the start of the character
+    assert_eq!(
+        sf.offset_in(LineCol { line: 0, col: 6 }, ColumnUnit::Utf16),
+        Some(5)
+    );
+
+    let span = Span::from_length(&sf, y, 1);
+    assert_eq!(span.start_line_col(), LineCol { line: 0, col: 13 });
+    assert_eq!(span.end_line_col(), LineCol { line: 0, col: 14 });
+}
+
+#[test]
+fn test_line_col_binary() {
+    let sf = SourceFile::new_bytes([0xff, 0xfe, b'\n', 0x80, b'a'], "test.bin");
+
+    // every byte is one column, also in utf-16
+    assert_eq!(sf.line_col(4), LineCol { line: 1, col: 1 });
+    assert_eq!(
+        sf.line_col_in(4, ColumnUnit::Utf16),
+        LineCol { line: 1, col: 1 }
+    );
+    assert_eq!(
+        sf.offset_in(LineCol { line: 1, col: 1 }, ColumnUnit::Utf16),
+        Some(4)
+    );
+    assert_eq!(sf.offset(0, 2), Some(2));
+}
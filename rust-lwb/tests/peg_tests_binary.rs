@@ -0,0 +1,200 @@
+use rust_lwb::codegen_prelude::ParsePairExpression;
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parser_sugar::parse_file;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::{convert, AstConversionError};
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+#[test]
+fn test_binary_header() {
+    let syntax = r#"
+header = "\x7fBIN" version u16le u32be [\x00-\x7f]*;
+version = u8;
+
+start at header;
+binary;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    let input = [
+        0x7f, b'B', b'I', b'N', 0x02, 0x34, 0x12, 0x00, 0x00, 0x01, 0x00, b'o', b'k',
+    ];
+    let sf2 = SourceFile::new_bytes(input, "input.bin");
+    let (parsed, errs) = parse_file(&ast, &sf2);
+    assert_eq!(errs.len(), 0);
+
+    let parts = if let ParsePairExpression::List(_, l) = parsed.constructor_value {
+        l
+    } else {
+        unreachable!()
+    };
+
+    // `version`, which is a single byte
+    assert_eq!(parts[1].span().position, 4);
+    assert_eq!(parts[1].span().as_bytes(), &[0x02]);
+
+    // `u16le`
+    let short = parts[2].span();
+    assert_eq!(short.position, 5);
+    assert_eq!(
+        u16::from_le_bytes(short.as_bytes().try_into().unwrap()),
+        0x1234
+    );
+
+    // `u32be`
+    let long = parts[3].span();
+    assert_eq!(long.position, 7);
+    assert_eq!(
+        u32::from_be_bytes(long.as_bytes().try_into().unwrap()),
+        0x100
+    );
+    assert_eq!(parts[4].span().as_str(), "ok");
+
+    // bytes above 0x7f are not accepted by the trailing character class
+    let mut input = input.to_vec();
+    input.push(0x80);
+    let sf3 = SourceFile::new_bytes(input, "input.bin");
+    let (_, errs) = parse_file(&ast, &sf3);
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].span.position, 13);
+}
+
+#[test]
+fn test_text_byte_escapes() {
+    // outside binary grammars, `\x` in a character class is just text
+    let syntax = r#"
+a = [\x41]+;
+
+start at a;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    let (_, errs) = parse_file(&ast, &SourceFile::new("\\x41", "input"));
+    assert_eq!(errs.len(), 0);
+    let (_, errs) = parse_file(&ast, &SourceFile::new("A", "input"));
+    assert_eq!(errs.len(), 1);
+
+    // in strings, only ascii characters can be escaped, since others are more than one byte
+    let syntax = r#"
+a = "\x41";
+b = "\xff";
+
+start at a;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    assert!(matches!(
+        convert(SyntaxFile::parse(&sf)),
+        Err(AstConversionError::ByteInText(byte)) if byte == "ff"
+    ));
+
+    // and the builtin integer sorts don't exist
+    let syntax = r#"
+a = u8;
+u16le = "x";
+
+start at a;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    assert!(!ast.sorts.contains_key("u8"));
+    assert_eq!(ast.sorts.len(), 2);
+}
+
+#[test]
+fn test_counted_repetition() {
+    let syntax = r#"
+message = u16le [\x00-\xff]{u16le} i8 byte{i8} "\x00";
+byte = [\x00-\xff];
+
+start at message;
+binary;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    let input = [0x03, 0x00, b'a', b'b', b'c', 0x01, b'd', 0x00];
+    let sf2 = SourceFile::new_bytes(input, "input.bin");
+    let (parsed, errs) = parse_file(&ast, &sf2);
+    assert_eq!(errs.len(), 0);
+
+    let ParsePairExpression::List(_, parts) = parsed.constructor_value else {
+        unreachable!()
+    };
+    let ParsePairExpression::List(span, bytes) = &parts[1] else {
+        unreachable!()
+    };
+    assert_eq!(bytes.len(), 3);
+    assert_eq!(span.as_str(), "abc");
+    let ParsePairExpression::List(_, bytes) = &parts[3] else {
+        unreachable!()
+    };
+    assert_eq!(bytes.len(), 1);
+
+    // the count is one more than the bytes that follow it
+    let input = [0x04, 0x00, b'a', b'b', b'c', 0x01, b'd', 0x00];
+    let (_, errs) = parse_file(&ast, &SourceFile::new_bytes(input, "input.bin"));
+    assert!(!errs.is_empty());
+
+    // a negative count is not a count
+    let input = [0x00, 0x00, 0xff, 0x00];
+    let (_, errs) = parse_file(&ast, &SourceFile::new_bytes(input, "input.bin"));
+    assert!(!errs.is_empty());
+    assert_eq!(errs[0].span.position, 2);
+}
+
+#[test]
+fn test_counted_repetition_errors() {
+    let syntax = r#"
+a = "n" [a-z]{u8};
+
+start at a;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    assert!(matches!(
+        convert(SyntaxFile::parse(&sf)),
+        Err(AstConversionError::CountInText(count)) if count == "u8"
+    ));
+
+    // the count has to be parsed before the repetition
+    let syntax = r#"
+a = [\x00-\xff]{u8} u8;
+
+start at a;
+binary;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    assert!(matches!(
+        convert(SyntaxFile::parse(&sf)),
+        Err(AstConversionError::BadCount(count)) if count == "u8"
+    ));
+
+    // in the same sequence, not around the repetition it is in
+    let syntax = r#"
+a = u8 ([\x00-\xff]{u8})*;
+
+start at a;
+binary;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    assert!(matches!(
+        convert(SyntaxFile::parse(&sf)),
+        Err(AstConversionError::BadCount(count)) if count == "u8"
+    ));
+
+    // and has to be an integer
+    let syntax = r#"
+a = b [\x00-\xff]{b};
+b = [\x00-\xff];
+
+start at a;
+binary;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    assert!(matches!(
+        convert(SyntaxFile::parse(&sf)),
+        Err(AstConversionError::BadCount(count)) if count == "b"
+    ));
+}
@@ -0,0 +1,114 @@
+use rust_lwb::diagnostics::{DiagnosticFormat, DiagnosticSeverity, Diagnostics};
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parser_sugar::parse_file_outcome;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::ParseError;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+use serde_json::Value;
+
+const STATEMENTS: &str = r#"
+program = statement*;
+statement:
+    print = "print" number ";";
+    echo = "echo" number ";"; {deprecated: "use `print` instead"}
+number = [0-9]+;
+layout = [ \n];
+start at program;
+"#;
+
+fn diagnostics(input: &str) -> Diagnostics {
+    let sf = SourceFile::new(STATEMENTS, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new(input, "input.language");
+    let outcome = parse_file_outcome(&ast, &input, &ParseOptions::new()).unwrap();
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.extend(&outcome.errors);
+    diagnostics.extend(&outcome.warnings);
+    diagnostics
+}
+
+#[test]
+fn test_diagnostics_json() {
+    let diagnostics = diagnostics("echo 1;\nprint 1;\nprint x;");
+    assert!(diagnostics.has_errors());
+
+    let json: Value = serde_json::from_str(&diagnostics.render(DiagnosticFormat::Json)).unwrap();
+    let records = json.as_array().unwrap();
+    assert_eq!(records.len(), 2);
+
+    let error = &records[0];
+    assert_eq!(error["file"], "input.language");
+    assert_eq!(error["severity"], "error");
+    let range = &error["range"];
+    assert_eq!(range["start"], 23);
+    assert_eq!(range["start_line"], 3);
+    assert_eq!(range["start_column"], 7);
+    assert!(error["labels"][0]["message"]
+        .as_str()
+        .unwrap()
+        .ends_with("[0-9] here"));
+
+    let warning = &records[1];
+    assert_eq!(warning["severity"], "warning");
+    assert_eq!(warning["message"], "`echo` statement is deprecated");
+    assert_eq!(warning["help"], "use `print` instead");
+    assert_eq!(warning["range"]["start_line"], 1);
+    assert_eq!(warning["range"]["end"], 7);
+    assert_eq!(warning["range"]["end_column"], 8);
+
+    // the human format is rendered by miette
+    assert!(diagnostics
+        .render(DiagnosticFormat::Human)
+        .contains("[0-9] here"));
+}
+
+#[test]
+fn test_diagnostics_sarif() {
+    let diagnostics = diagnostics("echo 1;\nprint x;");
+
+    let sarif: Value = serde_json::from_str(&diagnostics.render(DiagnosticFormat::Sarif)).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+
+    let error = &results[0];
+    assert_eq!(error["level"], "error");
+    assert!(error["message"]["text"]
+        .as_str()
+        .unwrap()
+        .contains("[0-9] here"));
+    let location = &error["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "input.language");
+    assert_eq!(location["region"]["startLine"], 2);
+    assert_eq!(location["region"]["startColumn"], 7);
+    assert_eq!(location["region"]["byteOffset"], 14);
+    // the enclosing statement is a related location
+    assert_eq!(
+        error["relatedLocations"][0]["physicalLocation"]["region"]["startColumn"],
+        1
+    );
+
+    assert_eq!(results[1]["level"], "warning");
+}
+
+#[test]
+fn test_diagnostics_without_location() {
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push_parse_error(&ParseError::UnknownSort("statement".to_string()));
+    let record = &diagnostics.records[0];
+    assert_eq!(record.severity, DiagnosticSeverity::Error);
+    assert_eq!(record.message, "sort statement does not exist");
+    assert!(record.range.is_none());
+
+    let sarif: Value = serde_json::from_str(&diagnostics.to_sarif()).unwrap();
+    assert!(sarif["runs"][0]["results"][0].get("locations").is_none());
+
+    assert_eq!(
+        "sarif".parse::<DiagnosticFormat>().unwrap(),
+        DiagnosticFormat::Sarif
+    );
+    assert!("xml".parse::<DiagnosticFormat>().is_err());
+}
@@ -0,0 +1,158 @@
+use itertools::Itertools;
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parser_sugar::parse_file;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::encoding::{DecodeError, DecodeOptions, SourceEncoding};
+use rust_lwb::sources::line_index::{ColumnUnit, LineCol};
+use rust_lwb::sources::source_file::SourceFile;
+
+const LINES: &str = r#"
+program = line*;
+line = word "\n";
+word = [^\n0-9\r]+;
+start at program;
+"#;
+
+fn utf16le(s: &str) -> Vec<u8> {
+    [0xff, 0xfe]
+        .into_iter()
+        .chain(s.encode_utf16().flat_map(u16::to_le_bytes))
+        .collect()
+}
+
+/// The spans of all words in the input, and the errors
+fn words(input: &SourceFile) -> (Vec<(usize, usize, String)>, Vec<usize>) {
+    let sf = SourceFile::new(LINES, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let (pairs, errs) = parse_file(&ast, input);
+    let words = pairs
+        .constructor_value
+        .span()
+        .as_str()
+        .split('\n')
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_string())
+        .collect_vec();
+
+    let mut position = input.iter();
+    let spans = words
+        .into_iter()
+        .map(|w| {
+            let start = position.position();
+            assert!(position.accept_str(&w));
+            let end = position.position();
+            position.advance();
+            (start, end, w)
+        })
+        .collect();
+    (spans, errs.iter().map(|e| e.span.position).collect())
+}
+
+#[test]
+fn test_decode_utf8_bom() {
+    let bytes = [b"\xef\xbb\xbf".as_slice(), "héllo\nab\n".as_bytes()].concat();
+    let sf = SourceFile::decode(&bytes, "test", &DecodeOptions::new()).unwrap();
+    assert_eq!(sf.encoding(), SourceEncoding::Utf8);
+    assert_eq!(sf.contents(), "héllo\nab\n");
+
+    // the byte order mark is not part of the first word
+    let (spans, errs) = words(&sf);
+    assert!(errs.is_empty());
+    assert_eq!(
+        spans,
+        vec![(3, 9, "héllo".to_string()), (10, 12, "ab".to_string())]
+    );
+    assert_eq!(sf.line_col(10), LineCol { line: 1, col: 0 });
+}
+
+#[test]
+fn test_decode_utf16_crlf() {
+    let bytes = utf16le("héllo\r\nab\r\n");
+    let options = DecodeOptions::new().with_normalized_newlines(true);
+    let sf = SourceFile::decode(&bytes, "test", &options).unwrap();
+    assert_eq!(sf.encoding(), SourceEncoding::Utf16Le);
+    assert_eq!(sf.contents(), "héllo\nab\n");
+
+    // spans are offsets in the utf-16 input
+    let (spans, errs) = words(&sf);
+    assert!(errs.is_empty());
+    assert_eq!(
+        spans,
+        vec![(2, 12, "héllo".to_string()), (16, 20, "ab".to_string())]
+    );
+    assert_eq!(sf.str_offset(16), 7);
+    assert_eq!(sf.original_offset(7), 16);
+
+    assert_eq!(sf.line_index().line_count(), 3);
+    assert_eq!(
+        sf.line_col_in(18, ColumnUnit::Utf16),
+        LineCol { line: 1, col: 1 }
+    );
+    assert_eq!(
+        sf.offset_in(LineCol { line: 1, col: 1 }, ColumnUnit::Utf16),
+        Some(18)
+    );
+}
+
+#[test]
+fn test_decode_errors_point_at_original() {
+    let bytes = utf16le("ab\r\nc1\r\n");
+    let options = DecodeOptions::new().with_normalized_newlines(true);
+    let sf = SourceFile::decode(&bytes, "test", &options).unwrap();
+
+    let (_, errs) = words(&sf);
+    assert_eq!(errs, vec![12]);
+    assert_eq!(
+        sf.line_col_in(12, ColumnUnit::Utf16),
+        LineCol { line: 1, col: 1 }
+    );
+
+    // without normalizing the newlines, `\r` is not accepted
+    let sf = SourceFile::decode(&bytes, "test", &DecodeOptions::new()).unwrap();
+    assert_eq!(sf.contents(), "ab\r\nc1\r\n");
+    let (_, errs) = words(&sf);
+    assert_eq!(errs[0], 6);
+}
+
+#[test]
+fn test_decode_utf16be_without_bom() {
+    let bytes = "ab\n"
+        .encode_utf16()
+        .flat_map(u16::to_be_bytes)
+        .collect_vec();
+    let options = DecodeOptions::new().with_encoding(SourceEncoding::Utf16Be);
+    let sf = SourceFile::decode(&bytes, "test", &options).unwrap();
+    assert_eq!(sf.contents(), "ab\n");
+    assert_eq!(words(&sf).0, vec![(0, 4, "ab".to_string())]);
+}
+
+#[test]
+fn test_decode_invalid() {
+    let options = DecodeOptions::new();
+    assert_eq!(
+        SourceFile::decode(b"ab\xff", "test", &options).unwrap_err(),
+        DecodeError::InvalidUtf8(2)
+    );
+    assert_eq!(
+        SourceFile::decode([0xff, 0xfe, b'a'], "test", &options).unwrap_err(),
+        DecodeError::OddUtf16Length
+    );
+    // a lone surrogate
+    assert_eq!(
+        SourceFile::decode([0xff, 0xfe, b'a', 0, 0x00, 0xd8], "test", &options).unwrap_err(),
+        DecodeError::InvalidUtf16(4)
+    );
+}
+
+#[test]
+fn test_open_utf16() {
+    let path = std::env::temp_dir().join(format!("lwb-encoding-{}.txt", std::process::id()));
+    std::fs::write(&path, utf16le("ab\n")).unwrap();
+    let sf = SourceFile::open(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(sf.contents(), "ab\n");
+    assert_eq!(sf.encoding(), SourceEncoding::Utf16Le);
+    assert_eq!(sf.bytes().len(), 8);
+}
@@ -0,0 +1,205 @@
+use itertools::Itertools;
+use miette::Diagnostic;
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_error::{ErrorContext, PEGParseError};
+use rust_lwb::parser::peg::parser_sugar::parse_file;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+fn parse_errors(syntax: &str, input: &str) -> Vec<PEGParseError> {
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new(input, "input.language");
+    parse_file(&ast, &input).1
+}
+
+const STATEMENTS: &str = r#"
+program = statement*;
+statement:
+    if = "if" expression "then" statement;
+    print = "print" expression ";";
+expression:
+    add = expression "+" number;
+    number;
+number = [0-9]+;
+layout = [ \n];
+start at program;
+"#;
+
+#[test]
+fn test_error_context() {
+    let errs = parse_errors(STATEMENTS, "print 1;\nif 1 + then print 2;");
+    assert_eq!(errs.len(), 1);
+    let err = &errs[0];
+    assert_eq!(err.span.position, 16);
+
+    assert_eq!(
+        err.context,
+        vec![
+            ErrorContext {
+                sort: "program".to_string(),
+                constructor: Some("program".to_string()),
+                position: 0,
+                start: 0
+            },
+            ErrorContext {
+                sort: "statement".to_string(),
+                constructor: Some("if".to_string()),
+                position: 8,
+                start: 9
+            },
+            ErrorContext {
+                sort: "expression".to_string(),
+                constructor: Some("add".to_string()),
+                position: 11,
+                start: 12
+            },
+            ErrorContext {
+                sort: "number".to_string(),
+                constructor: Some("number".to_string()),
+                position: 15,
+                start: 16
+            },
+        ]
+    );
+    assert_eq!(
+        err.to_string(),
+        "A parse error occured in … → `if` statement → `add` expression → number"
+    );
+
+    // the start of every enclosing construct is labelled
+    let labels = err
+        .labels()
+        .unwrap()
+        .filter_map(|l| l.label().map(|t| (l.offset(), t.to_string())))
+        .collect_vec();
+    assert!(labels.contains(&(9, "while parsing this `if` statement".to_string())));
+    assert!(labels.contains(&(12, "while parsing this `add` expression".to_string())));
+    assert!(labels.contains(&(0, "while parsing this program".to_string())));
+}
+
+#[test]
+fn test_error_context_layout() {
+    let syntax = r##"
+program = statement*;
+statement:
+    print = "print" number ";";
+number = [0-9]+;
+layout:
+    space = [ \n];
+    comment = "#" [^\n]* "\n";
+start at program;
+"##;
+
+    // labels point past all layout in front of a sort, not only past the whitespace
+    let errs = parse_errors(syntax, "print 1;\n# comment\nprint ;");
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].context[1].position, 8);
+    assert_eq!(errs[0].context[1].start, 19);
+    let labels = errs[0]
+        .labels()
+        .unwrap()
+        .filter_map(|l| l.label().map(|t| (l.offset(), t.to_string())))
+        .collect_vec();
+    assert!(labels.contains(&(19, "while parsing this `print` statement".to_string())));
+}
+
+#[test]
+fn test_error_context_summary() {
+    let errs = parse_errors(STATEMENTS, "print ;");
+    assert_eq!(errs.len(), 1);
+    assert_eq!(
+        errs[0].to_string(),
+        "A parse error occured in … → `print` statement → `number` expression → number"
+    );
+}
+
+fn expected_label(err: &PEGParseError) -> String {
+    err.labels()
+        .unwrap()
+        .filter(|l| l.offset() == err.span.position)
+        .filter_map(|l| l.label().map(|t| t.to_string()))
+        .find(|l| l.starts_with("Expected"))
+        .unwrap()
+}
+
+#[test]
+fn test_error_display_name() {
+    let syntax = r#"
+statement:
+    print = "print" expression ";";
+expression:
+    add = expression "+" atom;
+    atom;
+atom: {display-name: "an expression"}
+    number = [0-9]+;
+    variable = [a-z]+;
+    paren = "(" expression ")";
+layout = [ ];
+start at statement;
+    "#;
+
+    let errs = parse_errors(syntax, "print ;");
+    assert_eq!(errs.len(), 1);
+    assert_eq!(expected_label(&errs[0]), "Expected an expression here");
+
+    // once the sort has started, errors inside it are reported as usual
+    let errs = parse_errors(syntax, "print (;");
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].span.position, 7);
+    assert_eq!(expected_label(&errs[0]), "Expected an expression here");
+    let errs = parse_errors(syntax, "print 1 +;");
+    assert_eq!(expected_label(&errs[0]), "Expected an expression here");
+    let errs = parse_errors(syntax, "print (1;");
+    assert!(expected_label(&errs[0]).ends_with("')', '+' here"));
+}
+
+#[test]
+fn test_error_expected_sorted() {
+    let syntax = r#"
+statement:
+    while = "while" ";";
+    break = "break" ";";
+    block = "{" statement* "}";
+    also-while = "while" "(" ")";
+start at statement;
+    "#;
+
+    let errs = parse_errors(syntax, "x");
+    assert_eq!(
+        expected_label(&errs[0]),
+        "Expected one of 'break', 'while', '{' here"
+    );
+}
+
+#[test]
+fn test_error_suggestion() {
+    let errs = parse_errors(STATEMENTS, "pirnt 1;");
+    assert_eq!(errs[0].suggestion(), Some("print"));
+    assert_eq!(errs[0].help().unwrap().to_string(), "did you mean `print`?");
+
+    let errs = parse_errors(STATEMENTS, "fi 1 then print 1;");
+    assert_eq!(errs[0].suggestion(), Some("if"));
+
+    // too different from any keyword
+    let errs = parse_errors(STATEMENTS, "return 1;");
+    assert_eq!(errs[0].suggestion(), None);
+    assert!(errs[0].help().is_none());
+}
+
+#[test]
+fn test_error_interpolates_text() {
+    let syntax = r#"
+program = "let" name "=" "1";
+name = [a-z]+; {error: "`{text}` is not a valid name"}
+layout = [ ];
+start at program;
+    "#;
+
+    let errs = parse_errors(syntax, "let 1abc = 1");
+    assert_eq!(
+        errs[0].help().unwrap().to_string(),
+        "if a name would be parsed here then `1abc` is not a valid name"
+    );
+}
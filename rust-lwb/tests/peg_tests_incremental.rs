@@ -0,0 +1,213 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parse_outcome::ParseOutcome;
+use rust_lwb::parser::peg::parser_incremental::{reparse, ParseTree, TextEdit};
+use rust_lwb::parser::peg::parser_sugar::parse_file_outcome;
+use rust_lwb::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::encoding::{DecodeOptions, SourceEncoding};
+use rust_lwb::sources::source_file::SourceFile;
+
+const STATEMENTS: &str = r#"
+program = statement*;
+statement:
+    let = "let" name "=" expr ";";
+    print = "print" expr ";";
+expr:
+    add = expr "+" term;
+    term = term;
+term:
+    mul = term "*" atom;
+    atom = atom;
+atom:
+    number = [0-9]+;
+    name = name;
+    paren = "(" expr ")";
+name = [a-z]+; {no-layout}
+layout = [ \n];
+start at program;
+"#;
+
+fn grammar() -> SyntaxFileAst {
+    let sf = SourceFile::new(STATEMENTS, "test.syntax");
+    convert(SyntaxFile::parse(&sf)).unwrap()
+}
+
+/// Reparses `input` after the edits, and checks that the result is the same as parsing the edited input from scratch.
+fn check<'src>(ast: &'src SyntaxFileAst, input: &str, edits: &[TextEdit]) -> ParseTree<'src> {
+    let tree = ParseTree::new(ast, SourceFile::new(input, "input.language"));
+    let reparsed = reparse(&tree, edits);
+
+    let mut expected = input.to_string();
+    edits.iter().for_each(|e| e.apply(&mut expected));
+    assert_eq!(reparsed.file().contents(), expected);
+
+    let fresh = parse_file_outcome(ast, reparsed.file(), &ParseOptions::new()).unwrap();
+    assert_eq!(
+        format!("{:?}", reparsed.tree()),
+        format!("{:?}", fresh.tree),
+        "reparsing {input:?} into {expected:?}"
+    );
+    let errors = |errors: &[_]| errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(errors(reparsed.errors()), errors(&fresh.errors));
+    reparsed
+}
+
+const PROGRAM: &str = "let x = 1 + 2 * 3;\nprint x * (y + 4);\nlet zz = x;\n";
+
+#[test]
+fn test_insert() {
+    let ast = grammar();
+    let tree = check(&ast, PROGRAM, &[TextEdit::insert(19, "print 5;\n")]);
+    assert!(tree.errors().is_empty());
+    assert!(tree.reused() > 0);
+
+    //Inside a name, which is parsed without layout
+    check(&ast, PROGRAM, &[TextEdit::insert(43, "z")]);
+    //Extending a left recursive expression
+    check(&ast, PROGRAM, &[TextEdit::insert(17, " + 7")]);
+}
+
+#[test]
+fn test_delete_and_replace() {
+    let ast = grammar();
+    let tree = check(&ast, PROGRAM, &[TextEdit::delete(19..37)]);
+    assert_eq!(
+        tree.file().contents(),
+        "let x = 1 + 2 * 3;\n\nlet zz = x;\n"
+    );
+    assert!(tree.reused() > 0);
+
+    check(&ast, PROGRAM, &[TextEdit::new(8..9, "(1 * 8)")]);
+    check(
+        &ast,
+        PROGRAM,
+        &[TextEdit::new(0..PROGRAM.len(), "print 1;")],
+    );
+}
+
+#[test]
+fn test_multiple_edits() {
+    let ast = grammar();
+    //The second edit is in the contents after the first one was applied
+    check(
+        &ast,
+        PROGRAM,
+        &[
+            TextEdit::insert(0, "print 0;\n"),
+            TextEdit::new(35..36, "yy"),
+        ],
+    );
+    check(
+        &ast,
+        PROGRAM,
+        &[TextEdit::delete(4..5), TextEdit::insert(4, "abc")],
+    );
+    check(&ast, PROGRAM, &[]);
+}
+
+#[test]
+fn test_start_and_end() {
+    let ast = grammar();
+    check(&ast, PROGRAM, &[TextEdit::insert(0, "\n")]);
+    check(
+        &ast,
+        PROGRAM,
+        &[TextEdit::insert(PROGRAM.len(), "print 9;")],
+    );
+    check(
+        &ast,
+        PROGRAM,
+        &[TextEdit::delete(PROGRAM.len() - 1..PROGRAM.len())],
+    );
+    check(&ast, "", &[TextEdit::insert(0, "print 1;")]);
+}
+
+#[test]
+fn test_errors() {
+    let ast = grammar();
+    let broken = check(&ast, PROGRAM, &[TextEdit::delete(17..18)]);
+    assert!(!broken.errors().is_empty());
+
+    //A tree with errors keeps the results before the error, so fixing the error reuses them
+    let semicolon = PROGRAM.len() - 2;
+    let broken = check(&ast, PROGRAM, &[TextEdit::delete(semicolon..semicolon + 1)]);
+    assert!(!broken.errors().is_empty());
+    let fixed = reparse(&broken, &[TextEdit::insert(semicolon, ";")]);
+    assert!(fixed.errors().is_empty());
+    assert!(fixed.reused() > 0);
+    assert_eq!(
+        format!("{:?}", fixed.tree()),
+        format!("{:?}", check(&ast, PROGRAM, &[]).tree())
+    );
+}
+
+#[test]
+fn test_chained_reparses() {
+    let ast = grammar();
+    let mut tree = ParseTree::new(&ast, SourceFile::new(PROGRAM, "input.language"));
+    //Every reparse reuses the memo of the one before it
+    for statement in ["print a;\n", "let b = a + 1;\n", "print (a) * b;\n"] {
+        let end = tree.file().contents().len();
+        tree = reparse(&tree, &[TextEdit::insert(end, statement)]);
+        assert!(tree.reused() > 0);
+    }
+
+    let fresh = parse_file_outcome(&ast, tree.file(), &ParseOptions::new()).unwrap();
+    assert!(tree.errors().is_empty());
+    assert_eq!(format!("{:?}", tree.tree()), format!("{:?}", fresh.tree));
+}
+
+#[test]
+fn test_errors_work() {
+    let ast = grammar();
+    let options = ParseOptions::new().with_stats(true);
+    let input = PROGRAM.repeat(20);
+    let tree = ParseTree::with_options(
+        &ast,
+        SourceFile::new(&input, "input.language"),
+        options.clone(),
+    )
+    .unwrap();
+
+    //The errors are found by a single parse from scratch, which is not repeated when reusing the previous parse fails
+    let broken = reparse(&tree, &[TextEdit::delete(17..18)]);
+    let fresh = parse_file_outcome(&ast, broken.file(), &options).unwrap();
+    assert!(!broken.errors().is_empty());
+    let invocations =
+        |outcome: &ParseOutcome<_>| outcome.stats.as_ref().unwrap().total().invocations;
+    assert!(invocations(broken.outcome()) <= invocations(&fresh));
+}
+
+#[test]
+fn test_decoded_file() {
+    let ast = grammar();
+    // "print 1;\r\nprint 2;" in utf-16le, with a byte order mark
+    let bytes: Vec<u8> = [0xff, 0xfe]
+        .into_iter()
+        .chain(
+            "print 1;\r\nprint 2;"
+                .encode_utf16()
+                .flat_map(u16::to_le_bytes),
+        )
+        .collect();
+    let options = DecodeOptions::new().with_normalized_newlines(true);
+    let file = SourceFile::decode(bytes, "input.language", &options).unwrap();
+    let tree = ParseTree::new(&ast, file);
+
+    let reparsed = reparse(&tree, &[TextEdit::new(15..16, "34")]);
+    assert_eq!(reparsed.file().contents(), "print 1;\nprint 34;");
+    assert_eq!(reparsed.file().encoding(), SourceEncoding::Utf16Le);
+    assert!(reparsed.reused() > 0);
+
+    //Spans are still offsets in the utf-16 input
+    let fresh = parse_file_outcome(&ast, reparsed.file(), &ParseOptions::new()).unwrap();
+    assert!(reparsed.errors().is_empty());
+    assert_eq!(
+        format!("{:?}", reparsed.tree()),
+        format!("{:?}", fresh.tree)
+    );
+    assert_eq!(reparsed.tree().span().position, 2);
+    assert_eq!(reparsed.tree().span().length, 38);
+}
@@ -0,0 +1,51 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::{Budget, CancellationToken, ParseAborted, ParseOptions};
+use rust_lwb::parser::peg::parser_sugar::parse_file_with_options;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+use std::time::Instant;
+
+#[test]
+fn test_parse_limits() {
+    let syntax = r#"
+list = "[" list* "]";
+start at list;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("[[][[]][]]", "input.language");
+
+    let res = parse_file_with_options(&ast, &input, &ParseOptions::new().with_max_steps(10_000));
+    assert!(res.unwrap().1.is_empty());
+
+    let res = parse_file_with_options(&ast, &input, &ParseOptions::new().with_max_steps(10));
+    assert_eq!(
+        res.err(),
+        Some(ParseAborted::BudgetExceeded(Budget::Steps(10)))
+    );
+
+    let res = parse_file_with_options(
+        &ast,
+        &input,
+        &ParseOptions::new().with_deadline(Instant::now()),
+    );
+    assert_eq!(
+        res.err(),
+        Some(ParseAborted::BudgetExceeded(Budget::Deadline))
+    );
+
+    let token = CancellationToken::new();
+    let options = ParseOptions::new().with_cancellation_token(token.clone());
+    assert!(parse_file_with_options(&ast, &input, &options).is_ok());
+    token.cancel();
+    let res = parse_file_with_options(&ast, &input, &options);
+    assert_eq!(res.err(), Some(ParseAborted::Cancelled));
+
+    // recovering from errors reparses the input, which also counts towards the budget
+    let options = ParseOptions::new().with_max_steps(100);
+    assert!(parse_file_with_options(&ast, &input, &options).is_ok());
+    let input = SourceFile::new("[[][[]]]]]]][]", "input.language");
+    assert!(parse_file_with_options(&ast, &input, &options).is_err());
+}
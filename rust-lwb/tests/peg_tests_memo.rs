@@ -0,0 +1,161 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parse_stats::ParseStats;
+use rust_lwb::parser::peg::parser_sugar::{parse_file_outcome, parse_file_with_stats};
+use rust_lwb::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+fn syntax(syntax: &str) -> SyntaxFileAst {
+    let sf = SourceFile::new(syntax, "test.syntax");
+    convert(SyntaxFile::parse(&sf)).unwrap()
+}
+
+#[test]
+fn test_memo_small_sorts() {
+    // both alternatives start with a word, so the word at the start is parsed twice
+    let statement = |annotation: &str| {
+        syntax(&format!(
+            r#"
+stmt:
+    call = word "(" ")";
+    assign = word "=" word;
+word = [a-z]+; {annotation}
+layout = [ ];
+start at stmt;
+    "#
+        ))
+    };
+    let input = SourceFile::new("a = b", "input.language");
+
+    // small sorts are parsed again instead of memoized
+    let ast = statement("");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(errs.is_empty());
+    let word = stats.sort("word");
+    assert_eq!(word.invocations, 3);
+    assert_eq!(word.memo_hits, 0);
+    assert_eq!(word.inserted, 0);
+
+    let ast = statement("{memo: always}");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(errs.is_empty());
+    let word = stats.sort("word");
+    assert_eq!(word.invocations, 3);
+    assert_eq!(word.memo_hits, 1);
+    // a placeholder and the result, at both positions
+    assert_eq!(word.inserted, 4);
+}
+
+#[test]
+fn test_memo_never() {
+    // left recursion is parsed with the cache, so recursive sorts are always memoized
+    let ast = syntax(
+        r#"
+expr: {memo: never}
+    add = expr "+" term;
+    term = term;
+term:
+    call = name "(" expr ")";
+    name = name;
+name = [a-z]+;
+layout = [ ];
+start at expr;
+    "#,
+    );
+    let input = SourceFile::new("a + f(b + c) + d", "input.language");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(errs.is_empty());
+    assert!(stats.sort("expr").inserted > 0);
+    assert!(stats.sort("expr").left_recursion_iterations > 0);
+
+    // a sort that is too large to be parsed again by default
+    let ast = syntax(
+        r#"
+stmt:
+    call = name "(" ")";
+    assign = name "=" name;
+name = [a-z]+ [0-9]* [a-z]* [0-9]* [a-z]* [0-9]* [a-z]*; {memo: never}
+layout = [ ];
+start at stmt;
+    "#,
+    );
+    let input = SourceFile::new("a1 = b2", "input.language");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(errs.is_empty());
+    assert_eq!(stats.sort("name").inserted, 0);
+
+    // the errors are found with every sort memoized
+    let input = SourceFile::new("a1 = (", "input.language");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert_eq!(errs.len(), 1);
+    assert!(stats.sort("name").inserted > 0);
+}
+
+#[test]
+fn test_memo_max_entries() {
+    let ast = syntax(
+        r#"
+list = "[" delimited(value, ",", *) "]";
+value:
+    list = list;
+    number = [0-9]+;
+layout = [ ];
+start at list;
+    "#,
+    );
+
+    let items: Vec<String> = (0..200).map(|i| format!("[{i}, [{i}]]")).collect();
+    let valid = format!("[{}]", items.join(", "));
+    let invalid = format!("[{}, [1 2], 3]", items.join(", "));
+    for input in [valid, invalid] {
+        let input = SourceFile::new(input, "input.language");
+        let options = ParseOptions::new().with_stats(true);
+        let limited = options.clone().with_max_memo_entries(16);
+
+        let outcome = parse_file_outcome(&ast, &input, &options).unwrap();
+        let capped = parse_file_outcome(&ast, &input, &limited).unwrap();
+        let (stats, capped_stats) = (outcome.stats.unwrap(), capped.stats.unwrap());
+        assert_eq!(stats.total().evicted, 0);
+        assert_eq!(capped_stats.total().memo_misses, stats.total().memo_misses);
+
+        // evicting never changes the result
+        assert_eq!(format!("{:?}", outcome.tree), format!("{:?}", capped.tree));
+        assert_eq!(
+            format!("{:?}", outcome.errors),
+            format!("{:?}", capped.errors)
+        );
+    }
+}
+
+#[test]
+fn test_memo_max_entries_backtracking() {
+    // a call fails at the end, after which the same items are parsed again as the indices
+    let ast = syntax(
+        r#"
+expr:
+    call = "f" arguments "(" ")";
+    index = "f" indices "[" "]";
+arguments = item*;
+indices = item*;
+item = [0-9]+; {no-layout, memo: always}
+layout = [ ];
+start at expr;
+    "#,
+    );
+
+    let items: Vec<String> = (0..100).map(|i| i.to_string()).collect();
+    let input = SourceFile::new(format!("f {} []", items.join(" ")), "input.language");
+    let options = ParseOptions::new().with_stats(true);
+    let limited = options.clone().with_max_memo_entries(16);
+
+    let outcome = parse_file_outcome(&ast, &input, &options).unwrap();
+    let capped = parse_file_outcome(&ast, &input, &limited).unwrap();
+    assert!(outcome.errors.is_empty());
+    assert_eq!(format!("{:?}", outcome.tree), format!("{:?}", capped.tree));
+
+    // the items are still needed when the expression backtracks, so they are not evicted and parsed again
+    let parsed = |stats: Option<ParseStats>| stats.unwrap().total().memo_misses;
+    assert_eq!(parsed(capped.stats), parsed(outcome.stats));
+}
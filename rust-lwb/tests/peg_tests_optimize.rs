@@ -0,0 +1,116 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parser_sugar::parse_file_outcome;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+/// Parses every input with and without optimizing the grammar, and checks that the trees and errors are the same.
+fn assert_same_with_optimize(syntax: &str, inputs: &[&str]) {
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    for input in inputs {
+        let input = SourceFile::new(*input, "input.language");
+        for recovery in [true, false] {
+            let options = ParseOptions::new().with_recovery(recovery);
+            let show = |options: &ParseOptions| {
+                let outcome = parse_file_outcome(&ast, &input, options).unwrap();
+                let messages: Vec<String> = outcome.errors.iter().map(|e| e.to_string()).collect();
+                (
+                    format!("{:?}", outcome.tree),
+                    format!("{:?}", outcome.errors),
+                    messages,
+                )
+            };
+            let optimized = show(&options.clone().with_optimize(true));
+            let written = show(&options.with_optimize(false));
+            assert_eq!(optimized, written, "input: {:?}", input.contents());
+        }
+    }
+}
+
+#[test]
+fn test_optimize_json() {
+    let syntax = r#"
+string-char:
+    escaped = "\\" [bfnrt\\/"];
+    normal = [^"\\];
+string = "\"" string-char* "\""; {no-layout}
+digit = [0-9];
+number = "-"? digit+; {no-layout}
+value:
+    true = "true";
+    false = "false";
+    null = "null";
+    string = string;
+    number = number;
+    object = "{" delimited(kv-pair, ",", *) "}";
+    array = "[" delimited(value, ",", *) "]";
+kv-pair = string ":" value;
+start at value;
+layout = [\n\r\t ];
+    "#;
+
+    assert_same_with_optimize(
+        syntax,
+        &[
+            r#"{"a": [1, -2, true, null], "b\"": {}}"#,
+            r#"["é", "→", [], {"x": "ü"}]"#,
+            r#"{"a": [1, -2, tru, null], "b\"": {}}"#,
+            r#"["a", , "b"]"#,
+            r#"{"a" 1}"#,
+            "",
+        ],
+    );
+}
+
+#[test]
+fn test_optimize_passes() {
+    // `word` is inlined, the alternatives of `op` and `letter` are merged into character classes,
+    // and the alternatives of `stmt` and `letter` that start the same are factored.
+    let syntax = r#"
+ident = [^ \n\r\t;=+*$@!%>-]+; {no-layout}
+word = ident;
+op:
+    arrow = "->";
+    plus = "+";
+    minus = "-";
+    times = [*];
+letter:
+    x = "x";
+    y = [y];
+    v-u = "v" "u";
+    v = "v";
+expr:
+    bin = word op expr;
+    letter = "!" letter;
+    word = word;
+stmt:
+    let-eq = "let" word "=" expr ";";
+    let = "let" word ";";
+    bare = "let" ";";
+    tight = "@" word "$"; {no-layout}
+    tight-twice = "@" word "@"; {no-layout}
+    bad = "=="; {error: "unexpected {text}"}
+program = stmt*;
+start at program;
+layout:
+    space = [\n\r\t ];
+    comment = "%" [^\n]*;
+    "#;
+
+    assert_same_with_optimize(
+        syntax,
+        &[
+            "let a = b + c -> d * e; let f; let ;",
+            "let é = !x; let a = !y; let b = !v u; let c = !v;",
+            "@abé$ @ab@ % comment\n let a = b;",
+            "let a = b + ; let f",
+            "let a == b;",
+            "@ab $",
+            "let a = !v v;",
+            "== let a;",
+        ],
+    );
+}
@@ -0,0 +1,233 @@
+use itertools::Itertools;
+use rust_lwb::language::Language;
+use rust_lwb::parser::ast::generate_ast::BasicAstInfo;
+use rust_lwb::parser::peg::parse_options::{Budget, CancellationToken, ErrorOrder, ParseOptions};
+use rust_lwb::parser::peg::parser_compiled::CompiledParser;
+use rust_lwb::parser::peg::parser_sugar::{
+    parse_file_outcome, parse_prefix, parse_prefix_with_options,
+};
+use rust_lwb::parser::syntax_file::ast::Number;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::{ParseError, SyntaxFile};
+use rust_lwb::sources::source_file::SourceFile;
+
+#[test]
+fn test_parse_outcome_recovery() {
+    let syntax = r#"
+program = ("{" ("x"+ ";")* "}")*;
+start at program;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("{x;;x;;x;}", "input.language");
+    let positions = |options: &ParseOptions| {
+        let outcome = parse_file_outcome(&ast, &input, options).unwrap();
+        (
+            outcome.errors.iter().map(|e| e.span.position).collect_vec(),
+            outcome.recovered,
+        )
+    };
+
+    assert_eq!(positions(&ParseOptions::new()), (vec![3, 6], true));
+    assert_eq!(
+        positions(&ParseOptions::new().with_recovery(false)),
+        (vec![3], false)
+    );
+    assert_eq!(
+        positions(&ParseOptions::new().with_max_errors(1)),
+        (vec![3], false)
+    );
+    assert_eq!(
+        positions(&ParseOptions::new().with_max_errors(2)),
+        (vec![3, 6], false)
+    );
+    assert_eq!(
+        positions(&ParseOptions::new().with_error_order(ErrorOrder::Position)),
+        (vec![3, 6], true)
+    );
+
+    let input = SourceFile::new("{x;}", "input.language");
+    let outcome = parse_file_outcome(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(outcome.is_ok());
+    assert!(!outcome.recovered);
+    assert!(outcome.warnings.is_empty());
+    assert!(outcome.stats.is_none());
+
+    let outcome = parse_file_outcome(&ast, &input, &ParseOptions::new().with_stats(true)).unwrap();
+    assert_eq!(outcome.stats.unwrap().sort("program").invocations, 1);
+}
+
+#[test]
+fn test_parse_outcome_starting_sort() {
+    let syntax = r#"
+program = item*;
+item = "x" number;
+number = [0-9]+;
+start at program;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("42", "input.language");
+
+    assert!(!parse_file_outcome(&ast, &input, &ParseOptions::new())
+        .unwrap()
+        .is_ok());
+    let outcome = parse_file_outcome(
+        &ast,
+        &input,
+        &ParseOptions::new().with_starting_sort("number"),
+    )
+    .unwrap();
+    assert!(outcome.is_ok());
+    assert_eq!(outcome.tree.sort, "number");
+}
+
+#[test]
+fn test_parse_outcome_features() {
+    let syntax = r#"
+statement:
+    print = "print" number;
+    await = "await" number; {feature: async}
+    yield = "yield" number; {feature: generators}
+number = [0-9]+;
+layout = [ ];
+start at statement;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let parses = |input: &str, options: &ParseOptions| {
+        let input = SourceFile::new(input, "input.language");
+        let outcome = parse_file_outcome(&ast, &input, options).unwrap();
+        outcome
+            .is_ok()
+            .then(|| outcome.tree.constructor_name.to_string())
+    };
+
+    let options = ParseOptions::new();
+    assert_eq!(parses("print 1", &options), Some("print".to_string()));
+    assert_eq!(parses("await 1", &options), None);
+    assert_eq!(parses("yield 1", &options), None);
+
+    let options = ParseOptions::new().with_feature("generators");
+    assert_eq!(parses("await 1", &options), None);
+    assert_eq!(parses("yield 1", &options), Some("yield".to_string()));
+
+    let options = ParseOptions::new().with_features(["async", "generators"]);
+    assert_eq!(parses("await 1", &options), Some("await".to_string()));
+    assert_eq!(parses("yield 1", &options), Some("yield".to_string()));
+}
+
+#[test]
+fn test_parse_prefix_offset() {
+    let sf = SourceFile::new("é 42 é", "input.syntax");
+
+    let (number, end) = SyntaxFile::parse_prefix::<Number<BasicAstInfo>>("number", &sf, 2).unwrap();
+    assert_eq!(number.1, "42");
+    assert_eq!(end, 5);
+
+    //An offset in the middle of a character is an error, not a panic
+    assert!(matches!(
+        SyntaxFile::parse_prefix::<Number<BasicAstInfo>>("number", &sf, 1),
+        Err(ParseError::InvalidOffset(1))
+    ));
+    assert!(matches!(
+        SyntaxFile::parse_prefix::<Number<BasicAstInfo>>("number", &sf, 9),
+        Err(ParseError::InvalidOffset(9))
+    ));
+}
+
+#[test]
+fn test_parse_prefix_errors() {
+    let syntax = r#"
+list = "[" number* "]";
+number = [0-9]+;
+layout = [ ];
+start at list;
+    "#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let parser = CompiledParser::new(&ast);
+    let input = SourceFile::new("é [1 2 3]", "input");
+
+    let (_, end, errs) = parse_prefix(&ast, "list", &input, 2).unwrap();
+    assert!(errs.is_empty());
+    assert_eq!(end, 10);
+
+    //Unknown sorts and offsets which are not at the start of a character are errors, not panics
+    assert!(matches!(
+        parse_prefix(&ast, "tuple", &input, 2),
+        Err(ParseError::UnknownSort(sort)) if sort == "tuple"
+    ));
+    assert!(matches!(
+        parser.parse_prefix("list", &input, 1),
+        Err(ParseError::InvalidOffset(1))
+    ));
+    assert!(matches!(
+        parser.parse_raw_prefix("list", &input, 11),
+        Err(ParseError::InvalidOffset(11))
+    ));
+
+    //Prefix parses are limited by the options
+    let options = ParseOptions::new().with_max_steps(3);
+    assert!(matches!(
+        parser.parse_prefix_with_options("list", &input, 2, &options),
+        Err(ParseError::BudgetExceeded(Budget::Steps(3)))
+    ));
+    let token = CancellationToken::new();
+    token.cancel();
+    let options = ParseOptions::new().with_cancellation_token(token);
+    assert!(matches!(
+        parse_prefix_with_options(&ast, "number", &input, 3, &options),
+        Err(ParseError::Cancelled)
+    ));
+}
+
+#[test]
+fn test_parse_outcome_error_order() {
+    let syntax = r#"
+program = statement*;
+statement = expr ";";
+expr:
+    add = expr "+" atom;
+    atom = atom;
+atom:
+    number = [0-9]+;
+    group = "(" expr ")";
+layout = " ";
+start at program;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("1+ (", "input.language");
+    let positions = |options: &ParseOptions| {
+        let outcome = parse_file_outcome(&ast, &input, options).unwrap();
+        outcome.errors.iter().map(|e| e.span.position).collect_vec()
+    };
+
+    //Recovering from the error in the group finds the error of the addition before it
+    assert_eq!(positions(&ParseOptions::new()), [4, 3, 4]);
+    assert_eq!(
+        positions(&ParseOptions::new().with_error_order(ErrorOrder::Position)),
+        [3, 4, 4]
+    );
+}
+
+#[test]
+fn test_language_starting_sort() {
+    let sf = SourceFile::new("start at program;", "input.syntax");
+
+    //The AST of a language is the AST of its starting sort
+    assert!(
+        SyntaxFile::parse_outcome(&sf, &ParseOptions::new().with_starting_sort("program"))
+            .unwrap()
+            .is_ok()
+    );
+    assert!(matches!(
+        SyntaxFile::parse_outcome(&sf, &ParseOptions::new().with_starting_sort("number")),
+        Err(ParseError::StartingSort(sort)) if sort == "number"
+    ));
+}
@@ -0,0 +1,160 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parse_trace::Trace;
+use rust_lwb::parser::peg::parser_sugar::{parse_file_with_options, parse_file_with_stats};
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+#[test]
+fn test_parse_stats() {
+    let syntax = r#"
+expr:
+    add = expr "+" num;
+    num = num;
+num = [0-9]+; {memo: always}
+start at expr;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("1+2+3", "input.language");
+
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(errs.is_empty());
+
+    let expr = stats.sort("expr");
+    // the seed is grown to "1+2", "1+2+3", and a last time which fails
+    assert_eq!(expr.left_recursion_iterations, 3);
+    // every iteration reverts the cache entries of the previous one
+    assert!(expr.reverted > 0);
+    assert_eq!(expr.memo_misses, 1);
+    assert!(expr.memo_hits >= 3);
+    assert_eq!(expr.invocations, expr.memo_hits + expr.memo_misses);
+
+    let num = stats.sort("num");
+    assert!(num.invocations >= 3);
+    assert!(num.inserted >= 3);
+
+    assert_eq!(stats.sort("does-not-exist").invocations, 0);
+    assert_eq!(
+        stats.total().invocations,
+        stats.sorts.values().map(|s| s.invocations).sum::<u64>()
+    );
+
+    let table = stats.render_table();
+    assert!(table.starts_with("sort "));
+    assert!(table.lines().any(|l| l.starts_with("expr ")));
+    assert!(table.lines().last().unwrap().starts_with("total "));
+
+    let json = stats.to_json();
+    assert!(json.contains("\"expr\": {"));
+    assert!(json.contains("\"left_recursion_iterations\": 3"));
+
+    // collecting statistics does not change the result
+    let (res, _) = parse_file_with_options(&ast, &input, &ParseOptions::new()).unwrap();
+    let (res_stats, _, _) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert_eq!(format!("{res:?}"), format!("{res_stats:?}"));
+}
+
+#[test]
+fn test_parse_stats_recovery() {
+    let syntax = r#"
+list = "[" list* "]";
+start at list;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    let input = SourceFile::new("[[]]", "input.language");
+    let (_, _, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    let input = SourceFile::new("[[]]]", "input.language");
+    let (_, errs, recovered) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert_eq!(errs.len(), 1);
+
+    // all attempts made while recovering are counted
+    assert!(recovered.sort("list").invocations > stats.sort("list").invocations);
+}
+
+#[test]
+fn test_parse_stats_recovery_work() {
+    let syntax = r#"
+program = statement*;
+statement:
+    let = "let" name "=" expr ";";
+    print = "print" expr ";";
+expr:
+    add = expr "+" atom;
+    atom = atom;
+atom:
+    number = [0-9]+;
+    name = name;
+    paren = "(" expr ")";
+name = [a-z]+; {no-layout}
+layout = [ \n];
+start at program;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    // one broken statement in every 25
+    let program = |statements: usize| {
+        let mut program = String::new();
+        for i in 0..statements {
+            if i % 25 == 12 {
+                program.push_str("let x = (a + $ 3);\n");
+            } else {
+                program.push_str(&format!("let x = (a + {i});\nprint x + y;\n"));
+            }
+        }
+        SourceFile::new(program, "input.language")
+    };
+
+    let options = ParseOptions::new().with_max_errors(usize::MAX);
+    let work = |statements| {
+        let (_, errs, stats) = parse_file_with_stats(&ast, &program(statements), &options).unwrap();
+        (errs.len(), stats.total().invocations)
+    };
+    let (errs_small, small) = work(200);
+    let (errs_large, large) = work(800);
+    assert_eq!(errs_large, 4 * errs_small);
+
+    // recovering continues from the error, so the work per error does not grow with the size of the file
+    assert!(large < 5 * small, "{small} {large}");
+}
+
+#[test]
+fn test_parse_stats_skipped_alternatives() {
+    let syntax = r#"
+value:
+    list = "[" value* "]";
+    string = "\"" [a-z]* "\"";
+    number = num;
+num = [0-9]+;
+layout = [ ];
+start at value;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    // no value starts with a digit, so numbers are never tried
+    let input = SourceFile::new("[\"a\" [\"b\"] ]", "input.language");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(errs.is_empty());
+    assert_eq!(stats.sort("num").invocations, 0);
+
+    // when the parse fails, it is done again to find the errors of the skipped alternatives
+    let input = SourceFile::new("[\"a\" 1 x]", "input.language");
+    let (_, errs, stats) = parse_file_with_stats(&ast, &input, &ParseOptions::new()).unwrap();
+    assert_eq!(errs.len(), 1);
+    assert!(stats.sort("num").invocations > 0);
+    let options = ParseOptions::new()
+        .with_recovery(false)
+        .with_trace(Trace::new());
+    let (_, traced) = parse_file_with_options(&ast, &input, &options).unwrap();
+    assert_eq!(errs[0].to_string(), traced[0].to_string());
+    assert_eq!(errs[0].span.position, traced[0].span.position);
+}
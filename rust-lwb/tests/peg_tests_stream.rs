@@ -0,0 +1,102 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::{CancellationToken, ParseOptions};
+use rust_lwb::parser::peg::parser_stream::{parse_many, parse_many_with_options};
+use rust_lwb::parser::peg::parser_sugar_ast::SyntaxFileAst;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::{ParseError, SyntaxFile};
+use rust_lwb::sources::source_file::SourceFile;
+use rust_lwb::sources::stream::ChunkedInput;
+
+const ITEMS: &str = r#"
+item:
+    list = "[" item* "]";
+    word = [a-z]+; {no-layout}
+layout = [ \n];
+start at item;
+"#;
+
+fn grammar() -> SyntaxFileAst {
+    let sf = SourceFile::new(ITEMS, "test.syntax");
+    convert(SyntaxFile::parse(&sf)).unwrap()
+}
+
+const INPUT: &str = "[abc [de f] [[gh] ijk]]\nlmn\n[o [p q] [r [s t]] uvw]\n";
+
+#[test]
+fn test_chunks_smaller_than_items() {
+    let ast = grammar();
+    for chunk_size in [1, 2, 5, 1024] {
+        let input = ChunkedInput::new(INPUT.as_bytes(), "test.stream").with_chunk_size(chunk_size);
+        let mut items = parse_many(&ast, input);
+        let texts = items
+            .by_ref()
+            .map(|item| {
+                let (pair, errs) = item.unwrap();
+                assert!(errs.is_empty(), "{errs:?}");
+                pair.span().as_str().to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            texts,
+            ["[abc [de f] [[gh] ijk]]", "lmn", "[o [p q] [r [s t]] uvw]"],
+            "chunk size {chunk_size}"
+        );
+        assert!(items.input().is_complete());
+
+        //The parts of an item that were parsed before more input was read are not parsed again
+        if chunk_size < 10 {
+            assert!(items.reused() > 0, "chunk size {chunk_size}");
+        }
+    }
+}
+
+#[test]
+fn test_chunks_error() {
+    let ast = grammar();
+    let input = ChunkedInput::new("[a b]\n[c [d] e".as_bytes(), "test.stream").with_chunk_size(2);
+    let mut items = parse_many(&ast, input);
+
+    let (first, errs) = items.next().unwrap().unwrap();
+    assert_eq!(first.span().as_str(), "[a b]");
+    assert!(errs.is_empty());
+
+    //The error is the same as when the item is parsed at once
+    let (_, errs) = items.next().unwrap().unwrap();
+    assert!(items.next().is_none());
+    let input = ChunkedInput::new("[c [d] e".as_bytes(), "test.stream");
+    let (_, expected) = parse_many(&ast, input).next().unwrap().unwrap();
+    assert_eq!(
+        errs.iter().map(ToString::to_string).collect::<Vec<_>>(),
+        expected.iter().map(ToString::to_string).collect::<Vec<_>>()
+    );
+    assert!(!errs.is_empty());
+}
+
+#[test]
+fn test_chunks_options() {
+    let syntax = r#"
+list = "[" word* "]";
+word = [a-z]+; {no-layout}
+layout = [ \n];
+start at list;
+"#;
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let words = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    //Only words, which are not cut off at the end of a chunk
+    let options = ParseOptions::new().with_starting_sort("word");
+    let input = ChunkedInput::new("abc de\nf".as_bytes(), "test.stream").with_chunk_size(1);
+    let texts = parse_many_with_options(&words, input, &options)
+        .map(|item| item.unwrap().0.span().as_str().to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(texts, ["abc", "de", "f"]);
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let options = ParseOptions::new().with_cancellation_token(token);
+    let ast = grammar();
+    let input = ChunkedInput::new(INPUT.as_bytes(), "test.stream");
+    let mut items = parse_many_with_options(&ast, input, &options);
+    assert!(matches!(items.next(), Some(Err(ParseError::Cancelled))));
+    assert!(items.next().is_none());
+}
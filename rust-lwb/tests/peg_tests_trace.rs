@@ -0,0 +1,146 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parse_trace::{Trace, TraceEvent, TraceTree};
+use rust_lwb::parser::peg::parser_sugar::parse_file_with_options;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+#[test]
+fn test_parse_trace() {
+    let syntax = r#"
+expr:
+    add = expr "+" num;
+    num = num;
+num = [0-9]+;
+layout = [ ];
+start at expr;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("1 + 2+3", "input.language");
+
+    let trace = Trace::new();
+    let options = ParseOptions::new().with_trace(trace.clone());
+    let (_, errs) = parse_file_with_options(&ast, &input, &options).unwrap();
+    assert!(errs.is_empty());
+
+    let events = trace.events();
+    assert_eq!(
+        events[0],
+        TraceEvent::Enter {
+            sort: "expr".to_string(),
+            position: 0
+        }
+    );
+    assert!(events.contains(&TraceEvent::SeedGrown {
+        sort: "expr".to_string(),
+        position: 0,
+        end: 5
+    }));
+    assert!(events.contains(&TraceEvent::Layout {
+        position: 1,
+        end: 2
+    }));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, TraceEvent::MemoHit { sort, .. } if sort == "expr")));
+
+    // every sort that is entered is exited again
+    let enters = events
+        .iter()
+        .filter(|e| matches!(e, TraceEvent::Enter { .. }))
+        .count();
+    let exits = events
+        .iter()
+        .filter(|e| matches!(e, TraceEvent::Exit { .. }))
+        .count();
+    assert_eq!(enters, exits);
+    let tree = trace.tree();
+    let TraceTree::Sort { exit, children } = &tree[0] else {
+        panic!("the starting sort should be traced first");
+    };
+    assert_eq!(
+        exit,
+        &TraceEvent::Exit {
+            sort: "expr".to_string(),
+            position: 0,
+            end: 7,
+            ok: true
+        }
+    );
+    assert!(!children.is_empty());
+
+    let terminal = trace.render_terminal(&input);
+    assert!(terminal.starts_with("ok expr 0..7 \"1 + 2+3\"\n"));
+    assert!(terminal.contains("  ok num 0..1 \"1\"\n"));
+
+    let html = trace.render_html(&input);
+    assert!(html.contains("<summary class=\"ok\">ok expr 0..7 <code>1 + 2+3</code></summary>"));
+}
+
+#[test]
+fn test_parse_trace_recovery() {
+    let syntax = r#"
+list = "[" list* "]";
+start at list;
+    "#;
+
+    let sf = SourceFile::new(syntax, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("[[]]]", "input.language");
+
+    let trace = Trace::new();
+    let options = ParseOptions::new().with_trace(trace.clone());
+    let (_, errs) = parse_file_with_options(&ast, &input, &options).unwrap();
+    assert_eq!(errs.len(), 1);
+
+    let events = trace.events();
+    assert!(events.contains(&TraceEvent::Retry {
+        position: 4,
+        skip: 0
+    }));
+    assert!(events.contains(&TraceEvent::Retry {
+        position: 4,
+        skip: 1
+    }));
+    assert!(events.contains(&TraceEvent::Expected {
+        position: 2,
+        expected: "'['".to_string()
+    }));
+    assert!(trace
+        .render_terminal(&input)
+        .contains("  failed list 3..3\n    expected '[' at 3\n"));
+}
+
+#[test]
+fn test_parse_trace_display_name() {
+    let trace = |annotation: &str| {
+        let syntax = format!(
+            r#"
+list = "[" atom* "]";
+atom: {annotation}
+    number = [0-9]+;
+    word = [a-z]+;
+layout = " ";
+start at list;
+    "#
+        );
+        let sf = SourceFile::new(syntax, "test.syntax");
+        let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+        let input = SourceFile::new("[ 1 ab 23 c ]", "input.language");
+
+        let trace = Trace::new();
+        let options = ParseOptions::new().with_trace(trace.clone());
+        let (_, errs) = parse_file_with_options(&ast, &input, &options).unwrap();
+        assert!(errs.is_empty());
+        trace.events()
+    };
+
+    // a display name only changes the errors, a parse without errors does the same work
+    assert_eq!(
+        trace("{display-name: \"an atom\", memo: always}"),
+        trace("{memo: always}")
+    );
+}
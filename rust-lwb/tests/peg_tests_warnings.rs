@@ -0,0 +1,65 @@
+use itertools::Itertools;
+use miette::{Diagnostic, Severity};
+use rust_lwb::language::Language;
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parser_sugar::parse_file_outcome;
+use rust_lwb::parser::syntax_file::convert_syntax_file_ast::convert;
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+
+const FUNCTIONS: &str = r#"
+program = item*;
+item:
+    fn = "fn" name ";";
+    function = "function" name ";"; {deprecated: "use `fn` instead"}
+    global = "global" name ";"; {warning: "globals make programs hard to follow"}
+    global-fn = "global" "fn" name ";";
+name = [a-z]+; {no-layout}
+layout = [ ];
+start at program;
+"#;
+
+#[test]
+fn test_warnings() {
+    let sf = SourceFile::new(FUNCTIONS, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+    let input = SourceFile::new("fn a; function b; global c; function d;", "input.language");
+
+    let outcome = parse_file_outcome(&ast, &input, &ParseOptions::new()).unwrap();
+    // warnings don't make the parse fail
+    assert!(outcome.is_ok());
+    assert_eq!(
+        outcome
+            .warnings
+            .iter()
+            .map(|w| (w.span.position, w.constructor.as_str(), w.deprecated))
+            .collect_vec(),
+        vec![
+            (6, "function", true),
+            (18, "global", false),
+            (28, "function", true)
+        ]
+    );
+
+    let deprecated = &outcome.warnings[0];
+    assert_eq!(deprecated.span.as_str(), "function b;");
+    assert_eq!(deprecated.to_string(), "`function` item is deprecated");
+    assert_eq!(deprecated.severity(), Some(Severity::Warning));
+    assert_eq!(deprecated.help().unwrap().to_string(), "use `fn` instead");
+
+    let warning = &outcome.warnings[1];
+    assert_eq!(warning.to_string(), "globals make programs hard to follow");
+    assert!(warning.help().is_none());
+}
+
+#[test]
+fn test_warnings_backtracking() {
+    let sf = SourceFile::new(FUNCTIONS, "test.syntax");
+    let ast = convert(SyntaxFile::parse(&sf)).unwrap();
+
+    // `global` is tried first, but the input is parsed as `global-fn`, which has no warning
+    let input = SourceFile::new("global fn a;", "input.language");
+    let outcome = parse_file_outcome(&ast, &input, &ParseOptions::new()).unwrap();
+    assert!(outcome.is_ok());
+    assert!(outcome.warnings.is_empty());
+}
@@ -0,0 +1,119 @@
+use rust_lwb::language::Language;
+use rust_lwb::parser::ast::generate_ast::{generate_ast, BasicAstNode};
+use rust_lwb::parser::peg::parse_options::ParseOptions;
+use rust_lwb::parser::peg::parser_sugar::parse_file_outcome;
+use rust_lwb::parser::syntax_file::{load_parser, parse_language_outcome};
+use rust_lwb::sources::source_file::SourceFile;
+use std::fmt::Debug;
+
+mod items {
+    use rust_lwb::language;
+
+    pub mod grammar {
+        use rust_lwb_macros::generate;
+
+        generate!(
+            r#"
+program = item*;
+item:
+    let = "let" name "=" expr end;
+    var = "var" name "=" expr end; {deprecated: "use `let` instead"}
+    list = "[" delimited(expr, ",", *, trailing) "]" end;
+expr:
+    add = expr "+" atom;
+    atom;
+atom: {part-of: expr}
+    hex = "0x" [0-9a-f]+; {no-layout, warning: "write numbers in decimal"}
+    number = [0-9]+; {no-layout}
+    name;
+    paren = "(" expr ")";
+name = [a-z]+; {no-layout}
+end: {hidden}
+    semicolon = ";";
+    newline = "\n";
+layout = [ ];
+start at program;
+"#,
+            false,
+            false
+        );
+    }
+
+    language!(pub Items at mod grammar);
+}
+
+use items::Items;
+
+/// Checks that the AST built from the result of the parser is the same as the one built from the resugared tree,
+/// and that both give the same warnings.
+fn check<L: Language>(parser: &[u8], input: &str)
+where
+    L::Ast: BasicAstNode + Debug,
+{
+    let sf = SourceFile::new(input, "input.language");
+    let options = ParseOptions::new();
+    let raw = parse_language_outcome::<L::Ast>(&sf, parser, &options).unwrap();
+    assert!(raw.is_ok(), "{input}: {:?}", raw.errors);
+
+    let ast = load_parser(parser).unwrap();
+    let sugared = parse_file_outcome(&ast, &sf, &options).unwrap();
+    assert_eq!(
+        format!("{:?}", raw.tree.unwrap()),
+        format!("{:?}", generate_ast::<L::Ast>(&sugared.tree)),
+        "{input}"
+    );
+    assert_eq!(
+        format!("{:?}", raw.warnings),
+        format!("{:?}", sugared.warnings),
+        "{input}"
+    );
+}
+
+#[test]
+fn test_raw_ast_merged_sorts() {
+    for input in [
+        "let a = 1;",
+        "let a = b + (c + 0x2)\n",
+        "let a = ((1)) + 2 + 3;let b = a\n",
+    ] {
+        check::<Items>(items::grammar::PARSER, input);
+    }
+}
+
+#[test]
+fn test_raw_ast_delimited() {
+    for input in [
+        "[];",
+        "[,];",
+        "[1];",
+        "[1,];",
+        "[1, a + 2, 0x3];",
+        "[1, a + 2, 0x3,]\n",
+    ] {
+        check::<Items>(items::grammar::PARSER, input);
+    }
+}
+
+#[test]
+fn test_raw_ast_warnings() {
+    let input = "var a = 0x1;[0x2, 3, 0x4,];var b = c\n";
+    check::<Items>(items::grammar::PARSER, input);
+
+    let sf = SourceFile::new(input, "input.language");
+    let outcome = Items::parse_outcome(&sf, &ParseOptions::new()).unwrap();
+    let warnings = outcome
+        .warnings
+        .iter()
+        .map(|w| (w.span.as_str(), w.constructor.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        warnings,
+        vec![
+            ("var a = 0x1;", "var"),
+            ("0x1", "hex"),
+            ("0x2", "hex"),
+            ("0x4", "hex"),
+            ("var b = c\n", "var"),
+        ]
+    );
+}
@@ -0,0 +1,196 @@
+use rust_lwb::bincode;
+use rust_lwb::diagnostics::{DiagnosticFormat, Diagnostics, MultiFileDiagnostic};
+use rust_lwb::language::Language;
+use rust_lwb::parser::ast::{AstNode, SpannedAstInfo};
+use rust_lwb::parser::syntax_file::SyntaxFile;
+use rust_lwb::sources::source_file::SourceFile;
+use rust_lwb::sources::source_map::{SourceMap, SourceMapError};
+use rust_lwb::sources::span::Span;
+use std::path::PathBuf;
+
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("lwb-source-map-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(name);
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn test_load_on_demand() {
+    let path = temp_file("on_demand.txt", "on disk");
+    let map = SourceMap::new();
+
+    let id = map.id(&path);
+    assert_eq!(map.id(&path), id);
+    assert_eq!(map.lookup(&path), Some(id));
+    assert_eq!(map.path(id), Some(path.clone()));
+
+    let file = map.file(id).unwrap();
+    assert_eq!(file.contents(), "on disk");
+    assert_eq!(file.id(), Some(id));
+    // the file is only read once
+    assert!(file.same_file(&map.load(&path).unwrap()));
+
+    std::fs::write(&path, "changed").unwrap();
+    assert_eq!(map.file(id).unwrap().contents(), "on disk");
+    map.reload(id).unwrap();
+    assert_eq!(map.file(id).unwrap().contents(), "changed");
+}
+
+#[test]
+fn test_overlay() {
+    let path = temp_file("overlay.txt", "saved");
+    let map = SourceMap::new();
+    let id = map.id(&path);
+    assert_eq!(map.file(id).unwrap().contents(), "saved");
+
+    assert_eq!(map.set_overlay(&path, "unsaved"), id);
+    assert_eq!(map.file(id).unwrap().contents(), "unsaved");
+    // reloading does not throw away the editor buffer
+    map.reload(id).unwrap();
+    assert_eq!(map.file(id).unwrap().contents(), "unsaved");
+
+    map.remove_overlay(&path);
+    assert_eq!(map.file(id).unwrap().contents(), "saved");
+    assert_eq!(map.len(), 1);
+}
+
+#[test]
+fn test_errors() {
+    let map = SourceMap::new();
+    let missing = map.id("this/file/does/not/exist");
+    assert!(matches!(map.file(missing), Err(SourceMapError::Io { .. })));
+
+    let other = SourceMap::new();
+    other.set_overlay("a", "");
+    let foreign = other.set_overlay("b", "");
+    assert!(matches!(
+        map.file(foreign),
+        Err(SourceMapError::UnknownFile(_))
+    ));
+
+    let span = Span::from_length(&SourceFile::new_for_test("abc"), 0, 1);
+    assert!(matches!(map.resolve(&span), Err(SourceMapError::NoFileId)));
+}
+
+#[test]
+fn test_resolve_out_of_bounds() {
+    let map = SourceMap::new();
+    let id = map.set_overlay("a", "abcdef");
+    let old = map.file(id).unwrap();
+    map.set_overlay("a", "aé");
+
+    assert_eq!(
+        map.resolve(&Span::from_length(&old, 1, 2))
+            .unwrap()
+            .as_str(),
+        "é"
+    );
+    // past the end of the new version, or in the middle of a character
+    for (position, length) in [(2, 2), (4, 1), (2, 0), (usize::MAX, 1)] {
+        assert!(matches!(
+            map.resolve(&Span::from_length(&old, position, length)),
+            Err(SourceMapError::OutOfBounds { .. })
+        ));
+    }
+}
+
+#[test]
+fn test_resolve_deserialized_ast() {
+    let map = SourceMap::new();
+    let id = map.set_overlay("test.syntax", "a = \"a\";\nstart at a;\n");
+    let ast = SyntaxFile::parse(&map.file(id).unwrap());
+
+    let bytes = bincode::serialize(&ast).unwrap();
+    let deserialized: <SyntaxFile as Language>::Ast = bincode::deserialize(&bytes).unwrap();
+    let span = deserialized.ast_info().span();
+    assert!(span.source.is_detached());
+    assert_eq!(span.source.id(), Some(id));
+
+    let resolved = map.resolve(span).unwrap();
+    assert!(!resolved.source.is_detached());
+    assert_eq!(resolved.as_str(), ast.ast_info().span().as_str());
+}
+
+#[test]
+#[should_panic(expected = "can't merge spans")]
+fn test_merge_different_files() {
+    let map = SourceMap::new();
+    let a = map.file(map.set_overlay("a", "abc")).unwrap();
+    let b = map.file(map.set_overlay("b", "abc")).unwrap();
+    Span::from_length(&a, 0, 1).merge(&Span::from_length(&b, 1, 1));
+}
+
+#[test]
+fn test_merge_different_maps() {
+    let a = SourceMap::new();
+    let b = SourceMap::new();
+    let a = a.file(a.set_overlay("a", "abc")).unwrap();
+    let b = b.file(b.set_overlay("a", "abc")).unwrap();
+
+    // the files have the same id, but in different source maps
+    assert_eq!(a.id(), b.id());
+    assert!(!a.same_file(&b));
+    assert!(Span::from_length(&a, 0, 1)
+        .try_merge(&Span::from_length(&b, 1, 1))
+        .is_none());
+}
+
+#[test]
+fn test_merge_versions_of_file() {
+    let map = SourceMap::new();
+    let id = map.set_overlay("a", "abc");
+    let old = map.file(id).unwrap();
+    map.set_overlay("a", "abcd");
+    let new = map.file(id).unwrap();
+
+    assert!(old.same_file(&new));
+    assert_eq!(
+        Span::from_length(&new, 2, 2)
+            .merge(&Span::from_length(&old, 0, 1))
+            .as_str(),
+        "abcd"
+    );
+}
+
+#[test]
+fn test_multi_file_diagnostic() {
+    let map = SourceMap::new();
+    let a = map
+        .file(map.set_overlay("a.lang", "fn f() {}\nfn g() {}"))
+        .unwrap();
+    let b = map.file(map.set_overlay("b.lang", "fn f() {}")).unwrap();
+
+    let diagnostic = MultiFileDiagnostic::error("`f` is defined twice")
+        .with_label(Span::from_length(&b, 3, 1), "defined again here")
+        .with_label(Span::from_length(&a, 3, 1), "first defined here")
+        .with_label(Span::from_length(&a, 13, 1), "not this one")
+        .with_help("rename one of them");
+    assert_eq!(diagnostic.files().len(), 2);
+    assert_eq!(diagnostic.files()[1].spans().len(), 2);
+
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.push(&diagnostic);
+    let record = &diagnostics.records[0];
+    assert_eq!(record.file.as_deref(), Some("b.lang"));
+    let files: Vec<_> = record.labels.iter().map(|l| l.file.as_str()).collect();
+    assert_eq!(files, ["b.lang", "a.lang", "a.lang"]);
+    assert_eq!(record.labels[2].range.start_line, 2);
+
+    let rendered = diagnostics.render(DiagnosticFormat::Human);
+    assert!(rendered.contains("defined again here"));
+    assert!(rendered.contains("first defined here"));
+
+    let sarif: serde_json::Value =
+        serde_json::from_str(&diagnostics.render(DiagnosticFormat::Sarif)).unwrap();
+    let result = &sarif["runs"][0]["results"][0];
+    assert_eq!(
+        result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "b.lang"
+    );
+    assert_eq!(
+        result["relatedLocations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "a.lang"
+    );
+}